@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BodyPartLayers } from "./BodyPartLayers";
+import type { BodyPartRelativeSize } from "./BodyPartRelativeSize";
+import type { RawMetadata } from "./RawMetadata";
+import type { TemplateUse } from "./TemplateUse";
+
+export interface BodyDetailPlan {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  materials: Array<TemplateUse>;
+  tissues: Array<TemplateUse>;
+  layers: Array<BodyPartLayers>;
+  relativeSizes: Array<BodyPartRelativeSize>;
+  positions: Array<string>;
+  relations: Array<string>;
+}
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BodyDetailPlanToken =
+  | "AddMaterial"
+  | "AddTissue"
+  | "Layers"
+  | "LayersOver"
+  | "LayersUnder"
+  | "Position"
+  | "Relation"
+  | "RelativeSize"
+  | "Unknown";
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BodyPartToken } from "./BodyPartToken";
+import type { SingPlurName } from "./SingPlurName";
+
+export interface BodyPart {
+  identifier: string;
+  name: SingPlurName;
+  connection: string;
+  connectionCategory: string;
+  connectionType?: BodyPartToken;
+  category: string;
+  relativeSize: number;
+  number: number;
+  individualNames: Array<SingPlurName>;
+  tags: Array<BodyPartToken>;
+}
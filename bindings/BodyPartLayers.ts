@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BodyPartSelector } from "./BodyPartSelector";
+import type { LayerPlacement } from "./LayerPlacement";
+import type { TissueLayer } from "./TissueLayer";
+
+export interface BodyPartLayers {
+  selector: BodyPartSelector;
+  placement: LayerPlacement;
+  layers: Array<TissueLayer>;
+  position: string;
+}
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BodyPartToken } from "./BodyPartToken";
+import type { SingPlurName } from "./SingPlurName";
+import type { TissueLayer } from "./TissueLayer";
+
+export interface BodyPartNode {
+  identifier: string;
+  name: SingPlurName;
+  category: string;
+  relativeSize: number;
+  number: number;
+  tags: Array<BodyPartToken>;
+  layers: Array<TissueLayer>;
+  children: Array<BodyPartNode>;
+}
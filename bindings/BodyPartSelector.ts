@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BodyPartSelectorKind } from "./BodyPartSelectorKind";
+
+export interface BodyPartSelector {
+  by: BodyPartSelectorKind;
+  value: string;
+}
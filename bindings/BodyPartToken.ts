@@ -0,0 +1,45 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BodyPartToken =
+  | "BodyPart"
+  | "Connection"
+  | "ConnectionCategory"
+  | "ConnectionType"
+  | "Category"
+  | "DefaultRelativeSize"
+  | "Number"
+  | "IndividualName"
+  | "BodyGloss"
+  | "Aperture"
+  | "Breathe"
+  | "Circulation"
+  | "Connector"
+  | "Digit"
+  | "Embedded"
+  | "Flier"
+  | "Geldable"
+  | "Grasp"
+  | "Guts"
+  | "Head"
+  | "Hear"
+  | "Internal"
+  | "Joint"
+  | "Left"
+  | "Limb"
+  | "LowerBody"
+  | "Mouth"
+  | "Nervous"
+  | "PreventsParentCollapse"
+  | "Right"
+  | "Skeleton"
+  | "Sight"
+  | "Small"
+  | "Smell"
+  | "Socket"
+  | "Stance"
+  | "Thought"
+  | "Totemable"
+  | "UnderPressure"
+  | "UpperBody"
+  | "VerminButcherItem"
+  | "Unknown";
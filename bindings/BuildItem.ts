@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BuildItemToken } from "./BuildItemToken";
+import type { Material } from "./Material";
+import type { MaterialUsage } from "./MaterialUsage";
+
+export interface BuildItem {
+  quantity: number;
+  itemType: string;
+  itemSubtype: string;
+  material?: Material;
+  materialUsage: Array<MaterialUsage>;
+  tags: Array<BuildItemToken>;
+}
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BuildItem } from "./BuildItem";
+import type { BuildingStage } from "./BuildingStage";
+import type { BuildingToken } from "./BuildingToken";
+import type { Color } from "./Color";
+import type { ObjectType } from "./ObjectType";
+import type { RawMetadata } from "./RawMetadata";
+
+export interface Building {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  buildingType: ObjectType;
+  name: string;
+  nameColor: Color;
+  dimensions: Array<number>;
+  workLocation: Array<number>;
+  buildLabors: Array<string>;
+  buildKey: string;
+  buildStages: number;
+  blocks: Array<Array<boolean>>;
+  stages: Array<BuildingStage>;
+  buildItems: Array<BuildItem>;
+  graphics: Array<string>;
+  tags: Array<BuildingToken>;
+}
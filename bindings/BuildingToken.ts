@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BuildingToken =
+  | "Name"
+  | "NameColor"
+  | "Dim"
+  | "WorkLocation"
+  | "BuildLabor"
+  | "BuildKey"
+  | "BuildStages"
+  | "Block"
+  | "Tile"
+  | "Color"
+  | "BuildItem"
+  | "NeedsMagma"
+  | "Unknown";
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CVTag =
+  | "NewTag"
+  | "AddTag"
+  | "RemoveTag"
+  | "ConvertTag"
+  | "ConvertTagMaster"
+  | "ConvertTagTarget"
+  | "ConvertTagReplacement"
+  | "ConditionalNewTag"
+  | "ConditionalAddTag"
+  | "ConditionalRemoveTag"
+  | "ConditionalConvertTag"
+  | "Unknown";
@@ -1,10 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BodyPartLayers } from "./BodyPartLayers";
 import type { BodySize } from "./BodySize";
 import type { CasteTag } from "./CasteTag";
+import type { CreatureInteraction } from "./CreatureInteraction";
 import type { Milkable } from "./Milkable";
 import type { Name } from "./Name";
 import type { SingPlurName } from "./SingPlurName";
 import type { Tile } from "./Tile";
+import type { TissueLayerColorModifier } from "./TissueLayerColorModifier";
 
 export interface Caste {
   identifier: string;
@@ -30,4 +33,10 @@ export interface Caste {
   bodySize: Array<BodySize>;
   milkable: Milkable;
   tile: Tile;
+  body: Array<string>;
+  bodyDetailPlans: Array<string>;
+  tissueLayers: Array<BodyPartLayers>;
+  interactions: Array<CreatureInteraction>;
+  tissueLayerColorModifiers: Array<TissueLayerColorModifier>;
+  rawTokens: Array<[string, string]>;
 }
@@ -135,4 +135,13 @@ export type CasteTag =
   | "CasteGlowTile"
   | "CasteGlowColor"
   | "ChangeBodySizePercent"
+  | "Body"
+  | "BodyDetailPlan"
+  | "TissueLayer"
+  | "CanDoInteraction"
+  | "CanDoInteractionProperty"
+  | "SetTissueLayerGroup"
+  | "PlusTissueLayerGroup"
+  | "TissueLayerColorModifier"
+  | "TissueLayerColorModifierNoun"
   | "Unknown";
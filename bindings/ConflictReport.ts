@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DeclaredConflict } from "./DeclaredConflict";
+import type { DuplicateObject } from "./DuplicateObject";
+import type { MissingRequirement } from "./MissingRequirement";
+import type { OverlappingPatch } from "./OverlappingPatch";
+
+export interface ConflictReport {
+  declaredConflicts: Array<DeclaredConflict>;
+  missingRequirements: Array<MissingRequirement>;
+  duplicateObjects: Array<DuplicateObject>;
+  overlappingPatches: Array<OverlappingPatch>;
+}
@@ -2,10 +2,12 @@
 import type { Biome } from "./Biome";
 import type { Caste } from "./Caste";
 import type { CreatureTag } from "./CreatureTag";
+import type { Material } from "./Material";
 import type { Name } from "./Name";
 import type { RawMetadata } from "./RawMetadata";
 import type { SelectCreature } from "./SelectCreature";
 import type { SingPlurName } from "./SingPlurName";
+import type { TemplateUse } from "./TemplateUse";
 import type { Tile } from "./Tile";
 
 export interface Creature {
@@ -25,6 +27,10 @@ export interface Creature {
   name: Name;
   copyTagsFrom: string;
   applyCreatureVariation: Array<string>;
+  tagsAfterCreatureVariation?: Array<[string, string]>;
   objectId: string;
   selectCreatureVariation: Array<SelectCreature>;
+  materials: Array<Material>;
+  tissues: Array<TemplateUse>;
+  rawTokens: Array<[string, string]>;
 }
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CreatureInteractionToken } from "./CreatureInteractionToken";
+
+export interface CreatureInteraction {
+  interaction: string;
+  interactionObjectId: string;
+  adventureName: string;
+  usageHints: Array<string>;
+  locationHints: Array<string>;
+  bodyPartsRequired: Array<string>;
+  material: string;
+  verb: string;
+  targetVerb: string;
+  targets: Array<[CreatureInteractionToken, string]>;
+  waitPeriod: number;
+  freeAction: boolean;
+}
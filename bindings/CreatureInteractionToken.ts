@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreatureInteractionToken =
+  | "AdventureName"
+  | "UsageHint"
+  | "LocationHint"
+  | "BodyPartRequired"
+  | "Material"
+  | "Verb"
+  | "TargetVerb"
+  | "Target"
+  | "TargetRange"
+  | "MaxTargetNumber"
+  | "WaitPeriod"
+  | "FreeAction"
+  | "Unknown";
@@ -30,6 +30,9 @@ export type CreatureTag =
   | "PopulationNumber"
   | "CopyTagsFrom"
   | "ApplyCreatureVariation"
+  | "ApplyCurrentCreatureVariation"
+  | "UseMaterialTemplate"
+  | "UseTissueTemplate"
   | "CreatureTile"
   | "AltTile"
   | "Color"
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CVTag } from "./CVTag";
+
+export interface CreatureVariationRule {
+  ruleType: CVTag;
+  tag: string;
+  value: string;
+  target: string;
+  replacement: string;
+  argumentIndex: number;
+  argumentRequirement: string;
+}
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ObjectType } from "./ObjectType";
+import type { RawMetadata } from "./RawMetadata";
+
+export interface CutObject {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  objectType: ObjectType;
+}
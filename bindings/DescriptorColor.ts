@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RawMetadata } from "./RawMetadata";
+import type { Rgb } from "./Rgb";
+
+export interface DescriptorColor {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  name: string;
+  word: string;
+  rgb: Rgb;
+  hex: string;
+}
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DescriptorShapeToken } from "./DescriptorShapeToken";
+import type { RawMetadata } from "./RawMetadata";
+import type { SingPlurName } from "./SingPlurName";
+
+export interface DescriptorShape {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  name: SingPlurName;
+  adjectives: Array<string>;
+  words: Array<string>;
+  tile: string;
+  categories: Array<string>;
+  tags: Array<DescriptorShapeToken>;
+}
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DiagnosticSeverity } from "./DiagnosticSeverity";
+
+export interface Diagnostic {
+  severity: DiagnosticSeverity;
+  message: string;
+  filePath: string;
+  lineNumber: number;
+  objectIdentifier: string;
+  rawToken: string;
+}
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RawModuleLocation } from "./RawModuleLocation";
+
+export interface DroppedModule {
+  identifier: string;
+  numericVersion: number;
+  displayedVersion: string;
+  location: RawModuleLocation;
+  parentDirectory: string;
+  keptNumericVersion: number;
+}
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ObjectSource } from "./ObjectSource";
+import type { ObjectType } from "./ObjectType";
+
+export interface DuplicateObject {
+  objectType: ObjectType;
+  identifier: string;
+  definitions: Array<ObjectSource>;
+}
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InteractionEffect } from "./InteractionEffect";
+import type { InteractionSource } from "./InteractionSource";
+import type { InteractionTarget } from "./InteractionTarget";
+import type { RawMetadata } from "./RawMetadata";
+
+export interface Interaction {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  sources: Array<InteractionSource>;
+  targets: Array<InteractionTarget>;
+  effects: Array<InteractionEffect>;
+}
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Syndrome } from "./Syndrome";
+
+export interface InteractionEffect {
+  effectType: string;
+  targets: Array<string>;
+  immediate: boolean;
+  intermittent: string;
+  arenaName: string;
+  locations: Array<string>;
+  item: string;
+  propelForce: string;
+  syndromes: Array<Syndrome>;
+}
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InteractionToken } from "./InteractionToken";
+
+export interface InteractionSource {
+  sourceType: string;
+  name: string;
+  spheres: Array<string>;
+  secretGoals: Array<string>;
+  secrets: Array<string>;
+  regions: Array<string>;
+  frequency: number;
+  strings: Array<[InteractionToken, string]>;
+}
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface InteractionTarget {
+  identifier: string;
+  targetType: string;
+  locations: Array<string>;
+  materials: Array<string>;
+  affectedClasses: Array<string>;
+  immuneClasses: Array<string>;
+  affectedCreatures: Array<[string, string]>;
+  immuneCreatures: Array<[string, string]>;
+  requires: Array<string>;
+  forbidden: Array<string>;
+  forbiddenSyndromeClasses: Array<string>;
+  cannotTargetIfAlreadyAffected: boolean;
+  manualInput: string;
+}
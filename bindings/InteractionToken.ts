@@ -0,0 +1,36 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type InteractionToken =
+  | "Source"
+  | "Target"
+  | "Effect"
+  | "SourceName"
+  | "SourceSphere"
+  | "SourceSecretGoal"
+  | "SourceSecret"
+  | "SourceRegion"
+  | "SourceFrequency"
+  | "SourceHistString1"
+  | "SourceHistString2"
+  | "SourceTriggerString"
+  | "SourceTriggerStringSecond"
+  | "SourceTriggerStringThird"
+  | "TargetLocation"
+  | "TargetMaterial"
+  | "TargetAffectedCreature"
+  | "TargetAffectedClass"
+  | "TargetImmuneCreature"
+  | "TargetImmuneClass"
+  | "TargetRequires"
+  | "TargetForbidden"
+  | "TargetCannotHaveSyndromeClass"
+  | "TargetCannotTargetIfAlreadyAffected"
+  | "TargetManualInput"
+  | "EffectTarget"
+  | "EffectImmediate"
+  | "EffectIntermittent"
+  | "EffectArenaName"
+  | "EffectLocation"
+  | "EffectItem"
+  | "EffectPropelForce"
+  | "Unknown";
@@ -0,0 +1,45 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ArmorLayer } from "./ArmorLayer";
+import type { ItemAttack } from "./ItemAttack";
+import type { ItemToken } from "./ItemToken";
+import type { ObjectType } from "./ObjectType";
+import type { RawMetadata } from "./RawMetadata";
+import type { SingPlurName } from "./SingPlurName";
+
+export interface Item {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  itemType: ObjectType;
+  name: SingPlurName;
+  adjective: string;
+  prePlural: string;
+  materialPlaceholder: string;
+  tile: string;
+  size: number;
+  materialSize: number;
+  value: number;
+  level: number;
+  skill: string;
+  twoHanded: number;
+  minimumSize: number;
+  rangedSkill: string;
+  rangedAmmoClass: string;
+  shootForce: number;
+  shootMaxVelocity: number;
+  attacks: Array<ItemAttack>;
+  ammoClass: string;
+  armorLevel: number;
+  coverage: number;
+  layer: ArmorLayer;
+  layerSize: number;
+  layerPermit: number;
+  upStep: number;
+  upperBodyStep: number;
+  lowerBodyStep: number;
+  blockChance: number;
+  toolUses: Array<string>;
+  containerCapacity: number;
+  hits: number;
+  tags: Array<ItemToken>;
+}
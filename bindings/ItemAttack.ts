@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface ItemAttack {
+  attackType: string;
+  contactArea: number;
+  penetrationSize: number;
+  verbSecondPerson: string;
+  verbThirdPerson: string;
+  noun: string;
+  velocityMultiplier: number;
+  prepareTime: number;
+  recoverTime: number;
+  flags: Array<string>;
+}
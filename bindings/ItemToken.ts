@@ -0,0 +1,70 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ItemToken =
+  | "Name"
+  | "Adjective"
+  | "PrePlural"
+  | "MaterialPlaceholder"
+  | "Size"
+  | "MaterialSize"
+  | "Value"
+  | "Tile"
+  | "Level"
+  | "Skill"
+  | "TwoHanded"
+  | "MinimumSize"
+  | "Ranged"
+  | "ShootForce"
+  | "ShootMaxVelocity"
+  | "Attack"
+  | "AttackPrepareAndRecover"
+  | "AttackFlagIndependentMultiattack"
+  | "AttackFlagCatchAttack"
+  | "AttackFlagBadMultiattack"
+  | "Class"
+  | "ArmorLevel"
+  | "Coverage"
+  | "Layer"
+  | "LayerSize"
+  | "LayerPermit"
+  | "UpStep"
+  | "UpperBodyStep"
+  | "LowerBodyStep"
+  | "BlockChance"
+  | "Shaped"
+  | "Barred"
+  | "Scaled"
+  | "Leather"
+  | "Chain"
+  | "Soft"
+  | "Hard"
+  | "Metal"
+  | "MetalArmorLevels"
+  | "StructuralElasticityChainAll"
+  | "StructuralElasticityChainMetal"
+  | "StructuralElasticityWovenThread"
+  | "ToolUse"
+  | "ContainerCapacity"
+  | "Hits"
+  | "HardMat"
+  | "Wood"
+  | "MetalMat"
+  | "WoodMat"
+  | "StoneMat"
+  | "GlassMat"
+  | "CeramicMat"
+  | "BoneMat"
+  | "ShellMat"
+  | "LeatherMat"
+  | "SoftMat"
+  | "SheetMat"
+  | "CanStone"
+  | "IsScrew"
+  | "IsSpike"
+  | "Furniture"
+  | "IncompleteItem"
+  | "InvertedTile"
+  | "NoDefaultJob"
+  | "Unimprovable"
+  | "NoDefaultImprovements"
+  | "Unknown";
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RawModuleLocation } from "./RawModuleLocation";
+
+export interface ModuleReference {
+  identifier: string;
+  name: string;
+  displayedVersion: string;
+  location: RawModuleLocation;
+}
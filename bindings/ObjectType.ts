@@ -43,4 +43,10 @@ export type ObjectType =
   | "Interaction"
   | "Unknown"
   | "SelectCreature"
-  | "CreatureCaste";
+  | "CreatureCaste"
+  | "Word"
+  | "Symbol"
+  | "SelectPlant"
+  | "SelectInorganic"
+  | "CutCreature"
+  | "CutInorganic";
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ObjectSource } from "./ObjectSource";
+import type { ObjectType } from "./ObjectType";
+
+export interface OverlappingPatch {
+  targetType: ObjectType;
+  identifier: string;
+  patches: Array<ObjectSource>;
+  sharedTokens: Array<string>;
+}
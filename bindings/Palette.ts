@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RawMetadata } from "./RawMetadata";
+
+export interface Palette {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  file: string;
+  defaultRow: number;
+  rows: Array<[string, number]>;
+}
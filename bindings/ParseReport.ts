@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Diagnostic } from "./Diagnostic";
+import type { DroppedModule } from "./DroppedModule";
+
+export interface ParseReport {
+  diagnostics: Array<Diagnostic>;
+  droppedModules: Array<DroppedModule>;
+}
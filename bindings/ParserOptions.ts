@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModuleVersionPolicy } from "./ModuleVersionPolicy";
 import type { ObjectType } from "./ObjectType";
 import type { ParsingJob } from "./ParsingJob";
 import type { RawModuleLocation } from "./RawModuleLocation";
@@ -7,11 +8,15 @@ export interface ParserOptions {
   attachMetadataToRaws: boolean;
   skipApplyCopyTagsFrom: boolean;
   skipApplyCreatureVariations: boolean;
+  skipApplyMaterialTemplates: boolean;
   rawsToParse: Array<ObjectType>;
   locationsToParse: Array<RawModuleLocation>;
+  moduleLoadOrder: Array<string>;
+  moduleVersionPolicy: ModuleVersionPolicy;
   targetPath: string;
   job: ParsingJob;
   serializeResultToJson: boolean;
   outputPath: string;
   outputToFile: boolean;
+  cachePath: string;
 }
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Material } from "./Material";
+import type { ProductToken } from "./ProductToken";
+
+export interface Product {
+  probability: number;
+  quantity: number;
+  itemType: string;
+  itemSubtype: string;
+  material?: Material;
+  toContainer: string;
+  dimension: number;
+  token: string;
+  tags: Array<ProductToken>;
+}
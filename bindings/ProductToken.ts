@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ProductToken =
+  | "ProductToContainer"
+  | "ProductDimension"
+  | "ProductToken"
+  | "ProductPaste"
+  | "ProductPressed"
+  | "ForceEdge"
+  | "TransferArtifactStatus"
+  | "Unknown";
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ObjectType } from "./ObjectType";
+import type { RawElement } from "./RawElement";
+import type { RawObjectBlock } from "./RawObjectBlock";
+import type { RawText } from "./RawText";
+
+export interface RawDocument {
+  header: RawText;
+  objectType: ObjectType;
+  preamble: Array<RawElement>;
+  objects: Array<RawObjectBlock>;
+}
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RawText } from "./RawText";
+import type { RawToken } from "./RawToken";
+
+export type RawElement = { "Token": RawToken } | { "Text": RawText };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RawElement } from "./RawElement";
+import type { RawToken } from "./RawToken";
+
+export interface RawObjectBlock {
+  header: RawToken;
+  elements: Array<RawElement>;
+}
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Product } from "./Product";
+import type { RawMetadata } from "./RawMetadata";
+import type { ReactionToken } from "./ReactionToken";
+import type { Reagent } from "./Reagent";
+
+export interface Reaction {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  name: string;
+  description: string;
+  buildings: Array<string>;
+  skill: string;
+  category: string;
+  reagents: Array<Reagent>;
+  products: Array<Product>;
+  improvements: Array<string>;
+  maxMultiplier: number;
+  skillIp: number;
+  skillRollRange: number;
+  coalQuantity: number;
+  tags: Array<ReactionToken>;
+}
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReactionToken =
+  | "Name"
+  | "Building"
+  | "Skill"
+  | "Category"
+  | "Description"
+  | "Reagent"
+  | "Product"
+  | "Improvement"
+  | "Automatic"
+  | "Fuel"
+  | "AdventureModeEnabled"
+  | "MaxMultiplier"
+  | "SkillIp"
+  | "SkillRollRange"
+  | "CoalQuantity"
+  | "Unknown";
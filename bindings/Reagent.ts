@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Material } from "./Material";
+import type { MaterialUsage } from "./MaterialUsage";
+import type { ReagentToken } from "./ReagentToken";
+
+export interface Reagent {
+  identifier: string;
+  quantity: number;
+  itemType: string;
+  itemSubtype: string;
+  material?: Material;
+  reactionClass: string;
+  hasMaterialReactionProduct: string;
+  hasItemReactionProduct: string;
+  hasToolUse: string;
+  metalOre: string;
+  contains: Array<string>;
+  minDimension: number;
+  materialUsage: Array<MaterialUsage>;
+  tags: Array<ReagentToken>;
+}
@@ -0,0 +1,37 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReagentToken =
+  | "HasMaterialReactionProduct"
+  | "HasItemReactionProduct"
+  | "ReactionClass"
+  | "Contains"
+  | "HasToolUse"
+  | "MinDimension"
+  | "MetalOre"
+  | "PreserveReagent"
+  | "Unrotten"
+  | "UseBodyComponent"
+  | "Empty"
+  | "NotContainBarrelItem"
+  | "DoesNotDetermineProductAmount"
+  | "DoesNotAbsorb"
+  | "NotEngraved"
+  | "NotImproved"
+  | "NotWeb"
+  | "WebOnly"
+  | "ContainsLiquid"
+  | "AnyWeaponMaterial"
+  | "AnyPlantMaterial"
+  | "AnySilkMaterial"
+  | "AnyYarnMaterial"
+  | "AnySoapMaterial"
+  | "AnyToothMaterial"
+  | "AnyHornMaterial"
+  | "AnyPearlMaterial"
+  | "AnyShellMaterial"
+  | "AnyLeatherMaterial"
+  | "AnyBoneMaterial"
+  | "AnyStrandTissue"
+  | "AnyAnimalMaterial"
+  | "CanUseDye"
+  | "Unknown";
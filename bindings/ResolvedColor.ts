@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Rgb } from "./Rgb";
+
+export interface ResolvedColor {
+  identifier: string;
+  name: string;
+  rgb: Rgb;
+  hex: string;
+}
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RawMetadata } from "./RawMetadata";
+
+export interface Sound {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  files: Array<string>;
+  conditions: Array<string>;
+}
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RawMetadata } from "./RawMetadata";
+
+export interface TextSet {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  lines: Array<string>;
+}
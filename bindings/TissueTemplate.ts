@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RawMetadata } from "./RawMetadata";
+import type { SingPlurName } from "./SingPlurName";
+import type { TissueToken } from "./TissueToken";
+
+export interface TissueTemplate {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  name: SingPlurName;
+  material: string;
+  materialState: string;
+  relativeThickness: number;
+  healingRate: number;
+  vascular: number;
+  painReceptors: number;
+  insulation: number;
+  shape: string;
+  subordinateToTissue: string;
+  styleUnitId: string;
+  tags: Array<TissueToken>;
+}
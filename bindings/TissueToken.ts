@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TissueToken =
+  | "Name"
+  | "Material"
+  | "MaterialState"
+  | "RelativeThickness"
+  | "HealingRate"
+  | "Vascular"
+  | "PainReceptors"
+  | "Shape"
+  | "Insulation"
+  | "SubordinateToTissue"
+  | "StyleUnitId"
+  | "Arteries"
+  | "Connects"
+  | "ConnectiveTissueAnchor"
+  | "Cosmetic"
+  | "Functional"
+  | "MajorArteries"
+  | "Nervous"
+  | "Scars"
+  | "Settable"
+  | "Splintable"
+  | "Structural"
+  | "Styleable"
+  | "ThickensOnEnergyStorage"
+  | "ThickensOnStrength"
+  | "TissueLeaks"
+  | "Unknown";
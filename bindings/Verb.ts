@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WordToken } from "./WordToken";
+
+export interface Verb {
+  presentFirst: string;
+  presentThird: string;
+  preterite: string;
+  pastParticiple: string;
+  presentParticiple: string;
+  usages: Array<WordToken>;
+}
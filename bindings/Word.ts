@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Adjective } from "./Adjective";
+import type { Noun } from "./Noun";
+import type { Prefix } from "./Prefix";
+import type { RawMetadata } from "./RawMetadata";
+import type { Verb } from "./Verb";
+
+export interface Word {
+  metadata: RawMetadata;
+  identifier: string;
+  objectId: string;
+  noun?: Noun;
+  verb?: Verb;
+  adjective?: Adjective;
+  prefix?: Prefix;
+}
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WordToken =
+  | "Noun"
+  | "Verb"
+  | "Adjective"
+  | "Prefix"
+  | "AdjectiveDistance"
+  | "FrontCompoundNounSingular"
+  | "FrontCompoundNounPlural"
+  | "RearCompoundNounSingular"
+  | "RearCompoundNounPlural"
+  | "TheNounSingular"
+  | "TheNounPlural"
+  | "OfNounSingular"
+  | "OfNounPlural"
+  | "FrontCompoundAdjective"
+  | "RearCompoundAdjective"
+  | "TheCompoundAdjective"
+  | "FrontCompoundPrefix"
+  | "TheCompoundPrefix"
+  | "StandardVerb"
+  | "Unknown";
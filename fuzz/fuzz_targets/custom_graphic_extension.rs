@@ -0,0 +1,18 @@
+//! Parse the arbitrary value of a `[CUSTOM_*:VALUE]` token as any type of custom extension.
+#![no_main]
+
+use dfraw_json_parser::parser::graphics::{
+    custom_extension::CustomGraphicExtension, phf_table::CUSTOM_GRAPHIC_TAGS, tokens::GraphicType,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u8, &str)| {
+    let (extension_type, value) = input;
+    let extension_type = CUSTOM_GRAPHIC_TAGS
+        .values()
+        .nth(usize::from(extension_type) % CUSTOM_GRAPHIC_TAGS.len())
+        .copied()
+        .unwrap_or(GraphicType::Unknown);
+
+    CustomGraphicExtension::from_value(extension_type, value);
+});
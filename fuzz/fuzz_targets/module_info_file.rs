@@ -0,0 +1,11 @@
+//! Parse arbitrary text as the info.txt file of a module.
+#![no_main]
+
+use dfraw_json_parser::parser::{
+    module_info_file::ModuleInfoFile, raw_locations::RawModuleLocation,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|info_text: &str| {
+    ModuleInfoFile::from_info_text(info_text, RawModuleLocation::Mods, "fuzz");
+});
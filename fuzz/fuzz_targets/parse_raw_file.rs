@@ -0,0 +1,45 @@
+//! Parse arbitrary bytes as a raw file, then resolve the parsed raws (`SELECT_*`, `CUT_*`,
+//! `COPY_TAGS_FROM`, templates and variations) like `parse` does.
+#![no_main]
+
+use dfraw_json_parser::{
+    options::ParserOptions,
+    parser::{
+        module_info_file::ModuleInfoFile, object_types::ObjectType, try_parse_raws_from_reader,
+    },
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let module = ModuleInfoFile::synthetic("fuzz", "Fuzz");
+    let mut options = ParserOptions::new("fuzz");
+    options.set_raws_to_parse(vec![
+        ObjectType::Creature,
+        ObjectType::Plant,
+        ObjectType::Inorganic,
+        ObjectType::Graphics,
+        ObjectType::TilePage,
+        ObjectType::Entity,
+        ObjectType::MaterialTemplate,
+        ObjectType::CreatureVariation,
+        ObjectType::Item,
+        ObjectType::Reaction,
+        ObjectType::Building,
+        ObjectType::Body,
+        ObjectType::BodyDetailPlan,
+        ObjectType::TissueTemplate,
+        ObjectType::Language,
+        ObjectType::Interaction,
+        ObjectType::DescriptorColor,
+        ObjectType::DescriptorPattern,
+        ObjectType::DescriptorShape,
+        ObjectType::Palette,
+        ObjectType::Music,
+        ObjectType::Sound,
+        ObjectType::TextSet,
+    ]);
+
+    if let Ok(mut raws) = try_parse_raws_from_reader(data, "fuzz", &module, &options) {
+        dfraw_json_parser::resolve_raws(&mut raws, &options);
+    }
+});
@@ -0,0 +1,18 @@
+//! Parse an arbitrary `[KEY:VALUE]` token as a sprite of any type of graphic.
+#![no_main]
+
+use dfraw_json_parser::parser::graphics::{
+    phf_table::GRAPHIC_TYPE_TAGS, sprite_graphic::SpriteGraphic, tokens::GraphicType,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u8, &str, &str)| {
+    let (graphic_type, key, value) = input;
+    let graphic_type = GRAPHIC_TYPE_TAGS
+        .values()
+        .nth(usize::from(graphic_type) % GRAPHIC_TYPE_TAGS.len())
+        .copied()
+        .unwrap_or(GraphicType::Unknown);
+
+    SpriteGraphic::from_token(key, value, graphic_type);
+});
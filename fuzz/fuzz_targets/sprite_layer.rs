@@ -0,0 +1,9 @@
+//! Parse the arbitrary value of a `[LAYER:VALUE]` token.
+#![no_main]
+
+use dfraw_json_parser::parser::graphics::sprite_layer::SpriteLayer;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|value: &str| {
+    SpriteLayer::parse_layer_from_value(value);
+});
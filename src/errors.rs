@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+use crate::{options::ParsingJob, parser::object_types::ObjectType};
+
+/// Errors which can be returned by the `try_*` parsing functions.
+///
+/// The non-`try` functions (e.g. `parse`) log these errors and return an empty result instead.
+#[derive(Debug, thiserror::Error)]
+pub enum ParserError {
+    /// The target path (or output path) in the `ParserOptions` can't be used for the requested job.
+    #[error("invalid path {}: {reason}", path.display())]
+    InvalidPath { path: PathBuf, reason: String },
+    /// The `ParsingJob` in the `ParserOptions` is not handled by the function that was called.
+    #[error("parsing job {0:?} is not supported by this function")]
+    UnsupportedJob(ParsingJob),
+    /// The `ParserOptions` are missing something required for the job (e.g. a location to parse).
+    #[error("invalid parser options: {0}")]
+    InvalidOptions(String),
+    /// The raw file has an `[OBJECT:...]` tag with a type we don't recognize.
+    #[error("unknown object type {object_type} in {}", path.display())]
+    UnknownObjectType { object_type: String, path: PathBuf },
+    /// The raw file has more than one `[OBJECT:...]` type in it.
+    #[error("object type mismatch in {}: expected {expected}, found {found}", path.display())]
+    ObjectTypeMismatch {
+        expected: ObjectType,
+        found: String,
+        path: PathBuf,
+    },
+    /// The raw can't be written as raw text (see `parser::writer`).
+    #[error("writing {0} objects as raw text is not supported")]
+    UnsupportedObjectType(ObjectType),
+    /// The module directory does not contain an info.txt file.
+    #[error("no info.txt found at {}", .0.display())]
+    MissingInfoFile(PathBuf),
+    /// A file could not be opened, read or written.
+    #[error("unable to access {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /// A line in the file could not be decoded (or, when writing, encoded) with the encoding used
+    /// by Dwarf Fortress.
+    #[error("encoding error on line {line} of {}: {source}", path.display())]
+    Encoding {
+        path: PathBuf,
+        line: usize,
+        #[source]
+        source: std::io::Error,
+    },
+    /// A parsed object could not be serialized to JSON.
+    #[error("unable to serialize to JSON: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
@@ -24,6 +24,40 @@ which creates a tauri app that lets the user look at the raws on their machine i
 and filterable manner. The "tauri" feature flag enables functions which will emit parsing progress
 back to the tauri window.
 
+## Parallel Parsing
+
+The "rayon" feature flag parses modules, and the raw files in each module, in parallel. The results
+(and the diagnostics in the `ParseReport`) are put back together in the same order as a sequential
+parse, so the output is the same either way. The `SELECT_*`, `CUT_*` and template steps run after
+all the raw files are parsed.
+
+## Streaming
+
+`parse` keeps all of the parsed raws in memory. `parse_iter` instead parses one raw file at a time as
+it is iterated, and `util::JsonArrayWriter` writes raws to any `Write` as a JSON array one at a time.
+The raws from `parse_iter` haven't had the `SELECT_*`, `CUT_*` and template steps applied, since those
+need all the raws at once; `resolve_raws` applies them to a collected set of raws.
+
+## Raw Documents
+
+`parser::document::RawDocument` is the lower-level syntax tree the parser reads raw files through. It
+keeps every token, comment and bit of whitespace along with its position in the file, so tools like
+editors and linters can point at (or change) the exact text of a token.
+
+## Writing Raws
+
+`parser::writer` writes parsed creatures, plants, inorganics, entities, graphics and tile pages back
+out as raw text (`raws_to_raw_text` and `write_raw_file`), which is useful for tools that generate or
+modify mods. Parsing the written text gives the same raws back, but only what the parser keeps is
+written: comments, token order and tokens the parser doesn't know about are lost.
+
+## Mod Conflicts
+
+`detect_conflicts` finds why a combination of mods might not work together: modules which declare a
+conflict with each other (`CONFLICTS_WITH_ID`), required modules which are missing (`REQUIRES_ID`),
+objects defined by more than one module, and objects patched with `SELECT_*` by more than one module.
+`parser::conflicts::find_conflicts` does the same for modules and raws which are already parsed.
+
 ## Glossary of Terms
 
 ### Raw
@@ -65,27 +99,42 @@ for the steam workshop if it is a mod downloaded from the steam workshop.
 #![warn(clippy::pedantic)]
 #![allow(clippy::must_use_candidate)]
 
-use options::{ParserOptions, ParsingJob};
+use options::{ModuleVersionPolicy, ParserOptions, ParsingJob};
 use parser::{
+    cache::RawFileCache,
+    conflicts::{find_conflicts, ConflictReport},
+    diagnostics::{self, DiagnosticSeverity, ParseReport},
     helpers::{
         absorb_select_creature::absorb_select_creature, apply_copy_from::apply_copy_tags_from,
+        apply_creature_variations::apply_creature_variations, apply_cut_objects::apply_cut_objects,
+        apply_material_templates::apply_material_templates,
+        apply_select_objects::apply_select_objects, link_building_graphics::link_building_graphics,
+        link_creature_interactions::link_creature_interactions,
     },
+    load_order::compute_load_order,
     module_info_file::ModuleInfoFile,
+    module_versions::{select_module_versions, DroppedModule},
     raws::RawObject,
     searchable::Searchable,
 };
-use std::path::{Path, PathBuf};
-use util::options_has_valid_paths;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+use util::{validate_options_paths, JsonArrayWriter};
 use walkdir::{DirEntry, WalkDir};
 
 use crate::parser::raw_locations::RawModuleLocation;
 
+pub mod errors;
 pub mod options;
 pub mod parser;
 #[cfg(feature = "tauri")]
 mod tauri_lib;
 pub mod util;
 
+pub use errors::ParserError;
 #[cfg(feature = "tauri")]
 pub use tauri_lib::ProgressPayload;
 
@@ -93,6 +142,12 @@ pub use tauri_lib::ProgressPayload;
 ///
 /// Note: This is unable to parse the info.txt file for a module. Use `parse_module_info_file` for that.
 ///
+/// If there is an error, it is logged and an empty vector is returned. Use `try_parse` to handle
+/// the error instead.
+///
+/// Modules which aren't parsed because of the `ModuleVersionPolicy` are logged as warnings. Use
+/// `find_dropped_modules` (or `parse_with_report`) to get a list of them.
+///
 /// # Arguments
 ///
 /// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
@@ -101,120 +156,286 @@ pub use tauri_lib::ProgressPayload;
 ///
 /// A vector of boxed dynamic raw objects.
 pub fn parse(options: &ParserOptions) -> Vec<Box<dyn RawObject>> {
-    // Guard against invalid path
-    if !options_has_valid_paths(options) {
-        log::error!(
-            "Returning early for bad path. Provided options:\n{:#?}",
-            options
-        );
-        return Vec::new();
+    match try_parse(options) {
+        Ok(results) => results,
+        Err(e) => {
+            log::error!("Unable to parse: {e}\nProvided options:\n{options:#?}");
+            Vec::new()
+        }
     }
+}
+
+/// Given the supplied `ParserOptions`, parse the raws and return a vector of boxed dynamic raw objects.
+///
+/// Note: This is unable to parse the info.txt file for a module. Use `try_parse_module_info_file` for that.
+///
+/// When parsing a module or a location, problems with individual raw files are logged and that file is
+/// skipped. Only a problem with the job itself (e.g. a bad target path) is returned as an error.
+///
+/// Modules which aren't parsed because of the `ModuleVersionPolicy` are logged as warnings. Use
+/// `try_find_dropped_modules` (or `try_parse_with_report`) to get a list of them.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+///
+/// # Returns
+///
+/// A vector of boxed dynamic raw objects.
+///
+/// # Errors
+///
+/// * `ParserError::InvalidPath` if the target path is not valid for the job
+/// * `ParserError::InvalidOptions` if no usable location is provided for `ParsingJob::SingleLocation`
+/// * `ParserError::MissingInfoFile` if the module for `ParsingJob::SingleModule` has no info.txt
+/// * `ParserError::UnsupportedJob` if the job is to parse info.txt files
+/// * Any error from reading the raw file for `ParsingJob::SingleRaw`
+pub fn try_parse(options: &ParserOptions) -> Result<Vec<Box<dyn RawObject>>, ParserError> {
+    // Guard against invalid path
+    validate_options_paths(options)?;
     let target_path = Path::new(&options.target_path);
-    let mut results: Vec<Box<dyn RawObject>> = Vec::new();
 
-    match options.job {
-        ParsingJob::All => {
-            // Set file paths for each location
-            let data_path = target_path.join("data");
-            let vanilla_path = data_path.join("vanilla");
-            let installed_mods_path = data_path.join("installed_mods");
-            let workshop_mods_path = target_path.join("mods");
+    let mut results: Vec<Box<dyn RawObject>> = if let ParsingJob::SingleRaw = options.job {
+        // The provided path should be a raw file directly
+        parser::try_parse_raws_from_single_file(&target_path, options)?
+    } else {
+        parse_modules(&modules_for_job(target_path, options)?, options)
+    };
 
-            // Parse each location
-            if options
-                .locations_to_parse
-                .contains(&RawModuleLocation::Vanilla)
-            {
-                results.extend(parse_location(&vanilla_path, options));
-            }
-            if options
-                .locations_to_parse
-                .contains(&RawModuleLocation::InstalledMods)
-            {
-                results.extend(parse_location(&installed_mods_path, options));
-            }
-            if options
-                .locations_to_parse
-                .contains(&RawModuleLocation::Mods)
-            {
-                results.extend(parse_location(&workshop_mods_path, options));
-            }
-        }
-        ParsingJob::SingleLocation => {
-            // Set the file path for the chosen location
-            let location_path = if let Some(location) = options.locations_to_parse.first() {
-                match location {
-                    RawModuleLocation::Vanilla => target_path.join("data").join("vanilla"),
-                    RawModuleLocation::InstalledMods => {
-                        target_path.join("data").join("installed_mods")
-                    }
-                    RawModuleLocation::Mods => target_path.join("mods"),
-                    RawModuleLocation::Unknown => {
-                        log::error!(
-                            "Unknown location provided to parse! Provided options:\n{:#?}",
-                            options
-                        );
-                        return Vec::new();
-                    }
-                }
-            } else {
-                log::error!(
-                    "No location provided to parse! Provided options:\n{:#?}",
-                    options
-                );
-                return Vec::new();
-            };
+    resolve_raws(&mut results, options);
 
-            // Parse the location
-            results.extend(parse_location(&location_path, options));
-        }
-        ParsingJob::SingleModule => {
-            // The provided path should be a module directory
+    Ok(results)
+}
 
-            // Check for info.txt
-            let info_txt_path = target_path.join("info.txt");
-            if !info_txt_path.exists() {
-                let dir_name = target_path.file_name().unwrap_or_default();
-                let dir_name_str = dir_name.to_str().unwrap_or("");
-
-                if !(dir_name_str.eq("mod_upload")
-                    || dir_name_str.eq("examples and notes")
-                    || dir_name_str.eq("interaction examples"))
-                {
-                    log::error!(
-                        "No info.txt as expected in {:?}. Is this DF 50.xx? Provided options:\n{:#?}",
-                        target_path.file_name().unwrap_or_default(),
-                        options
-                    );
-                }
-
-                return Vec::new();
-            }
+/// Given the supplied `ParserOptions`, parse the raws one raw file at a time, and return an iterator
+/// over the parsed raws. Unlike `try_parse`, the raws are not all kept in memory at once: each raw
+/// file is only parsed when the iterator reaches it.
+///
+/// The modules to parse are found (and put in load order) when this is called. The `SELECT_*`,
+/// `CUT_*` and template steps need all the raws at once, so they are not applied: the raws are
+/// returned as they are in the raw files. Collect the raws and call `resolve_raws` to apply them.
+/// The raw files are parsed one after another (even with the "rayon" feature), and the cache in
+/// `ParserOptions::cache_path` is not used.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+///
+/// # Returns
+///
+/// An iterator over the parsed raws, in the same order as `try_parse` (before the resolution steps).
+/// If the job can't be parsed, the iterator yields the error and then ends. If one of the raw files
+/// in a module can't be parsed, the iterator yields the error for that file and goes on with the
+/// next file (`try_parse` logs these errors and skips the file instead).
+///
+/// The errors are the same as for `try_parse`, plus the errors of `try_parse_raws_from_single_file`
+/// for each raw file.
+pub fn parse_iter(
+    options: &ParserOptions,
+) -> impl Iterator<Item = Result<Box<dyn RawObject>, ParserError>> + '_ {
+    raw_file_results(options).flatten()
+}
 
-            results.extend(parse_module(&target_path, options));
+/// Apply the steps which need all of the parsed raws at once: absorbing `SELECT_CREATURE` (and the
+/// other `SELECT_*` patches) into their targets, removing the targets of `CUT_*` objects, applying
+/// `COPY_TAGS_FROM`, creature variations and material templates (unless skipped in the `options`),
+/// and linking graphics and interactions to the raws which use them.
+///
+/// `try_parse` does this already. It is only needed for raws from `parse_iter`.
+///
+/// # Arguments
+///
+/// * `results` - The parsed raws, in load order.
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+pub fn resolve_raws(results: &mut Vec<Box<dyn RawObject>>, options: &ParserOptions) {
+    // Absorb select_creature
+    absorb_select_creature(results);
+    // Apply SELECT_PLANT and SELECT_INORGANIC patches
+    apply_select_objects(results);
+    // Remove the targets of CUT_CREATURE and CUT_INORGANIC
+    apply_cut_objects(results);
+    // Apply copy_tags_from
+    if !options.skip_apply_copy_tags_from {
+        apply_copy_tags_from(results);
+    }
+    // Apply creature variations
+    if !options.skip_apply_creature_variations {
+        apply_creature_variations(results);
+    }
+    // Apply material templates
+    if !options.skip_apply_material_templates {
+        apply_material_templates(results);
+    }
+    // Link custom workshop graphics to their buildings
+    link_building_graphics(results);
+    // Link creature interactions (CAN_DO_INTERACTION) to the parsed interactions
+    link_creature_interactions(results);
+}
+
+/// Given the supplied `ParserOptions`, parse the raws and return them along with a `ParseReport` of
+/// everything the parser could not understand (unknown tokens, values which failed to parse, and raw
+/// files which were skipped).
+///
+/// If there is an error, it is logged and an empty vector is returned with the report. Use
+/// `try_parse_with_report` to handle the error instead.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+///
+/// # Returns
+///
+/// A tuple of the parsed raws and the `ParseReport` for the parse.
+pub fn parse_with_report(options: &ParserOptions) -> (Vec<Box<dyn RawObject>>, ParseReport) {
+    let (result, report) = diagnostics::capture(|| try_parse(options));
+    match result {
+        Ok(results) => (results, report),
+        Err(e) => {
+            log::error!("Unable to parse: {e}\nProvided options:\n{options:#?}");
+            (Vec::new(), report)
         }
-        ParsingJob::SingleRaw => {
-            // The provided path should be a raw file directly
-            results.extend(parser::parse_raws_from_single_file(&target_path, options));
+    }
+}
+
+/// Given the supplied `ParserOptions`, parse the raws and return them along with a `ParseReport` of
+/// everything the parser could not understand.
+///
+/// Each diagnostic in the report includes its severity, the raw file path, the line number, the
+/// identifier of the object being parsed and the text of the raw token.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+///
+/// # Returns
+///
+/// A tuple of the parsed raws and the `ParseReport` for the parse.
+///
+/// # Errors
+///
+/// The same errors as `try_parse`.
+pub fn try_parse_with_report(
+    options: &ParserOptions,
+) -> Result<(Vec<Box<dyn RawObject>>, ParseReport), ParserError> {
+    let (result, report) = diagnostics::capture(|| try_parse(options));
+    result.map(|results| (results, report))
+}
+
+/// Given the supplied `ParserOptions`, find the conflicts between the modules which would be parsed:
+/// declared conflicts between them, modules they require which aren't being parsed, objects
+/// defined by more than one of them, and objects patched by more than one of them (see
+/// `find_conflicts`).
+///
+/// If there is an error, it is logged and an empty report is returned. Use `try_detect_conflicts`
+/// to handle the error instead.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+///
+/// # Returns
+///
+/// The `ConflictReport` for the modules.
+pub fn detect_conflicts(options: &ParserOptions) -> ConflictReport {
+    match try_detect_conflicts(options) {
+        Ok(report) => report,
+        Err(e) => {
+            log::error!("Unable to detect conflicts: {e}\nProvided options:\n{options:#?}");
+            ConflictReport::default()
         }
-        ParsingJob::SingleModuleInfoFile | ParsingJob::AllModuleInfoFiles => {
-            // The provided path should be the info.txt file for a module
-            log::warn!(
-                "Unable to parse info.txt file in this dispatch. Provided options:\n{:#?}",
-                options
-            );
-            return Vec::new();
+    }
+}
+
+/// Given the supplied `ParserOptions`, find the conflicts between the modules which would be parsed
+/// (see `find_conflicts`).
+///
+/// The modules are found the same way as for `try_parse`, and their raws are parsed (but not
+/// resolved, since that removes the `SELECT_*` patches) to find the objects they define and patch.
+/// Only the types of raws in `raws_to_parse` are compared.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+///
+/// # Returns
+///
+/// The `ConflictReport` for the modules.
+///
+/// # Errors
+///
+/// * `ParserError::InvalidPath` if the target path is not valid for the job
+/// * `ParserError::InvalidOptions` if no usable location is provided for `ParsingJob::SingleLocation`
+/// * `ParserError::MissingInfoFile` if the module for `ParsingJob::SingleModule` has no info.txt
+/// * `ParserError::UnsupportedJob` if the job is to parse a single raw file or info.txt files
+pub fn try_detect_conflicts(options: &ParserOptions) -> Result<ConflictReport, ParserError> {
+    // Guard against invalid path
+    validate_options_paths(options)?;
+    let target_path = Path::new(&options.target_path);
+
+    let modules = modules_for_job(target_path, options)?;
+    let raws = parse_modules(&modules, options);
+    let module_info_files: Vec<ModuleInfoFile> =
+        modules.into_iter().map(|(_, module)| module).collect();
+
+    Ok(find_conflicts(&module_info_files, &raws))
+}
+
+/// Given the supplied `ParserOptions`, find the modules which `parse` skips because another version
+/// of them is kept by the `ModuleVersionPolicy` (see `select_module_versions`). The raws aren't
+/// parsed, only the info.txt files of the modules.
+///
+/// If there is an error, it is logged and an empty vector is returned. Use
+/// `try_find_dropped_modules` to handle the error instead.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+///
+/// # Returns
+///
+/// The modules which aren't parsed, in the order they were found.
+pub fn find_dropped_modules(options: &ParserOptions) -> Vec<DroppedModule> {
+    match try_find_dropped_modules(options) {
+        Ok(dropped_modules) => dropped_modules,
+        Err(e) => {
+            log::error!("Unable to find dropped modules: {e}\nProvided options:\n{options:#?}");
+            Vec::new()
         }
     }
+}
 
-    // Absorb select_creature
-    absorb_select_creature(&mut results);
-    // Apply copy_tags_from
-    if !options.skip_apply_copy_tags_from {
-        apply_copy_tags_from(&mut results);
+/// Given the supplied `ParserOptions`, find the modules which `try_parse` skips because another
+/// version of them is kept by the `ModuleVersionPolicy` (see `select_module_versions`). The raws
+/// aren't parsed, only the info.txt files of the modules.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+///
+/// # Returns
+///
+/// The modules which aren't parsed, in the order they were found. This is empty for the jobs which
+/// parse a single raw file or a single module.
+///
+/// # Errors
+///
+/// * `ParserError::InvalidPath` if the target path is not valid for the job
+/// * `ParserError::InvalidOptions` if no usable location is provided for `ParsingJob::SingleLocation`
+/// * `ParserError::MissingInfoFile` if the module for `ParsingJob::SingleModule` has no info.txt
+/// * `ParserError::UnsupportedJob` if the job is to parse info.txt files
+pub fn try_find_dropped_modules(
+    options: &ParserOptions,
+) -> Result<Vec<DroppedModule>, ParserError> {
+    // Guard against invalid path
+    validate_options_paths(options)?;
+    if let ParsingJob::SingleRaw = options.job {
+        return Ok(Vec::new());
     }
+    let target_path = Path::new(&options.target_path);
 
-    results
+    let (modules, report) = diagnostics::capture(|| modules_for_job(target_path, options));
+    modules?;
+    Ok(report.get_dropped_modules().to_vec())
 }
 
 /// Parses the module info file using the provided parser options.
@@ -223,6 +444,9 @@ pub fn parse(options: &ParserOptions) -> Vec<Box<dyn RawObject>> {
 ///
 /// Note: This expects the `job` field to be `ParsingJob::SingleModuleInfoFile`. If it is not, it will return an empty `ModuleInfoFile`.
 ///
+/// If there is an error, it is logged and an empty `ModuleInfoFile` is returned. Use
+/// `try_parse_module_info_file` to handle the error instead.
+///
 /// # Arguments
 ///
 /// * `options` - A reference to the parser options.
@@ -231,25 +455,42 @@ pub fn parse(options: &ParserOptions) -> Vec<Box<dyn RawObject>> {
 ///
 /// Returns a `ModuleInfoFile` struct containing the parsed module information.
 pub fn parse_module_info_file(options: &ParserOptions) -> ModuleInfoFile {
-    // Guard against invalid path
-    if !options_has_valid_paths(options) {
-        log::error!(
-            "Returning early for bad path. Provided options:\n{:#?}",
-            options
-        );
-        return ModuleInfoFile::default();
+    match try_parse_module_info_file(options) {
+        Ok(module_info_file) => module_info_file,
+        Err(e) => {
+            log::error!("Unable to parse module info file: {e}\nProvided options:\n{options:#?}");
+            ModuleInfoFile::default()
+        }
     }
+}
+
+/// Parses the module info file using the provided parser options.
+///
+/// The only part of the parser options that is used is the `target_path` field and the `job` field.
+///
+/// # Arguments
+///
+/// * `options` - A reference to the parser options.
+///
+/// # Returns
+///
+/// Returns a `ModuleInfoFile` struct containing the parsed module information.
+///
+/// # Errors
+///
+/// * `ParserError::InvalidPath` if the target path is not valid
+/// * `ParserError::UnsupportedJob` if the job is not `ParsingJob::SingleModuleInfoFile`
+/// * Any error from reading the info.txt file
+pub fn try_parse_module_info_file(options: &ParserOptions) -> Result<ModuleInfoFile, ParserError> {
+    // Guard against invalid path
+    validate_options_paths(options)?;
     let target_path = Path::new(&options.target_path);
 
     if let ParsingJob::SingleModuleInfoFile = options.job {
         // The provided path should be the info.txt file for a module
-        parse_module_info_file_direct(&target_path)
+        parser::try_parse_info_file_from_file_path(&target_path)
     } else {
-        log::error!(
-            "Wrong job provided to parse module info file! Provided options:\n{:#?}",
-            options
-        );
-        ModuleInfoFile::default()
+        Err(ParserError::UnsupportedJob(options.job.clone()))
     }
 }
 
@@ -271,24 +512,101 @@ pub fn parse_to_json(options: &ParserOptions) -> Vec<String> {
     json_results
 }
 
+/// Parses the input data to JSON format based on the provided options.
+///
+/// # Arguments
+///
+/// * `options` - A reference to the parser options.
+///
+/// # Returns
+///
+/// A vector of strings, where each string represents a JSON object.
+///
+/// # Errors
+///
+/// * Any error from `try_parse`
+/// * `ParserError::Serialization` if a raw object can't be serialized
+pub fn try_parse_to_json(options: &ParserOptions) -> Result<Vec<String>, ParserError> {
+    try_parse(options)?
+        .iter()
+        .map(|result| serde_json::to_string(result).map_err(ParserError::from))
+        .collect()
+}
+
 /// Parses the input data and writes the output to a JSON file, based on the provided options.
 ///
 /// # Arguments
 ///
 /// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
 pub fn parse_to_file(options: &ParserOptions) {
-    // Guard against bad output path
-    if !options_has_valid_paths(options) {
-        log::error!(
-            "Returning early for bad output path. Provided options:\n{:#?}",
-            options
-        );
-        return;
+    if let Err(e) = try_parse_to_file(options) {
+        log::error!("Unable to parse to file: {e}\nProvided options:\n{options:#?}");
     }
+}
+
+/// Parses the input data and writes the output to a JSON file, based on the provided options.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+///
+/// The raws are written as a JSON array, one raw at a time, so only the parsed raws (and not their
+/// JSON) are kept in memory.
+///
+/// # Errors
+///
+/// * Any error from `try_parse`
+/// * `ParserError::Io` if the output file can't be created
+/// * `ParserError::Serialization` if a raw object can't be serialized or written
+pub fn try_parse_to_file(options: &ParserOptions) -> Result<(), ParserError> {
+    // Guard against bad output path
+    validate_options_paths(options)?;
+
+    let results = try_parse(options)?;
+
+    log::info!(
+        "try_parse_to_file: Writing {} raws to file {:?}",
+        results.len(),
+        options.output_path.display()
+    );
+    let out_file = File::create(&options.output_path).map_err(|source| ParserError::Io {
+        path: options.output_path.clone(),
+        source,
+    })?;
+    write_raws_as_json(&results, BufWriter::new(out_file))
+}
 
-    let results = parse_to_json(options);
+/// Parses the raws and writes them to `writer` as a JSON array, one raw at a time, so only the parsed
+/// raws (and not their JSON) are kept in memory.
+///
+/// To avoid keeping all the parsed raws in memory too, write the raws from `parse_iter` with a
+/// `util::JsonArrayWriter` instead (without the resolution steps, see `parse_iter`).
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
+/// * `writer` - Where to write the JSON to.
+///
+/// # Errors
+///
+/// * Any error from `try_parse`
+/// * `ParserError::Serialization` if a raw object can't be serialized or written
+pub fn try_parse_to_writer<W: Write>(
+    options: &ParserOptions,
+    writer: W,
+) -> Result<(), ParserError> {
+    let results = try_parse(options)?;
+    write_raws_as_json(&results, writer)
+}
 
-    util::write_json_string_vec_to_file(&results, &options.output_path);
+/// Writes the raws to `writer` as a JSON array.
+fn write_raws_as_json<W: Write>(raws: &[Box<dyn RawObject>], writer: W) -> Result<(), ParserError> {
+    let mut json_writer = JsonArrayWriter::new(writer);
+    for raw in raws {
+        json_writer.write(raw)?;
+    }
+    json_writer.finish()?;
+    Ok(())
 }
 
 #[cfg(feature = "tauri")]
@@ -315,6 +633,25 @@ pub fn parse_with_tauri_emit(
     tauri_lib::parse(options, window)
 }
 
+#[cfg(feature = "tauri")]
+/// Parse a directory of raws while emitting `PROGRESS` events to the supplied tauri window. This is
+/// the same as `parse_with_tauri_emit`, except that errors are returned instead of logged.
+///
+/// Properties:
+///
+/// * `options`: The `ParserOptions` to use for parsing.
+/// * `window`: A `tauri::Window` to emit `PROGRESS` events to.
+///
+/// # Errors
+///
+/// The same errors as `try_parse`.
+pub fn try_parse_with_tauri_emit(
+    options: &ParserOptions,
+    window: tauri::Window,
+) -> Result<Vec<Box<dyn RawObject>>, ParserError> {
+    tauri_lib::try_parse(options, window)
+}
+
 #[cfg(feature = "tauri")]
 /// The function `parse_with_tauri_emit_to_json_vec` takes in `options` and `window` as parameters and
 /// returns a vector of strings.
@@ -335,38 +672,400 @@ pub fn parse_with_tauri_emit_to_json_vec(
     tauri_lib::parse_to_json_vec(options, window)
 }
 
-/// Parses the raws in the provided location path, and returns a vector of boxed dynamic raw objects.
+#[cfg(feature = "tauri")]
+/// Parse a directory of raws into JSON strings while emitting `PROGRESS` events to the supplied
+/// tauri window. This is the same as `parse_with_tauri_emit_to_json_vec`, except that errors are
+/// returned instead of logged.
+///
+/// Arguments:
+///
+/// * `options`: A reference to a `ParserOptions` struct, which contains options for parsing.
+/// * `window`: A `tauri::Window` to emit `PROGRESS` events to.
+///
+/// # Errors
+///
+/// The same errors as `try_parse_to_json`.
+pub fn try_parse_with_tauri_emit_to_json_vec(
+    options: &ParserOptions,
+    window: tauri::Window,
+) -> Result<Vec<String>, ParserError> {
+    tauri_lib::try_parse_to_json_vec(options, window)
+}
+
+/// Find the modules to parse for the job in the `options`, and put them in load order.
+///
+/// Arguments:
+///
+/// * `target_path`: The target path from the `options`.
+/// * `options`: The `ParserOptions` being used for parsing.
+///
+/// Returns:
+///
+/// The module paths and their info, in load order. For `ParsingJob::SingleModule`, this is empty if
+/// the directory isn't a module.
+///
+/// # Errors
+///
+/// * `ParserError::InvalidOptions` if no usable location is provided for `ParsingJob::SingleLocation`
+/// * `ParserError::MissingInfoFile` if the module for `ParsingJob::SingleModule` has no info.txt
+/// * `ParserError::UnsupportedJob` if the job isn't to parse modules
+fn modules_for_job(
+    target_path: &Path,
+    options: &ParserOptions,
+) -> Result<Vec<(PathBuf, ModuleInfoFile)>, ParserError> {
+    match options.job {
+        ParsingJob::All => {
+            // Set file paths for each location
+            let data_path = target_path.join("data");
+            let vanilla_path = data_path.join("vanilla");
+            let installed_mods_path = data_path.join("installed_mods");
+            let workshop_mods_path = target_path.join("mods");
+
+            // Find the modules in each location
+            let mut module_paths: Vec<PathBuf> = Vec::new();
+            if options
+                .locations_to_parse
+                .contains(&RawModuleLocation::Vanilla)
+            {
+                module_paths.extend(module_paths_in_location(&vanilla_path));
+            }
+            if options
+                .locations_to_parse
+                .contains(&RawModuleLocation::InstalledMods)
+            {
+                module_paths.extend(module_paths_in_location(&installed_mods_path));
+            }
+            if options
+                .locations_to_parse
+                .contains(&RawModuleLocation::Mods)
+            {
+                module_paths.extend(module_paths_in_location(&workshop_mods_path));
+            }
+
+            Ok(modules_in_load_order(&module_paths, options))
+        }
+        ParsingJob::SingleLocation => {
+            // Set the file path for the chosen location
+            let location_path = single_location_path(target_path, options)?;
+
+            Ok(modules_in_load_order(
+                &module_paths_in_location(&location_path),
+                options,
+            ))
+        }
+        ParsingJob::SingleModule => {
+            // The provided path should be a module directory
+
+            // Check for info.txt
+            if !module_has_info_file(target_path)? {
+                return Ok(Vec::new());
+            }
+
+            let module_info_file = parse_module_info_file_direct(&target_path.join("info.txt"));
+            Ok(vec![(target_path.to_path_buf(), module_info_file)])
+        }
+        ParsingJob::SingleRaw
+        | ParsingJob::SingleModuleInfoFile
+        | ParsingJob::AllModuleInfoFiles => Err(ParserError::UnsupportedJob(options.job.clone())),
+    }
+}
+
+/// The results of parsing each raw file, one `Vec` per raw file.
+type RawFileResults<'a> =
+    Box<dyn Iterator<Item = Vec<Result<Box<dyn RawObject>, ParserError>>> + 'a>;
+
+/// The raws parsed from each raw file for the job in the `options`, parsed as the iterator reaches
+/// each file. This is what `parse_iter` flattens.
+///
+/// Arguments:
+///
+/// * `options`: The `ParserOptions` being used for parsing.
+///
+/// Returns:
+///
+/// An iterator with the results from each raw file. If the job can't be parsed, there is a single
+/// result with the error.
+fn raw_file_results(options: &ParserOptions) -> RawFileResults<'_> {
+    if let Err(e) = validate_options_paths(options) {
+        return Box::new(std::iter::once(vec![Err(e)]));
+    }
+    let target_path = Path::new(&options.target_path);
+
+    if let ParsingJob::SingleRaw = options.job {
+        // The provided path should be a raw file directly
+        return Box::new(std::iter::once_with(
+            move || match parser::try_parse_raws_from_single_file(&target_path, options) {
+                Ok(raws) => raws.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            },
+        ));
+    }
+
+    let modules = match modules_for_job(target_path, options) {
+        Ok(modules) => modules,
+        Err(e) => return Box::new(std::iter::once(vec![Err(e)])),
+    };
+
+    Box::new(
+        modules
+            .into_iter()
+            .flat_map(move |(module_path, module_info_file)| {
+                module_raw_files(&module_path, &module_info_file)
+                    .into_iter()
+                    .map(move |file_path| {
+                        match parser::try_parse_raws_from_single_file_with_info(
+                            &file_path,
+                            &module_info_file,
+                            options,
+                        ) {
+                            Ok(raws) => raws.into_iter().map(Ok).collect(),
+                            Err(e) => {
+                                log::error!("parse_iter: {e}");
+                                vec![Err(e)]
+                            }
+                        }
+                    })
+            }),
+    )
+}
+
+/// Get the path to the location that should be parsed for `ParsingJob::SingleLocation`, which is the
+/// first location in `locations_to_parse`.
+///
+/// Arguments:
+///
+/// * `target_path`: The path to the dwarf fortress directory.
+/// * `options`: The `ParserOptions` being used for parsing.
+///
+/// Returns:
+///
+/// The path to the location directory.
+fn single_location_path(
+    target_path: &Path,
+    options: &ParserOptions,
+) -> Result<PathBuf, ParserError> {
+    match options.locations_to_parse.first() {
+        Some(RawModuleLocation::Unknown) => Err(ParserError::InvalidOptions(String::from(
+            "unknown location provided to parse",
+        ))),
+        Some(location) => Ok(target_path.join(location.get_path())),
+        None => Err(ParserError::InvalidOptions(String::from(
+            "no location provided to parse",
+        ))),
+    }
+}
+
+/// Check if a module directory has an info.txt file. Some directories in the mods folder are not
+/// modules (e.g. `mod_upload`), and those are not treated as an error.
+///
+/// Arguments:
+///
+/// * `module_path`: The path to the module directory.
+///
+/// Returns:
+///
+/// `true` if the info.txt exists, `false` if it doesn't but the directory is a known non-module.
+fn module_has_info_file(module_path: &Path) -> Result<bool, ParserError> {
+    let info_txt_path = module_path.join("info.txt");
+    if info_txt_path.exists() {
+        return Ok(true);
+    }
+
+    let dir_name = module_path.file_name().unwrap_or_default();
+    let dir_name_str = dir_name.to_str().unwrap_or("");
+
+    if dir_name_str.eq("mod_upload")
+        || dir_name_str.eq("examples and notes")
+        || dir_name_str.eq("interaction examples")
+    {
+        return Ok(false);
+    }
+
+    Err(ParserError::MissingInfoFile(info_txt_path))
+}
+
+/// Returns the paths of the module directories in the location, sorted by directory name so the
+/// order doesn't depend on the file system.
+///
+/// # Arguments
+///
+/// * `location_path` - A reference to the path of the location (e.g. `data/vanilla`).
+///
+/// # Returns
+///
+/// The paths of the module directories.
+fn module_paths_in_location<P: AsRef<Path>>(location_path: &P) -> Vec<PathBuf> {
+    let mut module_paths: Vec<PathBuf> = util::subdirectories(location_path.as_ref().to_path_buf())
+        .unwrap_or_default()
+        .iter()
+        .map(|entry| entry.path().to_path_buf())
+        .collect();
+    module_paths.sort();
+
+    log::info!(
+        "Found {} raw modules in {}",
+        module_paths.len(),
+        location_path.as_ref().display(),
+    );
+
+    module_paths
+}
+
+/// Parses the info.txt files of the modules.
+///
+/// # Arguments
+///
+/// * `module_paths` - The paths of the module directories.
+///
+/// # Returns
+///
+/// The module paths and their info, in the same order.
+fn read_module_info_files(module_paths: &[PathBuf]) -> Vec<(PathBuf, ModuleInfoFile)> {
+    module_paths
+        .iter()
+        .map(|module_path| {
+            (
+                module_path.clone(),
+                parse_module_info_file_direct(&module_path.join("info.txt")),
+            )
+        })
+        .collect()
+}
+
+/// Puts the modules in load order (see `compute_load_order`), setting the load order position on
+/// each module's info.
+///
+/// # Arguments
+///
+/// * `modules` - The module paths and their info, in the order they were found.
+/// * `explicit_order` - Module identifiers in the order they should be loaded, or empty.
+///
+/// # Returns
+///
+/// The module paths and their info, in load order.
+fn order_modules(
+    modules: Vec<(PathBuf, ModuleInfoFile)>,
+    explicit_order: &[String],
+) -> Vec<(PathBuf, ModuleInfoFile)> {
+    let (module_paths, module_info_files): (Vec<PathBuf>, Vec<ModuleInfoFile>) =
+        modules.into_iter().unzip();
+    let load_order = compute_load_order(&module_info_files, explicit_order);
+
+    let mut modules: Vec<Option<(PathBuf, ModuleInfoFile)>> = module_paths
+        .into_iter()
+        .zip(module_info_files)
+        .map(Some)
+        .collect();
+
+    load_order
+        .iter()
+        .enumerate()
+        .filter_map(|(position, &index)| {
+            let (module_path, mut module_info_file) = modules[index].take()?;
+            module_info_file.set_load_order(position);
+            Some((module_path, module_info_file))
+        })
+        .collect()
+}
+
+/// Drops the versions of the modules which the `ModuleVersionPolicy` doesn't keep (see
+/// `select_module_versions`). Each dropped module is recorded in the `ParseReport`.
+///
+/// # Arguments
+///
+/// * `modules` - The module paths and their info, in the order they were found.
+/// * `policy` - Which versions of each module to keep.
+///
+/// # Returns
+///
+/// The module paths and their info of the modules to parse, in the same order.
+fn dedup_module_versions(
+    modules: Vec<(PathBuf, ModuleInfoFile)>,
+    policy: &ModuleVersionPolicy,
+) -> Vec<(PathBuf, ModuleInfoFile)> {
+    let (module_paths, module_info_files): (Vec<PathBuf>, Vec<ModuleInfoFile>) =
+        modules.into_iter().unzip();
+    let (kept_modules, dropped_modules) = select_module_versions(&module_info_files, policy);
+
+    if !dropped_modules.is_empty() {
+        log::info!(
+            "Dropped {} module versions because of the module version policy",
+            dropped_modules.len()
+        );
+    }
+    for dropped_module in dropped_modules {
+        diagnostics::report_dropped_module(dropped_module);
+    }
+
+    module_paths
+        .into_iter()
+        .zip(module_info_files)
+        .enumerate()
+        .filter(|(index, _)| kept_modules.contains(index))
+        .map(|(_, module)| module)
+        .collect()
+}
+
+/// Parses the info.txt files of the modules, drops the versions the `ModuleVersionPolicy` doesn't
+/// keep, and puts the modules in load order (see `compute_load_order`), setting the load order
+/// position on each module's info.
+///
+/// # Arguments
+///
+/// * `module_paths` - The paths of the module directories, in the order they were found.
+/// * `options` - A reference to a `ParserOptions` struct, which may have an explicit load order
+///   and a module version policy.
+///
+/// # Returns
+///
+/// The module paths and their info, in load order.
+pub(crate) fn modules_in_load_order(
+    module_paths: &[PathBuf],
+    options: &ParserOptions,
+) -> Vec<(PathBuf, ModuleInfoFile)> {
+    let modules = dedup_module_versions(
+        read_module_info_files(module_paths),
+        &options.module_version_policy,
+    );
+    order_modules(modules, &options.module_load_order)
+}
+
+/// Parses the raws in the provided module directories, in load order, and returns a vector of boxed
+/// dynamic raw objects.
 ///
 /// This is meant to be a private function, because the main entry point should be `parse`.
 ///
 /// # Arguments
 ///
-/// * `location_path` - A reference to the path to parse.
+/// * `modules` - The module paths and their info, in load order.
 /// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
 ///
 /// # Returns
 ///
 /// A vector of boxed dynamic raw objects.
-fn parse_location<P: AsRef<Path>>(
-    location_path: &P,
+fn parse_modules(
+    modules: &[(PathBuf, ModuleInfoFile)],
     options: &ParserOptions,
 ) -> Vec<Box<dyn RawObject>> {
-    let mut results: Vec<Box<dyn RawObject>> = Vec::new();
-    let location_path: PathBuf = location_path.as_ref().to_path_buf();
-    // Get a list of all subdirectories in the location
-    let raw_modules_in_location: Vec<DirEntry> =
-        util::subdirectories(location_path).unwrap_or_default();
-
-    log::info!(
-        "Found {} raw modules in {:?}",
-        raw_modules_in_location.len(),
-        options.locations_to_parse.first().unwrap(),
-    );
-
-    // Loop over each module and parse it
-    for raw_module in raw_modules_in_location {
-        let module = parse_module(&raw_module.path(), options);
-        results.extend(module);
+    let cache = (!options.cache_path.as_os_str().is_empty())
+        .then(|| RawFileCache::load(&options.cache_path, options));
+
+    // Parse each module (in parallel with the "rayon" feature), keeping the results in load order
+    let results = util::map_in_order(modules, |(module_path, module_info_file)| {
+        parse_module(module_path, module_info_file, options, cache.as_ref())
+    })
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if let Some(cache) = cache {
+        if let Err(e) = cache.save() {
+            log::warn!("Unable to save the raw file cache: {e}");
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Unable to save the raw file cache: {e}"),
+            );
+        }
     }
 
     results
@@ -430,21 +1129,63 @@ fn parse_module_info_file_direct<P: AsRef<Path>>(module_info_file_path: &P) -> M
 ///
 /// * `module_path`: The `module_path` parameter is the path to the module directory that contains the
 /// raw files to be parsed.
+/// * `module_info_file`: The parsed info.txt file of the module.
 /// * `options`: The `options` parameter is of type `ParserOptions`, which is a struct that contains
 /// various options for the parser. It is passed to the `parse_raws_from_single_file` function to
 /// control the parsing behavior.
+/// * `cache`: The raw file cache to get unchanged raw files from, if `options.cache_path` is set.
 ///
 /// Returns:
 ///
 /// The function `parse_module` returns a vector of boxed dynamic objects (`Vec<Box<dyn RawObject>>`).
 fn parse_module<P: AsRef<Path>>(
     module_path: &P,
+    module_info_file: &ModuleInfoFile,
     options: &ParserOptions,
+    cache: Option<&RawFileCache>,
 ) -> Vec<Box<dyn RawObject>> {
-    // Get information from the module info file
-    let module_info_file_path = module_path.as_ref().join("info.txt");
-    let module_info_file = parse_module_info_file_direct(&module_info_file_path);
+    let raw_file_paths = module_raw_files(module_path, module_info_file);
+
+    // Parse the raw files (in parallel with the "rayon" feature), keeping the results in file order
+    util::map_in_order(&raw_file_paths, |file_path| {
+        parse_module_raw_file(file_path, module_info_file, options, cache)
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
 
+/// Parse one of a module's raw files, from the cache if one is given (see `parse_module`).
+pub(crate) fn parse_module_raw_file(
+    file_path: &Path,
+    module_info_file: &ModuleInfoFile,
+    options: &ParserOptions,
+    cache: Option<&RawFileCache>,
+) -> Vec<Box<dyn RawObject>> {
+    match cache {
+        Some(cache) => cache.parse_raw_file(file_path, module_info_file, options),
+        None => {
+            parser::parse_raws_from_single_file_with_info(&file_path, module_info_file, options)
+        }
+    }
+}
+
+/// Finds the raw files in a module directory, in the order they are parsed: the raw files in the
+/// `objects` directory, then the ones in the `graphics` directory. Every way of parsing a module
+/// starts here, so this logs which module is being parsed.
+///
+/// # Arguments
+///
+/// * `module_path` - The path to the module directory.
+/// * `module_info_file` - The parsed info.txt file of the module.
+///
+/// # Returns
+///
+/// The paths of the raw files in the module.
+pub(crate) fn module_raw_files<P: AsRef<Path>>(
+    module_path: &P,
+    module_info_file: &ModuleInfoFile,
+) -> Vec<PathBuf> {
     log::info!(
         "draw_json_parser: Parsing raws for {} v{}",
         module_info_file.get_identifier(),
@@ -495,55 +1236,47 @@ fn parse_module<P: AsRef<Path>>(
         return Vec::new();
     }
 
-    let mut results = Vec::new();
-
-    // Parse the objects
+    // Find the raw files to parse, objects first and then graphics
+    let mut raw_file_paths: Vec<PathBuf> = Vec::new();
     if parse_objects {
-        for entry in WalkDir::new(objects_path)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-        {
-            if entry.file_type().is_file() {
-                let file_path = entry.path();
-                let file_name = file_path.file_name().unwrap_or_default();
-                let file_name_str = file_name.to_str().unwrap_or_default();
-
-                if Path::new(file_name_str)
-                    .extension()
-                    .map_or(false, |ext| ext.eq_ignore_ascii_case("txt"))
-                {
-                    results.extend(parser::parse_raws_from_single_file(&file_path, options));
-                }
-            }
-        }
+        raw_file_paths.extend(raw_files_in_directory(&objects_path));
     }
-
-    // Parse the graphics
     if parse_graphics {
-        for entry in WalkDir::new(graphics_path)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-        {
-            if entry.file_type().is_file() {
-                let file_path = entry.path();
-                let file_name = file_path.file_name().unwrap_or_default();
-                let file_name_str = file_name.to_str().unwrap_or_default();
-
-                if Path::new(file_name_str)
-                    .extension()
-                    .map_or(false, |ext| ext.eq_ignore_ascii_case("txt"))
-                {
-                    results.extend(parser::parse_raws_from_single_file(&file_path, options));
-                }
-            }
-        }
+        raw_file_paths.extend(raw_files_in_directory(&graphics_path));
     }
 
-    results
+    raw_file_paths
+}
+
+/// Finds all the raw files (files with a `.txt` extension) in a directory and its subdirectories.
+///
+/// # Arguments
+///
+/// * `directory` - The directory to search in (e.g. the `objects` directory of a module).
+///
+/// # Returns
+///
+/// The paths of the raw files, in the order they were found.
+fn raw_files_in_directory(directory: &Path) -> Vec<PathBuf> {
+    WalkDir::new(directory)
+        .into_iter()
+        .filter_map(std::result::Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("txt"))
+        })
+        .map(|entry| entry.path().to_path_buf())
+        .collect()
 }
 
 /// The function `parse_info_modules` parses module information files based on the provided options.
 ///
+/// If there is an error, it is logged and an empty vector is returned. Use `try_parse_info_modules`
+/// to handle the error instead.
+///
 /// Arguments:
 ///
 /// * `options`: A reference to a `ParserOptions` struct, which contains various options for parsing
@@ -553,14 +1286,38 @@ fn parse_module<P: AsRef<Path>>(
 ///
 /// The function `parse_info_modules` returns a `Vec<ModuleInfoFile>`.
 pub fn parse_info_modules(options: &ParserOptions) -> Vec<ModuleInfoFile> {
-    // Guard against invalid path
-    if !options_has_valid_paths(options) {
-        log::error!(
-            "draw_json_parser: returning early for bad path. Provided options:\n{:#?}",
-            options
-        );
-        return Vec::new();
+    match try_parse_info_modules(options) {
+        Ok(results) => results,
+        Err(e) => {
+            log::error!(
+                "draw_json_parser: Unable to parse info.txt files: {e}\nProvided options:\n{options:#?}"
+            );
+            Vec::new()
+        }
     }
+}
+
+/// The function `try_parse_info_modules` parses module information files based on the provided options.
+///
+/// Arguments:
+///
+/// * `options`: A reference to a `ParserOptions` struct, which contains various options for parsing
+///   module information.
+///
+/// Returns:
+///
+/// The function `try_parse_info_modules` returns a `Vec<ModuleInfoFile>`.
+///
+/// # Errors
+///
+/// * `ParserError::InvalidPath` if the target path is not valid for the job
+/// * `ParserError::InvalidOptions` if no usable location is provided for `ParsingJob::SingleLocation`
+/// * `ParserError::MissingInfoFile` if the module for `ParsingJob::SingleModule` has no info.txt
+/// * `ParserError::UnsupportedJob` if the job is `ParsingJob::SingleRaw`
+/// * Any error from reading the info.txt file for `ParsingJob::SingleModuleInfoFile`
+pub fn try_parse_info_modules(options: &ParserOptions) -> Result<Vec<ModuleInfoFile>, ParserError> {
+    // Guard against invalid path
+    validate_options_paths(options)?;
     let target_path = Path::new(&options.target_path);
     let mut results: Vec<ModuleInfoFile> = Vec::new();
 
@@ -594,28 +1351,7 @@ pub fn parse_info_modules(options: &ParserOptions) -> Vec<ModuleInfoFile> {
         }
         ParsingJob::SingleLocation => {
             // Set the file path for the chosen location
-            let location_path = if let Some(location) = options.locations_to_parse.first() {
-                match location {
-                    RawModuleLocation::Vanilla => target_path.join("data").join("vanilla"),
-                    RawModuleLocation::InstalledMods => {
-                        target_path.join("data").join("installed_mods")
-                    }
-                    RawModuleLocation::Mods => target_path.join("mods"),
-                    RawModuleLocation::Unknown => {
-                        log::error!(
-                            "draw_json_parser: Unknown location provided to parse! Provided options:\n{:#?}",
-                            options
-                        );
-                        return Vec::new();
-                    }
-                }
-            } else {
-                log::error!(
-                    "draw_json_parser: No location provided to parse! Provided options:\n{:#?}",
-                    options
-                );
-                return Vec::new();
-            };
+            let location_path = single_location_path(target_path, options)?;
 
             // Parse the location
             results.extend(parse_module_info_files_at_location(&location_path));
@@ -624,43 +1360,24 @@ pub fn parse_info_modules(options: &ParserOptions) -> Vec<ModuleInfoFile> {
             // The provided path should be a module directory
 
             // Check for info.txt
-            let info_txt_path = target_path.join("info.txt");
-            if !info_txt_path.exists() {
-                let dir_name = target_path.file_name().unwrap_or_default();
-                let dir_name_str = dir_name.to_str().unwrap_or("");
-
-                if !(dir_name_str.eq("mod_upload")
-                    || dir_name_str.eq("examples and notes")
-                    || dir_name_str.eq("interaction examples"))
-                {
-                    log::error!(
-                        "draw_json_parser: No info.txt as expected in {:?}. Is this DF 50.xx? Provided options:\n{:#?}",
-                        target_path.file_name().unwrap_or_default(),
-                        options
-                    );
-                }
-
-                return Vec::new();
+            if !module_has_info_file(target_path)? {
+                return Ok(Vec::new());
             }
 
-            let module_info_file = parse_module_info_file_direct(&info_txt_path);
-            results.push(module_info_file);
+            let info_txt_path = target_path.join("info.txt");
+            results.push(parser::try_parse_info_file_from_file_path(&info_txt_path)?);
         }
         ParsingJob::SingleRaw => {
             // The provided path should be a raw file directly
-            log::warn!(
-                "draw_json_parser: Unable to parse info.txt file in this dispatch. Provided options:\n{:#?}",
-                options
-            );
-            return Vec::new();
+            return Err(ParserError::UnsupportedJob(options.job.clone()));
         }
         ParsingJob::SingleModuleInfoFile => {
             // The provided path should be the info.txt file for a module
-            results.push(parse_module_info_file_direct(&target_path));
+            results.push(parser::try_parse_info_file_from_file_path(&target_path)?);
         }
     }
     log::info!("draw_json_parser: Parsed {} info.txt files", results.len());
-    results
+    Ok(results)
 }
 
 /// The function `parse_info_modules_to_json` takes a `ParserOptions` object as input, parses the
@@ -683,6 +1400,24 @@ pub fn parse_info_modules_to_json(options: &ParserOptions) -> Vec<String> {
     json_results
 }
 
+/// The function `try_parse_info_modules_to_json` parses the information modules using the options,
+/// and returns a vector of JSON strings representing the parsed results.
+///
+/// Arguments:
+///
+/// * `options`: A reference to a `ParserOptions` struct.
+///
+/// # Errors
+///
+/// * Any error from `try_parse_info_modules`
+/// * `ParserError::Serialization` if a module info file can't be serialized
+pub fn try_parse_info_modules_to_json(options: &ParserOptions) -> Result<Vec<String>, ParserError> {
+    try_parse_info_modules(options)?
+        .iter()
+        .map(|result| serde_json::to_string(result).map_err(ParserError::from))
+        .collect()
+}
+
 /// The function `parse_info_modules_to_file` parses information modules to JSON and writes the results
 /// to a file.
 ///
@@ -690,17 +1425,30 @@ pub fn parse_info_modules_to_json(options: &ParserOptions) -> Vec<String> {
 ///
 /// * `options`: A reference to a `ParserOptions` struct.
 pub fn parse_info_modules_to_file(options: &ParserOptions) {
-    // Guard against bad output path
-    if !options_has_valid_paths(options) {
+    if let Err(e) = try_parse_info_modules_to_file(options) {
         log::error!(
-            "draw_json_parser: Returning early for bad output path. Provided options:\n{:#?}",
-            options
+            "draw_json_parser: Unable to parse info.txt files to file: {e}\nProvided options:\n{options:#?}"
         );
-        return;
     }
+}
+
+/// The function `try_parse_info_modules_to_file` parses information modules to JSON and writes the
+/// results to a file.
+///
+/// Arguments:
+///
+/// * `options`: A reference to a `ParserOptions` struct.
+///
+/// # Errors
+///
+/// * Any error from `try_parse_info_modules_to_json`
+/// * `ParserError::Io` if the output file can't be written
+pub fn try_parse_info_modules_to_file(options: &ParserOptions) -> Result<(), ParserError> {
+    // Guard against bad output path
+    validate_options_paths(options)?;
 
-    let results = parse_info_modules_to_json(options);
-    util::write_json_string_vec_to_file(&results, &options.output_path);
+    let results = try_parse_info_modules_to_json(options)?;
+    util::try_write_json_string_vec_to_file(&results, &options.output_path)
 }
 
 /// The function `build_search_string` takes a `raw_object` that implements the `Searchable` trait and
@@ -1,4 +1,7 @@
-use std::path::{Path, PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +13,7 @@ use crate::parser::{object_types::ObjectType, raw_locations::RawModuleLocation};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 pub struct ParserOptions {
     /// Whether to attach a metadata field to the raws.
     /// If true, all raws will have a `metadata` field which shows information about the
@@ -25,21 +28,59 @@ pub struct ParserOptions {
     pub skip_apply_copy_tags_from: bool,
     /// Whether to skip the apply "creature variations" resolution step.
     /// When this is true, it will just leave the variations attached to the creature
-    /// in a `creature_variations` field.
+    /// in an `applyCreatureVariation` field, and any tags after `APPLY_CURRENT_CREATURE_VARIATION`
+    /// in a `tagsAfterCreatureVariation` field.
     /// If false, it will modify the creature data to include the variations.
     ///
-    /// Note: This is currently not implemented.
+    /// Note: The variations are only applied if `ObjectType::CreatureVariation` is in `raws_to_parse`.
     ///
     /// Default: false.
     pub skip_apply_creature_variations: bool,
+    /// Whether to skip the apply "material templates" resolution step.
+    /// When this is true, materials which use a template will only have the properties
+    /// set on the material itself, with the template in a `templateIdentifier` field.
+    /// If false, the template's properties are applied to the material first.
+    ///
+    /// Note: The templates are only applied if `ObjectType::MaterialTemplate` is in `raws_to_parse`.
+    ///
+    /// Default: false.
+    pub skip_apply_material_templates: bool,
     /// What kind of raws to parse. If this is left empty, all raws will be parsed.
-    /// Default: [ Creature, Plant, Inorganic, MaterialTemplate ]
+    ///
+    /// Item raw files are all `[OBJECT:ITEM]`, so `ObjectType::Item` enables parsing every
+    /// kind of item (weapons, armor, tools, etc.). Likewise, `ObjectType::Building` enables parsing
+    /// both workshops and furnaces.
+    ///
+    /// Default: `[ Creature, Plant, Inorganic, MaterialTemplate, Graphics, TilePage, CreatureVariation, Item, Reaction, Building, Body, BodyDetailPlan, TissueTemplate, Language, Interaction, DescriptorColor, DescriptorPattern, DescriptorShape, Palette, Music, Sound, TextSet ]`
     pub raws_to_parse: Vec<ObjectType>,
     /// What locations to parse raws from. If this is left empty, all locations will be parsed.
     /// When parsing a single file, this is ignored. If the job is to parse a single location,
     /// only the first location in this list will be used.
     /// Default: Vanilla.
     pub locations_to_parse: Vec<RawModuleLocation>,
+    /// The order to load the modules in, as a list of module identifiers (e.g. from a world's list
+    /// of mods). Modules which aren't in the list are loaded after the ones which are.
+    ///
+    /// If this is left empty, the order is worked out from the `REQUIRES_ID_BEFORE_ME` and
+    /// `REQUIRES_ID_AFTER_ME` tokens in the modules' info.txt files. The load order decides which
+    /// `SELECT_*`, `CUT_*` and `COPY_TAGS_FROM` apply when several modules change the same object.
+    ///
+    /// Dwarf Fortress keeps the modules a world uses (and their load order) in the world's binary
+    /// save data, which isn't read here. To parse the raws of a world, parse the `Vanilla` and
+    /// `InstalledMods` locations with the world's modules in this list, and pin their versions with
+    /// `ModuleVersionPolicy::Pinned`. Modules which other worlds use are still parsed, after the
+    /// world's modules.
+    ///
+    /// Default: empty
+    #[serde(default)]
+    pub module_load_order: Vec<String>,
+    /// Which versions to parse when more than one version of a module is found (e.g. several
+    /// versions of a mod in `installed_mods`). The modules which aren't parsed are listed in the
+    /// `ParseReport`.
+    ///
+    /// Default: All
+    #[serde(default)]
+    pub module_version_policy: ModuleVersionPolicy,
     /// The path to the dwarf fortress directory if parsing ALL or a SingleLocation. If
     /// parsing a single module, this should be the path to the module (which includes the
     /// info.txt file). If parsing a single raw file, this should be the path directly to the raw.
@@ -61,10 +102,17 @@ pub struct ParserOptions {
     /// Whether output to a file or not
     /// Default: false
     pub output_to_file: bool,
+    /// The path of a file to cache the parsed raws in. When this is set, each raw file is only parsed
+    /// again if it has changed since the last parse (the `SELECT_*`, `CUT_*` and template steps
+    /// always run again). The cache is only used when parsing modules, not for `ParsingJob::SingleRaw`.
+    ///
+    /// If left empty, no cache is used.
+    #[serde(default)]
+    pub cache_path: PathBuf,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 pub enum ParsingJob {
     SingleRaw,
     SingleModule,
@@ -74,12 +122,27 @@ pub enum ParsingJob {
     AllModuleInfoFiles,
 }
 
+/// Which versions of a module to parse when more than one version of it is found.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default, ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+pub enum ModuleVersionPolicy {
+    /// Parse every version of every module.
+    #[default]
+    All,
+    /// Parse only the version with the highest `NUMERIC_VERSION` of each module.
+    Newest,
+    /// Parse the given `NUMERIC_VERSION` of each module identifier in the map. Modules which aren't
+    /// in the map (or whose pinned version isn't found) use the newest version.
+    Pinned(HashMap<String, u32>),
+}
+
 impl Default for ParserOptions {
     fn default() -> Self {
         Self {
             attach_metadata_to_raws: false,
             skip_apply_copy_tags_from: false,
             skip_apply_creature_variations: false,
+            skip_apply_material_templates: false,
             serialize_result_to_json: false,
             output_to_file: false,
             raws_to_parse: vec![
@@ -89,11 +152,30 @@ impl Default for ParserOptions {
                 ObjectType::MaterialTemplate,
                 ObjectType::Graphics,
                 ObjectType::TilePage,
+                ObjectType::CreatureVariation,
+                ObjectType::Item,
+                ObjectType::Reaction,
+                ObjectType::Building,
+                ObjectType::Body,
+                ObjectType::BodyDetailPlan,
+                ObjectType::TissueTemplate,
+                ObjectType::Language,
+                ObjectType::Interaction,
+                ObjectType::DescriptorColor,
+                ObjectType::DescriptorPattern,
+                ObjectType::DescriptorShape,
+                ObjectType::Palette,
+                ObjectType::Music,
+                ObjectType::Sound,
+                ObjectType::TextSet,
             ],
             locations_to_parse: vec![RawModuleLocation::Vanilla],
+            module_load_order: Vec::new(),
+            module_version_policy: ModuleVersionPolicy::All,
             target_path: PathBuf::from(""),
             job: ParsingJob::All,
             output_path: PathBuf::from(""),
+            cache_path: PathBuf::from(""),
         }
     }
 }
@@ -130,11 +212,18 @@ impl ParserOptions {
 
     /// Skip the apply "creature variations" resolution step.
     ///
-    /// Note: This is currently not implemented.
+    /// Default: false.
     pub fn skip_apply_creature_variations(&mut self) {
         self.skip_apply_creature_variations = true;
     }
 
+    /// Skip the apply "material templates" resolution step.
+    ///
+    /// Default: false.
+    pub fn skip_apply_material_templates(&mut self) {
+        self.skip_apply_material_templates = true;
+    }
+
     /// Sets what kind of raws to parse.
     /// The default value will parse all the raws that are currently supported:
     /// * `ObjectType::Creature`
@@ -155,6 +244,28 @@ impl ParserOptions {
         self.locations_to_parse = locations_to_parse;
     }
 
+    /// Sets the order to load the modules in, as a list of module identifiers. Modules which aren't
+    /// in the list are loaded after the ones which are.
+    ///
+    /// If left unset, the order is worked out from the modules' info.txt files.
+    pub fn set_module_load_order(&mut self, module_load_order: Vec<String>) {
+        self.module_load_order = module_load_order;
+    }
+
+    /// Sets which versions to parse when more than one version of a module is found.
+    /// * `ModuleVersionPolicy::All` will parse every version.
+    /// * `ModuleVersionPolicy::Newest` will parse only the newest version of each module.
+    /// * `ModuleVersionPolicy::Pinned` will parse the given version of each module in the map, and
+    ///   the newest version of the others.
+    ///
+    /// If left unset, every version will be parsed.
+    ///
+    /// The versions which aren't parsed are logged as warnings. They are only listed in the
+    /// `ParseReport` (see `parse_with_report`), not in the result of `parse`.
+    pub fn set_module_version_policy(&mut self, module_version_policy: ModuleVersionPolicy) {
+        self.module_version_policy = module_version_policy;
+    }
+
     /// Sets the job to perform.
     /// * `ParsingJob::SingleRaw` will parse a single raw file. (e.g. `creature_standard.txt` in `data/vanilla/vanilla_creatures/objects/`)
     /// * `ParsingJob::SingleModule` will parse a single module (e.g. `vanilla_creatures` in `data/vanilla/`)
@@ -189,4 +300,12 @@ impl ParserOptions {
     pub fn output_to_file(&mut self) {
         self.output_to_file = true;
     }
+
+    /// Sets the path of the file to cache the parsed raws in, so that only the raw files which have
+    /// changed are parsed again on the next parse. The file is created if it doesn't exist.
+    ///
+    /// If left empty, no cache is used.
+    pub fn set_cache_path<P: AsRef<Path>>(&mut self, cache_path: P) {
+        self.cache_path = cache_path.as_ref().to_path_buf();
+    }
 }
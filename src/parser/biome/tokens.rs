@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub enum Biome {
     Mountain,
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{names::SingPlurName, serializer_helper};
+
+use super::{phf_table::BODY_PART_TOKENS, tokens::BodyPartToken};
+
+/// A body part from a `[BP:...]` token in a body raw, and the tokens which follow it.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BodyPart {
+    identifier: String,
+    name: SingPlurName,
+
+    /// The body part this connects to (`CON`).
+    #[serde(skip_serializing_if = "String::is_empty")]
+    connection: String,
+    /// The category of the body parts this connects to (`CON_CAT`).
+    #[serde(skip_serializing_if = "String::is_empty")]
+    connection_category: String,
+    /// The type of the body parts this connects to (`CONTYPE`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connection_type: Option<BodyPartToken>,
+
+    #[serde(skip_serializing_if = "String::is_empty")]
+    category: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    relative_size: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    number: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    individual_names: Vec<SingPlurName>,
+
+    /// The type flags of the body part, e.g. `UpperBody` or `Grasp`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<BodyPartToken>,
+}
+
+impl BodyPart {
+    /// Create a body part from the value of a `BP` token.
+    ///
+    /// Arguments:
+    ///
+    /// * `value`: The value of the token, e.g. `UB:upper body:upper bodies`
+    pub fn from_value(value: &str) -> Self {
+        let (identifier, names) = value.split_once(':').unwrap_or((value, ""));
+        Self {
+            identifier: String::from(identifier),
+            name: SingPlurName::from_value(names),
+            ..Self::default()
+        }
+    }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn get_name(&self) -> &SingPlurName {
+        &self.name
+    }
+    pub fn get_connection(&self) -> &str {
+        &self.connection
+    }
+    pub fn get_connection_category(&self) -> &str {
+        &self.connection_category
+    }
+    pub fn get_connection_type(&self) -> Option<&BodyPartToken> {
+        self.connection_type.as_ref()
+    }
+    pub fn get_category(&self) -> &str {
+        &self.category
+    }
+    pub fn get_relative_size(&self) -> u32 {
+        self.relative_size
+    }
+    pub fn get_number(&self) -> u32 {
+        self.number
+    }
+    pub fn get_tags(&self) -> &[BodyPartToken] {
+        self.tags.as_slice()
+    }
+    pub fn has_tag(&self, tag: &BodyPartToken) -> bool {
+        self.tags.contains(tag)
+    }
+    pub fn parse_tag(&mut self, tag: &BodyPartToken, value: &str) {
+        match tag {
+            BodyPartToken::Connection => {
+                self.connection = String::from(value);
+            }
+            BodyPartToken::ConnectionCategory => {
+                self.connection_category = String::from(value);
+            }
+            BodyPartToken::ConnectionType => {
+                self.connection_type = BODY_PART_TOKENS.get(value).cloned();
+            }
+            BodyPartToken::Category => {
+                self.category = String::from(value);
+            }
+            BodyPartToken::DefaultRelativeSize => {
+                self.relative_size = value.parse().unwrap_or_default();
+            }
+            BodyPartToken::Number => {
+                self.number = value.parse().unwrap_or_default();
+            }
+            BodyPartToken::IndividualName => {
+                self.individual_names.push(SingPlurName::from_value(value));
+            }
+            BodyPartToken::BodyPart | BodyPartToken::BodyGloss | BodyPartToken::Unknown => {}
+            _ => {
+                if !self.tags.contains(tag) {
+                    self.tags.push(tag.clone());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod body_part;
+pub mod node;
+pub mod phf_table;
+pub mod raw;
+pub mod tokens;
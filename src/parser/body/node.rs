@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    body_detail_plan::layers::TissueLayer, names::SingPlurName, serializer_helper,
+};
+
+use super::{body_part::BodyPart, tokens::BodyPartToken};
+
+/// A body part in a creature's expanded body, with its tissue layers and the body parts connected
+/// to it. See `build_body_part_tree`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BodyPartNode {
+    identifier: String,
+    name: SingPlurName,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    category: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    relative_size: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    number: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<BodyPartToken>,
+    /// The tissue layers of the body part, from the innermost out.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    layers: Vec<TissueLayer>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<BodyPartNode>,
+}
+
+impl BodyPartNode {
+    pub fn new(part: &BodyPart, relative_size: u32, layers: Vec<TissueLayer>) -> Self {
+        Self {
+            identifier: String::from(part.get_identifier()),
+            name: part.get_name().clone(),
+            category: String::from(part.get_category()),
+            relative_size,
+            number: part.get_number(),
+            tags: part.get_tags().to_vec(),
+            layers,
+            children: Vec::new(),
+        }
+    }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn get_name(&self) -> &SingPlurName {
+        &self.name
+    }
+    pub fn get_category(&self) -> &str {
+        &self.category
+    }
+    pub fn get_relative_size(&self) -> u32 {
+        self.relative_size
+    }
+    pub fn get_tags(&self) -> &[BodyPartToken] {
+        self.tags.as_slice()
+    }
+    pub fn get_layers(&self) -> &[TissueLayer] {
+        self.layers.as_slice()
+    }
+    pub fn get_children(&self) -> &[BodyPartNode] {
+        self.children.as_slice()
+    }
+    pub fn push_child(&mut self, child: BodyPartNode) {
+        self.children.push(child);
+    }
+    /// Returns true if the body part is a limb (an arm, leg, etc.).
+    pub fn is_limb(&self) -> bool {
+        self.tags.contains(&BodyPartToken::Limb)
+    }
+}
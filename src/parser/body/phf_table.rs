@@ -0,0 +1,45 @@
+use super::tokens::BodyPartToken;
+
+pub static BODY_PART_TOKENS: phf::Map<&'static str, BodyPartToken> = phf::phf_map! {
+    "BP" => BodyPartToken::BodyPart,
+    "CON" => BodyPartToken::Connection,
+    "CON_CAT" => BodyPartToken::ConnectionCategory,
+    "CONTYPE" => BodyPartToken::ConnectionType,
+    "CATEGORY" => BodyPartToken::Category,
+    "DEFAULT_RELSIZE" => BodyPartToken::DefaultRelativeSize,
+    "NUMBER" => BodyPartToken::Number,
+    "INDIVIDUAL_NAME" => BodyPartToken::IndividualName,
+    "BODYGLOSS" => BodyPartToken::BodyGloss,
+    "APERTURE" => BodyPartToken::Aperture,
+    "BREATHE" => BodyPartToken::Breathe,
+    "CIRCULATION" => BodyPartToken::Circulation,
+    "CONNECTOR" => BodyPartToken::Connector,
+    "DIGIT" => BodyPartToken::Digit,
+    "EMBEDDED" => BodyPartToken::Embedded,
+    "FLIER" => BodyPartToken::Flier,
+    "GELDABLE" => BodyPartToken::Geldable,
+    "GRASP" => BodyPartToken::Grasp,
+    "GUTS" => BodyPartToken::Guts,
+    "HEAD" => BodyPartToken::Head,
+    "HEAR" => BodyPartToken::Hear,
+    "INTERNAL" => BodyPartToken::Internal,
+    "JOINT" => BodyPartToken::Joint,
+    "LEFT" => BodyPartToken::Left,
+    "LIMB" => BodyPartToken::Limb,
+    "LOWERBODY" => BodyPartToken::LowerBody,
+    "MOUTH" => BodyPartToken::Mouth,
+    "NERVOUS" => BodyPartToken::Nervous,
+    "PREVENTS_PARENT_COLLAPSE" => BodyPartToken::PreventsParentCollapse,
+    "RIGHT" => BodyPartToken::Right,
+    "SKELETON" => BodyPartToken::Skeleton,
+    "SIGHT" => BodyPartToken::Sight,
+    "SMALL" => BodyPartToken::Small,
+    "SMELL" => BodyPartToken::Smell,
+    "SOCKET" => BodyPartToken::Socket,
+    "STANCE" => BodyPartToken::Stance,
+    "THOUGHT" => BodyPartToken::Thought,
+    "TOTEMABLE" => BodyPartToken::Totemable,
+    "UNDER_PRESSURE" => BodyPartToken::UnderPressure,
+    "UPPERBODY" => BodyPartToken::UpperBody,
+    "VERMIN_BUTCHER_ITEM" => BodyPartToken::VerminButcherItem,
+};
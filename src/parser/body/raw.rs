@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{body_part::BodyPart, phf_table::BODY_PART_TOKENS, tokens::BodyPartToken};
+
+/// A set of body parts from an `[OBJECT:BODY]` raw file, e.g. `[BODY:HUMANOID_NECK]`.
+///
+/// Creatures build their body from one or more of these with `[BODY:...]`. The parts of all the
+/// bodies a creature uses are connected together into a tree (see `build_body_part_tree`).
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Body {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parts: Vec<BodyPart>,
+}
+
+impl Body {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Body),
+            ..Self::default()
+        }
+    }
+    pub fn get_parts(&self) -> &[BodyPart] {
+        self.parts.as_slice()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for Body {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::Body
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = BODY_PART_TOKENS.get(key) else {
+            log::debug!(
+                "Body::parse_tag: Unknown body tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(DiagnosticSeverity::Info, &format!("Unknown body tag {key}"));
+            return;
+        };
+
+        match tag {
+            BodyPartToken::BodyPart => {
+                self.parts.push(BodyPart::from_value(value));
+            }
+            BodyPartToken::BodyGloss => {
+                // Glosses are defined between the bodies in the file, and aren't part of any body.
+            }
+            _ => {
+                let Some(part) = self.parts.last_mut() else {
+                    diagnostics::report(
+                        DiagnosticSeverity::Warning,
+                        &format!("Body part tag {key} found before any BP"),
+                    );
+                    return;
+                };
+                part.parse_tag(tag, value);
+            }
+        }
+    }
+}
+
+impl Searchable for Body {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        for part in &self.parts {
+            vec.push(String::from(part.get_identifier()));
+            vec.extend(part.get_name().as_vec());
+            vec.push(String::from(part.get_category()));
+        }
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum BodyPartToken {
+    /// Arguments: identifier, name, plural name
+    ///
+    /// Starts a new body part, e.g. `[BP:UB:upper body:upper bodies]`. The plural name can be `STP`.
+    /// The tokens which follow it apply to this body part.
+    BodyPart,
+    /// Arguments: body part identifier
+    ///
+    /// Connects the body part to the body part with the given identifier.
+    Connection,
+    /// Arguments: category
+    ///
+    /// Connects the body part to every body part with the given category.
+    ConnectionCategory,
+    /// Arguments: body part type
+    ///
+    /// Connects the body part to every body part with the given type, e.g. `UPPERBODY`.
+    ConnectionType,
+    /// Arguments: category
+    ///
+    /// The category of the body part, which is used to select it in other raws.
+    Category,
+    /// Arguments: size
+    ///
+    /// The size of the body part relative to the other body parts.
+    DefaultRelativeSize,
+    /// Arguments: count
+    ///
+    /// The number of these body parts, e.g. for teeth.
+    Number,
+    /// Arguments: name, plural name
+    ///
+    /// The name of one of the body parts when there are several (see `NUMBER`).
+    IndividualName,
+    /// Arguments: identifier, adjective, singular/plural forms...
+    ///
+    /// Defines a gloss for body part names. Body glosses are not kept by the parser.
+    BodyGloss,
+    /// The body part is an aperture, e.g. a mouth.
+    Aperture,
+    /// The body part breathes.
+    Breathe,
+    /// The body part circulates blood.
+    Circulation,
+    /// The body part is a connector, e.g. a tendon.
+    Connector,
+    /// The body part is a digit.
+    Digit,
+    /// The body part is embedded, and can only be reached through its parent.
+    Embedded,
+    /// The body part can fly.
+    Flier,
+    /// The body part can be gelded.
+    Geldable,
+    /// The body part can grasp.
+    Grasp,
+    /// The body part contains guts.
+    Guts,
+    /// The body part is a head.
+    Head,
+    /// The body part can hear.
+    Hear,
+    /// The body part is internal.
+    Internal,
+    /// The body part is a joint.
+    Joint,
+    /// The body part is on the left side.
+    Left,
+    /// The body part is a limb.
+    Limb,
+    /// The body part is a lower body.
+    LowerBody,
+    /// The body part is a mouth.
+    Mouth,
+    /// The body part is nervous tissue.
+    Nervous,
+    /// The body part prevents its parent from collapsing.
+    PreventsParentCollapse,
+    /// The body part is on the right side.
+    Right,
+    /// The body part is part of the skeleton.
+    Skeleton,
+    /// The body part can see.
+    Sight,
+    /// The body part is small.
+    Small,
+    /// The body part can smell.
+    Smell,
+    /// The body part is a socket.
+    Socket,
+    /// The body part can stand.
+    Stance,
+    /// The body part thinks.
+    Thought,
+    /// The body part can be kept as a trophy.
+    Totemable,
+    /// The body part is under pressure.
+    UnderPressure,
+    /// The body part is an upper body.
+    UpperBody,
+    /// The body part is butchered as an item for vermin.
+    VerminButcherItem,
+    #[default]
+    Unknown,
+}
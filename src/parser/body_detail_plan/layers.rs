@@ -0,0 +1,251 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    body::{body_part::BodyPart, phf_table::BODY_PART_TOKENS},
+    serializer_helper,
+};
+
+use super::phf_table::BODY_PART_SELECTOR_TOKENS;
+
+/// How a `BodyPartSelector` picks body parts.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum BodyPartSelectorKind {
+    /// `BY_CATEGORY`: body parts with the given `CATEGORY`, or every body part for `ALL`
+    Category,
+    /// `BY_TYPE`: body parts with the given type flag, e.g. `GRASP`
+    Type,
+    /// `BY_TOKEN`: the body part with the given identifier
+    Token,
+    #[default]
+    Unknown,
+}
+
+/// Selects the body parts a token applies to, e.g. `BY_CATEGORY:HEAD`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BodyPartSelector {
+    by: BodyPartSelectorKind,
+    value: String,
+}
+
+impl BodyPartSelector {
+    /// Create a selector from its two parts, e.g. `BY_CATEGORY` and `HEAD`.
+    pub fn from_parts(kind: &str, value: &str) -> Self {
+        Self {
+            by: BODY_PART_SELECTOR_TOKENS
+                .get(kind)
+                .cloned()
+                .unwrap_or_default(),
+            value: String::from(value),
+        }
+    }
+    pub fn get_kind(&self) -> &BodyPartSelectorKind {
+        &self.by
+    }
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    /// Returns true if the body part is selected.
+    pub fn matches(&self, part: &BodyPart) -> bool {
+        match self.by {
+            BodyPartSelectorKind::Category => {
+                self.value == "ALL" || part.get_category() == self.value
+            }
+            BodyPartSelectorKind::Type => BODY_PART_TOKENS
+                .get(&self.value)
+                .is_some_and(|tag| part.has_tag(tag)),
+            BodyPartSelectorKind::Token => part.get_identifier() == self.value,
+            BodyPartSelectorKind::Unknown => false,
+        }
+    }
+}
+
+/// Where a set of tissue layers goes relative to the layers a body part already has.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum LayerPlacement {
+    /// `BP_LAYERS`: added outside the existing layers
+    #[default]
+    Layers,
+    /// `BP_LAYERS_OVER`: added outside the existing layers
+    Over,
+    /// `BP_LAYERS_UNDER`: added inside the existing layers
+    Under,
+}
+
+impl LayerPlacement {
+    pub fn is_default(&self) -> bool {
+        self == &Self::Layers
+    }
+}
+
+/// One tissue layer of a body part.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct TissueLayer {
+    /// The creature's name for the tissue, e.g. `SKIN`
+    tissue: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    thickness: u32,
+}
+
+impl TissueLayer {
+    pub fn new(tissue: &str, thickness: u32) -> Self {
+        Self {
+            tissue: String::from(tissue),
+            thickness,
+        }
+    }
+    pub fn get_tissue(&self) -> &str {
+        &self.tissue
+    }
+    pub fn get_thickness(&self) -> u32 {
+        self.thickness
+    }
+}
+
+/// Tissue layers for the selected body parts, from a `BP_LAYERS` token (or `BP_LAYERS_OVER` and
+/// `BP_LAYERS_UNDER`). The layers are listed from the innermost out.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BodyPartLayers {
+    selector: BodyPartSelector,
+    #[serde(skip_serializing_if = "LayerPlacement::is_default")]
+    placement: LayerPlacement,
+    layers: Vec<TissueLayer>,
+    /// Where a `TISSUE_LAYER` puts the tissue on the body parts, e.g. `FRONT`
+    #[serde(skip_serializing_if = "String::is_empty")]
+    position: String,
+}
+
+impl BodyPartLayers {
+    /// Create the layers from the value of a `BP_LAYERS` token.
+    ///
+    /// Arguments:
+    ///
+    /// * `value`: The value of the token, e.g. `BY_CATEGORY:BODY_UPPER:ARG3:50:ARG2:5:ARG1:1`
+    /// * `placement`: Where the layers go relative to the existing layers
+    pub fn from_value(value: &str, placement: LayerPlacement) -> Self {
+        let parts: Vec<&str> = value.split(':').collect();
+        let layers = parts
+            .get(2..)
+            .unwrap_or_default()
+            .chunks(2)
+            .map(|pair| {
+                TissueLayer::new(
+                    pair[0],
+                    pair.get(1).unwrap_or(&"").parse().unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        Self {
+            selector: BodyPartSelector::from_parts(
+                parts.first().unwrap_or(&""),
+                parts.get(1).unwrap_or(&""),
+            ),
+            placement,
+            layers,
+            position: String::new(),
+        }
+    }
+    /// Create the layers from the value of a creature's `TISSUE_LAYER` token, which adds a single
+    /// tissue over the selected body parts. The thickness is left at 0, to be taken from the tissue.
+    ///
+    /// Arguments:
+    ///
+    /// * `value`: The value of the token, e.g. `BY_CATEGORY:HEAD:HAIR:FRONT`
+    pub fn from_tissue_layer_value(value: &str) -> Self {
+        let parts: Vec<&str> = value.split(':').collect();
+        Self {
+            selector: BodyPartSelector::from_parts(
+                parts.first().unwrap_or(&""),
+                parts.get(1).unwrap_or(&""),
+            ),
+            placement: LayerPlacement::Over,
+            layers: vec![TissueLayer::new(parts.get(2).unwrap_or(&""), 0)],
+            position: parts.get(3..).unwrap_or_default().join(":"),
+        }
+    }
+    pub fn get_selector(&self) -> &BodyPartSelector {
+        &self.selector
+    }
+    pub fn get_placement(&self) -> &LayerPlacement {
+        &self.placement
+    }
+    pub fn get_layers(&self) -> &[TissueLayer] {
+        self.layers.as_slice()
+    }
+    pub fn get_position(&self) -> &str {
+        &self.position
+    }
+    /// Returns a copy of these layers with any `ARGn` tissues replaced by the plan's arguments.
+    #[must_use]
+    pub fn with_arguments(&self, arguments: &[&str]) -> Self {
+        let mut layers = self.clone();
+        for layer in &mut layers.layers {
+            layer.tissue = replace_argument(&layer.tissue, arguments);
+        }
+        layers
+    }
+}
+
+/// The relative size of the selected body parts, from a `BP_RELSIZE` token.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BodyPartRelativeSize {
+    selector: BodyPartSelector,
+    size: u32,
+}
+
+impl BodyPartRelativeSize {
+    /// Create the relative size from the value of a `BP_RELSIZE` token, e.g. `BY_CATEGORY:EYE:20`.
+    pub fn from_value(value: &str) -> Self {
+        let parts: Vec<&str> = value.split(':').collect();
+        Self {
+            selector: BodyPartSelector::from_parts(
+                parts.first().unwrap_or(&""),
+                parts.get(1).unwrap_or(&""),
+            ),
+            size: parts.get(2).unwrap_or(&"").parse().unwrap_or_default(),
+        }
+    }
+    pub fn get_selector(&self) -> &BodyPartSelector {
+        &self.selector
+    }
+    pub fn get_size(&self) -> u32 {
+        self.size
+    }
+}
+
+/// Replace a body detail plan argument (`ARG1`, `ARG2`, ...) with the matching argument from the
+/// creature's `BODY_DETAIL_PLAN` token. Anything else is returned as it is.
+pub fn replace_argument(value: &str, arguments: &[&str]) -> String {
+    let Some(index) = value
+        .strip_prefix("ARG")
+        .and_then(|n| n.parse::<usize>().ok())
+    else {
+        return String::from(value);
+    };
+    String::from(
+        *index
+            .checked_sub(1)
+            .and_then(|i| arguments.get(i))
+            .unwrap_or(&""),
+    )
+}
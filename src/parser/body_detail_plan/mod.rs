@@ -0,0 +1,4 @@
+pub mod layers;
+pub mod phf_table;
+pub mod raw;
+pub mod tokens;
@@ -0,0 +1,18 @@
+use super::{layers::BodyPartSelectorKind, tokens::BodyDetailPlanToken};
+
+pub static BODY_DETAIL_PLAN_TOKENS: phf::Map<&'static str, BodyDetailPlanToken> = phf::phf_map! {
+    "ADD_MATERIAL" => BodyDetailPlanToken::AddMaterial,
+    "ADD_TISSUE" => BodyDetailPlanToken::AddTissue,
+    "BP_LAYERS" => BodyDetailPlanToken::Layers,
+    "BP_LAYERS_OVER" => BodyDetailPlanToken::LayersOver,
+    "BP_LAYERS_UNDER" => BodyDetailPlanToken::LayersUnder,
+    "BP_POSITION" => BodyDetailPlanToken::Position,
+    "BP_RELATION" => BodyDetailPlanToken::Relation,
+    "BP_RELSIZE" => BodyDetailPlanToken::RelativeSize,
+};
+
+pub static BODY_PART_SELECTOR_TOKENS: phf::Map<&'static str, BodyPartSelectorKind> = phf::phf_map! {
+    "BY_CATEGORY" => BodyPartSelectorKind::Category,
+    "BY_TYPE" => BodyPartSelectorKind::Type,
+    "BY_TOKEN" => BodyPartSelectorKind::Token,
+};
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+    tissue_template::template_use::TemplateUse,
+};
+
+use super::{
+    layers::{BodyPartLayers, BodyPartRelativeSize, LayerPlacement},
+    phf_table::BODY_DETAIL_PLAN_TOKENS,
+    tokens::BodyDetailPlanToken,
+};
+
+/// A body detail plan from an `[OBJECT:BODY_DETAIL_PLAN]` raw file, e.g.
+/// `[BODY_DETAIL_PLAN:VERTEBRATE_TISSUE_LAYERS]`.
+///
+/// Creatures use a plan with `[BODY_DETAIL_PLAN:VERTEBRATE_TISSUE_LAYERS:SKIN:FAT:MUSCLE:BONE:CARTILAGE]`,
+/// where the arguments after the identifier replace `ARG1`, `ARG2`, etc. in the plan's tokens.
+/// The plan is kept with the placeholders; they are replaced when building a body part tree.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BodyDetailPlan {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    materials: Vec<TemplateUse>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tissues: Vec<TemplateUse>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    layers: Vec<BodyPartLayers>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    relative_sizes: Vec<BodyPartRelativeSize>,
+    /// The `BP_POSITION` values, e.g. `BY_TOKEN:REYE:FRONT`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    positions: Vec<String>,
+    /// The `BP_RELATION` values, e.g. `BY_CATEGORY:EYELID:AROUND:BY_CATEGORY:EYE:50`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    relations: Vec<String>,
+}
+
+impl BodyDetailPlan {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(
+                metadata,
+                identifier,
+                &ObjectType::BodyDetailPlan,
+            ),
+            ..Self::default()
+        }
+    }
+    pub fn get_materials(&self) -> &[TemplateUse] {
+        self.materials.as_slice()
+    }
+    pub fn get_tissues(&self) -> &[TemplateUse] {
+        self.tissues.as_slice()
+    }
+    pub fn get_layers(&self) -> &[BodyPartLayers] {
+        self.layers.as_slice()
+    }
+    pub fn get_relative_sizes(&self) -> &[BodyPartRelativeSize] {
+        self.relative_sizes.as_slice()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for BodyDetailPlan {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::BodyDetailPlan
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = BODY_DETAIL_PLAN_TOKENS.get(key) else {
+            log::debug!(
+                "BodyDetailPlan::parse_tag: Unknown body detail plan tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown body detail plan tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            BodyDetailPlanToken::AddMaterial => {
+                self.materials.push(TemplateUse::from_value(value));
+            }
+            BodyDetailPlanToken::AddTissue => {
+                self.tissues.push(TemplateUse::from_value(value));
+            }
+            BodyDetailPlanToken::Layers => {
+                self.layers
+                    .push(BodyPartLayers::from_value(value, LayerPlacement::Layers));
+            }
+            BodyDetailPlanToken::LayersOver => {
+                self.layers
+                    .push(BodyPartLayers::from_value(value, LayerPlacement::Over));
+            }
+            BodyDetailPlanToken::LayersUnder => {
+                self.layers
+                    .push(BodyPartLayers::from_value(value, LayerPlacement::Under));
+            }
+            BodyDetailPlanToken::RelativeSize => {
+                self.relative_sizes
+                    .push(BodyPartRelativeSize::from_value(value));
+            }
+            BodyDetailPlanToken::Position => {
+                self.positions.push(String::from(value));
+            }
+            BodyDetailPlanToken::Relation => {
+                self.relations.push(String::from(value));
+            }
+            BodyDetailPlanToken::Unknown => {}
+        }
+    }
+}
+
+impl Searchable for BodyDetailPlan {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.extend(self.materials.iter().map(|m| String::from(m.get_name())));
+        vec.extend(self.tissues.iter().map(|t| String::from(t.get_name())));
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum BodyDetailPlanToken {
+    /// Arguments: name, material template
+    ///
+    /// Adds a material made from a template to the creature, e.g. `[ADD_MATERIAL:SKIN:SKIN_TEMPLATE]`.
+    AddMaterial,
+    /// Arguments: name, tissue template
+    ///
+    /// Adds a tissue made from a template to the creature, e.g. `[ADD_TISSUE:SKIN:SKIN_TEMPLATE]`.
+    AddTissue,
+    /// Arguments: selector, then pairs of tissue and relative thickness
+    ///
+    /// Sets the tissue layers of the selected body parts, from the innermost layer out, e.g.
+    /// `[BP_LAYERS:BY_CATEGORY:BODY_UPPER:ARG3:50:ARG2:5:ARG1:1]`.
+    Layers,
+    /// Arguments: selector, then pairs of tissue and relative thickness
+    ///
+    /// Adds tissue layers outside the existing layers of the selected body parts.
+    LayersOver,
+    /// Arguments: selector, then pairs of tissue and relative thickness
+    ///
+    /// Adds tissue layers inside the existing layers of the selected body parts.
+    LayersUnder,
+    /// Arguments: selector, position
+    ///
+    /// Sets the position of the selected body parts, e.g. `[BP_POSITION:BY_TOKEN:REYE:FRONT]`.
+    Position,
+    /// Arguments: selector, relation, selector, coverage
+    ///
+    /// Sets how the selected body parts relate to other body parts, e.g. eyelids covering eyes.
+    Relation,
+    /// Arguments: selector, relative size
+    ///
+    /// Sets the relative size of the selected body parts.
+    RelativeSize,
+    #[default]
+    Unknown,
+}
@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct BodySize {
     years: u32,
     days: u32,
@@ -22,4 +23,8 @@ impl BodySize {
         }
         BodySize::default()
     }
+    /// The value of a `BODY_SIZE` token, e.g. `0:0:30000` (the reverse of `from_value`).
+    pub fn to_value(&self) -> String {
+        format!("{}:{}:{}", self.years, self.days, self.size_cm3)
+    }
 }
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    material::{phf_table::MATERIAL_USAGE_TOKENS, raw::Material, tokens::MaterialUsage},
+    reaction::reagent::parse_item_and_material,
+};
+
+use super::{phf_table::BUILD_ITEM_TOKENS, tokens::BuildItemToken};
+
+/// An item needed to build a building, from a token like `[BUILD_ITEM:1:BUCKET:NONE:NONE:NONE]`
+/// and the tokens which follow it.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BuildItem {
+    quantity: u32,
+    /// The item type, e.g. `BUCKET`. Empty if any item can be used.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    item_type: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    item_subtype: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    material: Option<Material>,
+    /// Material flags the item's material must have, e.g. `[IS_STONE]`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    material_usage: Vec<MaterialUsage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<BuildItemToken>,
+}
+
+impl BuildItem {
+    /// Create a build item from the value of a `BUILD_ITEM` token.
+    ///
+    /// Arguments:
+    ///
+    /// * `value`: The value of the token, e.g. `1:BUCKET:NONE:NONE:NONE`
+    pub fn from_value(value: &str) -> Self {
+        let parts: Vec<&str> = value.split(':').collect();
+        let (item_type, item_subtype, material) =
+            parse_item_and_material(parts.get(1..).unwrap_or_default());
+
+        Self {
+            quantity: parts.first().unwrap_or(&"").parse().unwrap_or_default(),
+            item_type,
+            item_subtype,
+            material,
+            ..Self::default()
+        }
+    }
+    /// Returns true if the key is a token which can follow a `BUILD_ITEM`.
+    pub fn is_build_item_token(key: &str) -> bool {
+        BUILD_ITEM_TOKENS.contains_key(key) || MATERIAL_USAGE_TOKENS.contains_key(key)
+    }
+    pub fn get_item_type(&self) -> &str {
+        &self.item_type
+    }
+    pub fn parse_tag(&mut self, key: &str) {
+        if let Some(usage) = MATERIAL_USAGE_TOKENS.get(key) {
+            self.material_usage.push(usage.clone());
+            return;
+        }
+
+        let Some(tag) = BUILD_ITEM_TOKENS.get(key) else {
+            log::debug!("BuildItem::parse_tag: Unknown build item tag {key}");
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown build item tag {key}"),
+            );
+            return;
+        };
+        self.tags.push(tag.clone());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod build_item;
+pub mod phf_table;
+pub mod raw;
+pub mod stage;
+pub mod tokens;
@@ -0,0 +1,26 @@
+use super::tokens::{BuildItemToken, BuildingToken};
+
+pub static BUILDING_TOKENS: phf::Map<&'static str, BuildingToken> = phf::phf_map! {
+    "NAME" => BuildingToken::Name,
+    "NAME_COLOR" => BuildingToken::NameColor,
+    "DIM" => BuildingToken::Dim,
+    "WORK_LOCATION" => BuildingToken::WorkLocation,
+    "BUILD_LABOR" => BuildingToken::BuildLabor,
+    "BUILD_KEY" => BuildingToken::BuildKey,
+    "BUILD_STAGES" => BuildingToken::BuildStages,
+    "BLOCK" => BuildingToken::Block,
+    "TILE" => BuildingToken::Tile,
+    "COLOR" => BuildingToken::Color,
+    "BUILD_ITEM" => BuildingToken::BuildItem,
+    "NEEDS_MAGMA" => BuildingToken::NeedsMagma,
+};
+
+pub static BUILD_ITEM_TOKENS: phf::Map<&'static str, BuildItemToken> = phf::phf_map! {
+    "BUILDMAT" => BuildItemToken::BuildMat,
+    "WORTHLESS_STONE_ONLY" => BuildItemToken::WorthlessStoneOnly,
+    "FIRE_BUILD_SAFE" => BuildItemToken::FireBuildSafe,
+    "MAGMA_BUILD_SAFE" => BuildItemToken::MagmaBuildSafe,
+    "CAN_USE_ARTIFACT" => BuildItemToken::CanUseArtifact,
+    "EMPTY" => BuildItemToken::Empty,
+    "NOT_IMPROVED" => BuildItemToken::NotImproved,
+};
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    color::Color,
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{
+    build_item::BuildItem, phf_table::BUILDING_TOKENS, stage::BuildingStage, tokens::BuildingToken,
+};
+
+/// The most rows a building can have (its `DIM` is at most 31x31). `BLOCK`, `TILE` and `COLOR`
+/// rows past this are ignored, rather than growing the matrices to whatever row a raw asks for.
+const MAX_ROWS: usize = 31;
+
+/// A custom building from an `[OBJECT:BUILDING]` raw file, e.g. `[BUILDING_WORKSHOP:SOAP_MAKER]`.
+///
+/// Workshops and furnaces share this struct. The kind is kept in `building_type`
+/// (`BuildingWorkshop` or `BuildingFurnace`), which is also what `get_type` returns.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::struct_field_names)]
+#[serde(default)]
+pub struct Building {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+    building_type: ObjectType,
+
+    #[serde(skip_serializing_if = "String::is_empty")]
+    name: String,
+    #[serde(skip_serializing_if = "Color::is_default")]
+    name_color: Color,
+    /// The width and height of the building, in tiles.
+    dimensions: [u32; 2],
+    /// The x and y of the tile the worker uses, starting at 1:1. 0:0 if there is none.
+    work_location: [u32; 2],
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    build_labors: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    build_key: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    build_stages: u32,
+
+    /// Which tiles block movement, as a list of rows (from the top).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    blocks: Vec<Vec<bool>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stages: Vec<BuildingStage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    build_items: Vec<BuildItem>,
+
+    /// The object IDs of the `CUSTOM_WORKSHOP_GRAPHICS` for this building.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    graphics: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<BuildingToken>,
+}
+
+impl Building {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    /// Create a new building.
+    ///
+    /// Arguments:
+    ///
+    /// * `identifier`: The identifier of the building, e.g. `SOAP_MAKER`
+    /// * `metadata`: The metadata of the raw file
+    /// * `building_type`: The kind of building, `BuildingWorkshop` or `BuildingFurnace`
+    pub fn new(identifier: &str, metadata: &RawMetadata, building_type: ObjectType) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &building_type),
+            building_type,
+            ..Self::default()
+        }
+    }
+    pub fn get_stages(&self) -> &[BuildingStage] {
+        self.stages.as_slice()
+    }
+    pub fn get_build_items(&self) -> &[BuildItem] {
+        self.build_items.as_slice()
+    }
+    pub fn get_graphics(&self) -> &[String] {
+        self.graphics.as_slice()
+    }
+    /// Link a `CUSTOM_WORKSHOP_GRAPHICS` graphic to this building.
+    ///
+    /// Arguments:
+    ///
+    /// * `object_id`: The object ID of the graphic
+    pub fn add_graphic(&mut self, object_id: &str) {
+        if !self.graphics.iter().any(|id| id == object_id) {
+            self.graphics.push(String::from(object_id));
+        }
+    }
+    /// Get the stage with the given number, adding it if it doesn't exist yet.
+    fn stage_mut(&mut self, stage: u32) -> &mut BuildingStage {
+        if let Some(index) = self.stages.iter().position(|s| s.get_stage() == stage) {
+            return &mut self.stages[index];
+        }
+        self.stages.push(BuildingStage::new(stage));
+        let index = self.stages.len() - 1;
+        &mut self.stages[index]
+    }
+}
+
+/// Check the row number of a `BLOCK`, `TILE` or `COLOR` token, reporting rows which are out of
+/// range.
+fn is_valid_row(row: usize) -> bool {
+    if (1..=MAX_ROWS).contains(&row) {
+        return true;
+    }
+    diagnostics::report(
+        DiagnosticSeverity::Warning,
+        &format!("Building row {row} is out of range (1 to {MAX_ROWS})"),
+    );
+    false
+}
+
+/// Parse a value like `3:3` into a pair of numbers.
+fn parse_pair(value: &str) -> [u32; 2] {
+    let mut split = value.split(':');
+    [
+        split.next().unwrap_or_default().parse().unwrap_or_default(),
+        split.next().unwrap_or_default().parse().unwrap_or_default(),
+    ]
+}
+
+#[typetag::serde]
+impl RawObject for Building {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        if self.name.is_empty() {
+            return &self.identifier;
+        }
+        &self.name
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &self.building_type
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = BUILDING_TOKENS.get(key) else {
+            // Anything else belongs to the last build item
+            if BuildItem::is_build_item_token(key) {
+                if let Some(build_item) = self.build_items.last_mut() {
+                    build_item.parse_tag(key);
+                    return;
+                }
+            }
+            log::debug!(
+                "Building::parse_tag: Unknown building tag {} in {}",
+                key,
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown building tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            BuildingToken::Name => {
+                self.name = String::from(value);
+            }
+            BuildingToken::NameColor => {
+                self.name_color = Color::from_value(value);
+            }
+            BuildingToken::Dim => {
+                self.dimensions = parse_pair(value);
+            }
+            BuildingToken::WorkLocation => {
+                self.work_location = parse_pair(value);
+            }
+            BuildingToken::BuildLabor => {
+                self.build_labors.push(String::from(value));
+            }
+            BuildingToken::BuildKey => {
+                self.build_key = String::from(value);
+            }
+            BuildingToken::BuildStages => {
+                self.build_stages = value.parse().unwrap_or_default();
+            }
+            BuildingToken::Block => {
+                // [BLOCK:row:blocked:blocked:...]
+                let mut split = value.split(':');
+                let row: usize = split.next().unwrap_or_default().parse().unwrap_or_default();
+                if !is_valid_row(row) {
+                    return;
+                }
+                let cells: Vec<bool> = split.map(|cell| cell == "1").collect();
+                let index = row.saturating_sub(1);
+                if self.blocks.len() <= index {
+                    self.blocks.resize(index + 1, Vec::new());
+                }
+                self.blocks[index] = cells;
+            }
+            BuildingToken::Tile | BuildingToken::Color => {
+                // [TILE:stage:row:cell:cell:...] and [COLOR:stage:row:cell:cell:...]
+                let parts: Vec<&str> = value.split(':').collect();
+                let stage: u32 = parts.first().unwrap_or(&"").parse().unwrap_or_default();
+                let row: usize = parts.get(1).unwrap_or(&"").parse().unwrap_or_default();
+                if !is_valid_row(row) {
+                    return;
+                }
+                let cells = parts.get(2..).unwrap_or_default();
+                if tag == &BuildingToken::Tile {
+                    self.stage_mut(stage).set_tile_row(row, cells);
+                } else {
+                    self.stage_mut(stage).set_color_row(row, cells);
+                }
+            }
+            BuildingToken::BuildItem => {
+                self.build_items.push(BuildItem::from_value(value));
+            }
+            _ => {
+                self.tags.push(tag.clone());
+            }
+        }
+    }
+}
+
+impl Searchable for Building {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.push(self.name.clone());
+        vec.push(format!("{:?}", self.building_type));
+        vec.extend(self.build_labors.iter().cloned());
+        vec.extend(
+            self.build_items
+                .iter()
+                .map(|item| String::from(item.get_item_type())),
+        );
+        vec.extend(self.tags.iter().map(|tag| format!("{tag:?}")));
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum BuildingToken {
+    /// Arguments: name
+    ///
+    /// The name of the building.
+    Name,
+    /// Arguments: color
+    ///
+    /// The color of the building's name.
+    NameColor,
+    /// Arguments: width, height
+    ///
+    /// The size of the building in tiles. The maximum is 31x31.
+    Dim,
+    /// Arguments: x, y
+    ///
+    /// The tile where the worker stands, starting at 1:1 in the top left. 0:0 means the worker can't use the building.
+    WorkLocation,
+    /// Arguments: labor
+    ///
+    /// A labor which can build the building. `NONE` allows any dwarf to build it.
+    BuildLabor,
+    /// Arguments: key
+    ///
+    /// The hotkey used to build the building, e.g. `CUSTOM_SHIFT_S`.
+    BuildKey,
+    /// Arguments: number of stages
+    ///
+    /// The number of construction stages the building goes through.
+    BuildStages,
+    /// Arguments: row, blocked (0 or 1) for each tile in the row
+    ///
+    /// Which tiles of the building can't be walked through.
+    Block,
+    /// Arguments: stage, row, tile for each tile in the row
+    ///
+    /// The tiles shown for a row of the building at a construction stage. Stage 3 is the finished building.
+    Tile,
+    /// Arguments: stage, row, color (or `MAT`) for each tile in the row
+    ///
+    /// The colors of a row of the building at a construction stage. `MAT` uses the color of the building material.
+    Color,
+    /// Arguments: quantity, item token, material token
+    ///
+    /// An item needed to build the building, e.g. `[BUILD_ITEM:1:BUCKET:NONE:NONE:NONE]`. The tokens which follow it apply to this item.
+    BuildItem,
+    /// The building must be built over magma.
+    NeedsMagma,
+    /// An unknown token.
+    #[default]
+    Unknown,
+}
+
+/// Tokens which follow a `BUILD_ITEM` and apply to it. Material flags (e.g. `[IS_STONE]`) are
+/// parsed as `MaterialUsage` instead.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum BuildItemToken {
+    /// The item must be a building material (e.g. a boulder, log or block).
+    BuildMat,
+    /// The item must be made of stone which has no other use.
+    WorthlessStoneOnly,
+    /// The item must be made of a material which won't melt in a fire.
+    FireBuildSafe,
+    /// The item must be made of a material which won't melt in magma.
+    MagmaBuildSafe,
+    /// The item can be an artifact.
+    CanUseArtifact,
+    /// The item must be empty.
+    Empty,
+    /// The item must not be improved.
+    NotImproved,
+    /// An unknown token.
+    #[default]
+    Unknown,
+}
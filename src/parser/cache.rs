@@ -0,0 +1,361 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, BufWriter, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::UNIX_EPOCH,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ParserError, options::ParserOptions};
+
+use super::{
+    diagnostics::{self, Diagnostic, ParseReport},
+    module_info_file::ModuleInfoFile,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+};
+
+/// The raws parsed from a single raw file, and what the file looked like when it was parsed.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+struct CachedRawFile {
+    /// The size of the file in bytes.
+    size: u64,
+    /// The time the file was last modified, in nanoseconds since the unix epoch.
+    modified: u64,
+    /// The hash of the contents of the file (see `hash_contents`).
+    hash: u64,
+    /// The module the file was parsed as part of (see `module_key`).
+    module: String,
+    /// The type of object in the file, from its `[OBJECT:...]` token.
+    object_type: ObjectType,
+    /// The identifier of the file, from its first line.
+    raw_identifier: String,
+    /// The parsed raws, serialized to JSON (without their metadata).
+    raws: Vec<serde_json::Value>,
+    /// The diagnostics reported while parsing the file.
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// The contents of the cache file.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+struct CacheContents {
+    /// The version of the library which wrote the cache. A cache from another version is ignored.
+    parser_version: String,
+    /// The options the raws were parsed with (see `options_key`). If they change, the cache is
+    /// ignored.
+    options: String,
+    /// The cached raw files, by path.
+    files: BTreeMap<String, CachedRawFile>,
+}
+
+/// An on-disk cache of the raws parsed from each raw file, so that only the raw files which have
+/// changed since the last parse need to be parsed again.
+///
+/// Each raw file is cached by its path, and is re-used while its size and modification time (or, if
+/// only the modification time changed, the hash of its contents) are the same, and it belongs to the
+/// same version of the same module. The `SELECT_*`, `CUT_*` and template steps are not cached, and
+/// run on all the raws after every parse.
+///
+/// The cache is used when `ParserOptions::cache_path` is set.
+#[derive(Debug)]
+pub(crate) struct RawFileCache {
+    path: PathBuf,
+    contents: Mutex<CacheContents>,
+}
+
+impl RawFileCache {
+    /// Read the cache from `cache_path`. If there is no cache there, or it can't be used with the
+    /// `options` (or this version of the library), the cache starts out empty.
+    pub(crate) fn load(cache_path: &Path, options: &ParserOptions) -> Self {
+        let empty = CacheContents {
+            parser_version: String::from(env!("CARGO_PKG_VERSION")),
+            options: options_key(options),
+            files: BTreeMap::new(),
+        };
+
+        let contents = match read_cache_file(cache_path) {
+            Ok(Some(contents))
+                if contents.parser_version == empty.parser_version
+                    && contents.options == empty.options =>
+            {
+                log::info!(
+                    "RawFileCache::load: {} cached raw files in {}",
+                    contents.files.len(),
+                    cache_path.display()
+                );
+                contents
+            }
+            Ok(Some(_)) => {
+                log::info!(
+                    "RawFileCache::load: cache in {} is for other options, ignoring it",
+                    cache_path.display()
+                );
+                empty
+            }
+            Ok(None) => empty,
+            Err(e) => {
+                log::warn!("RawFileCache::load: ignoring cache: {e}");
+                empty
+            }
+        };
+
+        Self {
+            path: cache_path.to_path_buf(),
+            contents: Mutex::new(contents),
+        }
+    }
+
+    /// Write the cache back to its file. Raw files which no longer exist are dropped from the cache.
+    ///
+    /// # Errors
+    ///
+    /// * `ParserError::Io` if the cache file can't be written
+    /// * `ParserError::Serialization` if the cache can't be serialized
+    pub(crate) fn save(&self) -> Result<(), ParserError> {
+        let mut contents = self.lock();
+        contents.files.retain(|path, _| Path::new(path).exists());
+
+        let file = File::create(&self.path).map_err(|source| ParserError::Io {
+            path: self.path.clone(),
+            source,
+        })?;
+        serde_json::to_writer(BufWriter::new(file), &*contents)?;
+
+        log::info!(
+            "RawFileCache::save: saved {} raw files to {}",
+            contents.files.len(),
+            self.path.display()
+        );
+        Ok(())
+    }
+
+    /// Get the raws from a raw file, from the cache if the file hasn't changed, or by parsing it
+    /// (and caching the result) if it has. The diagnostics for the file are reported either way.
+    pub(crate) fn parse_raw_file(
+        &self,
+        raw_file_path: &Path,
+        module_info_file: &ModuleInfoFile,
+        options: &ParserOptions,
+    ) -> Vec<Box<dyn RawObject>> {
+        let key = raw_file_path.display().to_string();
+        let module = module_key(module_info_file);
+        let Some((size, modified)) = file_stamp(raw_file_path) else {
+            // Let the parser report why the file can't be read
+            return super::parse_raws_from_single_file_with_info(
+                &raw_file_path,
+                module_info_file,
+                options,
+            );
+        };
+
+        if let Some(cached) = self.find(&key, &module, size, modified, raw_file_path) {
+            if let Some(raws) = restore_raws(&cached, raw_file_path, module_info_file, options) {
+                log::debug!("RawFileCache: using cached raws for {key}");
+                diagnostics::merge(ParseReport::new(cached.diagnostics));
+                return raws;
+            }
+        }
+
+        let (raws, report) = diagnostics::capture(|| {
+            super::parse_raws_from_single_file_with_info(&raw_file_path, module_info_file, options)
+        });
+
+        match cache_raws(&raws, &report, size, modified, module, raw_file_path) {
+            Ok(cached) => {
+                self.lock().files.insert(key, cached);
+            }
+            Err(e) => log::warn!("RawFileCache: unable to cache {key}: {e}"),
+        }
+
+        diagnostics::merge(report);
+        raws
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheContents> {
+        self.contents
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Find the cached raws for the file, if the file hasn't changed since they were cached.
+    fn find(
+        &self,
+        key: &str,
+        module: &str,
+        size: u64,
+        modified: u64,
+        raw_file_path: &Path,
+    ) -> Option<CachedRawFile> {
+        let cached_hash = {
+            let contents = self.lock();
+            let cached = contents.files.get(key)?;
+            if cached.module != module || cached.size != size {
+                return None;
+            }
+            if cached.modified == modified {
+                return Some(cached.clone());
+            }
+            cached.hash
+        };
+
+        // Only the modification time changed, so check whether the contents did too
+        let hash = hash_contents(raw_file_path).ok()?;
+        if hash != cached_hash {
+            return None;
+        }
+        let mut contents = self.lock();
+        let cached = contents.files.get_mut(key)?;
+        cached.modified = modified;
+        Some(cached.clone())
+    }
+}
+
+/// Read the cache file, or `None` if there isn't one.
+fn read_cache_file(cache_path: &Path) -> Result<Option<CacheContents>, ParserError> {
+    if !cache_path.exists() {
+        return Ok(None);
+    }
+    let file = File::open(cache_path).map_err(|source| ParserError::Io {
+        path: cache_path.to_path_buf(),
+        source,
+    })?;
+    Ok(Some(serde_json::from_reader(BufReader::new(file))?))
+}
+
+/// The options which change what is parsed from a raw file. Parsing a raw file only uses
+/// `raws_to_parse` and `attach_metadata_to_raws`, and the metadata is rebuilt when the cached raws
+/// are restored (see `restore_raws`). The rest of the options are used to find the modules or by the
+/// steps after parsing, which aren't cached.
+fn options_key(options: &ParserOptions) -> String {
+    serde_json::to_string(&options.raws_to_parse).unwrap_or_default()
+}
+
+/// The module a raw file belongs to. The object ids of the raws depend on it, so the cached raws
+/// can only be used for the same version of the same module.
+fn module_key(module_info_file: &ModuleInfoFile) -> String {
+    format!(
+        "{:?}/{}/{}/{}",
+        module_info_file.get_location(),
+        module_info_file.get_identifier(),
+        module_info_file.get_name(),
+        module_info_file.get_version()
+    )
+}
+
+/// The size and modification time (in nanoseconds since the unix epoch) of a file.
+fn file_stamp(path: &Path) -> Option<(u64, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_nanos();
+    Some((metadata.len(), u64::try_from(modified).unwrap_or(u64::MAX)))
+}
+
+/// A 64-bit FNV-1a hash of the contents of a file. This is stable between runs (and versions of
+/// rust), unlike the hasher in the standard library.
+fn hash_contents(path: &Path) -> std::io::Result<u64> {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut bytes = Vec::new();
+    File::open(path)?.read_to_end(&mut bytes)?;
+    Ok(bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    }))
+}
+
+/// Serialize the raws parsed from a file so they can be cached.
+fn cache_raws(
+    raws: &[Box<dyn RawObject>],
+    report: &ParseReport,
+    size: u64,
+    modified: u64,
+    module: String,
+    raw_file_path: &Path,
+) -> Result<CachedRawFile, ParserError> {
+    let hash = hash_contents(raw_file_path).map_err(|source| ParserError::Io {
+        path: raw_file_path.to_path_buf(),
+        source,
+    })?;
+    // All the raws in a file share the file's metadata, which is rebuilt when they are restored
+    let (object_type, raw_identifier) = raws.first().map_or_else(
+        || (ObjectType::Unknown, String::new()),
+        |raw| {
+            let metadata = raw.get_metadata();
+            (
+                metadata.get_object_type().clone(),
+                String::from(metadata.get_raw_identifier()),
+            )
+        },
+    );
+
+    let mut serialized_raws = Vec::with_capacity(raws.len());
+    for raw in raws {
+        let mut value = serde_json::to_value(raw)?;
+        if let Some(object) = value.as_object_mut() {
+            object.remove("metadata");
+        }
+        serialized_raws.push(value);
+    }
+
+    Ok(CachedRawFile {
+        size,
+        modified,
+        hash,
+        module,
+        object_type,
+        raw_identifier,
+        raws: serialized_raws,
+        diagnostics: report.get_diagnostics().to_vec(),
+    })
+}
+
+/// Deserialize the cached raws, with metadata for the module and options they're restored for
+/// (which may have a different load order, or a different `attach_metadata_to_raws`). Returns
+/// `None` if any of the raws can't be deserialized.
+fn restore_raws(
+    cached: &CachedRawFile,
+    raw_file_path: &Path,
+    module_info_file: &ModuleInfoFile,
+    options: &ParserOptions,
+) -> Option<Vec<Box<dyn RawObject>>> {
+    let metadata = RawMetadata::new(
+        module_info_file,
+        &cached.object_type,
+        &cached.raw_identifier,
+        &raw_file_path,
+        options.attach_metadata_to_raws,
+    );
+    let mut metadata_value = serde_json::to_value(&metadata).ok()?;
+    // `hidden` is never serialized, but is read back if it is there
+    metadata_value
+        .as_object_mut()?
+        .insert(String::from("hidden"), metadata.is_hidden().into());
+
+    let mut raws: Vec<Box<dyn RawObject>> = Vec::with_capacity(cached.raws.len());
+    for value in &cached.raws {
+        let mut value = value.clone();
+        value
+            .as_object_mut()?
+            .insert(String::from("metadata"), metadata_value.clone());
+        match serde_json::from_value(value) {
+            Ok(raw) => raws.push(raw),
+            Err(e) => {
+                log::debug!(
+                    "RawFileCache: unable to restore cached raws for {}: {e}",
+                    raw_file_path.display()
+                );
+                return None;
+            }
+        }
+    }
+    Some(raws)
+}
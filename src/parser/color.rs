@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Color {
     foreground: u8,
     background: u8,
@@ -33,6 +34,13 @@ impl Color {
         }
         Color::default()
     }
+    /// The value of a token for the color, e.g. `6:0:1` (the reverse of `from_value`).
+    pub fn to_value(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.foreground, self.background, self.brightness
+        )
+    }
     pub fn is_default(&self) -> bool {
         self.foreground == 0 && self.background == 0 && self.brightness == 0
     }
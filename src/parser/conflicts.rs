@@ -0,0 +1,459 @@
+use std::{collections::HashMap, hash::Hash};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    module_info_file::ModuleInfoFile,
+    object_types::ObjectType,
+    raw_locations::RawModuleLocation,
+    raws::{RawMetadata, RawObject},
+    select_creature::raw::SelectCreature,
+    select_object::raw::SelectObject,
+};
+
+/// A module, as it is named in a `ConflictReport`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleReference {
+    /// The module's identifier (from its info.txt). This is empty if the raws' module wasn't one of
+    /// the modules given to `find_conflicts`.
+    identifier: String,
+    name: String,
+    displayed_version: String,
+    location: RawModuleLocation,
+}
+
+impl ModuleReference {
+    fn from_module(module: &ModuleInfoFile) -> Self {
+        Self {
+            identifier: module.get_identifier(),
+            name: module.get_name(),
+            displayed_version: module.get_version(),
+            location: module.get_location(),
+        }
+    }
+    /// The module a raw is from. Raws only know the name, version and location of their module, so
+    /// the module is looked up in `modules` by those.
+    fn from_metadata(metadata: &RawMetadata, modules: &[ModuleInfoFile]) -> Self {
+        let found = modules.iter().find(|module| {
+            module.get_name() == metadata.get_module_name()
+                && module.get_version() == metadata.get_module_version()
+                && &module.get_location() == metadata.get_location()
+        });
+        found.map_or_else(
+            || Self {
+                identifier: String::new(),
+                name: String::from(metadata.get_module_name()),
+                displayed_version: String::from(metadata.get_module_version()),
+                location: *metadata.get_location(),
+            },
+            Self::from_module,
+        )
+    }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_displayed_version(&self) -> &str {
+        &self.displayed_version
+    }
+    pub fn get_location(&self) -> RawModuleLocation {
+        self.location
+    }
+}
+
+/// A module which declares (with `CONFLICTS_WITH_ID`) that it conflicts with another of the modules.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeclaredConflict {
+    module: ModuleReference,
+    conflicts_with: ModuleReference,
+}
+
+impl DeclaredConflict {
+    pub fn get_module(&self) -> &ModuleReference {
+        &self.module
+    }
+    pub fn get_conflicts_with(&self) -> &ModuleReference {
+        &self.conflicts_with
+    }
+}
+
+/// A module which requires (with `REQUIRES_ID`) a module which isn't one of the modules.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingRequirement {
+    module: ModuleReference,
+    /// The identifier of the required module
+    required_identifier: String,
+}
+
+impl MissingRequirement {
+    pub fn get_module(&self) -> &ModuleReference {
+        &self.module
+    }
+    pub fn get_required_identifier(&self) -> &str {
+        &self.required_identifier
+    }
+}
+
+/// Where an object (or a patch) in a `ConflictReport` is defined.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectSource {
+    module: ModuleReference,
+    object_id: String,
+    raw_file_path: String,
+}
+
+impl ObjectSource {
+    fn new(raw: &dyn RawObject, module: ModuleReference) -> Self {
+        Self {
+            module,
+            object_id: String::from(raw.get_object_id()),
+            raw_file_path: String::from(raw.get_metadata().get_raw_file_path()),
+        }
+    }
+    pub fn get_module(&self) -> &ModuleReference {
+        &self.module
+    }
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn get_raw_file_path(&self) -> &str {
+        &self.raw_file_path
+    }
+}
+
+/// An object which is defined (e.g. with `[CREATURE:DWARF]`) by more than one module.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateObject {
+    object_type: ObjectType,
+    identifier: String,
+    /// The definitions, in load order
+    definitions: Vec<ObjectSource>,
+}
+
+impl DuplicateObject {
+    pub fn get_object_type(&self) -> &ObjectType {
+        &self.object_type
+    }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn get_definitions(&self) -> &[ObjectSource] {
+        self.definitions.as_slice()
+    }
+}
+
+/// An object which is patched (with `SELECT_CREATURE`, `SELECT_PLANT` or `SELECT_INORGANIC`) by
+/// more than one module.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OverlappingPatch {
+    /// The type of the patched object, e.g. `Creature` for `SELECT_CREATURE`
+    target_type: ObjectType,
+    identifier: String,
+    /// The patches, in load order
+    patches: Vec<ObjectSource>,
+    /// The tokens which are set by patches from more than one module, e.g. `BODY_SIZE`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    shared_tokens: Vec<String>,
+}
+
+impl OverlappingPatch {
+    pub fn get_target_type(&self) -> &ObjectType {
+        &self.target_type
+    }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn get_patches(&self) -> &[ObjectSource] {
+        self.patches.as_slice()
+    }
+    pub fn get_shared_tokens(&self) -> &[String] {
+        self.shared_tokens.as_slice()
+    }
+}
+
+/// The conflicts between a set of modules found by `find_conflicts`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConflictReport {
+    declared_conflicts: Vec<DeclaredConflict>,
+    missing_requirements: Vec<MissingRequirement>,
+    duplicate_objects: Vec<DuplicateObject>,
+    overlapping_patches: Vec<OverlappingPatch>,
+}
+
+impl ConflictReport {
+    pub fn get_declared_conflicts(&self) -> &[DeclaredConflict] {
+        self.declared_conflicts.as_slice()
+    }
+    pub fn get_missing_requirements(&self) -> &[MissingRequirement] {
+        self.missing_requirements.as_slice()
+    }
+    pub fn get_duplicate_objects(&self) -> &[DuplicateObject] {
+        self.duplicate_objects.as_slice()
+    }
+    pub fn get_overlapping_patches(&self) -> &[OverlappingPatch] {
+        self.overlapping_patches.as_slice()
+    }
+    /// Returns true if no conflicts were found.
+    pub fn is_empty(&self) -> bool {
+        self.declared_conflicts.is_empty()
+            && self.missing_requirements.is_empty()
+            && self.duplicate_objects.is_empty()
+            && self.overlapping_patches.is_empty()
+    }
+}
+
+/// Find the conflicts between `modules`, which are parsed (or loaded) together:
+///
+/// * modules which declare a conflict (`CONFLICTS_WITH_ID`) with another of the modules
+/// * modules which require (`REQUIRES_ID`) a module which isn't one of the modules
+/// * objects of the same type with the same identifier defined by more than one module
+/// * objects patched with `SELECT_*` tokens by more than one module
+///
+/// The patches are removed from the raws when they are applied, so `raws` should be the raws as
+/// they are in the raw files (e.g. from `parse_iter`), not the raws returned by `parse`. Objects
+/// are only compared between modules: an object defined twice in the same module isn't reported.
+///
+/// Arguments:
+///
+/// * `modules`: The info.txt files of the modules
+/// * `raws`: The raws parsed from the modules, in load order
+///
+/// Returns:
+///
+/// The conflicts which were found.
+pub fn find_conflicts(modules: &[ModuleInfoFile], raws: &[Box<dyn RawObject>]) -> ConflictReport {
+    let report = ConflictReport {
+        declared_conflicts: find_declared_conflicts(modules),
+        missing_requirements: find_missing_requirements(modules),
+        duplicate_objects: find_duplicate_objects(modules, raws),
+        overlapping_patches: find_overlapping_patches(modules, raws),
+    };
+    log::info!(
+        "find_conflicts: {} declared conflicts, {} missing requirements, {} duplicate objects, {} overlapping patches",
+        report.declared_conflicts.len(),
+        report.missing_requirements.len(),
+        report.duplicate_objects.len(),
+        report.overlapping_patches.len()
+    );
+    report
+}
+
+fn find_declared_conflicts(modules: &[ModuleInfoFile]) -> Vec<DeclaredConflict> {
+    let mut conflicts = Vec::new();
+    for module in modules {
+        for identifier in module.get_conflicts_with_ids() {
+            for other in modules
+                .iter()
+                .filter(|other| &other.get_identifier() == identifier)
+            {
+                conflicts.push(DeclaredConflict {
+                    module: ModuleReference::from_module(module),
+                    conflicts_with: ModuleReference::from_module(other),
+                });
+            }
+        }
+    }
+    conflicts
+}
+
+fn find_missing_requirements(modules: &[ModuleInfoFile]) -> Vec<MissingRequirement> {
+    let mut missing = Vec::new();
+    for module in modules {
+        for identifier in module.get_requires_ids() {
+            if !modules.iter().any(|m| &m.get_identifier() == identifier) {
+                missing.push(MissingRequirement {
+                    module: ModuleReference::from_module(module),
+                    required_identifier: identifier.clone(),
+                });
+            }
+        }
+    }
+    missing
+}
+
+/// An item (a raw, or a raw with what was worked out about it) and the module its raw is from.
+type InModule<T> = (T, ModuleReference);
+
+/// Group the items by `key`, keeping the groups (and the items in them) in the order they were
+/// first seen, and return the groups which have raws from more than one module.
+fn group_across_modules<'a, T, K: Eq + Hash + Clone>(
+    items: impl Iterator<Item = T>,
+    modules: &[ModuleInfoFile],
+    raw: impl Fn(&T) -> &'a dyn RawObject,
+    key: impl Fn(&T) -> K,
+) -> Vec<(K, Vec<InModule<T>>)> {
+    let mut groups: Vec<(K, Vec<InModule<T>>)> = Vec::new();
+    let mut group_index: HashMap<K, usize> = HashMap::new();
+    for item in items {
+        let module = ModuleReference::from_metadata(raw(&item).get_metadata(), modules);
+        let item_key = key(&item);
+        if let Some(&index) = group_index.get(&item_key) {
+            groups[index].1.push((item, module));
+        } else {
+            group_index.insert(item_key.clone(), groups.len());
+            groups.push((item_key, vec![(item, module)]));
+        }
+    }
+    groups.retain(|(_, items)| items.iter().any(|(_, module)| module != &items[0].1));
+    groups
+}
+
+/// Whether objects of this type define an object (rather than patch or remove one).
+fn is_definition(object_type: &ObjectType) -> bool {
+    !matches!(
+        object_type,
+        ObjectType::SelectCreature
+            | ObjectType::SelectPlant
+            | ObjectType::SelectInorganic
+            | ObjectType::CutCreature
+            | ObjectType::CutInorganic
+            | ObjectType::Unknown
+    )
+}
+
+fn find_duplicate_objects(
+    modules: &[ModuleInfoFile],
+    raws: &[Box<dyn RawObject>],
+) -> Vec<DuplicateObject> {
+    let definitions = raws
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|raw| is_definition(raw.get_type()));
+    group_across_modules(
+        definitions,
+        modules,
+        |raw| *raw,
+        |raw| (raw.get_type().clone(), String::from(raw.get_identifier())),
+    )
+    .into_iter()
+    .map(|((object_type, identifier), raws)| DuplicateObject {
+        object_type,
+        identifier,
+        definitions: raws
+            .into_iter()
+            .map(|(raw, module)| ObjectSource::new(raw, module))
+            .collect(),
+    })
+    .collect()
+}
+
+/// A patch (`SELECT_CREATURE`, `SELECT_PLANT` or `SELECT_INORGANIC`), with the type of object it
+/// applies to and the keys of the tokens it sets.
+struct Patch<'a> {
+    raw: &'a dyn RawObject,
+    target_type: ObjectType,
+    tokens: Vec<String>,
+}
+
+impl<'a> Patch<'a> {
+    /// The patch, or `None` if the raw isn't a patch.
+    fn from_raw(raw: &'a dyn RawObject) -> Option<Self> {
+        let (target_type, tokens) =
+            if let Some(select_creature) = raw.as_any().downcast_ref::<SelectCreature>() {
+                let tokens = select_creature
+                    .get_tags()
+                    .iter()
+                    .map(|tag| patch_token_key(tag))
+                    .collect();
+                (ObjectType::Creature, tokens)
+            } else if let Some(select_object) = raw.as_any().downcast_ref::<SelectObject>() {
+                let tokens = select_object
+                    .get_tags()
+                    .iter()
+                    .map(|(key, _)| patch_token_key(key))
+                    .collect();
+                (select_object.get_target_type(), tokens)
+            } else {
+                return None;
+            };
+        Some(Self {
+            raw,
+            target_type,
+            tokens,
+        })
+    }
+}
+
+/// The key of a token set by a patch, from either the `KEY:VALUE` of a `SelectCreature` tag or the
+/// key of a `SelectObject` tag, so the tokens of both kinds of patch can be compared.
+fn patch_token_key(tag: &str) -> String {
+    tag.split(':')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_uppercase()
+}
+
+fn find_overlapping_patches(
+    modules: &[ModuleInfoFile],
+    raws: &[Box<dyn RawObject>],
+) -> Vec<OverlappingPatch> {
+    let patches = raws.iter().filter_map(|raw| Patch::from_raw(raw.as_ref()));
+    group_across_modules(
+        patches,
+        modules,
+        |patch| patch.raw,
+        |patch| {
+            (
+                patch.target_type.clone(),
+                String::from(patch.raw.get_identifier()),
+            )
+        },
+    )
+    .into_iter()
+    .map(|((target_type, identifier), patches)| {
+        // The tokens set by patches from more than one module
+        let mut token_modules: Vec<(&str, Vec<&ModuleReference>)> = Vec::new();
+        for (patch, module) in &patches {
+            for token in &patch.tokens {
+                match token_modules.iter_mut().find(|(t, _)| t == token) {
+                    Some((_, token_modules)) => {
+                        if !token_modules.contains(&module) {
+                            token_modules.push(module);
+                        }
+                    }
+                    None => token_modules.push((token, vec![module])),
+                }
+            }
+        }
+        let shared_tokens = token_modules
+            .iter()
+            .filter(|(_, modules)| modules.len() > 1)
+            .map(|(token, _)| String::from(*token))
+            .collect();
+
+        OverlappingPatch {
+            target_type,
+            identifier,
+            shared_tokens,
+            patches: patches
+                .iter()
+                .map(|(patch, module)| ObjectSource::new(patch.raw, module.clone()))
+                .collect(),
+        }
+    })
+    .collect()
+}
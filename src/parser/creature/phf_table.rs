@@ -29,6 +29,9 @@ pub static CREATURE_TOKENS: phf::Map<&'static str, CreatureTag> = phf::phf_map!
     "POPULATION_NUMBER" => CreatureTag::PopulationNumber,
     "COPY_TAGS_FROM" => CreatureTag::CopyTagsFrom,
     "APPLY_CREATURE_VARIATION" => CreatureTag::ApplyCreatureVariation,
+    "APPLY_CURRENT_CREATURE_VARIATION" => CreatureTag::ApplyCurrentCreatureVariation,
+    "USE_MATERIAL_TEMPLATE" => CreatureTag::UseMaterialTemplate,
+    "USE_TISSUE_TEMPLATE" => CreatureTag::UseTissueTemplate,
     "CREATURE_TILE" => CreatureTag::CreatureTile,
     "ALTTILE" => CreatureTag::AltTile,
     "COLOR" => CreatureTag::Color,
@@ -1,10 +1,17 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::parser::{
     biome::{phf_map::BIOME_TOKENS, tokens::Biome},
     creature_caste::{phf_table::CASTE_TOKENS, raw::Caste},
     creature_variation::raw::CreatureVariationRequirements,
+    diagnostics::{self, DiagnosticSeverity},
     helpers::object_id::build_object_id_from_pieces,
+    material::{
+        phf_table::{MATERIAL_PROPERTY_TOKENS, MATERIAL_USAGE_TOKENS},
+        raw::Material,
+    },
     names::{Name, SingPlurName},
     object_types::ObjectType,
     ranges::parse_min_max_range,
@@ -13,6 +20,11 @@ use crate::parser::{
     select_creature::raw::SelectCreature,
     serializer_helper,
     tile::Tile,
+    tissue_template::template_use::TemplateUse,
+    writer::{
+        convert_raw_tokens, remove_raw_tokens, token_key, write_tags_without_raw_tokens, RawWriter,
+        ToRawText,
+    },
 };
 
 use super::{phf_table::CREATURE_TOKENS, tokens::CreatureTag};
@@ -21,16 +33,19 @@ use super::{phf_table::CREATURE_TOKENS, tokens::CreatureTag};
 /// that can be set in the raws. Not all the raws are represented here, only the ones that
 /// are currently supported by the library.
 ///
-/// Some items like `CREATURE_VARIATION` and `CREATURE_VARIATION_CASTE` are saved in their raw
-/// format. `SELECT_CREATURE` is saved here as a sub-creature object with all the properties
-/// from that raw. This is because the `SELECT_CREATURE` raws are used to create new creatures
-/// based on the properties of the creature they are applied to. But right now the application
-/// of those changes is not applied, in order to preserve the original creature. So instead,
-/// they are saved and can be applied later (at the consumer's discretion).
+/// `APPLY_CREATURE_VARIATION` tokens are saved in their raw format, and are applied to the creature
+/// after parsing (unless `skip_apply_creature_variations` is set). The tokens after an
+/// `APPLY_CURRENT_CREATURE_VARIATION` are saved too, and parsed once the variations are applied.
+/// `SELECT_CREATURE` is saved here as a sub-creature object with all the properties from that
+/// raw. This is because the `SELECT_CREATURE` raws are used to create new creatures based on the
+/// properties of the creature they are applied to. But right now the application of those changes
+/// is not applied, in order to preserve the original creature. So instead, they are saved and can
+/// be applied later (at the consumer's discretion).
 #[derive(ts_rs::TS)]
-#[ts(export)]
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Creature {
     #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
     metadata: RawMetadata,
@@ -68,9 +83,53 @@ pub struct Creature {
     copy_tags_from: String,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     apply_creature_variation: Vec<String>,
+    /// The tokens after `APPLY_CURRENT_CREATURE_VARIATION`, as key and value. They are parsed after
+    /// the creature variations are applied, so they can override what the variations change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags_after_creature_variation: Option<Vec<(String, String)>>,
     object_id: String,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     select_creature_variation: Vec<SelectCreature>,
+    /// Materials defined on the creature with `USE_MATERIAL_TEMPLATE`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    materials: Vec<Material>,
+    /// Tissues defined on the creature with `USE_TISSUE_TEMPLATE`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tissues: Vec<TemplateUse>,
+    /// The creature tokens which can't be written back from the other fields, as key and value, in
+    /// the order they were parsed (e.g. `[CHANGE_FREQUENCY_PERC:...]`, or a biome the library
+    /// doesn't recognize). Tokens it doesn't recognize at all are kept by the selected caste.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    raw_tokens: Vec<(String, String)>,
+}
+
+impl Default for Creature {
+    fn default() -> Self {
+        Self {
+            metadata: RawMetadata::default(),
+            identifier: String::new(),
+            castes: Vec::new(),
+            tags: Vec::new(),
+            biomes: Vec::new(),
+            pref_strings: Vec::new(),
+            tile: Tile::default(),
+            frequency: 50,
+            cluster_number: [1, 1],
+            population_number: [1, 1],
+            underground_depth: [0, 0],
+            general_baby_name: SingPlurName::default(),
+            general_child_name: SingPlurName::default(),
+            name: Name::default(),
+            copy_tags_from: String::new(),
+            apply_creature_variation: Vec::new(),
+            tags_after_creature_variation: None,
+            object_id: String::new(),
+            select_creature_variation: Vec::new(),
+            materials: Vec::new(),
+            tissues: Vec::new(),
+            raw_tokens: Vec::new(),
+        }
+    }
 }
 
 impl Creature {
@@ -82,9 +141,6 @@ impl Creature {
     pub fn empty() -> Creature {
         Creature {
             castes: vec![Caste::new("ALL")],
-            population_number: [1, 1],
-            cluster_number: [1, 1],
-            frequency: 50,
             ..Creature::default()
         }
     }
@@ -105,10 +161,7 @@ impl Creature {
         Creature {
             identifier: String::from(identifier),
             metadata: metadata.clone(),
-            frequency: 50,
             castes: vec![Caste::new("ALL")],
-            population_number: [1, 1],
-            cluster_number: [1, 1],
             object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Creature),
             ..Creature::default()
         }
@@ -123,6 +176,163 @@ impl Creature {
         &self.copy_tags_from
     }
 
+    /// Returns the `APPLY_CREATURE_VARIATION` values for this creature, in the order they were
+    /// found. Each is the variation identifier followed by any arguments, e.g. `ANIMAL_PERSON`
+    /// or `GIANT:1`.
+    pub fn get_creature_variations(&self) -> &[String] {
+        self.apply_creature_variation.as_slice()
+    }
+
+    /// Clear the list of creature variations. This is used once the variations have been applied.
+    pub fn clear_creature_variations(&mut self) {
+        self.apply_creature_variation.clear();
+    }
+
+    /// Save a token to parse after the creature variations are applied, if it came after an
+    /// `APPLY_CURRENT_CREATURE_VARIATION`. This includes `CASTE` and `SELECT_CASTE` tokens.
+    ///
+    /// Arguments:
+    ///
+    /// * `key`: The key of the token.
+    /// * `value`: The value of the token.
+    ///
+    /// Returns:
+    ///
+    /// true if the token was saved, in which case it shouldn't be parsed now.
+    pub fn save_tag_after_creature_variation(&mut self, key: &str, value: &str) -> bool {
+        let Some(tags) = self.tags_after_creature_variation.as_mut() else {
+            return false;
+        };
+        tags.push((String::from(key), String::from(value)));
+        true
+    }
+
+    /// Take the tokens which came after `APPLY_CURRENT_CREATURE_VARIATION`, to parse them with
+    /// `parse_tags_after_creature_variation` once the variations queued before it are applied.
+    /// Until they are taken, any tags parsed (e.g. by the variations) are saved with them.
+    pub fn take_tags_after_creature_variation(&mut self) -> Option<Vec<(String, String)>> {
+        self.tags_after_creature_variation.take()
+    }
+
+    /// Parse the tokens which came after `APPLY_CURRENT_CREATURE_VARIATION`, once the variations
+    /// queued before it have been applied. The tokens may queue more variations, in which case the
+    /// tokens after the next `APPLY_CURRENT_CREATURE_VARIATION` are saved again.
+    ///
+    /// Arguments:
+    ///
+    /// * `tags`: The tokens from `take_tags_after_creature_variation`.
+    pub fn parse_tags_after_creature_variation(&mut self, tags: &[(String, String)]) {
+        for (key, value) in tags {
+            if value.is_empty() {
+                diagnostics::set_token(&format!("[{key}]"));
+            } else {
+                diagnostics::set_token(&format!("[{key}:{value}]"));
+            }
+            if self.save_tag_after_creature_variation(key, value) {
+                continue;
+            }
+            match key.as_str() {
+                "CASTE" => self.add_caste(value),
+                "SELECT_CASTE" => self.select_caste(value),
+                _ => self.parse_tag(key, value),
+            }
+        }
+    }
+
+    /// Convert the value of a tag, as done by `CV_CONVERT_TAG` in a creature variation. Any tag
+    /// with the `key` that has the `target` in its value has it replaced with `replacement`.
+    ///
+    /// Only the tags which the library keeps the values of can be converted.
+    ///
+    /// Arguments:
+    ///
+    /// * `key`: The tag to convert (the `CVCT_MASTER`)
+    /// * `target`: The value to look for (the `CVCT_TARGET`)
+    /// * `replacement`: The value to replace it with (the `CVCT_REPLACEMENT`)
+    ///
+    /// Returns:
+    ///
+    /// false if tags with the `key` can't be converted.
+    pub fn convert_tag(&mut self, key: &str, target: &str, replacement: &str) -> bool {
+        if CASTE_TOKENS.contains_key(key) || !CREATURE_TOKENS.contains_key(key) {
+            // Unknown tokens are kept by the castes too
+            let mut converted = false;
+            for caste in &mut self.castes {
+                converted |= caste.convert_tag(key, target, replacement);
+            }
+            return converted;
+        }
+
+        match CREATURE_TOKENS.get(key) {
+            Some(CreatureTag::Biome) => {
+                let (Some(target), Some(replacement)) =
+                    (BIOME_TOKENS.get(target), BIOME_TOKENS.get(replacement))
+                else {
+                    return true;
+                };
+                for biome in &mut self.biomes {
+                    if biome == target {
+                        *biome = replacement.clone();
+                    }
+                }
+            }
+            Some(CreatureTag::PrefString) => {
+                for pref_string in &mut self.pref_strings {
+                    *pref_string = pref_string.replace(target, replacement);
+                }
+            }
+            _ => {
+                if !convert_raw_tokens(&mut self.raw_tokens, key, target, replacement) {
+                    log::debug!(
+                        "Creature::convert_tag: unable to convert {} for {}",
+                        key,
+                        self.identifier
+                    );
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Apply the material templates used by this creature's materials. Materials which don't use a
+    /// template (or use a template that isn't in `templates`) are left as they are.
+    ///
+    /// Arguments:
+    ///
+    /// * `templates`: The material of each `MaterialTemplate`, by template identifier.
+    ///
+    /// Returns:
+    ///
+    /// true if any of the materials were changed.
+    pub fn apply_material_templates(&mut self, templates: &HashMap<String, Material>) -> bool {
+        let mut changed = false;
+        for material in &mut self.materials {
+            if let Some(template) = templates.get(material.get_template_identifier()) {
+                material.apply_material_template(template);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Link the interactions of each caste (from `CAN_DO_INTERACTION`) to the parsed interactions.
+    ///
+    /// Arguments:
+    ///
+    /// * `interactions`: The object ID of each parsed interaction, by identifier.
+    ///
+    /// Returns:
+    ///
+    /// true if any of the interactions were linked.
+    pub fn link_interactions(&mut self, interactions: &HashMap<String, String>) -> bool {
+        let mut linked = false;
+        for caste in &mut self.castes {
+            linked |= caste.link_interactions(interactions);
+        }
+        linked
+    }
+
     /// Adds a `SelectCreature` object to the internal `SelectCreature` vector.
     ///
     /// Arguments:
@@ -152,6 +362,45 @@ impl Creature {
         self.castes.push(Caste::new(name));
     }
 
+    /// The function `parse_caste_tag` has the selected caste (the last one in `castes`) parse a caste
+    /// tag. A creature without any castes gets an ALL caste for the tag.
+    ///
+    /// Arguments:
+    ///
+    /// * `key`: The key of the caste tag.
+    /// * `value`: The value of the caste tag.
+    fn parse_caste_tag(&mut self, key: &str, value: &str) {
+        if self.castes.is_empty() {
+            self.add_caste("ALL");
+        }
+        if let Some(caste) = self.castes.last_mut() {
+            caste.parse_tag(key, value);
+        }
+    }
+
+    /// Report a tag the library doesn't recognize, and have the selected caste keep it to write it
+    /// back (since it may be a caste token).
+    fn parse_unknown_tag(&mut self, key: &str, value: &str) {
+        log::trace!("CreatureParsing: Unknown tag {key} with value {value}");
+        diagnostics::report(
+            DiagnosticSeverity::Info,
+            &format!("Unknown creature tag {key}"),
+        );
+        if self.castes.is_empty() {
+            self.add_caste("ALL");
+        }
+        if let Some(caste) = self.castes.last_mut() {
+            caste.push_raw_token(key, value);
+        }
+    }
+
+    /// Keep a creature token which can't be written back from the other fields, to write it back
+    /// as it is.
+    fn push_raw_token(&mut self, key: &str, value: &str) {
+        self.raw_tokens
+            .push((String::from(key), String::from(value)));
+    }
+
     /// The function `select_caste` moves a caste to the end of a list if it matches the given name,
     /// otherwise it adds a new caste with the given name. This essentially allows the other functions
     /// to assume that the caste they are working with is the last one in the list.
@@ -246,6 +495,17 @@ impl Creature {
         combined_creature.identifier = creature.identifier.clone();
         // our object_id is preserved
         combined_creature.object_id = creature.object_id.clone();
+        // our creature variations are applied after the ones we copied
+        combined_creature
+            .apply_creature_variation
+            .extend(creature.apply_creature_variation.iter().cloned());
+        // and so are our tags after them
+        if let Some(tags) = &creature.tags_after_creature_variation {
+            combined_creature
+                .tags_after_creature_variation
+                .get_or_insert_with(Vec::new)
+                .extend(tags.iter().cloned());
+        }
 
         // We need to loop over our castes and apply any differences.
         for caste in &creature.castes {
@@ -262,6 +522,27 @@ impl Creature {
             }
         }
 
+        // Loop over our materials and apply them to the matching material in combined_creature
+        for material in &creature.materials {
+            if let Some(combined_material) = combined_creature
+                .materials
+                .iter_mut()
+                .find(|m| m.get_name() == material.get_name())
+            {
+                combined_material.overwrite_material(material);
+            } else {
+                combined_creature.materials.push(material.clone());
+            }
+        }
+
+        // Our tissues replace the matching tissue in combined_creature
+        for tissue in &creature.tissues {
+            combined_creature
+                .tissues
+                .retain(|t| t.get_name() != tissue.get_name());
+            combined_creature.tissues.push(tissue.clone());
+        }
+
         // Loop over our pref_strings and if they aren't in combined_creature, add them
         for pref_string in &creature.pref_strings {
             if !combined_creature.pref_strings.contains(pref_string) {
@@ -283,6 +564,13 @@ impl Creature {
             }
         }
 
+        // Loop over our raw tokens and if they aren't in combined_creature, add them
+        for raw_token in &creature.raw_tokens {
+            if !combined_creature.raw_tokens.contains(raw_token) {
+                combined_creature.raw_tokens.push(raw_token.clone());
+            }
+        }
+
         // If any of our other properties are not default, we need to apply them to the combined creature.
         if !serializer_helper::is_default_frequency(&creature.frequency) {
             combined_creature.frequency = creature.frequency;
@@ -321,6 +609,11 @@ impl Creature {
         self.castes.as_slice()
     }
 
+    /// Returns the tissues defined on the creature with `USE_TISSUE_TEMPLATE`.
+    pub fn get_tissues(&self) -> &[TemplateUse] {
+        self.tissues.as_slice()
+    }
+
     pub fn does_not_exist(&self) -> bool {
         self.tags.contains(&CreatureTag::DoesNotExist)
     }
@@ -347,20 +640,25 @@ impl RawObject for Creature {
         &ObjectType::Creature
     }
     fn parse_tag(&mut self, key: &str, value: &str) {
-        if CASTE_TOKENS.contains_key(key) {
-            self.castes.last_mut().unwrap().parse_tag(key, value);
+        if self.save_tag_after_creature_variation(key, value) {
             return;
         }
-        if !CREATURE_TOKENS.contains_key(key) {
-            log::trace!("CreatureParsing: Unknown tag {} with value {}", key, value);
+        if CASTE_TOKENS.contains_key(key) {
+            self.parse_caste_tag(key, value);
             return;
         }
-
+        if !CREATURE_TOKENS.contains_key(key)
+            && (MATERIAL_PROPERTY_TOKENS.contains_key(key)
+                || MATERIAL_USAGE_TOKENS.contains_key(key))
+        {
+            // have our latest material parse the tag
+            if let Some(material) = self.materials.last_mut() {
+                material.parse_tag(key, value);
+                return;
+            }
+        }
         let Some(tag) = CREATURE_TOKENS.get(key) else {
-            log::warn!(
-                "Creature::parse_tag: called `Option::unwrap()` on a `None` value for presumed creature tag: {}",
-                key
-            );
+            self.parse_unknown_tag(key, value);
             return;
         };
 
@@ -371,6 +669,11 @@ impl RawObject for Creature {
                         "CreatureParsing: called `Option::unwrap()` on a `None` value for presumed biome: {}",
                         value
                     );
+                    diagnostics::report(
+                        DiagnosticSeverity::Warning,
+                        &format!("Unknown biome {value}"),
+                    );
+                    self.push_raw_token(key, value);
                     return;
                 };
                 self.biomes.push(biome.clone());
@@ -405,6 +708,20 @@ impl RawObject for Creature {
             CreatureTag::ApplyCreatureVariation => {
                 self.apply_creature_variation.push(String::from(value));
             }
+            CreatureTag::ApplyCurrentCreatureVariation => {
+                // The variations are applied after parsing, so the tags after this are saved to
+                // be parsed after them.
+                if !self.apply_creature_variation.is_empty() {
+                    self.tags_after_creature_variation = Some(Vec::new());
+                }
+            }
+            CreatureTag::UseMaterialTemplate => {
+                self.materials
+                    .push(Material::use_material_template_from_value(value));
+            }
+            CreatureTag::UseTissueTemplate => {
+                self.tissues.push(TemplateUse::from_value(value));
+            }
             CreatureTag::CreatureTile => {
                 self.tile.set_character(value);
             }
@@ -422,6 +739,10 @@ impl RawObject for Creature {
             }
             _ => {
                 self.tags.push(tag.clone());
+                if !value.is_empty() {
+                    // The value isn't kept, so the token is kept to write it back
+                    self.push_raw_token(key, value);
+                }
             }
         }
     }
@@ -430,6 +751,153 @@ impl RawObject for Creature {
     }
 }
 
+impl Creature {
+    /// Write the creature's names and tile.
+    fn write_names(&self, writer: &mut RawWriter) {
+        let tokens = &CREATURE_TOKENS;
+
+        if !self.name.is_empty() {
+            writer.token_for(tokens, &CreatureTag::Name, self.name.to_value());
+        }
+        if !self.general_baby_name.is_empty() {
+            writer.token_for(
+                tokens,
+                &CreatureTag::GeneralBabyName,
+                self.general_baby_name.to_value(),
+            );
+        }
+        if !self.general_child_name.is_empty() {
+            writer.token_for(
+                tokens,
+                &CreatureTag::GeneralChildName,
+                self.general_child_name.to_value(),
+            );
+        }
+        self.tile.write_tokens(
+            writer,
+            [
+                token_key(tokens, &CreatureTag::CreatureTile).unwrap_or_default(),
+                token_key(tokens, &CreatureTag::AltTile).unwrap_or_default(),
+                token_key(tokens, &CreatureTag::Color).unwrap_or_default(),
+                token_key(tokens, &CreatureTag::GlowTile).unwrap_or_default(),
+                token_key(tokens, &CreatureTag::GlowColor).unwrap_or_default(),
+            ],
+        );
+    }
+
+    /// Write the creature's tags, biomes, population and the other properties kept on the creature
+    /// (rather than its castes).
+    fn write_properties(&self, writer: &mut RawWriter) {
+        let tokens = &CREATURE_TOKENS;
+
+        write_tags_without_raw_tokens(writer, tokens, &self.tags, &self.raw_tokens);
+        for biome in &self.biomes {
+            if let Some(biome) = token_key(&BIOME_TOKENS, biome) {
+                writer.token_for(tokens, &CreatureTag::Biome, biome);
+            }
+        }
+        writer.raw_tokens(&self.raw_tokens);
+        for pref_string in &self.pref_strings {
+            writer.token_for(tokens, &CreatureTag::PrefString, pref_string);
+        }
+        if self.frequency != 50 {
+            writer.token_for(tokens, &CreatureTag::Frequency, self.frequency);
+        }
+        let ranges = [
+            (CreatureTag::ClusterNumber, self.cluster_number, [1, 1]),
+            (
+                CreatureTag::PopulationNumber,
+                self.population_number,
+                [1, 1],
+            ),
+            (
+                CreatureTag::UndergroundDepth,
+                self.underground_depth,
+                [0, 0],
+            ),
+        ];
+        for (tag, range, default) in ranges {
+            if range != default {
+                writer.token_for(tokens, &tag, format!("{}:{}", range[0], range[1]));
+            }
+        }
+    }
+
+    /// Write the materials and tissues the creature defines from templates.
+    fn write_materials_and_tissues(&self, writer: &mut RawWriter) {
+        let tokens = &CREATURE_TOKENS;
+
+        for material in &self.materials {
+            writer.token_for(
+                tokens,
+                &CreatureTag::UseMaterialTemplate,
+                format!(
+                    "{}:{}",
+                    material.get_name(),
+                    material.get_template_identifier()
+                ),
+            );
+            writer.indent();
+            material.write_properties(writer);
+            writer.outdent();
+        }
+        for tissue in &self.tissues {
+            writer.token_for(tokens, &CreatureTag::UseTissueTemplate, tissue.to_value());
+        }
+    }
+
+    /// Write the castes, in the order they are kept in.
+    fn write_castes(&self, writer: &mut RawWriter) {
+        for (i, caste) in self.castes.iter().enumerate() {
+            // The creature starts with the `ALL` caste, which is selected until another is.
+            if i == 0 && caste.get_identifier() == "ALL" {
+                caste.write_raw_text(writer);
+                continue;
+            }
+            if caste.get_identifier() == "ALL" {
+                writer.token("SELECT_CASTE", "ALL");
+            } else {
+                writer.token("CASTE", caste.get_identifier());
+            }
+            writer.indent();
+            caste.write_raw_text(writer);
+            writer.outdent();
+        }
+    }
+}
+
+impl ToRawText for Creature {
+    /// Write the creature as raw text. The castes are written after the creature's own tokens, in
+    /// the order they are kept in. The `SELECT_CREATURE` variations saved on the creature are not
+    /// written, since they are separate objects in the raws.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &CREATURE_TOKENS;
+        writer.begin_object("CREATURE", &self.identifier);
+
+        if !self.copy_tags_from.is_empty() {
+            writer.token_for(tokens, &CreatureTag::CopyTagsFrom, &self.copy_tags_from);
+        }
+        for creature_variation in &self.apply_creature_variation {
+            writer.token_for(
+                tokens,
+                &CreatureTag::ApplyCreatureVariation,
+                creature_variation,
+            );
+        }
+        self.write_names(writer);
+        self.write_properties(writer);
+        self.write_materials_and_tissues(writer);
+        self.write_castes(writer);
+
+        if let Some(tags) = &self.tags_after_creature_variation {
+            writer.token_for(tokens, &CreatureTag::ApplyCurrentCreatureVariation, "");
+            for (key, value) in tags {
+                writer.token(key, value);
+            }
+        }
+    }
+}
+
 #[typetag::serde]
 impl CreatureVariationRequirements for Creature {
     fn remove_tag(&mut self, key: &str) {
@@ -438,16 +906,19 @@ impl CreatureVariationRequirements for Creature {
 
     fn remove_tag_and_value(&mut self, key: &str, value: &str) {
         if CASTE_TOKENS.contains_key(key) {
-            self.castes
-                .last_mut()
-                .unwrap()
-                .remove_tag_and_value(key, value);
+            if let Some(caste) = self.castes.last_mut() {
+                caste.remove_tag_and_value(key, value);
+            }
             return;
         }
         if !CREATURE_TOKENS.contains_key(key) {
-            log::debug!("CreatureParsing: Unknown tag {} with value {}", key, value);
+            // Unknown tokens are kept by the caste they were in
+            if let Some(caste) = self.castes.last_mut() {
+                caste.remove_tag_and_value(key, value);
+            }
             return;
         }
+        remove_raw_tokens(&mut self.raw_tokens, key, value);
 
         let Some(tag) = CREATURE_TOKENS.get(key) else {
             log::warn!(
@@ -464,6 +935,10 @@ impl CreatureVariationRequirements for Creature {
                         "CreatureParsing: called `Option::unwrap()` on a `None` value for presumed biome: {}",
                         value
                     );
+                    diagnostics::report(
+                        DiagnosticSeverity::Warning,
+                        &format!("Unknown biome {value}"),
+                    );
                     return;
                 };
                 self.biomes.retain(|x| x != biome);
@@ -498,6 +973,15 @@ impl CreatureVariationRequirements for Creature {
             CreatureTag::ApplyCreatureVariation => {
                 self.apply_creature_variation.retain(|x| x != value);
             }
+            CreatureTag::ApplyCurrentCreatureVariation => {}
+            CreatureTag::UseMaterialTemplate => {
+                let material_name = value.split(':').next().unwrap_or_default();
+                self.materials.retain(|m| m.get_name() != material_name);
+            }
+            CreatureTag::UseTissueTemplate => {
+                let tissue_name = value.split(':').next().unwrap_or_default();
+                self.tissues.retain(|t| t.get_name() != tissue_name);
+            }
             CreatureTag::CreatureTile => {
                 self.tile.set_character("");
             }
@@ -571,6 +1055,8 @@ impl Searchable for Creature {
         vec.extend(self.general_baby_name.as_vec());
         // Add general child name
         vec.extend(self.general_child_name.as_vec());
+        // Add materials
+        vec.extend(self.materials.iter().flat_map(Searchable::get_search_vec));
         // Add identifier
         vec.push(self.identifier.clone());
 
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub enum CreatureTag {
     ArtificialHiveable,
@@ -33,6 +33,9 @@ pub enum CreatureTag {
     PopulationNumber,
     CopyTagsFrom,
     ApplyCreatureVariation,
+    ApplyCurrentCreatureVariation,
+    UseMaterialTemplate,
+    UseTissueTemplate,
     CreatureTile,
     AltTile,
     Color,
@@ -76,6 +79,11 @@ impl std::fmt::Display for CreatureTag {
             CreatureTag::PopulationNumber => write!(f, "PopulationNumber"),
             CreatureTag::CopyTagsFrom => write!(f, "CopyTagsFrom"),
             CreatureTag::ApplyCreatureVariation => write!(f, "ApplyCreatureVariation"),
+            CreatureTag::ApplyCurrentCreatureVariation => {
+                write!(f, "ApplyCurrentCreatureVariation")
+            }
+            CreatureTag::UseMaterialTemplate => write!(f, "UseMaterialTemplate"),
+            CreatureTag::UseTissueTemplate => write!(f, "UseTissueTemplate"),
             CreatureTag::CreatureTile => write!(f, "CreatureTile"),
             CreatureTag::AltTile => write!(f, "AltTile"),
             CreatureTag::Color => write!(f, "Color"),
@@ -136,4 +136,13 @@ pub static CASTE_TOKENS: phf::Map<&'static str, CasteTag> = phf::phf_map! {
     "CASTE_GLOWTILE" => CasteTag::CasteGlowTile,
     "CASTE_GLOWCOLOR" => CasteTag::CasteGlowColor,
     "CHANGE_BODY_SIZE_PERC" => CasteTag::ChangeBodySizePercent,
+    "BODY" => CasteTag::Body,
+    "BODY_DETAIL_PLAN" => CasteTag::BodyDetailPlan,
+    "TISSUE_LAYER" => CasteTag::TissueLayer,
+    "CAN_DO_INTERACTION" => CasteTag::CanDoInteraction,
+    "CDI" => CasteTag::CanDoInteractionProperty,
+    "SET_TL_GROUP" => CasteTag::SetTissueLayerGroup,
+    "PLUS_TL_GROUP" => CasteTag::PlusTissueLayerGroup,
+    "TL_COLOR_MODIFIER" => CasteTag::TissueLayerColorModifier,
+    "TLCM_NOUN" => CasteTag::TissueLayerColorModifierNoun,
 };
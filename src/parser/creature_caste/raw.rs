@@ -1,22 +1,33 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::parser::{
+    body_detail_plan::{layers::BodyPartLayers, phf_table::BODY_PART_SELECTOR_TOKENS},
     body_size::BodySize,
+    descriptor::color_modifier::TissueLayerColorModifier,
+    diagnostics::{self, DiagnosticSeverity},
+    interaction::creature_interaction::CreatureInteraction,
     milkable::Milkable,
     names::{Name, SingPlurName},
     ranges::parse_min_max_range,
     searchable::Searchable,
     serializer_helper,
     tile::Tile,
+    writer::{
+        convert_raw_tokens, remove_raw_tokens, token_key, write_tags_without_raw_tokens, RawWriter,
+        ToRawText,
+    },
 };
 
 use super::{phf_table::CASTE_TOKENS, tokens::CasteTag};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Caste {
     identifier: String,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -71,6 +82,32 @@ pub struct Caste {
     milkable: Milkable,
     #[serde(skip_serializing_if = "Tile::is_default")]
     tile: Tile,
+    // Body
+    /// The identifiers of the bodies from `BODY` tokens, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    body: Vec<String>,
+    /// The `BODY_DETAIL_PLAN` values, e.g. `VERTEBRATE_TISSUE_LAYERS:SKIN:FAT:MUSCLE:BONE:CARTILAGE`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    body_detail_plans: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tissue_layers: Vec<BodyPartLayers>,
+    // Interactions
+    /// The interactions from `CAN_DO_INTERACTION` tokens, with their `CDI` tokens.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    interactions: Vec<CreatureInteraction>,
+    // Appearance
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tissue_layer_color_modifiers: Vec<TissueLayerColorModifier>,
+    /// The tokens which can't be written back from the other fields, as key and value, in the
+    /// order they were parsed: tokens the library doesn't recognize, tags it doesn't keep the value
+    /// of (e.g. `[NATURAL_SKILL:...]`), and the tissue layer group tokens which the tokens after
+    /// them depend on. They are written back as they are.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    raw_tokens: Vec<(String, String)>,
+
+    /// The tissue layer group from the last `SET_TL_GROUP` (and `PLUS_TL_GROUP`) while parsing.
+    #[serde(skip)]
+    tissue_layer_group: Vec<String>,
 }
 
 impl Caste {
@@ -86,6 +123,10 @@ impl Caste {
                 "CasteParsing: called `Option::unwrap()` on a `None` value for presumed caste tag: {}",
                 key
             );
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Unknown caste tag {key}"),
+            );
             return;
         };
 
@@ -131,20 +172,117 @@ impl Caste {
             CasteTag::ChangeBodySizePercent => {
                 self.change_body_size_percentage = value.parse::<u32>().unwrap_or_default();
             }
-            _ => self.tags.push(tag.clone()),
+            CasteTag::Body => self.body.extend(value.split(':').map(String::from)),
+            CasteTag::BodyDetailPlan => self.body_detail_plans.push(String::from(value)),
+            CasteTag::TissueLayer => {
+                self.tissue_layers
+                    .push(BodyPartLayers::from_tissue_layer_value(value));
+            }
+            CasteTag::CanDoInteraction => {
+                self.interactions
+                    .push(CreatureInteraction::from_value(value));
+            }
+            CasteTag::CanDoInteractionProperty => {
+                // CDI tokens belong to the last CAN_DO_INTERACTION
+                let Some(interaction) = self.interactions.last_mut() else {
+                    diagnostics::report(
+                        DiagnosticSeverity::Warning,
+                        &format!("CDI:{value} before any CAN_DO_INTERACTION"),
+                    );
+                    return;
+                };
+                interaction.parse_tag(value);
+            }
+            CasteTag::SetTissueLayerGroup => {
+                self.tissue_layer_group = vec![String::from(value)];
+                self.push_raw_token(key, value);
+            }
+            CasteTag::PlusTissueLayerGroup => {
+                self.tissue_layer_group.push(String::from(value));
+                self.push_raw_token(key, value);
+            }
+            CasteTag::TissueLayerColorModifier => {
+                self.tissue_layer_color_modifiers
+                    .push(TissueLayerColorModifier::from_value(
+                        value,
+                        &self.tissue_layer_group,
+                    ));
+                self.push_raw_token(key, value);
+            }
+            CasteTag::TissueLayerColorModifierNoun => {
+                // TLCM tokens belong to the last TL_COLOR_MODIFIER
+                if let Some(modifier) = self.tissue_layer_color_modifiers.last_mut() {
+                    modifier.set_noun(value);
+                }
+                self.push_raw_token(key, value);
+            }
+            _ => {
+                // The value isn't kept, so the token is kept to write it back
+                self.tags.push(tag.clone());
+                self.push_raw_token(key, value);
+            }
         }
     }
 
+    /// Keep a token which can't be written back from the caste's other fields, to write it back as
+    /// it is (e.g. a token the library doesn't recognize).
+    ///
+    /// Arguments:
+    ///
+    /// * `key`: The key of the token.
+    /// * `value`: The value of the token.
+    pub fn push_raw_token(&mut self, key: &str, value: &str) {
+        self.raw_tokens
+            .push((String::from(key), String::from(value)));
+    }
+
     pub fn get_identifier(&self) -> &str {
         &self.identifier
     }
+    pub fn get_body(&self) -> &[String] {
+        self.body.as_slice()
+    }
+    pub fn get_body_detail_plans(&self) -> &[String] {
+        self.body_detail_plans.as_slice()
+    }
+    pub fn get_tissue_layers(&self) -> &[BodyPartLayers] {
+        self.tissue_layers.as_slice()
+    }
+    pub fn get_interactions(&self) -> &[CreatureInteraction] {
+        self.interactions.as_slice()
+    }
+    pub fn get_tissue_layer_color_modifiers(&self) -> &[TissueLayerColorModifier] {
+        self.tissue_layer_color_modifiers.as_slice()
+    }
+
+    /// Link each of the caste's interactions to the parsed interaction with the same identifier.
+    ///
+    /// Arguments:
+    ///
+    /// * `interactions`: The object ID of each parsed interaction, by identifier.
+    ///
+    /// Returns:
+    ///
+    /// true if any of the interactions were linked.
+    pub fn link_interactions(&mut self, interactions: &HashMap<String, String>) -> bool {
+        let mut linked = false;
+        for interaction in &mut self.interactions {
+            if let Some(object_id) = interactions.get(interaction.get_interaction()) {
+                interaction.set_interaction_object_id(object_id);
+                linked = true;
+            }
+        }
+        linked
+    }
 
     pub fn remove_tag_and_value(&mut self, key: &str, value: &str) {
+        let removed_raw_tokens = remove_raw_tokens(&mut self.raw_tokens, key, value);
         let Some(tag) = CASTE_TOKENS.get(key) else {
-            log::warn!(
-                "CreatureParsing: called `Option::unwrap()` on a `None` value for presumed caste tag: {}",
-                key
-            );
+            if !removed_raw_tokens {
+                log::warn!(
+                    "CreatureParsing: called `Option::unwrap()` on a `None` value for presumed caste tag: {key}"
+                );
+            }
             return;
         };
 
@@ -183,10 +321,63 @@ impl Caste {
             CasteTag::CasteGlowTile => self.tile.set_glow_character(""),
             CasteTag::CasteGlowColor => self.tile.set_glow_color(""),
             CasteTag::ChangeBodySizePercent => self.change_body_size_percentage = 0,
+            CasteTag::Body => {
+                let bodies: Vec<&str> = value.split(':').collect();
+                self.body.retain(|b| !bodies.contains(&b.as_str()));
+            }
+            CasteTag::BodyDetailPlan => self.body_detail_plans.retain(|p| p != value),
+            CasteTag::TissueLayer => {
+                let layers_to_remove = BodyPartLayers::from_tissue_layer_value(value);
+                self.tissue_layers.retain(|l| l != &layers_to_remove);
+            }
+            CasteTag::CanDoInteraction => {
+                self.interactions.retain(|i| i.get_interaction() != value);
+            }
             _ => self.tags.retain(|t| t != tag),
         }
     }
 
+    /// Convert the value of a tag on this caste, as done by `CV_CONVERT_TAG` in a creature variation.
+    /// Only the tags which keep their value (or are kept as raw tokens) can be converted, and false
+    /// is returned for the others.
+    pub fn convert_tag(&mut self, key: &str, target: &str, replacement: &str) -> bool {
+        match CASTE_TOKENS.get(key) {
+            Some(CasteTag::CreatureClass) => {
+                for class in &mut self.creature_class {
+                    *class = class.replace(target, replacement);
+                }
+            }
+            Some(CasteTag::Description) => {
+                self.description = self.description.replace(target, replacement);
+            }
+            Some(CasteTag::Body) => {
+                // The target can span several bodies, e.g. `2EYES:2EARS`
+                let body = self.body.join(":").replace(target, replacement);
+                self.body = body
+                    .split(':')
+                    .filter(|b| !b.is_empty())
+                    .map(String::from)
+                    .collect();
+            }
+            Some(CasteTag::BodyDetailPlan) => {
+                for plan in &mut self.body_detail_plans {
+                    *plan = plan.replace(target, replacement);
+                }
+            }
+            _ => {
+                if !convert_raw_tokens(&mut self.raw_tokens, key, target, replacement) {
+                    log::debug!(
+                        "Caste::convert_tag: unable to convert {} for {}",
+                        key,
+                        self.identifier
+                    );
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
     pub fn overwrite_caste(&mut self, other: &Caste) {
         // Include any tags from other that aren't in self
         for tag in &other.tags {
@@ -258,6 +449,27 @@ impl Caste {
         if !other.tile.is_default() {
             self.tile = other.tile.clone();
         }
+        if !other.body.is_empty() {
+            self.body.clone_from(&other.body);
+        }
+        if !other.body_detail_plans.is_empty() {
+            self.body_detail_plans.clone_from(&other.body_detail_plans);
+        }
+        if !other.tissue_layers.is_empty() {
+            self.tissue_layers.clone_from(&other.tissue_layers);
+        }
+        if !other.interactions.is_empty() {
+            self.interactions.clone_from(&other.interactions);
+        }
+        if !other.tissue_layer_color_modifiers.is_empty() {
+            self.tissue_layer_color_modifiers
+                .clone_from(&other.tissue_layer_color_modifiers);
+        }
+        for raw_token in &other.raw_tokens {
+            if !self.raw_tokens.contains(raw_token) {
+                self.raw_tokens.push(raw_token.clone());
+            }
+        }
     }
 
     pub fn is_egg_layer(&self) -> bool {
@@ -331,3 +543,131 @@ impl Searchable for Caste {
         vec
     }
 }
+
+impl Caste {
+    /// Write the caste's names, description and tile.
+    fn write_names(&self, writer: &mut RawWriter) {
+        let tokens = &CASTE_TOKENS;
+
+        if !self.caste_name.is_empty() {
+            writer.token_for(tokens, &CasteTag::CasteName, self.caste_name.to_value());
+        }
+        if !self.baby_name.is_empty() {
+            writer.token_for(tokens, &CasteTag::BabyName, self.baby_name.to_value());
+        }
+        if !self.child_name.is_empty() {
+            writer.token_for(tokens, &CasteTag::ChildName, self.child_name.to_value());
+        }
+        if !self.description.is_empty() {
+            writer.token_for(tokens, &CasteTag::Description, &self.description);
+        }
+        self.tile.write_tokens(
+            writer,
+            [
+                token_key(tokens, &CasteTag::CasteTile).unwrap_or_default(),
+                token_key(tokens, &CasteTag::CasteAltTile).unwrap_or_default(),
+                token_key(tokens, &CasteTag::CasteColor).unwrap_or_default(),
+                token_key(tokens, &CasteTag::CasteGlowTile).unwrap_or_default(),
+                token_key(tokens, &CasteTag::CasteGlowColor).unwrap_or_default(),
+            ],
+        );
+    }
+
+    /// Write the caste's body, its tissue layers and its sizes.
+    fn write_body(&self, writer: &mut RawWriter) {
+        let tokens = &CASTE_TOKENS;
+
+        if !self.body.is_empty() {
+            writer.token_for(tokens, &CasteTag::Body, self.body.join(":"));
+        }
+        for body_detail_plan in &self.body_detail_plans {
+            writer.token_for(tokens, &CasteTag::BodyDetailPlan, body_detail_plan);
+        }
+        for tissue_layers in &self.tissue_layers {
+            let selector = tissue_layers.get_selector();
+            let selector = format!(
+                "{}:{}",
+                token_key(&BODY_PART_SELECTOR_TOKENS, selector.get_kind()).unwrap_or_default(),
+                selector.get_value()
+            );
+            for layer in tissue_layers.get_layers() {
+                let mut value = format!("{selector}:{}", layer.get_tissue());
+                if !tissue_layers.get_position().is_empty() {
+                    value = format!("{value}:{}", tissue_layers.get_position());
+                }
+                writer.token_for(tokens, &CasteTag::TissueLayer, value);
+            }
+        }
+        for body_size in &self.body_size {
+            writer.token_for(tokens, &CasteTag::BodySize, body_size.to_value());
+        }
+        if self.change_body_size_percentage != 0 {
+            writer.token_for(
+                tokens,
+                &CasteTag::ChangeBodySizePercent,
+                self.change_body_size_percentage,
+            );
+        }
+    }
+
+    /// Write the caste's ages, litter sizes and the other numbers about its life.
+    fn write_life_cycle(&self, writer: &mut RawWriter) {
+        let tokens = &CASTE_TOKENS;
+
+        let ranges = [
+            (CasteTag::MaxAge, self.max_age),
+            (CasteTag::ClutchSize, self.clutch_size),
+            (CasteTag::LitterSize, self.litter_size),
+        ];
+        for (tag, [min, max]) in ranges {
+            if min != 0 || max != 0 {
+                writer.token_for(tokens, &tag, format!("{min}:{max}"));
+            }
+        }
+        let values = [
+            (CasteTag::Baby, self.baby),
+            (CasteTag::Child, self.child),
+            (CasteTag::EggSize, self.egg_size),
+            (CasteTag::Difficulty, self.difficulty),
+            (CasteTag::Grazer, self.grazer),
+            (CasteTag::GrassTrample, u32::from(self.grass_trample)),
+            (CasteTag::LowLightVision, self.low_light_vision),
+            (CasteTag::PetValue, self.pet_value),
+            (CasteTag::PopRatio, self.pop_ratio),
+        ];
+        for (tag, value) in values {
+            if value != 0 {
+                writer.token_for(tokens, &tag, value);
+            }
+        }
+        if !self.milkable.is_default() {
+            writer.token_for(tokens, &CasteTag::Milkable, self.milkable.to_value());
+        }
+        for creature_class in &self.creature_class {
+            writer.token_for(tokens, &CasteTag::CreatureClass, creature_class);
+        }
+    }
+}
+
+impl ToRawText for Caste {
+    /// Write the tokens of the caste. The `[CASTE:...]` token (or `[SELECT_CASTE:ALL]`) is written
+    /// by the creature, which knows where the caste is in its list of castes.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &CASTE_TOKENS;
+
+        self.write_names(writer);
+        self.write_body(writer);
+        self.write_life_cycle(writer);
+
+        write_tags_without_raw_tokens(writer, tokens, &self.tags, &self.raw_tokens);
+
+        // Interactions
+        for interaction in &self.interactions {
+            interaction.write_raw_text(writer);
+        }
+
+        // The tokens which can't be written from the fields (including the tissue layer color
+        // modifiers, since the tokens after their `SET_TL_GROUP` can depend on it)
+        writer.raw_tokens(&self.raw_tokens);
+    }
+}
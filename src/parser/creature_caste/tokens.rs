@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub enum CasteTag {
     LaysEggs,
@@ -138,6 +138,15 @@ pub enum CasteTag {
     CasteGlowTile,
     CasteGlowColor,
     ChangeBodySizePercent,
+    Body,
+    BodyDetailPlan,
+    TissueLayer,
+    CanDoInteraction,
+    CanDoInteractionProperty,
+    SetTissueLayerGroup,
+    PlusTissueLayerGroup,
+    TissueLayerColorModifier,
+    TissueLayerColorModifierNoun,
     #[default]
     Unknown,
 }
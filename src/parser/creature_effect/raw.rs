@@ -5,9 +5,10 @@ use crate::parser::serializer_helper;
 use super::tokens::CreatureEffectProperty;
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct CreatureEffect {
     severity: u32,
     probability: u8,
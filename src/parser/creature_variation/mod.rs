@@ -1,4 +1,4 @@
 pub mod phf_table;
 pub mod raw;
-pub mod simple;
+pub mod rules;
 pub mod tokens;
@@ -1,11 +1,139 @@
-use crate::parser::creature::raw::Creature;
+use serde::{Deserialize, Serialize};
 
-use super::tokens::CVTag;
+use crate::parser::{
+    creature::raw::Creature,
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
 
-#[typetag::serde(tag = "type")]
-pub trait CreatureVariation {
-    fn get_type(&self) -> CVTag;
-    fn apply_tag(&self, creature: &mut Creature);
+use super::{rules::CreatureVariationRule, tokens::CVTag};
+
+/// A `[CREATURE_VARIATION:...]` raw. It holds a list of rules which change a creature when
+/// the variation is applied with `[APPLY_CREATURE_VARIATION:...]`, e.g. the `GIANT` and
+/// `ANIMAL_PERSON` variations in vanilla.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct CreatureVariation {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rules: Vec<CreatureVariationRule>,
+}
+
+impl CreatureVariation {
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(
+                metadata,
+                identifier,
+                &ObjectType::CreatureVariation,
+            ),
+            ..Self::default()
+        }
+    }
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn get_rules(&self) -> &[CreatureVariationRule] {
+        self.rules.as_slice()
+    }
+
+    /// Apply all the rules of this variation to the creature.
+    ///
+    /// Arguments:
+    ///
+    /// * `creature`: The creature to apply the variation to
+    /// * `args`: The arguments given to `APPLY_CREATURE_VARIATION`, which replace `!ARG1`, `!ARG2`, etc.
+    pub fn apply_to_creature(&self, creature: &mut Creature, args: &[&str]) {
+        log::debug!(
+            "Applying creature variation {} to creature {} with args {:?}",
+            self.identifier,
+            creature.get_identifier(),
+            args
+        );
+        // Tags in the variation start out applying to all castes
+        creature.select_caste("ALL");
+        for rule in &self.rules {
+            rule.apply(creature, args);
+        }
+    }
+}
+
+#[typetag::serde]
+impl RawObject for CreatureVariation {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::CreatureVariation
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let tag = CVTag::from_key(key);
+        match tag {
+            CVTag::NewTag | CVTag::AddTag | CVTag::RemoveTag | CVTag::ConvertTag => {
+                self.rules
+                    .push(CreatureVariationRule::from_value(tag, value));
+            }
+            CVTag::ConditionalNewTag
+            | CVTag::ConditionalAddTag
+            | CVTag::ConditionalRemoveTag
+            | CVTag::ConditionalConvertTag => {
+                self.rules
+                    .push(CreatureVariationRule::from_conditional_value(tag, value));
+            }
+            CVTag::ConvertTagMaster | CVTag::ConvertTagTarget | CVTag::ConvertTagReplacement => {
+                // These belong to the convert rule that came before them
+                let Some(rule) = self.rules.last_mut().filter(|rule| rule.is_convert_rule()) else {
+                    log::warn!(
+                        "CreatureVariation::parse_tag: {} found outside of a CV_CONVERT_TAG in {}",
+                        key,
+                        self.identifier
+                    );
+                    return;
+                };
+                match tag {
+                    CVTag::ConvertTagMaster => rule.set_convert_master(value),
+                    CVTag::ConvertTagTarget => rule.set_convert_target(value),
+                    _ => rule.set_convert_replacement(value),
+                }
+            }
+            CVTag::Unknown => {}
+        }
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+}
+
+impl Searchable for CreatureVariation {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.get_identifier().to_string());
+        vec.push(format!("{:?}", self.get_type()));
+        vec.push("creatureVariation".to_string());
+
+        clean_search_vec(vec.as_slice())
+    }
 }
 
 #[typetag::serde]
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    creature::raw::Creature,
+    creature_variation::raw::CreatureVariationRequirements,
+    diagnostics::{self, DiagnosticSeverity},
+    raws::RawObject,
+    serializer_helper,
+};
+
+use super::tokens::CVTag;
+
+/// A single rule from a creature variation, e.g. `[CV_ADD_TAG:NAME:!ARG1]`.
+///
+/// For the conditional rules (`CV_*_CTAG`), the rule is only applied if the argument at
+/// `argument_index` (starting at 1) matches `argument_requirement`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct CreatureVariationRule {
+    rule_type: CVTag,
+    /// The tag to add or remove. For convert rules, this is the `CVCT_MASTER` tag.
+    tag: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    value: String,
+    /// The `CVCT_TARGET` of a convert rule.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    target: String,
+    /// The `CVCT_REPLACEMENT` of a convert rule.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    replacement: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    argument_index: u32,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    argument_requirement: String,
+}
+
+impl CreatureVariationRule {
+    /// Create a rule from the value of a `CV_NEW_TAG`, `CV_ADD_TAG`, `CV_REMOVE_TAG` or
+    /// `CV_CONVERT_TAG` token.
+    ///
+    /// Arguments:
+    ///
+    /// * `rule_type`: The `CVTag` of the token
+    /// * `value`: The value of the token, e.g. `NAME:!ARG1` for `[CV_ADD_TAG:NAME:!ARG1]`
+    pub fn from_value(rule_type: CVTag, value: &str) -> Self {
+        let (tag, value) = value.split_once(':').unwrap_or((value, ""));
+        Self {
+            rule_type,
+            tag: String::from(tag),
+            value: String::from(value),
+            ..Self::default()
+        }
+    }
+
+    /// Create a rule from the value of a conditional token (`CV_NEW_CTAG`, `CV_ADD_CTAG`,
+    /// `CV_REMOVE_CTAG` or `CV_CONVERT_CTAG`), which begins with the argument index and the
+    /// value that argument needs to have.
+    ///
+    /// Arguments:
+    ///
+    /// * `rule_type`: The `CVTag` of the token
+    /// * `value`: The value of the token, e.g. `1:GIANT:BODY_SIZE:...` for `[CV_ADD_CTAG:1:GIANT:BODY_SIZE:...]`
+    pub fn from_conditional_value(rule_type: CVTag, value: &str) -> Self {
+        let mut parts = value.splitn(3, ':');
+        let argument_index = parts.next().unwrap_or_default();
+        let argument_requirement = parts.next().unwrap_or_default();
+        let rest = parts.next().unwrap_or_default();
+
+        let Ok(argument_index) = argument_index.parse::<u32>() else {
+            log::warn!(
+                "CreatureVariationRule: invalid argument index '{argument_index}' in conditional tag"
+            );
+            return Self::from_value(rule_type, rest);
+        };
+
+        Self {
+            argument_index,
+            argument_requirement: String::from(argument_requirement),
+            ..Self::from_value(rule_type, rest)
+        }
+    }
+
+    pub fn get_rule_type(&self) -> &CVTag {
+        &self.rule_type
+    }
+    pub fn is_convert_rule(&self) -> bool {
+        self.rule_type == CVTag::ConvertTag || self.rule_type == CVTag::ConditionalConvertTag
+    }
+
+    /// Set the `CVCT_MASTER` tag of a convert rule.
+    pub fn set_convert_master(&mut self, tag: &str) {
+        self.tag = String::from(tag);
+    }
+    /// Set the `CVCT_TARGET` of a convert rule.
+    pub fn set_convert_target(&mut self, target: &str) {
+        self.target = String::from(target);
+    }
+    /// Set the `CVCT_REPLACEMENT` of a convert rule.
+    pub fn set_convert_replacement(&mut self, replacement: &str) {
+        self.replacement = String::from(replacement);
+    }
+
+    /// Check the requirement of a conditional rule against the arguments. Rules which are not
+    /// conditional always pass.
+    fn meets_requirement(&self, args: &[&str]) -> bool {
+        if self.argument_index == 0 {
+            return true;
+        }
+        let index = self.argument_index as usize - 1;
+        args.get(index)
+            .is_some_and(|arg| arg.eq_ignore_ascii_case(&self.argument_requirement))
+    }
+
+    /// Apply this rule to the creature, substituting any `!ARGn` in the rule with the arguments
+    /// given to `APPLY_CREATURE_VARIATION`.
+    ///
+    /// Arguments:
+    ///
+    /// * `creature`: The creature to apply the rule to
+    /// * `args`: The arguments for the variation (the first argument replaces `!ARG1`)
+    pub fn apply(&self, creature: &mut Creature, args: &[&str]) {
+        if !self.meets_requirement(args) {
+            return;
+        }
+
+        let tag = replace_args(&self.tag, args);
+        let value = replace_args(&self.value, args);
+
+        match self.rule_type {
+            CVTag::NewTag | CVTag::AddTag | CVTag::ConditionalNewTag | CVTag::ConditionalAddTag => {
+                match tag.as_str() {
+                    "CASTE" => creature.add_caste(&value),
+                    "SELECT_CASTE" | "SELECT_ADDITIONAL_CASTE" => creature.select_caste(&value),
+                    _ => creature.add_tag_and_value(&tag, &value),
+                }
+            }
+            CVTag::RemoveTag | CVTag::ConditionalRemoveTag => {
+                // Removing a tag removes it from every caste of the creature.
+                let castes: Vec<String> = creature
+                    .get_castes()
+                    .iter()
+                    .map(|caste| String::from(caste.get_identifier()))
+                    .collect();
+                for caste in &castes {
+                    creature.remove_tag_and_value_for_caste(&tag, &value, caste);
+                }
+            }
+            CVTag::ConvertTag | CVTag::ConditionalConvertTag => {
+                let target = replace_args(&self.target, args);
+                let replacement = replace_args(&self.replacement, args);
+                if !creature.convert_tag(&tag, &target, &replacement) {
+                    diagnostics::set_token(&format!("[CVCT_MASTER:{tag}]"));
+                    diagnostics::report(
+                        DiagnosticSeverity::Warning,
+                        &format!(
+                            "CV_CONVERT_TAG can't convert {tag}, so it is left unchanged on {}",
+                            creature.get_identifier()
+                        ),
+                    );
+                }
+            }
+            _ => {
+                log::debug!(
+                    "CreatureVariationRule: {:?} is not a rule that can be applied",
+                    self.rule_type
+                );
+            }
+        }
+    }
+}
+
+/// Replace each `!ARGn` in the text with the matching argument. This goes from the last
+/// argument to the first so that `!ARG10` is not replaced as `!ARG1` followed by a `0`.
+fn replace_args(text: &str, args: &[&str]) -> String {
+    if !text.contains("!ARG") {
+        return String::from(text);
+    }
+    let mut result = String::from(text);
+    for (index, arg) in args.iter().enumerate().rev() {
+        result = result.replace(&format!("!ARG{}", index + 1), arg);
+    }
+    result
+}
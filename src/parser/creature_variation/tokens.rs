@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::parser::diagnostics::{self, DiagnosticSeverity};
+
 use super::phf_table::CV_TOKENS;
 
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub enum CVTag {
     NewTag,
@@ -24,6 +28,10 @@ impl CVTag {
         let tag = CV_TOKENS.get(key).unwrap_or(&CVTag::Unknown);
         if tag == &CVTag::Unknown {
             log::warn!("Unknown creature variation (CV) tag: {}", key);
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Unknown creature variation tag {key}"),
+            );
         }
         tag.clone()
     }
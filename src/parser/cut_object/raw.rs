@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+/// A request to remove an object defined elsewhere, from a `[CUT_CREATURE:...]` or
+/// `[CUT_INORGANIC:...]` token. The target is removed when the cuts are applied (see
+/// `apply_cut_objects`).
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct CutObject {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+    /// Either `CutCreature` or `CutInorganic`
+    object_type: ObjectType,
+}
+
+impl CutObject {
+    pub fn new(identifier: &str, metadata: &RawMetadata, object_type: ObjectType) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &object_type),
+            object_type,
+        }
+    }
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    /// Returns the type of object the cut removes, e.g. `Creature` for a `CUT_CREATURE`.
+    pub fn get_target_type(&self) -> ObjectType {
+        match self.object_type {
+            ObjectType::CutCreature => ObjectType::Creature,
+            ObjectType::CutInorganic => ObjectType::Inorganic,
+            _ => ObjectType::Unknown,
+        }
+    }
+}
+
+#[typetag::serde]
+impl RawObject for CutObject {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &self.object_type
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, _value: &str) {
+        log::debug!(
+            "CutObject::parse_tag: Ignoring tag {key} after cut of {}",
+            self.identifier
+        );
+    }
+}
+
+impl Searchable for CutObject {
+    fn get_search_vec(&self) -> Vec<String> {
+        let vec = vec![
+            self.get_identifier().to_string(),
+            format!("{:?}", self.get_type()),
+        ];
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{phf_table::DESCRIPTOR_COLOR_TOKENS, rgb::Rgb, tokens::DescriptorColorToken};
+
+/// A color from an `[OBJECT:DESCRIPTOR_COLOR]` raw file, e.g. `[COLOR:AMBER]`.
+///
+/// Colors are referred to by identifier in material state colors, tissue layer color modifiers
+/// and color patterns.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct DescriptorColor {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "String::is_empty")]
+    name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    word: String,
+    rgb: Rgb,
+    /// The RGB value as a hex string, e.g. `#ffbf00`.
+    hex: String,
+}
+
+impl DescriptorColor {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(
+                metadata,
+                identifier,
+                &ObjectType::DescriptorColor,
+            ),
+            hex: Rgb::default().to_hex(),
+            ..Self::default()
+        }
+    }
+    pub fn get_word(&self) -> &str {
+        &self.word
+    }
+    pub fn get_rgb(&self) -> Rgb {
+        self.rgb
+    }
+    pub fn get_hex(&self) -> &str {
+        &self.hex
+    }
+}
+
+#[typetag::serde]
+impl RawObject for DescriptorColor {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::DescriptorColor
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = DESCRIPTOR_COLOR_TOKENS.get(key) else {
+            log::debug!(
+                "DescriptorColor::parse_tag: Unknown color tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown color tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            DescriptorColorToken::Name => self.name = String::from(value),
+            DescriptorColorToken::Word => self.word = String::from(value),
+            DescriptorColorToken::Rgb => {
+                self.rgb = Rgb::from_value(value);
+                self.hex = self.rgb.to_hex();
+            }
+            DescriptorColorToken::Unknown => {}
+        }
+    }
+}
+
+impl Searchable for DescriptorColor {
+    fn get_search_vec(&self) -> Vec<String> {
+        let vec = vec![self.identifier.clone(), self.name.clone(), self.hex.clone()];
+
+        clean_search_vec(vec.as_slice())
+    }
+}
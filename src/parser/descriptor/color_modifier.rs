@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// The colors a caste's tissue layers can have, from a token like
+/// `[TL_COLOR_MODIFIER:BROWN:1:IRIS_EYE_AMETHYST:1]`. Each creature picks one of the colors (or
+/// color patterns), weighted by its frequency.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct TissueLayerColorModifier {
+    /// The tissue layers the modifier applies to, from the `SET_TL_GROUP` and `PLUS_TL_GROUP`
+    /// tokens before it, e.g. `BY_CATEGORY:EYE:EYE`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    groups: Vec<String>,
+    /// The identifiers of the colors or color patterns, with their frequencies.
+    colors: Vec<(String, u32)>,
+    /// The noun used to describe the layers, from `TLCM_NOUN`, e.g. `eyes:PLURAL`.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    noun: String,
+}
+
+impl TissueLayerColorModifier {
+    pub fn from_value(value: &str, groups: &[String]) -> Self {
+        let parts: Vec<&str> = value.split(':').collect();
+        Self {
+            groups: groups.to_vec(),
+            colors: parts
+                .chunks(2)
+                .map(|pair| {
+                    (
+                        String::from(pair[0]),
+                        pair.get(1).and_then(|f| f.parse().ok()).unwrap_or_default(),
+                    )
+                })
+                .collect(),
+            noun: String::new(),
+        }
+    }
+    pub fn get_groups(&self) -> &[String] {
+        self.groups.as_slice()
+    }
+    pub fn get_colors(&self) -> &[(String, u32)] {
+        self.colors.as_slice()
+    }
+    pub fn get_noun(&self) -> &str {
+        &self.noun
+    }
+    pub fn set_noun(&mut self, noun: &str) {
+        self.noun = String::from(noun);
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{material::raw::Material, object_types::ObjectType, raws::RawObject};
+
+use super::{
+    color::DescriptorColor, color_modifier::TissueLayerColorModifier, pattern::DescriptorPattern,
+    rgb::Rgb,
+};
+
+/// A color identifier resolved to its `DescriptorColor`, e.g. `AMBER` to `#ffbf00`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ResolvedColor {
+    identifier: String,
+    name: String,
+    rgb: Rgb,
+    hex: String,
+}
+
+impl ResolvedColor {
+    fn from_color(color: &DescriptorColor) -> Self {
+        Self {
+            identifier: String::from(color.get_identifier()),
+            name: String::from(color.get_name()),
+            rgb: color.get_rgb(),
+            hex: String::from(color.get_hex()),
+        }
+    }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_rgb(&self) -> Rgb {
+        self.rgb
+    }
+    pub fn get_hex(&self) -> &str {
+        &self.hex
+    }
+}
+
+/// Resolves the color and color pattern identifiers used in other raws (material state colors
+/// and tissue layer color modifiers) to RGB values, using the parsed `DescriptorColor` and
+/// `DescriptorPattern` raws.
+///
+/// If several colors or patterns have the same identifier, the last one parsed is used (so a mod
+/// can replace them).
+pub struct ColorResolver<'a> {
+    colors: HashMap<&'a str, &'a DescriptorColor>,
+    patterns: HashMap<&'a str, &'a DescriptorPattern>,
+}
+
+impl<'a> ColorResolver<'a> {
+    pub fn new(all_raws: &'a [Box<dyn RawObject>]) -> Self {
+        let mut colors = HashMap::new();
+        let mut patterns = HashMap::new();
+
+        for raw in all_raws {
+            match raw.get_type() {
+                ObjectType::DescriptorColor => {
+                    if let Some(color) = raw.as_any().downcast_ref::<DescriptorColor>() {
+                        colors.insert(color.get_identifier(), color);
+                    }
+                }
+                ObjectType::DescriptorPattern => {
+                    if let Some(pattern) = raw.as_any().downcast_ref::<DescriptorPattern>() {
+                        patterns.insert(pattern.get_identifier(), pattern);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self { colors, patterns }
+    }
+
+    /// Returns the color with the given identifier, if it was parsed.
+    pub fn get_color(&self, identifier: &str) -> Option<ResolvedColor> {
+        self.colors
+            .get(identifier)
+            .map(|color| ResolvedColor::from_color(color))
+    }
+
+    /// Resolve a color or color pattern identifier. A color resolves to itself and a pattern to
+    /// each of its colors, in order.
+    ///
+    /// Returns:
+    ///
+    /// The colors, which is empty if the identifier isn't a parsed color or pattern.
+    pub fn resolve(&self, identifier: &str) -> Vec<ResolvedColor> {
+        if let Some(color) = self.get_color(identifier) {
+            return vec![color];
+        }
+        let Some(pattern) = self.patterns.get(identifier) else {
+            log::debug!("ColorResolver::resolve: unknown color or pattern {identifier}");
+            return Vec::new();
+        };
+        pattern
+            .get_colors()
+            .iter()
+            .filter_map(|color| self.get_color(color))
+            .collect()
+    }
+
+    /// Resolve each of the colors (or color patterns) a tissue layer color modifier can pick from.
+    ///
+    /// Returns:
+    ///
+    /// The resolved colors of each color or pattern identifier in the modifier, in order.
+    pub fn resolve_color_modifier(
+        &self,
+        modifier: &TissueLayerColorModifier,
+    ) -> Vec<(String, Vec<ResolvedColor>)> {
+        modifier
+            .get_colors()
+            .iter()
+            .map(|(identifier, _)| (identifier.clone(), self.resolve(identifier)))
+            .collect()
+    }
+
+    /// Resolve the state colors of a material (e.g. `[STATE_COLOR:ALL_SOLID:AMETHYST]` on a gem).
+    ///
+    /// Returns:
+    ///
+    /// The color of each state which has a known color, as (state, color), where the state is
+    /// `SOLID`, `LIQUID` or `GAS`.
+    pub fn resolve_state_colors(&self, material: &Material) -> Vec<(String, ResolvedColor)> {
+        let state_colors = material.get_state_colors();
+        [
+            ("SOLID", state_colors.get_solid()),
+            ("LIQUID", state_colors.get_liquid()),
+            ("GAS", state_colors.get_gas()),
+        ]
+        .into_iter()
+        .filter_map(|(state, color)| {
+            self.get_color(color)
+                .map(|resolved| (String::from(state), resolved))
+        })
+        .collect()
+    }
+}
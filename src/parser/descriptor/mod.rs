@@ -0,0 +1,8 @@
+pub mod color;
+pub mod color_modifier;
+pub mod color_resolver;
+pub mod pattern;
+pub mod phf_table;
+pub mod rgb;
+pub mod shape;
+pub mod tokens;
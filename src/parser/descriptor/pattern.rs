@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{phf_table::DESCRIPTOR_PATTERN_TOKENS, tokens::DescriptorPatternToken};
+
+/// A color pattern from an `[OBJECT:DESCRIPTOR_PATTERN]` raw file, e.g.
+/// `[COLOR_PATTERN:IRIS_EYE_AMETHYST]`, which combines several colors.
+///
+/// Patterns can be used instead of colors in tissue layer color modifiers.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct DescriptorPattern {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    /// The kind of pattern, e.g. `IRIS_EYE`.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pattern: String,
+    /// The identifiers of the colors in the pattern, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    colors: Vec<String>,
+}
+
+impl DescriptorPattern {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(
+                metadata,
+                identifier,
+                &ObjectType::DescriptorPattern,
+            ),
+            ..Self::default()
+        }
+    }
+    pub fn get_pattern(&self) -> &str {
+        &self.pattern
+    }
+    pub fn get_colors(&self) -> &[String] {
+        self.colors.as_slice()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for DescriptorPattern {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::DescriptorPattern
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = DESCRIPTOR_PATTERN_TOKENS.get(key) else {
+            log::debug!(
+                "DescriptorPattern::parse_tag: Unknown pattern tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown color pattern tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            DescriptorPatternToken::Pattern => self.pattern = String::from(value),
+            DescriptorPatternToken::Color => self.colors.push(String::from(value)),
+            DescriptorPatternToken::Unknown => {}
+        }
+    }
+}
+
+impl Searchable for DescriptorPattern {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.push(self.pattern.clone());
+        vec.extend(self.colors.iter().cloned());
+
+        clean_search_vec(vec.as_slice())
+    }
+}
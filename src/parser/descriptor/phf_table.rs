@@ -0,0 +1,23 @@
+use super::tokens::{DescriptorColorToken, DescriptorPatternToken, DescriptorShapeToken};
+
+pub static DESCRIPTOR_COLOR_TOKENS: phf::Map<&'static str, DescriptorColorToken> = phf::phf_map! {
+    "NAME" => DescriptorColorToken::Name,
+    "WORD" => DescriptorColorToken::Word,
+    "RGB" => DescriptorColorToken::Rgb,
+};
+
+pub static DESCRIPTOR_PATTERN_TOKENS: phf::Map<&'static str, DescriptorPatternToken> = phf::phf_map! {
+    "PATTERN" => DescriptorPatternToken::Pattern,
+    "CP_COLOR" => DescriptorPatternToken::Color,
+};
+
+pub static DESCRIPTOR_SHAPE_TOKENS: phf::Map<&'static str, DescriptorShapeToken> = phf::phf_map! {
+    "NAME" => DescriptorShapeToken::Name,
+    "ADJ" => DescriptorShapeToken::Adjective,
+    "WORD" => DescriptorShapeToken::Word,
+    "TILE" => DescriptorShapeToken::Tile,
+    "CATEGORY" => DescriptorShapeToken::Category,
+    "GEMS_USE_NOUN" => DescriptorShapeToken::GemsUseNoun,
+    "GEMS_USE_ADJ" => DescriptorShapeToken::GemsUseAdjective,
+    "GEMS_USE_ADJ_NOUN" => DescriptorShapeToken::GemsUseAdjectiveNoun,
+};
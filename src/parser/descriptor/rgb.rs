@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// A color as red, green and blue values from 0 to 255.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Rgb {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl Rgb {
+    pub fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+    /// Parse a value like `255:191:0` (from `[RGB:255:191:0]`). Any part which is missing or
+    /// isn't a number from 0 to 255 is 0.
+    pub fn from_value(value: &str) -> Self {
+        let mut split = value.split(':');
+        let mut next = || split.next().unwrap_or_default().parse().unwrap_or_default();
+        Self {
+            red: next(),
+            green: next(),
+            blue: next(),
+        }
+    }
+    pub fn get_red(&self) -> u8 {
+        self.red
+    }
+    pub fn get_green(&self) -> u8 {
+        self.green
+    }
+    pub fn get_blue(&self) -> u8 {
+        self.blue
+    }
+    /// The color as a CSS style hex string, e.g. `#ffbf00`.
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    names::SingPlurName,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{phf_table::DESCRIPTOR_SHAPE_TOKENS, tokens::DescriptorShapeToken};
+
+/// A shape from an `[OBJECT:DESCRIPTOR_SHAPE]` raw file, e.g. `[SHAPE:CIRCLE]`, which is used for
+/// cut gems and in descriptions of art.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct DescriptorShape {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "SingPlurName::is_empty")]
+    name: SingPlurName,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    adjectives: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    words: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    tile: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    categories: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<DescriptorShapeToken>,
+}
+
+impl DescriptorShape {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(
+                metadata,
+                identifier,
+                &ObjectType::DescriptorShape,
+            ),
+            ..Self::default()
+        }
+    }
+    pub fn get_adjectives(&self) -> &[String] {
+        self.adjectives.as_slice()
+    }
+    pub fn get_tags(&self) -> &[DescriptorShapeToken] {
+        self.tags.as_slice()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for DescriptorShape {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        self.name.get_singular()
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::DescriptorShape
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = DESCRIPTOR_SHAPE_TOKENS.get(key) else {
+            log::debug!(
+                "DescriptorShape::parse_tag: Unknown shape tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown shape tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            DescriptorShapeToken::Name => self.name = SingPlurName::from_value(value),
+            DescriptorShapeToken::Adjective => self.adjectives.push(String::from(value)),
+            DescriptorShapeToken::Word => self.words.push(String::from(value)),
+            DescriptorShapeToken::Tile => self.tile = String::from(value),
+            DescriptorShapeToken::Category => self.categories.push(String::from(value)),
+            DescriptorShapeToken::Unknown => {}
+            _ => self.tags.push(tag.clone()),
+        }
+    }
+}
+
+impl Searchable for DescriptorShape {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.extend(self.name.as_vec());
+        vec.extend(self.adjectives.iter().cloned());
+
+        clean_search_vec(vec.as_slice())
+    }
+}
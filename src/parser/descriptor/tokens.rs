@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum DescriptorColorToken {
+    /// Arguments: name
+    ///
+    /// The name of the color, e.g. `amber`.
+    Name,
+    /// Arguments: word
+    ///
+    /// The language word for the color, used in names, e.g. `AMBER`.
+    Word,
+    /// Arguments: red, green, blue
+    ///
+    /// The color as red, green and blue values from 0 to 255, e.g. `[RGB:255:191:0]`.
+    Rgb,
+    #[default]
+    Unknown,
+}
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum DescriptorPatternToken {
+    /// Arguments: pattern
+    ///
+    /// The kind of pattern, e.g. `STRIPES`, `SPOTS`, `MOTTLED`, `IRIS_EYE` or `PUPIL_EYE`.
+    Pattern,
+    /// Arguments: color
+    ///
+    /// A color in the pattern, e.g. `[CP_COLOR:WHITE]`. The order of the colors depends on the
+    /// kind of pattern.
+    Color,
+    #[default]
+    Unknown,
+}
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum DescriptorShapeToken {
+    /// Arguments: name, plural name
+    ///
+    /// The name of the shape, e.g. `[NAME:circle:circles]`.
+    Name,
+    /// Arguments: adjective
+    ///
+    /// An adjective for the shape, e.g. `circular`.
+    Adjective,
+    /// Arguments: word
+    ///
+    /// The language word for the shape, used in names.
+    Word,
+    /// Arguments: tile
+    ///
+    /// The tile used for the shape, e.g. `9`.
+    Tile,
+    /// Arguments: category
+    ///
+    /// A category of the shape, e.g. `SIMPLE`.
+    Category,
+    /// Gems cut into the shape are described with its name, e.g. `circle`.
+    GemsUseNoun,
+    /// Gems cut into the shape are described with its adjective, e.g. `circular`.
+    GemsUseAdjective,
+    /// Gems cut into the shape are described with its adjective and name.
+    GemsUseAdjectiveNoun,
+    #[default]
+    Unknown,
+}
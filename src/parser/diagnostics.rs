@@ -0,0 +1,232 @@
+use std::cell::RefCell;
+
+use serde::{Deserialize, Serialize};
+
+use super::module_versions::DroppedModule;
+
+/// How serious a `Diagnostic` is.
+///
+/// * `Error`: a file or value could not be parsed, and it was skipped or replaced by a default.
+/// * `Warning`: a token was not understood and was ignored.
+/// * `Info`: a token is not (yet) supported by the library and was ignored.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagnosticSeverity {
+    Error,
+    #[default]
+    Warning,
+    Info,
+}
+
+/// A single problem found while parsing, with enough information to find it in the raw files.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Diagnostic {
+    severity: DiagnosticSeverity,
+    message: String,
+    /// The raw file the diagnostic is from.
+    file_path: String,
+    /// The line in the raw file (starting at 1). This is 0 if the diagnostic isn't tied to a line.
+    line_number: usize,
+    /// The identifier of the object being parsed when the diagnostic was reported.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    object_identifier: String,
+    /// The full token text, e.g. `[BOGUS_TAG:1]`.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    raw_token: String,
+}
+
+impl Diagnostic {
+    pub fn get_severity(&self) -> DiagnosticSeverity {
+        self.severity
+    }
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    pub fn get_file_path(&self) -> &str {
+        &self.file_path
+    }
+    pub fn get_line_number(&self) -> usize {
+        self.line_number
+    }
+    pub fn get_object_identifier(&self) -> &str {
+        &self.object_identifier
+    }
+    pub fn get_raw_token(&self) -> &str {
+        &self.raw_token
+    }
+}
+
+/// The `ParseReport` collects all the diagnostics reported during a parse. It is returned alongside
+/// the parsed raws by `parse_with_report`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ParseReport {
+    diagnostics: Vec<Diagnostic>,
+    /// The modules which weren't parsed because of the `ModuleVersionPolicy`. This is the only place
+    /// they are listed: `parse` only logs them.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    dropped_modules: Vec<DroppedModule>,
+}
+
+impl ParseReport {
+    pub fn new(diagnostics: Vec<Diagnostic>) -> Self {
+        Self {
+            diagnostics,
+            dropped_modules: Vec::new(),
+        }
+    }
+    /// Get all the diagnostics in the order they were reported.
+    pub fn get_diagnostics(&self) -> &[Diagnostic] {
+        self.diagnostics.as_slice()
+    }
+    /// Get only the diagnostics with the given severity.
+    pub fn with_severity(&self, severity: DiagnosticSeverity) -> Vec<&Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .collect()
+    }
+    /// Returns true if any `DiagnosticSeverity::Error` diagnostics were reported.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|d| d.severity == DiagnosticSeverity::Error)
+    }
+    pub fn len(&self) -> usize {
+        self.diagnostics.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+    pub fn extend(&mut self, diagnostics: Vec<Diagnostic>) {
+        self.diagnostics.extend(diagnostics);
+    }
+    /// Get the modules which weren't parsed because another version of them was kept.
+    pub fn get_dropped_modules(&self) -> &[DroppedModule] {
+        self.dropped_modules.as_slice()
+    }
+}
+
+/// Where in the raw files the parser currently is. This is copied into each reported diagnostic.
+#[derive(Default)]
+struct DiagnosticContext {
+    file_path: String,
+    line_number: usize,
+    object_identifier: String,
+    raw_token: String,
+}
+
+#[derive(Default)]
+struct DiagnosticCollector {
+    context: DiagnosticContext,
+    diagnostics: Vec<Diagnostic>,
+    dropped_modules: Vec<DroppedModule>,
+}
+
+thread_local! {
+    // Only set while `capture` is running, so reporting is a no-op for a normal parse.
+    static COLLECTOR: RefCell<Option<DiagnosticCollector>> = const { RefCell::new(None) };
+}
+
+/// Run `f` and collect any diagnostics (and dropped modules) reported on this thread while it runs.
+pub(crate) fn capture<T>(f: impl FnOnce() -> T) -> (T, ParseReport) {
+    let previous = COLLECTOR.with(|c| c.replace(Some(DiagnosticCollector::default())));
+    let result = f();
+    let report = COLLECTOR
+        .with(|c| c.replace(previous))
+        .map(|collector| ParseReport {
+            diagnostics: collector.diagnostics,
+            dropped_modules: collector.dropped_modules,
+        })
+        .unwrap_or_default();
+    (result, report)
+}
+
+/// Returns true if diagnostics are being collected on this thread (i.e. inside `capture`).
+#[cfg(feature = "rayon")]
+pub(crate) fn is_capturing() -> bool {
+    COLLECTOR.with(|c| c.borrow().is_some())
+}
+
+/// Add the diagnostics and dropped modules from a `ParseReport` (collected on another thread, or
+/// read from the cache) to the ones being collected on this thread. This does nothing unless the
+/// parse is being run with `capture`.
+pub(crate) fn merge(report: ParseReport) {
+    COLLECTOR.with(|c| {
+        if let Some(collector) = c.borrow_mut().as_mut() {
+            collector.diagnostics.extend(report.diagnostics);
+            collector.dropped_modules.extend(report.dropped_modules);
+        }
+    });
+}
+
+fn with_context(f: impl FnOnce(&mut DiagnosticContext)) {
+    COLLECTOR.with(|c| {
+        if let Some(collector) = c.borrow_mut().as_mut() {
+            f(&mut collector.context);
+        }
+    });
+}
+
+/// Set the raw file being parsed. This resets the rest of the context.
+pub(crate) fn set_file(file_path: &str) {
+    with_context(|context| {
+        *context = DiagnosticContext {
+            file_path: String::from(file_path),
+            ..DiagnosticContext::default()
+        };
+    });
+}
+
+/// Set the line (starting at 1) being parsed.
+pub(crate) fn set_line(line_number: usize) {
+    with_context(|context| context.line_number = line_number);
+}
+
+/// Set the identifier of the object being parsed.
+pub(crate) fn set_object(object_identifier: &str) {
+    with_context(|context| context.object_identifier = String::from(object_identifier));
+}
+
+/// Set the full text of the token being parsed.
+pub(crate) fn set_token(raw_token: &str) {
+    with_context(|context| context.raw_token = String::from(raw_token));
+}
+
+/// Report a diagnostic at the current position of the parser. This does nothing unless the parse
+/// is being run with `capture`.
+pub(crate) fn report(severity: DiagnosticSeverity, message: &str) {
+    COLLECTOR.with(|c| {
+        if let Some(collector) = c.borrow_mut().as_mut() {
+            let context = &collector.context;
+            let diagnostic = Diagnostic {
+                severity,
+                message: String::from(message),
+                file_path: context.file_path.clone(),
+                line_number: context.line_number,
+                object_identifier: context.object_identifier.clone(),
+                raw_token: context.raw_token.clone(),
+            };
+            collector.diagnostics.push(diagnostic);
+        }
+    });
+}
+
+/// Record a module which wasn't parsed because of the `ModuleVersionPolicy`. This does nothing
+/// unless the parse is being run with `capture`. `select_module_versions` also logs each dropped
+/// module as a warning, so a normal parse doesn't drop them silently.
+pub(crate) fn report_dropped_module(dropped_module: DroppedModule) {
+    COLLECTOR.with(|c| {
+        if let Some(collector) = c.borrow_mut().as_mut() {
+            collector.dropped_modules.push(dropped_module);
+        }
+    });
+}
@@ -0,0 +1,482 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ParserError;
+
+use super::{
+    object_types::{ObjectType, OBJECT_TOKENS},
+    reader::header::read_raw_file_text,
+    refs::RAW_TOKEN_RE,
+};
+
+/// Where a piece of a raw file is in the file's text.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Span {
+    /// The byte offset of the start of the piece in the text
+    start: usize,
+    /// The byte offset just past the end of the piece in the text
+    end: usize,
+    /// The line the piece starts on (the header line is line 1)
+    line: usize,
+    /// The column (in characters) the piece starts at on its line, starting from 1
+    column: usize,
+}
+
+impl Span {
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+    pub fn get_end(&self) -> usize {
+        self.end
+    }
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+    /// The length of the piece in bytes.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// A token in a raw file, e.g. `[NAME:dog:dogs:dog]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RawToken {
+    /// The key of the token, e.g. `NAME`
+    key: String,
+    /// Everything after the first `:`, e.g. `dog:dogs:dog`, or empty if there is no `:`
+    value: String,
+    /// The text of the whole token, including the brackets
+    text: String,
+    span: Span,
+}
+
+impl RawToken {
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    /// The values of the token, split on `:`. A token with no value has no values.
+    pub fn get_values(&self) -> Vec<&str> {
+        if self.value.is_empty() {
+            return Vec::new();
+        }
+        self.value.split(':').collect()
+    }
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+}
+
+/// Text in a raw file which isn't part of a token: whitespace, line endings, and comments (Dwarf
+/// Fortress ignores anything outside of brackets).
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RawText {
+    text: String,
+    span: Span,
+}
+
+impl RawText {
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+    /// Whether the text is only whitespace (and line endings), rather than a comment.
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
+
+/// A piece of a raw file: either a token, or the text between tokens.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum RawElement {
+    Token(RawToken),
+    Text(RawText),
+}
+
+impl RawElement {
+    /// The text of the element, exactly as it is in the raw file.
+    pub fn get_text(&self) -> &str {
+        match self {
+            RawElement::Token(token) => token.get_text(),
+            RawElement::Text(text) => text.get_text(),
+        }
+    }
+    pub fn get_span(&self) -> Span {
+        match self {
+            RawElement::Token(token) => token.get_span(),
+            RawElement::Text(text) => text.get_span(),
+        }
+    }
+    pub fn as_token(&self) -> Option<&RawToken> {
+        match self {
+            RawElement::Token(token) => Some(token),
+            RawElement::Text(_) => None,
+        }
+    }
+}
+
+/// An object defined in a raw file: the token which starts it (e.g. `[CREATURE:DOG]`), and
+/// everything after it up to the next object.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RawObjectBlock {
+    header: RawToken,
+    elements: Vec<RawElement>,
+}
+
+impl RawObjectBlock {
+    /// The token which starts the object, e.g. `[CREATURE:DOG]`.
+    pub fn get_header(&self) -> &RawToken {
+        &self.header
+    }
+    /// The identifier of the object, e.g. `DOG`.
+    pub fn get_identifier(&self) -> &str {
+        self.header.get_value()
+    }
+    /// The tokens and text in the object after its header token.
+    pub fn get_elements(&self) -> &[RawElement] {
+        &self.elements
+    }
+    /// The tokens in the object after its header token.
+    pub fn tokens(&self) -> impl Iterator<Item = &RawToken> {
+        self.elements.iter().filter_map(RawElement::as_token)
+    }
+    /// The span of the whole object, from the start of its header token to the end of its last
+    /// element.
+    pub fn get_span(&self) -> Span {
+        let mut span = self.header.get_span();
+        if let Some(last) = self.elements.last() {
+            span.end = last.get_span().end;
+        }
+        span
+    }
+}
+
+/// A lossless syntax tree of a raw file.
+///
+/// The file is split into its header line (the raw file's identifier), the part before the first
+/// object (which has the `[OBJECT:...]` token), and the objects defined in the file. Every token
+/// and every piece of text between the tokens (whitespace, line endings and comments) is kept with
+/// its position, so `to_source` gives back exactly the text the document was parsed from.
+///
+/// An object starts at a token which starts an object of the file's `[OBJECT:...]` type (e.g.
+/// `[CREATURE:...]`, `[SELECT_CREATURE:...]` or `[CUT_CREATURE:...]` in a creature file), and runs
+/// up to the next one. Tokens are found the same way as by the parser: a token is on a single line,
+/// and tokens on the header line are ignored.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RawDocument {
+    /// The first line of the file (without its line ending), which is the raw file's identifier
+    header: RawText,
+    /// The type from the first `[OBJECT:...]` token, or `ObjectType::Unknown`
+    object_type: ObjectType,
+    /// Everything after the header line and before the first object
+    preamble: Vec<RawElement>,
+    objects: Vec<RawObjectBlock>,
+}
+
+impl RawDocument {
+    /// Tokenize the text of a raw file.
+    pub fn parse(raw_text: &str) -> Self {
+        let (header, elements) = tokenize(raw_text);
+
+        let object_type = elements
+            .iter()
+            .filter_map(RawElement::as_token)
+            .find(|token| token.key == "OBJECT")
+            .and_then(|token| OBJECT_TOKENS.get(&token.value))
+            .cloned()
+            .unwrap_or_default();
+        let header_keys = object_header_keys(&object_type);
+
+        let mut preamble: Vec<RawElement> = Vec::new();
+        let mut objects: Vec<RawObjectBlock> = Vec::new();
+        for element in elements {
+            match element {
+                RawElement::Token(token) if header_keys.contains(&token.key.as_str()) => {
+                    objects.push(RawObjectBlock {
+                        header: token,
+                        elements: Vec::new(),
+                    });
+                }
+                element => match objects.last_mut() {
+                    Some(object) => object.elements.push(element),
+                    None => preamble.push(element),
+                },
+            }
+        }
+
+        Self {
+            header,
+            object_type,
+            preamble,
+            objects,
+        }
+    }
+
+    /// Read a raw file (decoding it from the encoding used by Dwarf Fortress) and tokenize it.
+    ///
+    /// # Errors
+    ///
+    /// * `ParserError::InvalidPath` if the path doesn't exist or isn't a file
+    /// * `ParserError::Io` if the file can't be read
+    /// * `ParserError::Encoding` if the file can't be decoded
+    pub fn from_file<P: AsRef<Path>>(raw_file_path: &P) -> Result<Self, ParserError> {
+        Ok(Self::parse(&read_raw_file_text(raw_file_path)?))
+    }
+
+    /// The header line of the file.
+    pub fn get_header(&self) -> &RawText {
+        &self.header
+    }
+    /// The identifier of the raw file, from its header line.
+    pub fn get_raw_identifier(&self) -> &str {
+        self.header.get_text()
+    }
+    /// The type of the objects in the file, from its first `[OBJECT:...]` token. This is
+    /// `ObjectType::Unknown` if there is no `[OBJECT:...]` token, or its type is unknown.
+    pub fn get_object_type(&self) -> &ObjectType {
+        &self.object_type
+    }
+    /// The first `[OBJECT:...]` token in the file.
+    pub fn get_object_token(&self) -> Option<&RawToken> {
+        self.tokens().find(|token| token.key == "OBJECT")
+    }
+    /// The tokens and text after the header line and before the first object.
+    pub fn get_preamble(&self) -> &[RawElement] {
+        &self.preamble
+    }
+    /// The objects defined in the file.
+    pub fn get_objects(&self) -> &[RawObjectBlock] {
+        &self.objects
+    }
+    /// All of the elements after the header line, in the order they are in the file. The object
+    /// header tokens are included.
+    pub fn elements(&self) -> impl Iterator<Item = RawElementRef<'_>> {
+        self.preamble
+            .iter()
+            .map(RawElementRef::Element)
+            .chain(self.objects.iter().flat_map(|object| {
+                std::iter::once(RawElementRef::ObjectHeader(&object.header))
+                    .chain(object.elements.iter().map(RawElementRef::Element))
+            }))
+    }
+    /// All of the tokens after the header line, in the order they are in the file.
+    pub fn tokens(&self) -> impl Iterator<Item = &RawToken> {
+        self.elements().filter_map(|element| match element {
+            RawElementRef::ObjectHeader(token) => Some(token),
+            RawElementRef::Element(element) => element.as_token(),
+        })
+    }
+    /// The text the document was parsed from.
+    pub fn to_source(&self) -> String {
+        let mut source = String::from(self.header.get_text());
+        for element in self.elements() {
+            source.push_str(element.get_text());
+        }
+        source
+    }
+}
+
+/// An element of a `RawDocument`, as returned by `RawDocument::elements`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawElementRef<'a> {
+    /// The token which starts an object
+    ObjectHeader(&'a RawToken),
+    /// Any other token, or text
+    Element(&'a RawElement),
+}
+
+impl RawElementRef<'_> {
+    pub fn get_text(&self) -> &str {
+        match self {
+            RawElementRef::ObjectHeader(token) => token.get_text(),
+            RawElementRef::Element(element) => element.get_text(),
+        }
+    }
+    pub fn get_span(&self) -> Span {
+        match self {
+            RawElementRef::ObjectHeader(token) => token.get_span(),
+            RawElementRef::Element(element) => element.get_span(),
+        }
+    }
+}
+
+/// The keys of the tokens which start an object in a raw file of the given type.
+fn object_header_keys(object_type: &ObjectType) -> &'static [&'static str] {
+    match object_type {
+        ObjectType::Creature => &["CREATURE", "SELECT_CREATURE", "CUT_CREATURE"],
+        ObjectType::CreatureVariation => &["CREATURE_VARIATION"],
+        ObjectType::Plant => &["PLANT", "SELECT_PLANT"],
+        ObjectType::Inorganic => &["INORGANIC", "SELECT_INORGANIC", "CUT_INORGANIC"],
+        ObjectType::MaterialTemplate => &["MATERIAL_TEMPLATE"],
+        ObjectType::Graphics => &[
+            "CREATURE_GRAPHICS",
+            "CREATURE_CASTE_GRAPHICS",
+            "TILE_GRAPHICS",
+            "PLANT_GRAPHICS",
+            "CUSTOM_WORKSHOP_GRAPHICS",
+            "TILE_PAGE",
+        ],
+        ObjectType::TilePage => &["TILE_PAGE"],
+        ObjectType::Entity => &["ENTITY"],
+        ObjectType::Item => &[
+            "ITEM_AMMO",
+            "ITEM_ARMOR",
+            "ITEM_FOOD",
+            "ITEM_GLOVES",
+            "ITEM_HELM",
+            "ITEM_INSTRUMENT",
+            "ITEM_PANTS",
+            "ITEM_SHIELD",
+            "ITEM_SHOES",
+            "ITEM_SIEGEAMMO",
+            "ITEM_TOOL",
+            "ITEM_TOY",
+            "ITEM_TRAPCOMP",
+            "ITEM_WEAPON",
+        ],
+        ObjectType::Reaction => &["REACTION"],
+        ObjectType::Building => &["BUILDING_WORKSHOP", "BUILDING_FURNACE"],
+        ObjectType::Body => &["BODY"],
+        ObjectType::BodyDetailPlan => &["BODY_DETAIL_PLAN"],
+        ObjectType::TissueTemplate => &["TISSUE_TEMPLATE"],
+        ObjectType::Language => &["WORD", "TRANSLATION", "SYMBOL"],
+        ObjectType::Translation => &["TRANSLATION"],
+        ObjectType::Interaction => &["INTERACTION"],
+        ObjectType::DescriptorColor => &["COLOR"],
+        ObjectType::DescriptorPattern => &["COLOR_PATTERN"],
+        ObjectType::DescriptorShape => &["SHAPE"],
+        ObjectType::Palette => &["PALETTE"],
+        ObjectType::Music => &["MUSIC"],
+        ObjectType::Sound => &["SOUND"],
+        ObjectType::TextSet => &["TEXT_SET"],
+        _ => &[],
+    }
+}
+
+/// Split the text of a raw file into its header line, and the tokens and text after it (line by
+/// line). Consecutive pieces of text are joined together.
+fn tokenize(raw_text: &str) -> (RawText, Vec<RawElement>) {
+    let mut header = RawText::default();
+    let mut elements: Vec<RawElement> = Vec::new();
+    let mut offset = 0;
+
+    for (index, line_with_ending) in raw_text.split_inclusive('\n').enumerate() {
+        let line_number = index + 1;
+        // The same line as `str::lines` gives (without "\n" or "\r\n")
+        let line = line_with_ending
+            .strip_suffix('\n')
+            .map_or(line_with_ending, |line| {
+                line.strip_suffix('\r').unwrap_or(line)
+            });
+
+        let mut position = 0;
+        if index == 0 {
+            // The header line is the raw file's identifier, and has no tokens
+            header = RawText {
+                text: String::from(line),
+                span: Span {
+                    start: 0,
+                    end: line.len(),
+                    line: 1,
+                    column: 1,
+                },
+            };
+            position = line.len();
+        } else {
+            for cap in RAW_TOKEN_RE.captures_iter(line) {
+                let (Some(whole), Some(key), Some(value)) = (cap.get(0), cap.get(2), cap.get(3))
+                else {
+                    continue;
+                };
+                push_text(
+                    &mut elements,
+                    &line[position..whole.start()],
+                    offset + position,
+                    line_number,
+                    column_of(line, position),
+                );
+                elements.push(RawElement::Token(RawToken {
+                    key: String::from(key.as_str()),
+                    value: String::from(value.as_str()),
+                    text: String::from(whole.as_str()),
+                    span: Span {
+                        start: offset + whole.start(),
+                        end: offset + whole.end(),
+                        line: line_number,
+                        column: column_of(line, whole.start()),
+                    },
+                }));
+                position = whole.end();
+            }
+        }
+
+        push_text(
+            &mut elements,
+            &line_with_ending[position..],
+            offset + position,
+            line_number,
+            column_of(line, position),
+        );
+
+        offset += line_with_ending.len();
+    }
+
+    (header, elements)
+}
+
+/// Add text to the elements, joining it to the last element if that is text too.
+fn push_text(elements: &mut Vec<RawElement>, text: &str, start: usize, line: usize, column: usize) {
+    if text.is_empty() {
+        return;
+    }
+    if let Some(RawElement::Text(last)) = elements.last_mut() {
+        last.text.push_str(text);
+        last.span.end = start + text.len();
+        return;
+    }
+    elements.push(RawElement::Text(RawText {
+        text: String::from(text),
+        span: Span {
+            start,
+            end: start + text.len(),
+            line,
+            column,
+        },
+    }));
+}
+
+/// The column (in characters, starting from 1) of a byte offset in a line.
+fn column_of(line: &str, byte_offset: usize) -> usize {
+    line[..byte_offset.min(line.len())].chars().count() + 1
+}
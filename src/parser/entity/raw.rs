@@ -2,20 +2,23 @@ use serde::{Deserialize, Serialize};
 
 use crate::parser::{
     color::Color,
+    diagnostics::{self, DiagnosticSeverity},
     helpers::object_id::build_object_id_from_pieces,
     object_types::ObjectType,
     position::{phf_table::POSITION_TOKENS, raw::Position},
     raws::{RawMetadata, RawObject},
     searchable::{clean_search_vec, Searchable},
     serializer_helper,
+    writer::{write_tags, RawWriter, ToRawText},
 };
 
 use super::{phf_table::ENTITY_TOKENS, tokens::EntityToken};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Entity {
     #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
     metadata: RawMetadata,
@@ -158,29 +161,105 @@ pub struct Entity {
     source_hfid: u32,
 }
 
-impl Entity {
-    pub fn empty() -> Self {
-        Entity {
+impl Default for Entity {
+    fn default() -> Self {
+        Self {
+            metadata: RawMetadata::default(),
+            identifier: String::new(),
+            object_id: String::new(),
+            tags: Vec::new(),
+            creature: String::new(),
+            translation: String::new(),
+            exclusive_start_biome: String::new(),
+            biome_support: Vec::new(),
+            settlement_biome: Vec::new(),
+            start_biome: Vec::new(),
+            likes_sites: Vec::new(),
+            tolerates_sites: Vec::new(),
+            world_constructions: Vec::new(),
             // Default values which aren't rust defaults
             max_pop_number: 500,
             max_site_pop_number: 50,
             max_starting_civ_number: 3,
-
-            ..Default::default()
+            permitted_buildings: Vec::new(),
+            permitted_jobs: Vec::new(),
+            permitted_reactions: Vec::new(),
+            currency: Vec::new(),
+            art_facet_modifier: Vec::new(),
+            art_image_element_modifier: Vec::new(),
+            item_improvement_modifier: Vec::new(),
+            select_symbols: Vec::new(),
+            subselect_symbols: Vec::new(),
+            cull_symbols: Vec::new(),
+            friendly_color: Color::default(),
+            religion: String::new(),
+            religion_spheres: Vec::new(),
+            sphere_alignments: Vec::new(),
+            positions: Vec::new(),
+            land_holder_trigger: String::new(),
+            site_variable_positions: Vec::new(),
+            variable_positions: Vec::new(),
+            ethics: Vec::new(),
+            values: Vec::new(),
+            variable_values: Vec::new(),
+            active_season: String::new(),
+            banditry: 0.0,
+            progress_trigger_population: 0,
+            progress_trigger_production: 0,
+            progress_trigger_trade: 0,
+            progress_trigger_population_siege: 0,
+            progress_trigger_production_siege: 0,
+            progress_trigger_trade_siege: 0,
+            scholars: Vec::new(),
+            ammo: Vec::new(),
+            armors: Vec::new(),
+            diggers: Vec::new(),
+            gloves: Vec::new(),
+            helms: Vec::new(),
+            instrument: Vec::new(),
+            pants: Vec::new(),
+            shields: Vec::new(),
+            shoes: Vec::new(),
+            siege_ammo: Vec::new(),
+            tool: Vec::new(),
+            toys: Vec::new(),
+            trap_components: Vec::new(),
+            weapons: Vec::new(),
+            gem_shape: Vec::new(),
+            stone_shape: Vec::new(),
+            source_hfid: 0,
         }
     }
+}
+
+impl Entity {
+    pub fn empty() -> Self {
+        Self::default()
+    }
     pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
         Entity {
             identifier: String::from(identifier),
             metadata: metadata.clone(),
             object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Entity),
-            // Default values which aren't rust defaults
-            max_pop_number: 500,
-            max_site_pop_number: 50,
-            max_starting_civ_number: 3,
             ..Default::default()
         }
     }
+    /// Returns the identifier of the entity's language, from `TRANSLATION`.
+    pub fn get_translation(&self) -> &str {
+        &self.translation
+    }
+    /// Returns the `SELECT_SYMBOL` values, as (kind of name, symbol).
+    pub fn get_select_symbols(&self) -> &[(String, String)] {
+        self.select_symbols.as_slice()
+    }
+    /// Returns the `SUBSELECT_SYMBOL` values, as (kind of name, symbol).
+    pub fn get_subselect_symbols(&self) -> &[(String, String)] {
+        self.subselect_symbols.as_slice()
+    }
+    /// Returns the `CULL_SYMBOL` values, as (kind of name, symbol).
+    pub fn get_cull_symbols(&self) -> &[(String, String)] {
+        self.cull_symbols.as_slice()
+    }
 }
 
 #[typetag::serde]
@@ -223,6 +302,10 @@ impl RawObject for Entity {
                 "Entity::parse_tag: called `Option::unwrap()` on a `None` value for presumed Entity tag: {}",
                 key
             );
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Unknown entity tag {key}"),
+            );
             return;
         };
 
@@ -461,6 +544,188 @@ impl RawObject for Entity {
     }
 }
 
+impl ToRawText for Entity {
+    /// Write the entity as raw text, with its positions after its own tokens.
+    #[allow(clippy::too_many_lines)]
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &ENTITY_TOKENS;
+        writer.begin_object("ENTITY", &self.identifier);
+
+        let values = [
+            (EntityToken::Creature, &self.creature),
+            (EntityToken::Translation, &self.translation),
+            (
+                EntityToken::ExclusiveStartBiome,
+                &self.exclusive_start_biome,
+            ),
+            (EntityToken::Religion, &self.religion),
+            (EntityToken::LandHolderTrigger, &self.land_holder_trigger),
+            (EntityToken::ActiveSeason, &self.active_season),
+        ];
+        for (tag, value) in values {
+            if !value.is_empty() {
+                writer.token_for(tokens, &tag, value);
+            }
+        }
+        let lists = [
+            (EntityToken::SettlementBiome, &self.settlement_biome),
+            (EntityToken::StartBiome, &self.start_biome),
+            (EntityToken::LikesSite, &self.likes_sites),
+            (EntityToken::ToleratesSite, &self.tolerates_sites),
+            (EntityToken::WorldConstruction, &self.world_constructions),
+            (EntityToken::PermittedBuilding, &self.permitted_buildings),
+            (EntityToken::PermittedJob, &self.permitted_jobs),
+            (EntityToken::PermittedReaction, &self.permitted_reactions),
+            (EntityToken::ReligionSphere, &self.religion_spheres),
+            (EntityToken::SphereAlignment, &self.sphere_alignments),
+            (
+                EntityToken::SiteVariablePositions,
+                &self.site_variable_positions,
+            ),
+            (EntityToken::VariablePositions, &self.variable_positions),
+            (EntityToken::Scholar, &self.scholars),
+            (EntityToken::Ammo, &self.ammo),
+            (EntityToken::Digger, &self.diggers),
+            (EntityToken::Instrument, &self.instrument),
+            (EntityToken::Shield, &self.shields),
+            (EntityToken::SiegeAmmo, &self.siege_ammo),
+            (EntityToken::Tool, &self.tool),
+            (EntityToken::Toy, &self.toys),
+            (EntityToken::TrapComponent, &self.trap_components),
+            (EntityToken::Weapon, &self.weapons),
+            (EntityToken::GemShape, &self.gem_shape),
+            (EntityToken::StoneShape, &self.stone_shape),
+        ];
+        for (tag, list) in lists {
+            for value in list {
+                writer.token_for(tokens, &tag, value);
+            }
+        }
+        let chances = [
+            (EntityToken::BiomeSupport, &self.biome_support),
+            (EntityToken::Currency, &self.currency),
+            (EntityToken::ArtFacetModifier, &self.art_facet_modifier),
+            (
+                EntityToken::ArtImageElementModifier,
+                &self.art_image_element_modifier,
+            ),
+            (
+                EntityToken::ItemImprovementModifier,
+                &self.item_improvement_modifier,
+            ),
+            (EntityToken::Value, &self.values),
+        ];
+        for (tag, list) in chances {
+            for (value, chance) in list {
+                writer.token_for(tokens, &tag, format!("{value}:{chance}"));
+            }
+        }
+        for (value, min, max) in &self.variable_values {
+            writer.token_for(
+                tokens,
+                &EntityToken::VariableValue,
+                format!("{value}:{min}:{max}"),
+            );
+        }
+        // Equipment is kept with its rarity as a number, which is 0 when it's a word (e.g. `COMMON`)
+        let equipment = [
+            (EntityToken::Armor, &self.armors),
+            (EntityToken::Gloves, &self.gloves),
+            (EntityToken::Helm, &self.helms),
+            (EntityToken::Pants, &self.pants),
+            (EntityToken::Shoes, &self.shoes),
+        ];
+        for (tag, list) in equipment {
+            for (item, chance) in list {
+                if *chance == 0 {
+                    writer.token_for(tokens, &tag, item);
+                } else {
+                    writer.token_for(tokens, &tag, format!("{item}:{chance}"));
+                }
+            }
+        }
+        let symbols = [
+            (EntityToken::SelectSymbol, &self.select_symbols),
+            (EntityToken::SubselectSymbol, &self.subselect_symbols),
+            (EntityToken::CullSymbol, &self.cull_symbols),
+            (EntityToken::Ethic, &self.ethics),
+        ];
+        for (tag, list) in symbols {
+            for (value, option) in list {
+                if option.is_empty() {
+                    writer.token_for(tokens, &tag, value);
+                } else {
+                    writer.token_for(tokens, &tag, format!("{value}:{option}"));
+                }
+            }
+        }
+        if !self.friendly_color.is_default() {
+            writer.token_for(
+                tokens,
+                &EntityToken::FriendlyColor,
+                self.friendly_color.to_value(),
+            );
+        }
+
+        let numbers = [
+            (EntityToken::MaxPopNumber, self.max_pop_number, 500),
+            (EntityToken::MaxSitePopNumber, self.max_site_pop_number, 50),
+            (
+                EntityToken::MaxStartingCivNumber,
+                self.max_starting_civ_number,
+                3,
+            ),
+            (EntityToken::SourceHfid, self.source_hfid, 0),
+            (
+                EntityToken::ProgressTriggerPopulation,
+                u32::from(self.progress_trigger_population),
+                0,
+            ),
+            (
+                EntityToken::ProgressTriggerProduction,
+                u32::from(self.progress_trigger_production),
+                0,
+            ),
+            (
+                EntityToken::ProgressTriggerTrade,
+                u32::from(self.progress_trigger_trade),
+                0,
+            ),
+            (
+                EntityToken::ProgressTriggerPopulationSiege,
+                u32::from(self.progress_trigger_population_siege),
+                0,
+            ),
+            (
+                EntityToken::ProgressTriggerProductionSiege,
+                u32::from(self.progress_trigger_production_siege),
+                0,
+            ),
+            (
+                EntityToken::ProgressTriggerTradeSiege,
+                u32::from(self.progress_trigger_trade_siege),
+                0,
+            ),
+        ];
+        for (tag, number, default) in numbers {
+            if number != default {
+                writer.token_for(tokens, &tag, number);
+            }
+        }
+        if self.banditry != 0.0 {
+            writer.token_for(tokens, &EntityToken::Banditry, self.banditry);
+        }
+        write_tags(writer, tokens, &self.tags);
+
+        for position in &self.positions {
+            writer.token_for(tokens, &EntityToken::Position, position.get_identifier());
+            writer.indent();
+            position.write_raw_text(writer);
+            writer.outdent();
+        }
+    }
+}
+
 impl Searchable for Entity {
     fn get_search_vec(&self) -> Vec<String> {
         let mut vec = Vec::new();
@@ -1,14 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-use crate::parser::serializer_helper;
+use crate::parser::{
+    serializer_helper,
+    writer::{RawWriter, ToRawText},
+};
 
-use super::tokens::GraphicType;
+use super::{phf_table::CUSTOM_GRAPHIC_TAGS, tokens::GraphicType};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct CustomGraphicExtension {
     extension_type: GraphicType,
     #[serde(skip_serializing_if = "String::is_empty")]
@@ -79,3 +83,18 @@ impl CustomGraphicExtension {
         }
     }
 }
+
+impl ToRawText for CustomGraphicExtension {
+    /// Write the extension as a `[CUSTOM_...]` token.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        if self.tile_page_id.is_empty() {
+            writer.token_for(&CUSTOM_GRAPHIC_TAGS, &self.extension_type, self.value_1);
+        } else {
+            writer.token_for(
+                &CUSTOM_GRAPHIC_TAGS,
+                &self.extension_type,
+                format!("{}:{}:{}", self.tile_page_id, self.value_1, self.value_2),
+            );
+        }
+    }
+}
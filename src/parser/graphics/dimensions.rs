@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Default)]
+#[serde(default)]
 pub struct Dimensions {
     x: i32,
     y: i32,
@@ -58,6 +59,10 @@ impl Dimensions {
     pub fn new() -> Self {
         Dimensions::zero()
     }
+    /// The value of a token for the dimensions, e.g. `32:32` (the reverse of `from_token`).
+    pub fn to_value(self) -> String {
+        format!("{}:{}", self.x, self.y)
+    }
     pub fn is_default(self) -> bool {
         self.x == 0 && self.y == 0
     }
@@ -1,26 +1,29 @@
 use serde::{Deserialize, Serialize};
 
 use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
     helpers::object_id::build_object_id_from_pieces,
     object_types::ObjectType,
     raws::{RawMetadata, RawObject},
     searchable::{clean_search_vec, Searchable},
     serializer_helper,
+    writer::{RawWriter, ToRawText},
 };
 
 use super::{
     custom_extension::CustomGraphicExtension,
-    phf_table::{CUSTOM_GRAPHIC_TAGS, GROWTH_TAGS, PLANT_GRAPHIC_TEMPLATES},
+    phf_table::{CONDITION_TAGS, CUSTOM_GRAPHIC_TAGS, GROWTH_TAGS, PLANT_GRAPHIC_TEMPLATES},
     sprite_graphic::SpriteGraphic,
     sprite_layer::SpriteLayer,
     tokens::GraphicType,
 };
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Graphic {
     #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
     metadata: RawMetadata,
@@ -65,15 +68,19 @@ impl Graphic {
     }
     fn parse_layer_from_value(&mut self, value: &str) {
         if let Some(layer) = SpriteLayer::parse_layer_from_value(value) {
-            if self.layers.is_empty() {
-                self.layers.push((String::from("default"), Vec::new()));
+            match self.layers.last_mut() {
+                Some((_, layers)) => layers.push(layer),
+                None => self.layers.push((String::from("default"), vec![layer])),
             }
-            self.layers.last_mut().unwrap().1.push(layer);
         }
     }
     fn parse_layer_condition_token(&mut self, key: &str, value: &str) {
         // Conditions get attached to the last layer in the last layer group
-        if let Some(layer) = self.layers.last_mut().unwrap().1.last_mut() {
+        if let Some(layer) = self
+            .layers
+            .last_mut()
+            .and_then(|(_, layers)| layers.last_mut())
+        {
             layer.parse_condition_token(key, value);
         } else {
             log::warn!(
@@ -82,32 +89,38 @@ impl Graphic {
                 key,
                 value
             );
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Layer condition {key} has no layer to apply to"),
+            );
         }
     }
-    pub fn parse_sprite_from_tag(&mut self, key: &str, value: &str, graphic_type: GraphicType) {
-        // Check if key is LAYER_SET meaning a new layer group is starting
-        if let "LAYER_SET" = key {
-            // Parse the value into a SpriteLayer
-            self.parse_layer_set_from_value(value);
-            self.layer_mode = true;
-            return;
-        }
-
-        // Check if key is LAYER meaning a new layer should be added to the current layer group
-        if let "LAYER" = key {
-            // Parse the value into a SpriteLayer
-            self.parse_layer_from_value(value);
-            self.layer_mode = true;
-            return;
+    /// Parse the tokens that start layers and layer groups, returning whether the token was one.
+    fn parse_layer_token(&mut self, key: &str, value: &str) -> bool {
+        match key {
+            // A new layer group is starting
+            "LAYER_SET" => self.parse_layer_set_from_value(value),
+            // A new layer should be added to the current layer group
+            "LAYER" => self.parse_layer_from_value(value),
+            // Layers can be defined in groups.. for now we just ignore it
+            "LAYER_GROUP" => {}
+            "END_LAYER_GROUP" => {
+                self.layer_mode = false;
+                return true;
+            }
+            _ => return false,
         }
-
-        // Layers can be defined in groups.. for now we just ignore it
-        if let "LAYER_GROUP" = key {
-            self.layer_mode = true;
-            return;
+        self.layer_mode = true;
+        true
+    }
+    /// Add a sprite to the growth that was started last.
+    fn add_growth_sprite(&mut self, sprite_graphic: SpriteGraphic) {
+        if let Some(growth) = self.growths.last_mut() {
+            growth.1.push(sprite_graphic);
         }
-        if let "END_LAYER_GROUP" = key {
-            self.layer_mode = false;
+    }
+    pub fn parse_sprite_from_tag(&mut self, key: &str, value: &str, graphic_type: GraphicType) {
+        if self.parse_layer_token(key, value) {
             return;
         }
 
@@ -122,8 +135,9 @@ impl Graphic {
             return;
         }
 
-        // Check if the value is empty, which means we have a tag
-        if value.is_empty() {
+        // Check if the value is empty, which means we have a tag (unless it's a condition without a
+        // value, like CONDITION_NOT_CHILD, for the current layer)
+        if value.is_empty() && !(self.layer_mode && CONDITION_TAGS.contains_key(key)) {
             self.tags.push(String::from(key));
             return;
         }
@@ -141,6 +155,10 @@ impl Graphic {
                     key,
                     value
                 );
+                diagnostics::report(
+                    DiagnosticSeverity::Warning,
+                    &format!("Unable to parse {key} as a custom graphic extension"),
+                );
             }
             return;
         }
@@ -148,9 +166,7 @@ impl Graphic {
         // If the key is a growth token, parse it into a SpriteGraphic and add it to the current growth
         if let Some(_growth_type) = GROWTH_TAGS.get(key) {
             if let Some(sprite_graphic) = SpriteGraphic::from_token(key, value, graphic_type) {
-                if let Some(growth) = self.growths.last_mut() {
-                    growth.1.push(sprite_graphic);
-                };
+                self.add_growth_sprite(sprite_graphic);
             } else {
                 log::warn!(
                     "Graphic::parse_sprite_from_tag:_growth_type [{}] Failed to parse {},{} as SpriteGraphic",
@@ -158,6 +174,10 @@ impl Graphic {
                     key,
                     value
                 );
+                diagnostics::report(
+                    DiagnosticSeverity::Warning,
+                    &format!("Unable to parse growth sprite {key}"),
+                );
             }
             return;
         }
@@ -166,9 +186,7 @@ impl Graphic {
             if let Some(sprite_graphic) =
                 SpriteGraphic::from_token(key, value, GraphicType::Template)
             {
-                if let Some(growth) = self.growths.last_mut() {
-                    growth.1.push(sprite_graphic);
-                };
+                self.add_growth_sprite(sprite_graphic);
             } else {
                 log::warn!(
                     "Graphic::parse_sprite_from_tag:_plant_graphic_template [{}] Failed to parse {},{} as SpriteGraphic",
@@ -176,6 +194,10 @@ impl Graphic {
                     key,
                     value
                 );
+                diagnostics::report(
+                    DiagnosticSeverity::Warning,
+                    &format!("Unable to parse plant graphic template {key}"),
+                );
             }
             return;
         }
@@ -197,6 +219,10 @@ impl Graphic {
                 value,
                 graphic_type
             );
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Unable to parse sprite {key} as {graphic_type:?}"),
+            );
         }
     }
 
@@ -251,6 +277,57 @@ impl RawObject for Graphic {
     }
 }
 
+impl ToRawText for Graphic {
+    /// Write the graphic as raw text. The sprites are written before the layers, since the tokens
+    /// after a layer are its conditions.
+    ///
+    /// Sprites are only kept with their key when it's a condition (e.g. `[DEFAULT:...]` or
+    /// `[SHRUB:...]`), so the sprites of tile graphics and most growth sprites can't be written.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let key = match self.kind {
+            GraphicType::Creature => "CREATURE_GRAPHICS",
+            GraphicType::CreatureCaste => "CREATURE_CASTE_GRAPHICS",
+            GraphicType::Plant => "PLANT_GRAPHICS",
+            GraphicType::CustomWorkshop => "CUSTOM_WORKSHOP_GRAPHICS",
+            _ => "TILE_GRAPHICS",
+        };
+        writer.begin_object(key, &self.identifier);
+
+        for sprite in &self.sprites {
+            sprite.write_token(writer, self.kind);
+        }
+        for (growth, sprites) in &self.growths {
+            writer.token("GROWTH", growth);
+            writer.indent();
+            for sprite in sprites {
+                sprite.write_token(writer, self.kind);
+            }
+            writer.outdent();
+        }
+        for custom_extension in &self.custom_extensions {
+            custom_extension.write_raw_text(writer);
+        }
+        for tag in &self.tags {
+            writer.tag(tag);
+        }
+
+        for (i, (layer_set, layers)) in self.layers.iter().enumerate() {
+            // Layers outside of a layer set are put in the `default` set
+            let in_default_set = i == 0 && layer_set == "default";
+            if !in_default_set {
+                writer.token("LAYER_SET", layer_set);
+                writer.indent();
+            }
+            for layer in layers {
+                layer.write_raw_text(writer);
+            }
+            if !in_default_set {
+                writer.outdent();
+            }
+        }
+    }
+}
+
 impl Searchable for Graphic {
     fn get_search_vec(&self) -> Vec<String> {
         let mut vec = Vec::new();
@@ -1,7 +1,10 @@
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::parser::serializer_helper;
+use crate::parser::{
+    serializer_helper,
+    writer::{preferred_token_key, RawWriter},
+};
 
 use super::{
     dimensions::Dimensions,
@@ -10,10 +13,11 @@ use super::{
 };
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct SpriteGraphic {
     primary_condition: Condition,
     tile_page_id: String,
@@ -38,6 +42,10 @@ impl SpriteGraphic {
     pub fn get_tile_page_id(&self) -> &str {
         self.tile_page_id.as_str()
     }
+    /// Returns the row of the palette the sprite's colors are swapped with (0 if they aren't).
+    pub fn get_color_pallet_swap(&self) -> u32 {
+        self.color_pallet_swap
+    }
     pub fn from_token(key: &str, value: &str, graphic_type: GraphicType) -> Option<Self> {
         // Recombine token for parsing
         let token = format!("{key}:{value}");
@@ -711,3 +719,61 @@ impl SpriteGraphic {
         })
     }
 }
+
+impl SpriteGraphic {
+    /// Write the sprite as a `[CONDITION:TILE_PAGE:...]` token. Sprites copied from a template are
+    /// written as they were in the raws.
+    ///
+    /// The `graphic_type` is the type of the graphic the sprite belongs to. It decides whether the
+    /// secondary condition is written, since only creature sprites read it from the raws.
+    ///
+    /// Sprites without a condition (e.g. the sprites of tile graphics, which are kept without their
+    /// key) can't be written, so nothing is written for them.
+    pub fn write_token(&self, writer: &mut RawWriter, graphic_type: GraphicType) {
+        if self.primary_condition == Condition::CopyOfTemplate {
+            let (key, value) = self
+                .tile_page_id
+                .split_once(':')
+                .unwrap_or((self.tile_page_id.as_str(), ""));
+            writer.token(key, value);
+            return;
+        }
+        let Some(condition) =
+            preferred_token_key(&CONDITION_TAGS, &self.primary_condition, |key| {
+                !key.starts_with("CONDITION_")
+            })
+        else {
+            return;
+        };
+
+        let mut value = if self.large_image {
+            format!(
+                "{}:LARGE_IMAGE:{}:{}",
+                self.tile_page_id,
+                self.offset.to_value(),
+                self.offset2.to_value()
+            )
+        } else {
+            format!("{}:{}", self.tile_page_id, self.offset.to_value())
+        };
+        let specific_graphic_type = GRAPHIC_TYPE_TAGS
+            .get(condition)
+            .copied()
+            .unwrap_or(graphic_type);
+        if matches!(
+            specific_graphic_type,
+            GraphicType::Creature | GraphicType::CreatureCaste
+        ) && !self.secondary_condition.is_none()
+        {
+            if let Some(secondary_condition) =
+                preferred_token_key(&CONDITION_TAGS, &self.secondary_condition, |key| {
+                    !key.starts_with("CONDITION_")
+                })
+            {
+                value.push_str(":AS_IS:");
+                value.push_str(secondary_condition);
+            }
+        }
+        writer.token(condition, value);
+    }
+}
@@ -1,12 +1,18 @@
 use serde::{Deserialize, Serialize};
 
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    writer::{preferred_token_key, RawWriter, ToRawText},
+};
+
 use super::{dimensions::Dimensions, phf_table::CONDITION_TAGS, tokens::Condition};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct SpriteLayer {
     layer_name: String,
     tile_page_id: String,
@@ -36,6 +42,10 @@ impl SpriteLayer {
                 value,
                 key
             );
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Unknown layer condition {key}"),
+            );
         }
     }
     pub fn parse_layer_from_value(value: &str) -> Option<Self> {
@@ -194,3 +204,41 @@ impl SpriteLayer {
         })
     }
 }
+
+impl ToRawText for SpriteLayer {
+    /// Write the `[LAYER:...]` token and the conditions of the layer.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        if self.large_image {
+            writer.token(
+                "LAYER",
+                format!(
+                    "{}:{}:LARGE_IMAGE:{}:{}",
+                    self.layer_name,
+                    self.tile_page_id,
+                    self.offset.to_value(),
+                    self.offset_2.to_value()
+                ),
+            );
+        } else {
+            writer.token(
+                "LAYER",
+                format!(
+                    "{}:{}:{}",
+                    self.layer_name,
+                    self.tile_page_id,
+                    self.offset.to_value()
+                ),
+            );
+        }
+
+        writer.indent();
+        for (condition, value) in &self.conditions {
+            if let Some(key) = preferred_token_key(&CONDITION_TAGS, condition, |key| {
+                key.starts_with("CONDITION_")
+            }) {
+                writer.token(key, value);
+            }
+        }
+        writer.outdent();
+    }
+}
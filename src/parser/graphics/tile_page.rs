@@ -3,20 +3,23 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 
 use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
     helpers::object_id::build_object_id_from_pieces,
     object_types::ObjectType,
     raws::{RawMetadata, RawObject},
     searchable::{clean_search_vec, Searchable},
     serializer_helper,
+    writer::{RawWriter, ToRawText},
 };
 
 use super::{dimensions::Dimensions, phf_table::TILE_PAGE_TAGS, tokens::TilePageTag};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct TilePage {
     #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
     metadata: RawMetadata,
@@ -79,6 +82,10 @@ impl RawObject for TilePage {
                     key,
                     self.get_object_id()
                 );
+                diagnostics::report(
+                    DiagnosticSeverity::Warning,
+                    &format!("Unknown tile page tag {key}"),
+                );
             }
         }
     }
@@ -88,6 +95,31 @@ impl RawObject for TilePage {
     }
 }
 
+impl ToRawText for TilePage {
+    /// Write the tile page as raw text. The path of the image file is written relative to the raw
+    /// file the tile page was parsed from when it can be.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &TILE_PAGE_TAGS;
+        writer.begin_object("TILE_PAGE", &self.identifier);
+
+        let raw_path = PathBuf::from(self.metadata.get_raw_file_path());
+        let file = raw_path
+            .parent()
+            .and_then(|parent| self.file.strip_prefix(parent).ok())
+            .unwrap_or(&self.file);
+        let file = file
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if !file.is_empty() {
+            writer.token_for(tokens, &TilePageTag::File, file);
+        }
+        writer.token_for(tokens, &TilePageTag::TileDim, self.tile_dim.to_value());
+        writer.token_for(tokens, &TilePageTag::PageDim, self.page_dim.to_value());
+    }
+}
+
 impl Searchable for TilePage {
     fn get_search_vec(&self) -> Vec<String> {
         let mut vec = Vec::new();
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use super::phf_table::CONDITION_TAGS;
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
 pub enum Condition {
     None,
@@ -129,7 +129,7 @@ pub enum Condition {
 }
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub enum ColorModification {
     #[default]
@@ -137,7 +137,7 @@ pub enum ColorModification {
 }
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum PlantGraphicTemplate {
     StandardLeaves,
@@ -152,7 +152,7 @@ pub enum PlantGraphicTemplate {
 }
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub enum GrowthTag {
     Fruit,
@@ -165,7 +165,7 @@ pub enum GrowthTag {
 }
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum GraphicType {
     Creature,
@@ -297,7 +297,7 @@ pub enum GraphicType {
 }
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub enum TilePageTag {
     TileDim,
@@ -31,11 +31,18 @@ pub fn absorb_select_creature(all_raws: &mut Vec<Box<dyn RawObject>>) {
         if raw.get_type() == &ObjectType::Creature
             && target_creature_identifiers.contains(&raw.get_identifier())
         {
-            let select_creature_vec: Vec<SelectCreature> = all_select_creatures
+            // Only the SELECT_CREATURE from this creature's module or a module loaded after it
+            // apply, in load order.
+            let mut select_creature_vec: Vec<SelectCreature> = all_select_creatures
                 .iter()
-                .filter(|r| r.get_identifier() == raw.get_identifier())
+                .filter(|r| {
+                    r.get_identifier() == raw.get_identifier()
+                        && r.get_metadata().get_module_load_order()
+                            >= raw.get_metadata().get_module_load_order()
+                })
                 .cloned()
                 .collect();
+            select_creature_vec.sort_by_key(|r| r.get_metadata().get_module_load_order());
 
             if select_creature_vec.is_empty() {
                 // Skip this creature if there are no select_creature records for it
@@ -1,18 +1,13 @@
 use itertools::Itertools;
 
 use crate::parser::{
-    creature::raw::Creature,
-    helpers::{clone_raw_object_box, clone_raw_vector::with_purge},
-    object_types::ObjectType,
+    creature::raw::Creature, helpers::clone_raw_vector::with_purge, object_types::ObjectType,
     raws::RawObject,
 };
 
 #[allow(clippy::too_many_lines)]
 pub fn apply_copy_tags_from(all_raws: &mut Vec<Box<dyn RawObject>>) {
-    let untouched_raws: Vec<_> = all_raws
-        .iter()
-        .map(clone_raw_object_box::clone_raw_object_box)
-        .collect();
+    let untouched_raws = all_raws.clone();
 
     let creatures_with_copy_tags_from: Vec<Creature> = {
         untouched_raws
@@ -64,25 +59,36 @@ pub fn apply_copy_tags_from(all_raws: &mut Vec<Box<dyn RawObject>>) {
         })
         .collect::<Vec<Creature>>();
 
-    // The outside loop iterates over the source creatures, which we will use to copy tags from
-    // Inside the loop, we find which creatures have the source creature's identifier in their
-    // copy_tags_from field, and then apply the source creature's tags to those creatures.
+    // For each creature with a copy_tags_from, we find the source creature to copy the tags from
+    // and apply the source creature's tags to it. If more than one module has a creature with the
+    // source's identifier, the one in the latest module loaded no later than the target's is used
+    // (which is what the game would have when it loads the target), or else the earliest one.
     // Then we put the updated creatures into the new_creatures vector, which will be used to
     // replace the old creatures in the all_raws vector.
 
     let mut new_creatures: Vec<Creature> = Vec::new();
-    for source_creature in source_creatures {
-        let target_creatures: Vec<Creature> = creatures_with_copy_tags_from
+    for target_creature in &creatures_with_copy_tags_from {
+        let target_load_order = target_creature.get_metadata().get_module_load_order();
+        let candidates: Vec<&Creature> = source_creatures
             .iter()
             .filter(|c| {
-                c.get_copy_tags_from().to_lowercase()
-                    == source_creature.get_identifier().to_lowercase()
+                c.get_identifier().to_lowercase()
+                    == target_creature.get_copy_tags_from().to_lowercase()
             })
-            .cloned()
-            .collect::<Vec<Creature>>();
+            .collect();
 
-        for target_creature in target_creatures {
-            new_creatures.push(Creature::copy_tags_from(&target_creature, &source_creature));
+        let source_creature = candidates
+            .iter()
+            .filter(|c| c.get_metadata().get_module_load_order() <= target_load_order)
+            .max_by_key(|c| c.get_metadata().get_module_load_order())
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .min_by_key(|c| c.get_metadata().get_module_load_order())
+            });
+
+        if let Some(source_creature) = source_creature {
+            new_creatures.push(Creature::copy_tags_from(target_creature, source_creature));
         }
     }
 
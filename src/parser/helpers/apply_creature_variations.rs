@@ -0,0 +1,112 @@
+use crate::parser::{
+    creature::raw::Creature, creature_variation::raw::CreatureVariation, diagnostics,
+    helpers::clone_raw_vector::with_purge, object_types::ObjectType, raws::RawObject,
+};
+
+/// Apply the `APPLY_CREATURE_VARIATION` tokens of every creature in the raws, using the
+/// `CREATURE_VARIATION` raws which were parsed alongside them. The updated creatures replace
+/// the original ones in `all_raws`.
+///
+/// The tags after an `APPLY_CURRENT_CREATURE_VARIATION` are parsed after the variations queued
+/// before it are applied, so they can override what the variations change.
+///
+/// This should be run after `apply_copy_tags_from`, since creatures (like the giant creatures
+/// in vanilla) copy another creature and then apply a variation to the result.
+///
+/// Arguments:
+///
+/// * `all_raws`: The parsed raws, which are updated in place
+pub fn apply_creature_variations(all_raws: &mut Vec<Box<dyn RawObject>>) {
+    let creature_variations: Vec<CreatureVariation> = all_raws
+        .iter()
+        .filter(|r| r.get_type() == &ObjectType::CreatureVariation)
+        .filter_map(|r| r.as_any().downcast_ref::<CreatureVariation>())
+        .cloned()
+        .collect();
+
+    let creatures_with_variations: Vec<Creature> = all_raws
+        .iter()
+        .filter(|r| r.get_type() == &ObjectType::Creature)
+        .filter_map(|r| r.as_any().downcast_ref::<Creature>())
+        .filter(|c| !c.get_creature_variations().is_empty())
+        .cloned()
+        .collect();
+
+    log::info!(
+        "apply_creature_variations: applying {} creature variations to {} of {} raws",
+        creature_variations.len(),
+        creatures_with_variations.len(),
+        all_raws.len()
+    );
+
+    if creatures_with_variations.is_empty() {
+        return;
+    }
+
+    let mut new_creatures: Vec<Creature> = Vec::new();
+    for mut creature in creatures_with_variations {
+        // Each APPLY_CURRENT_CREATURE_VARIATION applies the variations queued before it, and the
+        // tags after it are parsed once they are applied (which may queue more variations).
+        loop {
+            let tags_after_variations = creature.take_tags_after_creature_variation();
+            let variations = creature.get_creature_variations().to_vec();
+            creature.clear_creature_variations();
+            for variation in &variations {
+                apply_creature_variation(&mut creature, variation, &creature_variations);
+            }
+
+            let Some(tags) = tags_after_variations else {
+                break;
+            };
+            diagnostics::set_file(creature.get_metadata().get_raw_file_path());
+            diagnostics::set_object(creature.get_identifier());
+            creature.parse_tags_after_creature_variation(&tags);
+        }
+        new_creatures.push(creature);
+    }
+
+    let object_ids_to_purge: Vec<&str> =
+        new_creatures.iter().map(RawObject::get_object_id).collect();
+
+    let mut new_raws: Vec<Box<dyn RawObject>> =
+        with_purge(all_raws.as_slice(), object_ids_to_purge.as_slice());
+
+    log::info!(
+        "apply_creature_variations: updated {} creatures",
+        new_creatures.len()
+    );
+
+    for creature in new_creatures {
+        new_raws.push(Box::new(creature));
+    }
+
+    *all_raws = new_raws;
+}
+
+/// Apply one `APPLY_CREATURE_VARIATION` value (the variation identifier followed by its arguments)
+/// to the creature. Anything the variation can't do is reported against the variation.
+fn apply_creature_variation(
+    creature: &mut Creature,
+    variation: &str,
+    creature_variations: &[CreatureVariation],
+) {
+    let mut parts = variation.split(':');
+    let identifier = parts.next().unwrap_or_default();
+    let args: Vec<&str> = parts.collect();
+
+    let Some(creature_variation) = creature_variations
+        .iter()
+        .find(|cv| cv.get_identifier().eq_ignore_ascii_case(identifier))
+    else {
+        log::warn!(
+            "apply_creature_variations: unable to find creature variation {} for {}",
+            identifier,
+            creature.get_identifier()
+        );
+        return;
+    };
+
+    diagnostics::set_file(creature_variation.get_metadata().get_raw_file_path());
+    diagnostics::set_object(creature_variation.get_identifier());
+    creature_variation.apply_to_creature(creature, args.as_slice());
+}
@@ -0,0 +1,81 @@
+use crate::parser::{
+    cut_object::raw::CutObject,
+    helpers::clone_raw_vector::with_purge,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+};
+
+/// Apply each `CUT_CREATURE` and `CUT_INORGANIC`, by removing the creatures and inorganics with the
+/// same identifier from `all_raws`. The cuts which removed something are removed as well.
+///
+/// Only objects from modules loaded before the cut's module are removed. An object from the same
+/// module as the cut (or a later one) is kept, because a module may cut an object and then define
+/// its own replacement for it.
+///
+/// Arguments:
+///
+/// * `all_raws`: The parsed raws, which are updated in place
+pub fn apply_cut_objects(all_raws: &mut Vec<Box<dyn RawObject>>) {
+    let cut_objects: Vec<&CutObject> = all_raws
+        .iter()
+        .filter(|r| {
+            r.get_type() == &ObjectType::CutCreature || r.get_type() == &ObjectType::CutInorganic
+        })
+        .filter_map(|r| r.as_any().downcast_ref::<CutObject>())
+        .collect();
+
+    if cut_objects.is_empty() {
+        return;
+    }
+
+    let mut object_ids_to_purge: Vec<&str> = Vec::new();
+
+    for cut in &cut_objects {
+        let cut_metadata = cut.get_metadata();
+        let targets: Vec<&str> = all_raws
+            .iter()
+            .filter(|r| {
+                r.get_type() == &cut.get_target_type()
+                    && r.get_identifier() == cut.get_identifier()
+                    && loaded_before(r.get_metadata(), cut_metadata)
+            })
+            .map(|r| r.get_object_id())
+            .collect();
+
+        if targets.is_empty() {
+            log::debug!(
+                "apply_cut_objects: nothing to cut for {:?} {}",
+                cut.get_type(),
+                cut.get_identifier()
+            );
+            continue;
+        }
+
+        object_ids_to_purge.extend(targets);
+        object_ids_to_purge.push(cut.get_object_id());
+    }
+
+    if object_ids_to_purge.is_empty() {
+        return;
+    }
+
+    let new_raws: Vec<Box<dyn RawObject>> =
+        with_purge(all_raws.as_slice(), object_ids_to_purge.as_slice());
+
+    log::info!(
+        "apply_cut_objects: removed {} objects",
+        all_raws.len() - new_raws.len()
+    );
+
+    *all_raws = new_raws;
+}
+
+/// Whether the object with the `target` metadata was loaded before the cut with the `cut` metadata.
+/// When no load order was worked out (everything is at 0), any other module counts as before.
+fn loaded_before(target: &RawMetadata, cut: &RawMetadata) -> bool {
+    if target.get_module_load_order() != cut.get_module_load_order() {
+        return target.get_module_load_order() < cut.get_module_load_order();
+    }
+    target.get_module_name() != cut.get_module_name()
+        || target.get_module_numerical_version() != cut.get_module_numerical_version()
+}
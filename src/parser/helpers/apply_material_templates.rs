@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use crate::parser::{
+    creature::raw::Creature, helpers::clone_raw_vector::with_purge, inorganic::raw::Inorganic,
+    material::raw::Material, material_template::raw::MaterialTemplate, object_types::ObjectType,
+    plant::raw::Plant, raws::RawObject,
+};
+
+/// Apply the `MaterialTemplate` raws to the materials which use them (with `USE_MATERIAL_TEMPLATE`)
+/// in inorganics, plants and creatures. The template's properties are used as a base, and the
+/// properties set on the material itself are applied on top. The updated raws replace the original
+/// ones in `all_raws`.
+///
+/// If more than one template has the same identifier, the last one parsed is used (so a mod can
+/// replace a vanilla template).
+///
+/// Arguments:
+///
+/// * `all_raws`: The parsed raws, which are updated in place
+pub fn apply_material_templates(all_raws: &mut Vec<Box<dyn RawObject>>) {
+    let templates: HashMap<String, Material> = all_raws
+        .iter()
+        .filter(|r| r.get_type() == &ObjectType::MaterialTemplate)
+        .filter_map(|r| r.as_any().downcast_ref::<MaterialTemplate>())
+        .map(|t| (String::from(t.get_identifier()), t.get_material().clone()))
+        .collect();
+
+    log::info!(
+        "apply_material_templates: applying {} material templates to {} raws",
+        templates.len(),
+        all_raws.len()
+    );
+
+    if templates.is_empty() {
+        return;
+    }
+
+    let mut new_objects: Vec<Box<dyn RawObject>> = Vec::new();
+    for raw in all_raws.iter() {
+        match raw.get_type() {
+            ObjectType::Inorganic => {
+                if let Some(inorganic) = raw.as_any().downcast_ref::<Inorganic>() {
+                    let mut inorganic = inorganic.clone();
+                    if inorganic.apply_material_templates(&templates) {
+                        new_objects.push(Box::new(inorganic));
+                    }
+                }
+            }
+            ObjectType::Plant => {
+                if let Some(plant) = raw.as_any().downcast_ref::<Plant>() {
+                    let mut plant = plant.clone();
+                    if plant.apply_material_templates(&templates) {
+                        new_objects.push(Box::new(plant));
+                    }
+                }
+            }
+            ObjectType::Creature => {
+                if let Some(creature) = raw.as_any().downcast_ref::<Creature>() {
+                    let mut creature = creature.clone();
+                    if creature.apply_material_templates(&templates) {
+                        new_objects.push(Box::new(creature));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let object_ids_to_purge: Vec<&str> =
+        new_objects.iter().map(|raw| raw.get_object_id()).collect();
+
+    let mut new_raws: Vec<Box<dyn RawObject>> =
+        with_purge(all_raws.as_slice(), object_ids_to_purge.as_slice());
+
+    log::info!(
+        "apply_material_templates: updated materials of {} raws",
+        new_objects.len()
+    );
+
+    new_raws.extend(new_objects);
+
+    *all_raws = new_raws;
+}
@@ -0,0 +1,84 @@
+use crate::parser::{
+    diagnostics, helpers::clone_raw_vector::with_purge, object_types::ObjectType, raws::RawObject,
+    select_object::raw::SelectObject,
+};
+
+/// Apply each `SELECT_PLANT` and `SELECT_INORGANIC` patch to the plants and inorganics with the
+/// same identifier, by letting the target parse the patch's tags. The patches are applied in module
+/// load order, and only to targets from the same module or a module loaded before the patch's. The
+/// patched objects replace the original ones in `all_raws`, and the patches which were applied are
+/// removed. Patches whose target wasn't parsed are kept, so they can still be found.
+///
+/// Arguments:
+///
+/// * `all_raws`: The parsed raws, which are updated in place
+pub fn apply_select_objects(all_raws: &mut Vec<Box<dyn RawObject>>) {
+    let mut select_objects: Vec<&SelectObject> = all_raws
+        .iter()
+        .filter(|r| {
+            r.get_type() == &ObjectType::SelectPlant || r.get_type() == &ObjectType::SelectInorganic
+        })
+        .filter_map(|r| r.as_any().downcast_ref::<SelectObject>())
+        .collect();
+
+    if select_objects.is_empty() {
+        return;
+    }
+    select_objects.sort_by_key(|s| s.get_metadata().get_module_load_order());
+
+    let mut object_ids_to_purge: Vec<&str> = Vec::new();
+    let mut patched_objects: Vec<Box<dyn RawObject>> = Vec::new();
+
+    for raw in &*all_raws {
+        let patches: Vec<&&SelectObject> = select_objects
+            .iter()
+            .filter(|s| {
+                &s.get_target_type() == raw.get_type()
+                    && s.get_identifier() == raw.get_identifier()
+                    && raw.get_metadata().get_module_load_order()
+                        <= s.get_metadata().get_module_load_order()
+            })
+            .collect();
+
+        if patches.is_empty() {
+            continue;
+        }
+
+        let mut patched_object = raw.clone();
+        for patch in &patches {
+            // Any problems with the tags are reported against the patch, not the target.
+            diagnostics::set_file(patch.get_metadata().get_raw_file_path());
+            diagnostics::set_object(patch.get_identifier());
+            for (key, value) in patch.get_tags() {
+                if value.is_empty() {
+                    diagnostics::set_token(&format!("[{key}]"));
+                } else {
+                    diagnostics::set_token(&format!("[{key}:{value}]"));
+                }
+                patched_object.parse_tag(key, value);
+            }
+            if !object_ids_to_purge.contains(&patch.get_object_id()) {
+                object_ids_to_purge.push(patch.get_object_id());
+            }
+        }
+
+        object_ids_to_purge.push(raw.get_object_id());
+        patched_objects.push(patched_object);
+    }
+
+    log::info!(
+        "apply_select_objects: applied {} patches to {} objects",
+        select_objects.len(),
+        patched_objects.len()
+    );
+
+    if patched_objects.is_empty() {
+        return;
+    }
+
+    let mut new_raws: Vec<Box<dyn RawObject>> =
+        with_purge(all_raws.as_slice(), object_ids_to_purge.as_slice());
+    new_raws.extend(patched_objects);
+
+    *all_raws = new_raws;
+}
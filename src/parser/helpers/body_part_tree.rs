@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use crate::parser::{
+    body::{body_part::BodyPart, node::BodyPartNode, raw::Body},
+    body_detail_plan::{
+        layers::{replace_argument, BodyPartLayers, LayerPlacement, TissueLayer},
+        raw::BodyDetailPlan,
+    },
+    creature::raw::Creature,
+    object_types::ObjectType,
+    raws::RawObject,
+    tissue_template::raw::TissueTemplate,
+};
+
+/// Build the expanded body part tree of a creature's caste, by resolving its `BODY`,
+/// `BODY_DETAIL_PLAN` and `TISSUE_LAYER` tokens against the parsed `Body`, `BodyDetailPlan` and
+/// `TissueTemplate` raws.
+///
+/// The tokens on the creature's `ALL` caste are used along with the tokens on the given caste. Body
+/// parts are connected with `CON`, `CON_CAT` and `CONTYPE`. A body part connected by category or
+/// type is added under each body part it connects to (e.g. fingers under both hands). Tissue layers
+/// with no thickness take the `RELATIVE_THICKNESS` of their tissue template.
+///
+/// Bodies, plans and templates which can't be found are skipped (and logged).
+///
+/// Arguments:
+///
+/// * `creature`: The creature to build the body of
+/// * `caste`: The identifier of the caste, e.g. `FEMALE`. Use `ALL` for the shared tokens only.
+/// * `all_raws`: The parsed raws, which should include the bodies, plans and tissue templates
+///
+/// Returns:
+///
+/// The body parts which aren't connected to anything (usually just the upper body), with the rest of
+/// the body parts as their children.
+pub fn build_body_part_tree(
+    creature: &Creature,
+    caste: &str,
+    all_raws: &[Box<dyn RawObject>],
+) -> Vec<BodyPartNode> {
+    let castes: Vec<_> = creature
+        .get_castes()
+        .iter()
+        .filter(|c| c.get_identifier() == "ALL" || c.get_identifier() == caste)
+        .collect();
+
+    let bodies: HashMap<&str, &Body> = all_raws
+        .iter()
+        .filter(|r| r.get_type() == &ObjectType::Body)
+        .filter_map(|r| r.as_any().downcast_ref::<Body>())
+        .map(|b| (b.get_identifier(), b))
+        .collect();
+    let plans: HashMap<&str, &BodyDetailPlan> = all_raws
+        .iter()
+        .filter(|r| r.get_type() == &ObjectType::BodyDetailPlan)
+        .filter_map(|r| r.as_any().downcast_ref::<BodyDetailPlan>())
+        .map(|p| (p.get_identifier(), p))
+        .collect();
+    let tissue_templates: HashMap<&str, &TissueTemplate> = all_raws
+        .iter()
+        .filter(|r| r.get_type() == &ObjectType::TissueTemplate)
+        .filter_map(|r| r.as_any().downcast_ref::<TissueTemplate>())
+        .map(|t| (t.get_identifier(), t))
+        .collect();
+
+    // Gather the body parts from each body, in order
+    let mut parts: Vec<&BodyPart> = Vec::new();
+    for body_identifier in castes.iter().flat_map(|c| c.get_body()) {
+        let Some(body) = bodies.get(body_identifier.as_str()) else {
+            log::warn!(
+                "build_body_part_tree: body {body_identifier} not found for {}",
+                creature.get_identifier()
+            );
+            continue;
+        };
+        parts.extend(body.get_parts());
+    }
+
+    // The creature's tissues (by name) and the template each is made from
+    let mut tissues: HashMap<String, String> = creature
+        .get_tissues()
+        .iter()
+        .map(|t| (String::from(t.get_name()), String::from(t.get_template())))
+        .collect();
+
+    let mut sizes: Vec<u32> = parts.iter().map(|p| p.get_relative_size()).collect();
+    let mut layers: Vec<Vec<TissueLayer>> = vec![Vec::new(); parts.len()];
+
+    for plan_value in castes.iter().flat_map(|c| c.get_body_detail_plans()) {
+        let mut split = plan_value.split(':');
+        let plan_identifier = split.next().unwrap_or_default();
+        let arguments: Vec<&str> = split.collect();
+        let Some(plan) = plans.get(plan_identifier) else {
+            log::warn!(
+                "build_body_part_tree: body detail plan {plan_identifier} not found for {}",
+                creature.get_identifier()
+            );
+            continue;
+        };
+
+        for tissue in plan.get_tissues() {
+            tissues
+                .entry(replace_argument(tissue.get_name(), &arguments))
+                .or_insert_with(|| replace_argument(tissue.get_template(), &arguments));
+        }
+        for plan_layers in plan.get_layers() {
+            apply_layers(&parts, &mut layers, &plan_layers.with_arguments(&arguments));
+        }
+        for relative_size in plan.get_relative_sizes() {
+            for (index, part) in parts.iter().enumerate() {
+                if relative_size.get_selector().matches(part) {
+                    sizes[index] = relative_size.get_size();
+                }
+            }
+        }
+    }
+
+    for tissue_layers in castes.iter().flat_map(|c| c.get_tissue_layers()) {
+        apply_layers(&parts, &mut layers, tissue_layers);
+    }
+
+    // Layers without a thickness use the thickness of their tissue template
+    for layer in layers.iter_mut().flatten() {
+        if layer.get_thickness() == 0 {
+            let thickness = tissues
+                .get(layer.get_tissue())
+                .and_then(|template| tissue_templates.get(template.as_str()))
+                .map_or(0, |template| template.get_relative_thickness());
+            *layer = TissueLayer::new(layer.get_tissue(), thickness);
+        }
+    }
+
+    // Work out which body parts each body part connects to
+    let parents: Vec<Vec<usize>> = parts
+        .iter()
+        .map(|part| {
+            (0..parts.len())
+                .filter(|&index| is_connected_to(part, parts[index]))
+                .collect()
+        })
+        .collect();
+
+    let mut path = Vec::new();
+    (0..parts.len())
+        .filter(|&index| parents[index].is_empty())
+        .map(|index| build_node(index, &parts, &sizes, &layers, &parents, &mut path))
+        .collect()
+}
+
+/// Add the tissue layers to each body part they select.
+fn apply_layers(parts: &[&BodyPart], layers: &mut [Vec<TissueLayer>], new_layers: &BodyPartLayers) {
+    for (index, part) in parts.iter().enumerate() {
+        if !new_layers.get_selector().matches(part) {
+            continue;
+        }
+        match new_layers.get_placement() {
+            LayerPlacement::Layers | LayerPlacement::Over => {
+                layers[index].extend(new_layers.get_layers().iter().cloned());
+            }
+            LayerPlacement::Under => {
+                layers[index].splice(0..0, new_layers.get_layers().iter().cloned());
+            }
+        }
+    }
+}
+
+/// Returns true if `part` connects to `parent`.
+fn is_connected_to(part: &BodyPart, parent: &BodyPart) -> bool {
+    if !part.get_connection().is_empty() {
+        return part.get_connection() == parent.get_identifier();
+    }
+    if !part.get_connection_category().is_empty() {
+        return part.get_connection_category() == parent.get_category();
+    }
+    if let Some(connection_type) = part.get_connection_type() {
+        return parent.has_tag(connection_type);
+    }
+    false
+}
+
+/// Build the node for a body part and its children. `path` holds the body parts above this one, so
+/// that a loop of connections can't recurse forever.
+fn build_node(
+    index: usize,
+    parts: &[&BodyPart],
+    sizes: &[u32],
+    layers: &[Vec<TissueLayer>],
+    parents: &[Vec<usize>],
+    path: &mut Vec<usize>,
+) -> BodyPartNode {
+    let mut node = BodyPartNode::new(parts[index], sizes[index], layers[index].clone());
+
+    path.push(index);
+    for child in 0..parts.len() {
+        if parents[child].contains(&index) && !path.contains(&child) {
+            node.push_child(build_node(child, parts, sizes, layers, parents, path));
+        }
+    }
+    path.pop();
+
+    node
+}
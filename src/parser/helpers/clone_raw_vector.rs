@@ -1,7 +1,5 @@
 use crate::parser::raws::RawObject;
 
-use super::clone_raw_object_box::clone_raw_object_box;
-
 /// The function `clone_raw_vector_with_purge` clones a vector of raw objects, excluding those with
 /// specified object IDs to purge.
 ///
@@ -25,8 +23,7 @@ pub fn with_purge(
         if object_ids_to_purge.contains(&raw.get_object_id()) {
             log::trace!("clone_raw_vector purging {}", raw.get_object_id());
         } else {
-            // Match the object type, downcast and clone into a new box in new_raws
-            new_raws.push(clone_raw_object_box(raw));
+            new_raws.push(raw.clone());
         }
     }
     new_raws
@@ -64,7 +61,7 @@ pub fn with_limit_and_page(
 
     for (pos, raw) in all_raws.iter().enumerate() {
         if pos >= start && pos < end {
-            new_raws.push(clone_raw_object_box(raw));
+            new_raws.push(raw.clone());
         }
     }
     new_raws
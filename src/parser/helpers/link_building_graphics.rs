@@ -0,0 +1,78 @@
+use crate::parser::{
+    building::raw::Building,
+    graphics::{raw::Graphic, tokens::GraphicType},
+    helpers::clone_raw_vector::with_purge,
+    object_types::ObjectType,
+    raws::RawObject,
+};
+
+/// Link each `CUSTOM_WORKSHOP_GRAPHICS` graphic to the building with the same identifier, by
+/// adding the graphic's object ID to the building. The updated buildings replace the original
+/// ones in `all_raws`.
+///
+/// Arguments:
+///
+/// * `all_raws`: The parsed raws, which are updated in place
+pub fn link_building_graphics(all_raws: &mut Vec<Box<dyn RawObject>>) {
+    let workshop_graphics: Vec<(String, String)> = all_raws
+        .iter()
+        .filter(|r| r.get_type() == &ObjectType::Graphics)
+        .filter_map(|r| r.as_any().downcast_ref::<Graphic>())
+        .filter(|g| g.get_graphic_type() == GraphicType::CustomWorkshop)
+        .map(|g| {
+            (
+                String::from(g.get_identifier()),
+                String::from(g.get_object_id()),
+            )
+        })
+        .collect();
+
+    if workshop_graphics.is_empty() {
+        return;
+    }
+
+    let mut linked_buildings: Vec<Building> = Vec::new();
+    for raw in all_raws.iter().filter(|r| {
+        r.get_type() == &ObjectType::BuildingWorkshop
+            || r.get_type() == &ObjectType::BuildingFurnace
+    }) {
+        let Some(building) = raw.as_any().downcast_ref::<Building>() else {
+            continue;
+        };
+        let mut building = building.clone();
+        let mut linked = false;
+        for (identifier, object_id) in &workshop_graphics {
+            if identifier.eq_ignore_ascii_case(building.get_identifier()) {
+                building.add_graphic(object_id);
+                linked = true;
+            }
+        }
+        if linked {
+            linked_buildings.push(building);
+        }
+    }
+
+    log::info!(
+        "link_building_graphics: linked {} workshop graphics to {} buildings",
+        workshop_graphics.len(),
+        linked_buildings.len()
+    );
+
+    if linked_buildings.is_empty() {
+        return;
+    }
+
+    let object_ids_to_purge: Vec<&str> = linked_buildings
+        .iter()
+        .map(RawObject::get_object_id)
+        .collect();
+
+    let mut new_raws: Vec<Box<dyn RawObject>> =
+        with_purge(all_raws.as_slice(), object_ids_to_purge.as_slice());
+
+    for building in linked_buildings {
+        new_raws.push(Box::new(building));
+    }
+
+    *all_raws = new_raws;
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use crate::parser::{
+    creature::raw::Creature, helpers::clone_raw_vector::with_purge, interaction::raw::Interaction,
+    object_types::ObjectType, raws::RawObject,
+};
+
+/// Link each `CAN_DO_INTERACTION` on a creature's castes to the `Interaction` with the same
+/// identifier, by setting the interaction's object ID on it. The updated creatures replace the
+/// original ones in `all_raws`.
+///
+/// If more than one interaction has the same identifier, the last one parsed is used (so a mod
+/// can replace a vanilla interaction).
+///
+/// Arguments:
+///
+/// * `all_raws`: The parsed raws, which are updated in place
+pub fn link_creature_interactions(all_raws: &mut Vec<Box<dyn RawObject>>) {
+    let interactions: HashMap<String, String> = all_raws
+        .iter()
+        .filter(|r| r.get_type() == &ObjectType::Interaction)
+        .filter_map(|r| r.as_any().downcast_ref::<Interaction>())
+        .map(|i| {
+            (
+                String::from(i.get_identifier()),
+                String::from(i.get_object_id()),
+            )
+        })
+        .collect();
+
+    if interactions.is_empty() {
+        return;
+    }
+
+    let mut linked_creatures: Vec<Creature> = Vec::new();
+    for raw in all_raws
+        .iter()
+        .filter(|r| r.get_type() == &ObjectType::Creature)
+    {
+        let Some(creature) = raw.as_any().downcast_ref::<Creature>() else {
+            continue;
+        };
+        let mut creature = creature.clone();
+        if creature.link_interactions(&interactions) {
+            linked_creatures.push(creature);
+        }
+    }
+
+    log::info!(
+        "link_creature_interactions: linked {} interactions to {} creatures",
+        interactions.len(),
+        linked_creatures.len()
+    );
+
+    if linked_creatures.is_empty() {
+        return;
+    }
+
+    let object_ids_to_purge: Vec<&str> = linked_creatures
+        .iter()
+        .map(RawObject::get_object_id)
+        .collect();
+
+    let mut new_raws: Vec<Box<dyn RawObject>> =
+        with_purge(all_raws.as_slice(), object_ids_to_purge.as_slice());
+
+    for creature in linked_creatures {
+        new_raws.push(Box::new(creature));
+    }
+
+    *all_raws = new_raws;
+}
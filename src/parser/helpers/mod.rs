@@ -1,5 +1,12 @@
 pub mod absorb_select_creature;
 pub mod apply_copy_from;
+pub mod apply_creature_variations;
+pub mod apply_cut_objects;
+pub mod apply_material_templates;
+pub mod apply_select_objects;
+pub mod body_part_tree;
 pub mod clone_raw_object_box;
 pub mod clone_raw_vector;
+pub mod link_building_graphics;
+pub mod link_creature_interactions;
 pub mod object_id;
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use slug::slugify;
 
@@ -7,6 +9,7 @@ use crate::parser::{
     raws::{RawMetadata, RawObject},
     searchable::{clean_search_vec, Searchable},
     serializer_helper,
+    writer::{token_key, write_tags, RawWriter, ToRawText},
 };
 
 use super::{
@@ -15,9 +18,10 @@ use super::{
 };
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Inorganic {
     identifier: String,
     #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
@@ -60,6 +64,25 @@ impl Inorganic {
             ..Inorganic::default()
         }
     }
+    pub fn get_material(&self) -> &Material {
+        &self.material
+    }
+    /// Apply the material template used by this inorganic's material, if there is one.
+    ///
+    /// Arguments:
+    ///
+    /// * `templates`: The material of each `MaterialTemplate`, by template identifier.
+    ///
+    /// Returns:
+    ///
+    /// true if the material was changed.
+    pub fn apply_material_templates(&mut self, templates: &HashMap<String, Material>) -> bool {
+        let Some(template) = templates.get(self.material.get_template_identifier()) else {
+            return false;
+        };
+        self.material.apply_material_template(template);
+        true
+    }
 }
 
 #[typetag::serde]
@@ -139,6 +162,49 @@ impl RawObject for Inorganic {
     }
 }
 
+impl ToRawText for Inorganic {
+    /// Write the inorganic as raw text, with its material after its own tokens.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &INORGANIC_TOKENS;
+        writer.begin_object("INORGANIC", &self.identifier);
+
+        if !self.environment_class.is_default() {
+            writer.token_for(
+                tokens,
+                &InorganicToken::Environment,
+                format!(
+                    "{}:{}:{}",
+                    token_key(&ENVIRONMENT_CLASS_TOKENS, &self.environment_class)
+                        .unwrap_or_default(),
+                    token_key(&INCLUSION_TYPE_TOKENS, &self.environment_inclusion_type)
+                        .unwrap_or_default(),
+                    self.environment_inclusion_frequency
+                ),
+            );
+        }
+        for environment in &self.environment_class_specific {
+            writer.token_for(tokens, &InorganicToken::EnvironmentSpecific, environment);
+        }
+        for (metal, chance) in &self.metal_ore_chance {
+            writer.token_for(
+                tokens,
+                &InorganicToken::MetalOre,
+                format!("{metal}:{chance}"),
+            );
+        }
+        for (metal, chance) in &self.thread_metal_chance {
+            writer.token_for(
+                tokens,
+                &InorganicToken::ThreadMetal,
+                format!("{metal}:{chance}"),
+            );
+        }
+        write_tags(writer, tokens, &self.tags);
+
+        self.material.write_raw_text(writer);
+    }
+}
+
 impl Searchable for Inorganic {
     fn get_search_vec(&self) -> Vec<String> {
         let mut vec = Vec::new();
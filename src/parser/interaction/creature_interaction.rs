@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    serializer_helper,
+    writer::{token_key, RawWriter, ToRawText},
+};
+
+use super::{phf_table::CREATURE_INTERACTION_TOKENS, tokens::CreatureInteractionToken};
+
+/// An interaction a caste can do, from `[CAN_DO_INTERACTION:MATERIAL_EMISSION]` and the
+/// `[CDI:...]` tokens after it.
+///
+/// After parsing, the interaction is linked to the parsed `Interaction` with the same identifier
+/// (see `link_creature_interactions`), so the effects of e.g. a breath attack can be looked up.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct CreatureInteraction {
+    /// The identifier of the interaction, e.g. `MATERIAL_EMISSION`.
+    interaction: String,
+    /// The object ID of the parsed interaction, if it was found.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    interaction_object_id: String,
+
+    #[serde(skip_serializing_if = "String::is_empty")]
+    adventure_name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    usage_hints: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    location_hints: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    body_parts_required: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    material: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    verb: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    target_verb: String,
+    /// The `TARGET`, `TARGET_RANGE` and `MAX_TARGET_NUMBER` values, e.g. `A:LINE_OF_SIGHT`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    targets: Vec<(CreatureInteractionToken, String)>,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    wait_period: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_false")]
+    free_action: bool,
+}
+
+impl CreatureInteraction {
+    pub fn from_value(value: &str) -> Self {
+        Self {
+            interaction: String::from(value),
+            ..Self::default()
+        }
+    }
+    pub fn get_interaction(&self) -> &str {
+        &self.interaction
+    }
+    pub fn get_interaction_object_id(&self) -> &str {
+        &self.interaction_object_id
+    }
+    pub fn set_interaction_object_id(&mut self, object_id: &str) {
+        self.interaction_object_id = String::from(object_id);
+    }
+    pub fn get_adventure_name(&self) -> &str {
+        &self.adventure_name
+    }
+    pub fn get_usage_hints(&self) -> &[String] {
+        self.usage_hints.as_slice()
+    }
+    /// Parse the value of a `CDI` token, e.g. `ADV_NAME:Spit`.
+    pub fn parse_tag(&mut self, value: &str) {
+        let (key, value) = value.split_once(':').unwrap_or((value, ""));
+        let Some(tag) = CREATURE_INTERACTION_TOKENS.get(key) else {
+            log::debug!(
+                "CreatureInteraction::parse_tag: Unknown CDI tag {key} for {}",
+                self.interaction
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown creature interaction tag CDI:{key}"),
+            );
+            return;
+        };
+
+        match tag {
+            CreatureInteractionToken::AdventureName => self.adventure_name = String::from(value),
+            CreatureInteractionToken::UsageHint => self.usage_hints.push(String::from(value)),
+            CreatureInteractionToken::LocationHint => {
+                self.location_hints.push(String::from(value));
+            }
+            CreatureInteractionToken::BodyPartRequired => {
+                self.body_parts_required.push(String::from(value));
+            }
+            CreatureInteractionToken::Material => self.material = String::from(value),
+            CreatureInteractionToken::Verb => self.verb = String::from(value),
+            CreatureInteractionToken::TargetVerb => self.target_verb = String::from(value),
+            CreatureInteractionToken::Target
+            | CreatureInteractionToken::TargetRange
+            | CreatureInteractionToken::MaxTargetNumber => {
+                self.targets.push((tag.clone(), String::from(value)));
+            }
+            CreatureInteractionToken::WaitPeriod => {
+                self.wait_period = value.parse().unwrap_or_default();
+            }
+            CreatureInteractionToken::FreeAction => self.free_action = true,
+            CreatureInteractionToken::Unknown => {}
+        }
+    }
+}
+
+impl ToRawText for CreatureInteraction {
+    /// Write the `[CAN_DO_INTERACTION:...]` token, followed by a `[CDI:...]` token for each of its
+    /// properties.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        writer.token("CAN_DO_INTERACTION", &self.interaction);
+        writer.indent();
+
+        let mut properties: Vec<(CreatureInteractionToken, &str)> = Vec::new();
+        if !self.adventure_name.is_empty() {
+            properties.push((
+                CreatureInteractionToken::AdventureName,
+                &self.adventure_name,
+            ));
+        }
+        for usage_hint in &self.usage_hints {
+            properties.push((CreatureInteractionToken::UsageHint, usage_hint));
+        }
+        for location_hint in &self.location_hints {
+            properties.push((CreatureInteractionToken::LocationHint, location_hint));
+        }
+        for body_part in &self.body_parts_required {
+            properties.push((CreatureInteractionToken::BodyPartRequired, body_part));
+        }
+        if !self.material.is_empty() {
+            properties.push((CreatureInteractionToken::Material, &self.material));
+        }
+        if !self.verb.is_empty() {
+            properties.push((CreatureInteractionToken::Verb, &self.verb));
+        }
+        if !self.target_verb.is_empty() {
+            properties.push((CreatureInteractionToken::TargetVerb, &self.target_verb));
+        }
+        for (tag, value) in &self.targets {
+            properties.push((tag.clone(), value));
+        }
+        let wait_period = self.wait_period.to_string();
+        if self.wait_period != 0 {
+            properties.push((CreatureInteractionToken::WaitPeriod, &wait_period));
+        }
+        if self.free_action {
+            properties.push((CreatureInteractionToken::FreeAction, ""));
+        }
+
+        for (tag, value) in properties {
+            let Some(key) = token_key(&CREATURE_INTERACTION_TOKENS, &tag) else {
+                continue;
+            };
+            if value.is_empty() {
+                writer.token("CDI", key);
+            } else {
+                writer.token("CDI", format!("{key}:{value}"));
+            }
+        }
+        writer.outdent();
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+    syndrome::raw::Syndrome,
+};
+
+use super::tokens::InteractionToken;
+
+/// An effect of an interaction, from `[I_EFFECT:...]` and the `IE_` tokens after it, e.g.
+/// `ADD_SYNDROME`, `ANIMATE` or `MATERIAL_EMISSION`. Effects which add syndromes have the
+/// `[SYNDROME]` blocks which follow them.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct InteractionEffect {
+    effect_type: String,
+    /// The identifiers of the targets the effect applies to, e.g. `A`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    targets: Vec<String>,
+    #[serde(skip_serializing_if = "serializer_helper::is_false")]
+    immediate: bool,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    intermittent: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    arena_name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    item: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    propel_force: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    syndromes: Vec<Syndrome>,
+}
+
+impl InteractionEffect {
+    pub fn from_value(value: &str) -> Self {
+        Self {
+            effect_type: String::from(value),
+            ..Self::default()
+        }
+    }
+    pub fn get_effect_type(&self) -> &str {
+        &self.effect_type
+    }
+    pub fn get_targets(&self) -> &[String] {
+        self.targets.as_slice()
+    }
+    pub fn get_syndromes(&self) -> &[Syndrome] {
+        self.syndromes.as_slice()
+    }
+    /// Start a new syndrome for the effect, which the syndrome tokens that follow are added to.
+    pub fn add_syndrome(&mut self) {
+        self.syndromes.push(Syndrome::new());
+    }
+    /// Add a syndrome (or creature effect) tag to the last syndrome of the effect.
+    ///
+    /// Returns:
+    ///
+    /// false if the effect doesn't have a syndrome yet.
+    pub fn parse_syndrome_tag(&mut self, key: &str, value: &str) -> bool {
+        let Some(syndrome) = self.syndromes.last_mut() else {
+            return false;
+        };
+        syndrome.parse_tag(key, value);
+        true
+    }
+    pub fn parse_tag(&mut self, key: &InteractionToken, value: &str) {
+        match key {
+            InteractionToken::EffectTarget => self.targets.push(String::from(value)),
+            InteractionToken::EffectImmediate => self.immediate = true,
+            InteractionToken::EffectIntermittent => self.intermittent = String::from(value),
+            InteractionToken::EffectArenaName => self.arena_name = String::from(value),
+            InteractionToken::EffectLocation => self.locations.push(String::from(value)),
+            InteractionToken::EffectItem => self.item = String::from(value),
+            InteractionToken::EffectPropelForce => self.propel_force = String::from(value),
+            _ => {
+                log::debug!(
+                    "InteractionEffect::parse_tag: {key:?} is not an effect token for {}",
+                    self.effect_type
+                );
+            }
+        }
+    }
+}
+
+impl Searchable for InteractionEffect {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.effect_type.clone());
+        vec.push(self.arena_name.clone());
+        vec.extend(self.syndromes.iter().flat_map(Searchable::get_search_vec));
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,7 @@
+pub mod creature_interaction;
+pub mod effect;
+pub mod phf_table;
+pub mod raw;
+pub mod source;
+pub mod target;
+pub mod tokens;
@@ -0,0 +1,51 @@
+use super::tokens::{CreatureInteractionToken, InteractionToken};
+
+pub static INTERACTION_TOKENS: phf::Map<&'static str, InteractionToken> = phf::phf_map! {
+    "I_SOURCE" => InteractionToken::Source,
+    "I_TARGET" => InteractionToken::Target,
+    "I_EFFECT" => InteractionToken::Effect,
+    "IS_NAME" => InteractionToken::SourceName,
+    "IS_SPHERE" => InteractionToken::SourceSphere,
+    "IS_SECRET_GOAL" => InteractionToken::SourceSecretGoal,
+    "IS_SECRET" => InteractionToken::SourceSecret,
+    "IS_REGION" => InteractionToken::SourceRegion,
+    "IS_FREQUENCY" => InteractionToken::SourceFrequency,
+    "IS_HIST_STRING_1" => InteractionToken::SourceHistString1,
+    "IS_HIST_STRING_2" => InteractionToken::SourceHistString2,
+    "IS_TRIGGER_STRING" => InteractionToken::SourceTriggerString,
+    "IS_TRIGGER_STRING_SECOND" => InteractionToken::SourceTriggerStringSecond,
+    "IS_TRIGGER_STRING_THIRD" => InteractionToken::SourceTriggerStringThird,
+    "IT_LOCATION" => InteractionToken::TargetLocation,
+    "IT_MATERIAL" => InteractionToken::TargetMaterial,
+    "IT_AFFECTED_CREATURE" => InteractionToken::TargetAffectedCreature,
+    "IT_AFFECTED_CLASS" => InteractionToken::TargetAffectedClass,
+    "IT_IMMUNE_CREATURE" => InteractionToken::TargetImmuneCreature,
+    "IT_IMMUNE_CLASS" => InteractionToken::TargetImmuneClass,
+    "IT_REQUIRES" => InteractionToken::TargetRequires,
+    "IT_FORBIDDEN" => InteractionToken::TargetForbidden,
+    "IT_CANNOT_HAVE_SYNDROME_CLASS" => InteractionToken::TargetCannotHaveSyndromeClass,
+    "IT_CANNOT_TARGET_IF_ALREADY_AFFECTED" => InteractionToken::TargetCannotTargetIfAlreadyAffected,
+    "IT_MANUAL_INPUT" => InteractionToken::TargetManualInput,
+    "IE_TARGET" => InteractionToken::EffectTarget,
+    "IE_IMMEDIATE" => InteractionToken::EffectImmediate,
+    "IE_INTERMITTENT" => InteractionToken::EffectIntermittent,
+    "IE_ARENA_NAME" => InteractionToken::EffectArenaName,
+    "IE_LOCATION" => InteractionToken::EffectLocation,
+    "IE_ITEM" => InteractionToken::EffectItem,
+    "IE_PROPEL_FORCE" => InteractionToken::EffectPropelForce,
+};
+
+pub static CREATURE_INTERACTION_TOKENS: phf::Map<&'static str, CreatureInteractionToken> = phf::phf_map! {
+    "ADV_NAME" => CreatureInteractionToken::AdventureName,
+    "USAGE_HINT" => CreatureInteractionToken::UsageHint,
+    "LOCATION_HINT" => CreatureInteractionToken::LocationHint,
+    "BP_REQUIRED" => CreatureInteractionToken::BodyPartRequired,
+    "MATERIAL" => CreatureInteractionToken::Material,
+    "VERB" => CreatureInteractionToken::Verb,
+    "TARGET_VERB" => CreatureInteractionToken::TargetVerb,
+    "TARGET" => CreatureInteractionToken::Target,
+    "TARGET_RANGE" => CreatureInteractionToken::TargetRange,
+    "MAX_TARGET_NUMBER" => CreatureInteractionToken::MaxTargetNumber,
+    "WAIT_PERIOD" => CreatureInteractionToken::WaitPeriod,
+    "FREE_ACTION" => CreatureInteractionToken::FreeAction,
+};
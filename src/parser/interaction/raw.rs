@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    creature_effect::phf_table::CREATURE_EFFECT_TOKENS,
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+    syndrome::phf_table::SYNDROME_TOKEN,
+};
+
+use super::{
+    effect::InteractionEffect, phf_table::INTERACTION_TOKENS, source::InteractionSource,
+    target::InteractionTarget, tokens::InteractionToken,
+};
+
+/// An interaction from an `[OBJECT:INTERACTION]` raw file, e.g. `[INTERACTION:MATERIAL_EMISSION]`.
+///
+/// Interactions are made of sources (what lets the interaction happen), targets (what it can
+/// happen to) and effects (what it does, which often means adding a syndrome). They define
+/// necromancy, vampirism, werebeast curses and creature abilities like breath attacks.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Interaction {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sources: Vec<InteractionSource>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    targets: Vec<InteractionTarget>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    effects: Vec<InteractionEffect>,
+}
+
+impl Interaction {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Interaction),
+            ..Self::default()
+        }
+    }
+    pub fn get_sources(&self) -> &[InteractionSource] {
+        self.sources.as_slice()
+    }
+    pub fn get_targets(&self) -> &[InteractionTarget] {
+        self.targets.as_slice()
+    }
+    pub fn get_effects(&self) -> &[InteractionEffect] {
+        self.effects.as_slice()
+    }
+    /// Parse a `SYNDROME`, `SYN_` or `CE_` tag, which belongs to the last effect.
+    fn parse_syndrome_tag(&mut self, key: &str, value: &str) {
+        let Some(effect) = self.effects.last_mut() else {
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Syndrome tag {key} before any I_EFFECT"),
+            );
+            return;
+        };
+        if key == "SYNDROME" {
+            effect.add_syndrome();
+        } else if !effect.parse_syndrome_tag(key, value) {
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Syndrome tag {key} before any SYNDROME"),
+            );
+        }
+    }
+}
+
+#[typetag::serde]
+impl RawObject for Interaction {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::Interaction
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        // Effects can add syndromes, which reuse the syndrome and creature effect tokens.
+        if key == "SYNDROME"
+            || key == "CE"
+            || SYNDROME_TOKEN.contains_key(key)
+            || CREATURE_EFFECT_TOKENS.contains_key(key)
+        {
+            self.parse_syndrome_tag(key, value);
+            return;
+        }
+
+        let Some(tag) = INTERACTION_TOKENS.get(key) else {
+            log::debug!(
+                "Interaction::parse_tag: Unknown interaction tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown interaction tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            InteractionToken::Source => self.sources.push(InteractionSource::from_value(value)),
+            InteractionToken::Target => self.targets.push(InteractionTarget::from_value(value)),
+            InteractionToken::Effect => self.effects.push(InteractionEffect::from_value(value)),
+            InteractionToken::Unknown => {}
+            _ => {
+                // The other tokens belong to the last source, target or effect, which is picked
+                // by the token's prefix.
+                let parsed = if key.starts_with("IS_") {
+                    self.sources.last_mut().is_some_and(|source| {
+                        source.parse_tag(tag, value);
+                        true
+                    })
+                } else if key.starts_with("IT_") {
+                    self.targets.last_mut().is_some_and(|target| {
+                        target.parse_tag(tag, value);
+                        true
+                    })
+                } else {
+                    self.effects.last_mut().is_some_and(|effect| {
+                        effect.parse_tag(tag, value);
+                        true
+                    })
+                };
+                if !parsed {
+                    diagnostics::report(
+                        DiagnosticSeverity::Warning,
+                        &format!("Interaction tag {key} before its I_SOURCE, I_TARGET or I_EFFECT"),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl Searchable for Interaction {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        for source in &self.sources {
+            vec.push(String::from(source.get_source_type()));
+            vec.push(String::from(source.get_name()));
+            vec.extend(source.get_spheres().iter().cloned());
+        }
+        vec.extend(self.effects.iter().flat_map(Searchable::get_search_vec));
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::serializer_helper;
+
+use super::tokens::InteractionToken;
+
+/// A source of an interaction, from `[I_SOURCE:...]` and the `IS_` tokens after it. The source
+/// is what lets the interaction happen, e.g. `CREATURE_ACTION` for a creature's ability or
+/// `SECRET` for something which can be learned.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct InteractionSource {
+    source_type: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    spheres: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    secret_goals: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    secrets: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    regions: Vec<String>,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    frequency: u32,
+    /// The `IS_HIST_STRING_*` and `IS_TRIGGER_STRING*` texts, as (token, text).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    strings: Vec<(InteractionToken, String)>,
+}
+
+impl InteractionSource {
+    pub fn from_value(value: &str) -> Self {
+        Self {
+            source_type: String::from(value),
+            ..Self::default()
+        }
+    }
+    pub fn get_source_type(&self) -> &str {
+        &self.source_type
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_spheres(&self) -> &[String] {
+        self.spheres.as_slice()
+    }
+    pub fn parse_tag(&mut self, key: &InteractionToken, value: &str) {
+        match key {
+            InteractionToken::SourceName => self.name = String::from(value),
+            InteractionToken::SourceSphere => self.spheres.push(String::from(value)),
+            InteractionToken::SourceSecretGoal => self.secret_goals.push(String::from(value)),
+            InteractionToken::SourceSecret => self.secrets.push(String::from(value)),
+            InteractionToken::SourceRegion => self.regions.push(String::from(value)),
+            InteractionToken::SourceFrequency => {
+                self.frequency = value.parse().unwrap_or_default();
+            }
+            InteractionToken::SourceHistString1
+            | InteractionToken::SourceHistString2
+            | InteractionToken::SourceTriggerString
+            | InteractionToken::SourceTriggerStringSecond
+            | InteractionToken::SourceTriggerStringThird => {
+                self.strings.push((key.clone(), String::from(value)));
+            }
+            _ => {
+                log::debug!(
+                    "InteractionSource::parse_tag: {key:?} is not a source token for {}",
+                    self.source_type
+                );
+            }
+        }
+    }
+}
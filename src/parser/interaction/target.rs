@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::serializer_helper;
+
+use super::tokens::InteractionToken;
+
+/// A target of an interaction, from `[I_TARGET:A:CREATURE]` and the `IT_` tokens after it. The
+/// effects of the interaction refer to the target by its identifier (`A`).
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct InteractionTarget {
+    identifier: String,
+    /// The kind of target, e.g. `CREATURE`, `CORPSE`, `MATERIAL` or `LOCATION`.
+    target_type: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    materials: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    affected_classes: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    immune_classes: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    affected_creatures: Vec<(String, String)>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    immune_creatures: Vec<(String, String)>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requires: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    forbidden: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    forbidden_syndrome_classes: Vec<String>,
+    #[serde(skip_serializing_if = "serializer_helper::is_false")]
+    cannot_target_if_already_affected: bool,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    manual_input: String,
+}
+
+impl InteractionTarget {
+    pub fn from_value(value: &str) -> Self {
+        let (identifier, target_type) = value.split_once(':').unwrap_or((value, ""));
+        Self {
+            identifier: String::from(identifier),
+            target_type: String::from(target_type),
+            ..Self::default()
+        }
+    }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn get_target_type(&self) -> &str {
+        &self.target_type
+    }
+    pub fn get_requires(&self) -> &[String] {
+        self.requires.as_slice()
+    }
+    pub fn get_forbidden(&self) -> &[String] {
+        self.forbidden.as_slice()
+    }
+    pub fn parse_tag(&mut self, key: &InteractionToken, value: &str) {
+        match key {
+            InteractionToken::TargetLocation => self.locations.push(String::from(value)),
+            InteractionToken::TargetMaterial => self.materials.push(String::from(value)),
+            InteractionToken::TargetAffectedClass => {
+                self.affected_classes.push(String::from(value));
+            }
+            InteractionToken::TargetImmuneClass => self.immune_classes.push(String::from(value)),
+            InteractionToken::TargetAffectedCreature => {
+                self.affected_creatures.push(split_creature_caste(value));
+            }
+            InteractionToken::TargetImmuneCreature => {
+                self.immune_creatures.push(split_creature_caste(value));
+            }
+            InteractionToken::TargetRequires => self.requires.push(String::from(value)),
+            InteractionToken::TargetForbidden => self.forbidden.push(String::from(value)),
+            InteractionToken::TargetCannotHaveSyndromeClass => {
+                self.forbidden_syndrome_classes.push(String::from(value));
+            }
+            InteractionToken::TargetCannotTargetIfAlreadyAffected => {
+                self.cannot_target_if_already_affected = true;
+            }
+            InteractionToken::TargetManualInput => self.manual_input = String::from(value),
+            _ => {
+                log::debug!(
+                    "InteractionTarget::parse_tag: {key:?} is not a target token for {}",
+                    self.identifier
+                );
+            }
+        }
+    }
+}
+
+fn split_creature_caste(value: &str) -> (String, String) {
+    let (creature, caste) = value.split_once(':').unwrap_or((value, ""));
+    (String::from(creature), String::from(caste))
+}
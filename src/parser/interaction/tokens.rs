@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum InteractionToken {
+    /// Arguments: source type
+    ///
+    /// Starts a source of the interaction, e.g. `[I_SOURCE:CREATURE_ACTION]` or `[I_SOURCE:SECRET]`.
+    /// The `IS_` tokens which follow it apply to this source.
+    Source,
+    /// Arguments: target identifier, target type
+    ///
+    /// Starts a target of the interaction, e.g. `[I_TARGET:A:CREATURE]`. The `IT_` tokens which
+    /// follow it apply to this target.
+    Target,
+    /// Arguments: effect type
+    ///
+    /// Starts an effect of the interaction, e.g. `[I_EFFECT:ADD_SYNDROME]`. The `IE_` tokens and
+    /// syndromes which follow it apply to this effect.
+    Effect,
+    /// Arguments: name
+    ///
+    /// The name of a secret source, e.g. `the secrets of life and death`.
+    SourceName,
+    /// Arguments: sphere
+    ///
+    /// A sphere of a secret or deity source, e.g. `DEATH`.
+    SourceSphere,
+    /// Arguments: goal
+    ///
+    /// A goal which makes a historical figure seek a secret source, e.g. `IMMORTALITY`.
+    SourceSecretGoal,
+    /// Arguments: secret flag
+    ///
+    /// How a secret source can be learned, e.g. `SUPERNATURAL_LEARNING_POSSIBLE`.
+    SourceSecret,
+    /// Arguments: region type
+    ///
+    /// The kind of region of a region source, e.g. `EVIL`.
+    SourceRegion,
+    /// Arguments: frequency
+    ///
+    /// How often a region or disturbance source happens.
+    SourceFrequency,
+    /// Arguments: text
+    ///
+    /// The first part of the text used in historical records when the interaction happens.
+    SourceHistString1,
+    /// Arguments: text
+    ///
+    /// The second part of the text used in historical records when the interaction happens.
+    SourceHistString2,
+    /// Arguments: text
+    ///
+    /// The text shown when the interaction happens.
+    SourceTriggerString,
+    /// Arguments: text
+    ///
+    /// The text shown when the interaction happens, in the second person.
+    SourceTriggerStringSecond,
+    /// Arguments: text
+    ///
+    /// The text shown when the interaction happens, in the third person.
+    SourceTriggerStringThird,
+    /// Arguments: location
+    ///
+    /// Where the target is found, e.g. `CONTEXT_CREATURE`.
+    TargetLocation,
+    /// Arguments: material
+    ///
+    /// The material of the target, e.g. `CONTEXT_MATERIAL`.
+    TargetMaterial,
+    /// Arguments: creature, caste
+    ///
+    /// Only the given creature and caste can be targeted.
+    TargetAffectedCreature,
+    /// Arguments: creature class
+    ///
+    /// Only creatures of the given class can be targeted.
+    TargetAffectedClass,
+    /// Arguments: creature, caste
+    ///
+    /// The given creature and caste can't be targeted.
+    TargetImmuneCreature,
+    /// Arguments: creature class
+    ///
+    /// Creatures of the given class can't be targeted.
+    TargetImmuneClass,
+    /// Arguments: flag
+    ///
+    /// The target must have the flag, e.g. `CAN_LEARN`.
+    TargetRequires,
+    /// Arguments: flag
+    ///
+    /// The target can't have the flag, e.g. `NOT_LIVING`.
+    TargetForbidden,
+    /// Arguments: syndrome class
+    ///
+    /// The target can't have a syndrome of the given class, e.g. `WERECURSE`.
+    TargetCannotHaveSyndromeClass,
+    /// The target can't be targeted if it is already affected by the interaction.
+    TargetCannotTargetIfAlreadyAffected,
+    /// Arguments: text
+    ///
+    /// The text shown when the player picks the target by hand.
+    TargetManualInput,
+    /// Arguments: target identifier
+    ///
+    /// The target the effect applies to, e.g. `A`.
+    EffectTarget,
+    /// The effect happens immediately.
+    EffectImmediate,
+    /// Arguments: interval
+    ///
+    /// The effect happens repeatedly, e.g. `WEEKLY`.
+    EffectIntermittent,
+    /// Arguments: name
+    ///
+    /// The name of the effect in the arena, e.g. `Necromancer`.
+    EffectArenaName,
+    /// Arguments: location
+    ///
+    /// Where the effect happens.
+    EffectLocation,
+    /// Arguments: item type, item subtype
+    ///
+    /// The item the effect makes or changes.
+    EffectItem,
+    /// Arguments: force
+    ///
+    /// How hard a `PROPEL_UNIT` effect throws the target.
+    EffectPropelForce,
+    #[default]
+    Unknown,
+}
+
+/// The `CDI` tokens which describe how a creature uses an interaction, e.g. `[CDI:ADV_NAME:Spit]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum CreatureInteractionToken {
+    /// Arguments: name
+    ///
+    /// The name of the interaction in adventure mode, e.g. `Spit`.
+    AdventureName,
+    /// Arguments: hint
+    ///
+    /// When the AI uses the interaction, e.g. `ATTACK` or `FLEEING`.
+    UsageHint,
+    /// Arguments: hint
+    ///
+    /// Where the AI uses the interaction, e.g. `IN_WATER`.
+    LocationHint,
+    /// Arguments: selector, value
+    ///
+    /// A body part the creature needs to use the interaction, e.g. `BY_CATEGORY:MOUTH`.
+    BodyPartRequired,
+    /// Arguments: material, breath attack type
+    ///
+    /// The material the interaction emits, e.g. `LOCAL_CREATURE_MAT:SPIT:LIQUID_GLOB`.
+    Material,
+    /// Arguments: second person verb, third person verb, verb when mutual
+    ///
+    /// The verb used when the creature uses the interaction, e.g. `spit:spits:NA`.
+    Verb,
+    /// Arguments: second person verb, third person verb
+    ///
+    /// The verb used for the target of the interaction.
+    TargetVerb,
+    /// Arguments: target identifier, target flags
+    ///
+    /// How a target of the interaction is picked, e.g. `A:LINE_OF_SIGHT`.
+    Target,
+    /// Arguments: target identifier, range
+    ///
+    /// How far away a target of the interaction can be.
+    TargetRange,
+    /// Arguments: target identifier, count
+    ///
+    /// How many targets the interaction can have.
+    MaxTargetNumber,
+    /// Arguments: ticks
+    ///
+    /// How long the creature waits before using the interaction again.
+    WaitPeriod,
+    /// Using the interaction doesn't take the creature's turn.
+    FreeAction,
+    #[default]
+    Unknown,
+}
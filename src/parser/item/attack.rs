@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::serializer_helper;
+
+/// An attack that can be made with a weapon (or trap component), from a token like
+/// `[ATTACK:EDGE:20000:4000:slash:slashes:NO_SUB:1250]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ItemAttack {
+    /// `EDGE` or `BLUNT`
+    attack_type: String,
+    contact_area: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    penetration_size: u32,
+    verb_second_person: String,
+    verb_third_person: String,
+    /// The noun used for the attacking part, or `NO_SUB` to use the name of the item.
+    noun: String,
+    /// Multiplier for the velocity of the attack, in thousandths (1000 is normal speed).
+    velocity_multiplier: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    prepare_time: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    recover_time: u32,
+    /// The `ATTACK_FLAG_*` tokens which followed the attack, without the prefix.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    flags: Vec<String>,
+}
+
+impl ItemAttack {
+    /// Create an attack from the value of an `ATTACK` token.
+    ///
+    /// Arguments:
+    ///
+    /// * `value`: The value of the token, e.g. `EDGE:20000:4000:slash:slashes:NO_SUB:1250`
+    pub fn from_value(value: &str) -> Self {
+        let parts: Vec<&str> = value.split(':').collect();
+
+        Self {
+            attack_type: String::from(*parts.first().unwrap_or(&"")),
+            contact_area: parts.get(1).unwrap_or(&"").parse().unwrap_or_default(),
+            penetration_size: parts.get(2).unwrap_or(&"").parse().unwrap_or_default(),
+            verb_second_person: String::from(*parts.get(3).unwrap_or(&"")),
+            verb_third_person: String::from(*parts.get(4).unwrap_or(&"")),
+            noun: String::from(*parts.get(5).unwrap_or(&"")),
+            velocity_multiplier: parts.get(6).unwrap_or(&"1000").parse().unwrap_or(1000),
+            ..Self::default()
+        }
+    }
+    /// Set the prepare and recover times from the value of an `ATTACK_PREPARE_AND_RECOVER` token.
+    pub fn set_prepare_and_recover(&mut self, value: &str) {
+        let mut split = value.split(':');
+        self.prepare_time = split.next().unwrap_or_default().parse().unwrap_or_default();
+        self.recover_time = split.next().unwrap_or_default().parse().unwrap_or_default();
+    }
+    pub fn add_flag(&mut self, flag: &str) {
+        self.flags.push(String::from(flag));
+    }
+    pub fn get_verb(&self) -> &str {
+        &self.verb_second_person
+    }
+}
@@ -0,0 +1,81 @@
+use super::tokens::{ArmorLayer, ItemToken};
+
+pub static ITEM_TOKENS: phf::Map<&'static str, ItemToken> = phf::phf_map! {
+    "NAME" => ItemToken::Name,
+    "ADJECTIVE" => ItemToken::Adjective,
+    "PREPLURAL" => ItemToken::PrePlural,
+    "MATERIAL_PLACEHOLDER" => ItemToken::MaterialPlaceholder,
+    "SIZE" => ItemToken::Size,
+    "MATERIAL_SIZE" => ItemToken::MaterialSize,
+    "VALUE" => ItemToken::Value,
+    "TILE" => ItemToken::Tile,
+    "LEVEL" => ItemToken::Level,
+
+    "SKILL" => ItemToken::Skill,
+    "TWO_HANDED" => ItemToken::TwoHanded,
+    "MINIMUM_SIZE" => ItemToken::MinimumSize,
+    "RANGED" => ItemToken::Ranged,
+    "SHOOT_FORCE" => ItemToken::ShootForce,
+    "SHOOT_MAXVEL" => ItemToken::ShootMaxVelocity,
+    "ATTACK" => ItemToken::Attack,
+    "ATTACK_PREPARE_AND_RECOVER" => ItemToken::AttackPrepareAndRecover,
+    "ATTACK_FLAG_INDEPENDENT_MULTIATTACK" => ItemToken::AttackFlagIndependentMultiattack,
+    "ATTACK_FLAG_CATCH_ATTACK" => ItemToken::AttackFlagCatchAttack,
+    "ATTACK_FLAG_BAD_MULTIATTACK" => ItemToken::AttackFlagBadMultiattack,
+
+    "CLASS" => ItemToken::Class,
+
+    "ARMORLEVEL" => ItemToken::ArmorLevel,
+    "COVERAGE" => ItemToken::Coverage,
+    "LAYER" => ItemToken::Layer,
+    "LAYER_SIZE" => ItemToken::LayerSize,
+    "LAYER_PERMIT" => ItemToken::LayerPermit,
+    "UPSTEP" => ItemToken::UpStep,
+    "UBSTEP" => ItemToken::UpperBodyStep,
+    "LBSTEP" => ItemToken::LowerBodyStep,
+    "BLOCKCHANCE" => ItemToken::BlockChance,
+    "SHAPED" => ItemToken::Shaped,
+    "BARRED" => ItemToken::Barred,
+    "SCALED" => ItemToken::Scaled,
+    "LEATHER" => ItemToken::Leather,
+    "CHAIN" => ItemToken::Chain,
+    "SOFT" => ItemToken::Soft,
+    "HARD" => ItemToken::Hard,
+    "METAL" => ItemToken::Metal,
+    "METAL_ARMOR_LEVELS" => ItemToken::MetalArmorLevels,
+    "STRUCTURAL_ELASTICITY_CHAIN_ALL" => ItemToken::StructuralElasticityChainAll,
+    "STRUCTURAL_ELASTICITY_CHAIN_METAL" => ItemToken::StructuralElasticityChainMetal,
+    "STRUCTURAL_ELASTICITY_WOVEN_THREAD" => ItemToken::StructuralElasticityWovenThread,
+
+    "TOOL_USE" => ItemToken::ToolUse,
+    "CONTAINER_CAPACITY" => ItemToken::ContainerCapacity,
+    "HITS" => ItemToken::Hits,
+    "HARD_MAT" => ItemToken::HardMat,
+    "WOOD" => ItemToken::Wood,
+    "METAL_MAT" => ItemToken::MetalMat,
+    "WOOD_MAT" => ItemToken::WoodMat,
+    "STONE_MAT" => ItemToken::StoneMat,
+    "GLASS_MAT" => ItemToken::GlassMat,
+    "CERAMIC_MAT" => ItemToken::CeramicMat,
+    "BONE_MAT" => ItemToken::BoneMat,
+    "SHELL_MAT" => ItemToken::ShellMat,
+    "LEATHER_MAT" => ItemToken::LeatherMat,
+    "SOFT_MAT" => ItemToken::SoftMat,
+    "SHEET_MAT" => ItemToken::SheetMat,
+    "CAN_STONE" => ItemToken::CanStone,
+    "IS_SCREW" => ItemToken::IsScrew,
+    "IS_SPIKE" => ItemToken::IsSpike,
+    "FURNITURE" => ItemToken::Furniture,
+    "INCOMPLETE_ITEM" => ItemToken::IncompleteItem,
+    "INVERTED_TILE" => ItemToken::InvertedTile,
+    "NO_DEFAULT_JOB" => ItemToken::NoDefaultJob,
+    "UNIMPROVABLE" => ItemToken::Unimprovable,
+    "NO_DEFAULT_IMPROVEMENTS" => ItemToken::NoDefaultImprovements,
+};
+
+pub static ARMOR_LAYER_TOKENS: phf::Map<&'static str, ArmorLayer> = phf::phf_map! {
+    "UNDER" => ArmorLayer::Under,
+    "OVER" => ArmorLayer::Over,
+    "ARMOR" => ArmorLayer::Armor,
+    "COVER" => ArmorLayer::Cover,
+};
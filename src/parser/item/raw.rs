@@ -0,0 +1,332 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    names::SingPlurName,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{
+    attack::ItemAttack,
+    phf_table::{ARMOR_LAYER_TOKENS, ITEM_TOKENS},
+    tokens::{ArmorLayer, ItemToken},
+};
+
+/// An item definition from an `[OBJECT:ITEM]` raw file, e.g. `[ITEM_WEAPON:ITEM_WEAPON_SWORD_SHORT]`.
+///
+/// All the kinds of items share this struct. The kind is kept in `item_type` (e.g. `ItemWeapon`),
+/// which is also what `get_type` returns.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::struct_field_names)]
+#[serde(default)]
+pub struct Item {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+    item_type: ObjectType,
+
+    #[serde(skip_serializing_if = "SingPlurName::is_empty")]
+    name: SingPlurName,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    adjective: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pre_plural: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    material_placeholder: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    tile: String,
+
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    size: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    material_size: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    value: u32,
+    /// The level of a prepared meal.
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    level: u32,
+
+    // Weapons
+    #[serde(skip_serializing_if = "String::is_empty")]
+    skill: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    two_handed: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    minimum_size: u32,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    ranged_skill: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    ranged_ammo_class: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    shoot_force: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    shoot_max_velocity: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attacks: Vec<ItemAttack>,
+
+    // Ammo and siege ammo
+    #[serde(skip_serializing_if = "String::is_empty")]
+    ammo_class: String,
+
+    // Armor, clothing and shields
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    armor_level: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    coverage: u32,
+    #[serde(skip_serializing_if = "ArmorLayer::is_default")]
+    layer: ArmorLayer,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    layer_size: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    layer_permit: u32,
+    /// `UPSTEP`, where `MAX` is stored as `u32::MAX`
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    up_step: u32,
+    /// `UBSTEP`, where `MAX` is stored as `u32::MAX`
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    upper_body_step: u32,
+    /// `LBSTEP`, where `MAX` is stored as `u32::MAX`
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    lower_body_step: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    block_chance: u32,
+
+    // Tools and trap components
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tool_uses: Vec<String>,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    container_capacity: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    hits: u32,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<ItemToken>,
+}
+
+impl Item {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    /// Create a new item.
+    ///
+    /// Arguments:
+    ///
+    /// * `identifier`: The identifier of the item, e.g. `ITEM_WEAPON_SWORD_SHORT`
+    /// * `metadata`: The metadata of the raw file
+    /// * `item_type`: The kind of item, from the token which started it (e.g. `ItemWeapon` for `ITEM_WEAPON`)
+    pub fn new(identifier: &str, metadata: &RawMetadata, item_type: ObjectType) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &item_type),
+            item_type,
+            ..Self::default()
+        }
+    }
+    pub fn get_attacks(&self) -> &[ItemAttack] {
+        self.attacks.as_slice()
+    }
+    pub fn get_tool_uses(&self) -> &[String] {
+        self.tool_uses.as_slice()
+    }
+    pub fn get_armor_level(&self) -> u32 {
+        self.armor_level
+    }
+    pub fn get_skill(&self) -> &str {
+        &self.skill
+    }
+}
+
+/// Parse the value of a `UPSTEP`, `UBSTEP` or `LBSTEP` token, which is a number or `MAX`.
+fn parse_step(value: &str) -> u32 {
+    if value == "MAX" {
+        return u32::MAX;
+    }
+    value.parse().unwrap_or_default()
+}
+
+#[typetag::serde]
+impl RawObject for Item {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        if self.name.get_singular().is_empty() {
+            return &self.identifier;
+        }
+        self.name.get_singular()
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &self.item_type
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    #[allow(clippy::too_many_lines)]
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = ITEM_TOKENS.get(key) else {
+            log::debug!(
+                "Item::parse_tag: Unknown item tag {} in {}",
+                key,
+                self.identifier
+            );
+            diagnostics::report(DiagnosticSeverity::Info, &format!("Unknown item tag {key}"));
+            return;
+        };
+
+        match tag {
+            ItemToken::Name => {
+                self.name = SingPlurName::from_value(value);
+            }
+            ItemToken::Adjective => {
+                self.adjective = String::from(value);
+            }
+            ItemToken::PrePlural => {
+                self.pre_plural = String::from(value);
+            }
+            ItemToken::MaterialPlaceholder => {
+                self.material_placeholder = String::from(value);
+            }
+            ItemToken::Tile => {
+                self.tile = String::from(value);
+            }
+            ItemToken::Size => {
+                self.size = value.parse().unwrap_or_default();
+            }
+            ItemToken::MaterialSize => {
+                self.material_size = value.parse().unwrap_or_default();
+            }
+            ItemToken::Value => {
+                self.value = value.parse().unwrap_or_default();
+            }
+            ItemToken::Level => {
+                self.level = value.parse().unwrap_or_default();
+            }
+            ItemToken::Skill => {
+                self.skill = String::from(value);
+            }
+            ItemToken::TwoHanded => {
+                self.two_handed = value.parse().unwrap_or_default();
+            }
+            ItemToken::MinimumSize => {
+                self.minimum_size = value.parse().unwrap_or_default();
+            }
+            ItemToken::Ranged => {
+                let mut split = value.split(':');
+                self.ranged_skill = String::from(split.next().unwrap_or_default());
+                self.ranged_ammo_class = String::from(split.next().unwrap_or_default());
+            }
+            ItemToken::ShootForce => {
+                self.shoot_force = value.parse().unwrap_or_default();
+            }
+            ItemToken::ShootMaxVelocity => {
+                self.shoot_max_velocity = value.parse().unwrap_or_default();
+            }
+            ItemToken::Attack => {
+                self.attacks.push(ItemAttack::from_value(value));
+            }
+            ItemToken::AttackPrepareAndRecover
+            | ItemToken::AttackFlagIndependentMultiattack
+            | ItemToken::AttackFlagCatchAttack
+            | ItemToken::AttackFlagBadMultiattack => {
+                // These belong to the attack that came before them
+                let Some(attack) = self.attacks.last_mut() else {
+                    log::warn!(
+                        "Item::parse_tag: {} found before any ATTACK in {}",
+                        key,
+                        self.identifier
+                    );
+                    diagnostics::report(
+                        DiagnosticSeverity::Warning,
+                        &format!("{key} found before any ATTACK"),
+                    );
+                    return;
+                };
+                if tag == &ItemToken::AttackPrepareAndRecover {
+                    attack.set_prepare_and_recover(value);
+                } else {
+                    attack.add_flag(key.trim_start_matches("ATTACK_FLAG_"));
+                }
+            }
+            ItemToken::Class => {
+                self.ammo_class = String::from(value);
+            }
+            ItemToken::ArmorLevel => {
+                self.armor_level = value.parse().unwrap_or_default();
+            }
+            ItemToken::Coverage => {
+                self.coverage = value.parse().unwrap_or_default();
+            }
+            ItemToken::Layer => {
+                self.layer = ARMOR_LAYER_TOKENS.get(value).cloned().unwrap_or_default();
+            }
+            ItemToken::LayerSize => {
+                self.layer_size = value.parse().unwrap_or_default();
+            }
+            ItemToken::LayerPermit => {
+                self.layer_permit = value.parse().unwrap_or_default();
+            }
+            ItemToken::UpStep => {
+                self.up_step = parse_step(value);
+            }
+            ItemToken::UpperBodyStep => {
+                self.upper_body_step = parse_step(value);
+            }
+            ItemToken::LowerBodyStep => {
+                self.lower_body_step = parse_step(value);
+            }
+            ItemToken::BlockChance => {
+                self.block_chance = value.parse().unwrap_or_default();
+            }
+            ItemToken::ToolUse => {
+                self.tool_uses.push(String::from(value));
+            }
+            ItemToken::ContainerCapacity => {
+                self.container_capacity = value.parse().unwrap_or_default();
+            }
+            ItemToken::Hits => {
+                self.hits = value.parse().unwrap_or_default();
+            }
+            _ => {
+                self.tags.push(tag.clone());
+            }
+        }
+    }
+}
+
+impl Searchable for Item {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.push(format!("{:?}", self.item_type));
+        vec.extend(self.name.as_vec());
+        vec.push(self.adjective.clone());
+        vec.push(self.skill.clone());
+        vec.push(self.ranged_skill.clone());
+        vec.push(self.ammo_class.clone());
+        vec.extend(self.tool_uses.iter().cloned());
+        vec.extend(
+            self.attacks
+                .iter()
+                .map(|attack| String::from(attack.get_verb())),
+        );
+        vec.extend(self.tags.iter().map(|tag| format!("{tag:?}")));
+
+        clean_search_vec(vec.as_slice())
+    }
+}
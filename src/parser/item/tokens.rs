@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum ItemToken {
+    // # Common Tokens #
+    /// Arguments: singular name, plural name
+    ///
+    /// The name of the item, e.g. `[NAME:short sword:short swords]`.
+    Name,
+    /// Arguments: adjective
+    ///
+    /// An adjective which is always shown before the name of the item, e.g. "giant" for giant axe blades.
+    Adjective,
+    /// Arguments: text
+    ///
+    /// Text shown before the plural name of the item, e.g. "pair of" for trousers.
+    PrePlural,
+    /// Arguments: text
+    ///
+    /// Text shown in place of the material name when the item is made of a generic material, e.g. "leather" for a cloak.
+    MaterialPlaceholder,
+    /// Arguments: size
+    ///
+    /// The volume of the item. Affects weight and (for weapons) how hard the item hits.
+    Size,
+    /// Arguments: number of bars/boulders/etc.
+    ///
+    /// How much raw material is needed to make the item. Also affects the weight of the item.
+    MaterialSize,
+    /// Arguments: value
+    ///
+    /// The base value of the item.
+    Value,
+    /// Arguments: tile
+    ///
+    /// The tile used to show the item.
+    Tile,
+    /// Arguments: level
+    ///
+    /// The level of a prepared meal (`ITEM_FOOD`). This is the number of ingredients it uses.
+    Level,
+
+    // # Weapon Tokens #
+    /// Arguments: skill
+    ///
+    /// The skill used to wield the weapon in melee, e.g. `SWORD`.
+    Skill,
+    /// Arguments: creature size
+    ///
+    /// Creatures smaller than this size need two hands to wield the weapon.
+    TwoHanded,
+    /// Arguments: creature size
+    ///
+    /// Creatures smaller than this size can't wield the weapon at all.
+    MinimumSize,
+    /// Arguments: skill, ammo class
+    ///
+    /// Makes the weapon a ranged weapon which fires ammo of the given class, e.g. `[RANGED:CROSSBOW:BOLT]`.
+    Ranged,
+    /// Arguments: force
+    ///
+    /// How hard a ranged weapon shoots its ammo.
+    ShootForce,
+    /// Arguments: velocity
+    ///
+    /// The maximum speed of ammo shot by a ranged weapon.
+    ShootMaxVelocity,
+    /// Arguments: attack type, contact area, penetration size, verb (2nd person), verb (3rd person), noun, velocity multiplier
+    ///
+    /// Adds an attack to the weapon, e.g. `[ATTACK:EDGE:20000:4000:slash:slashes:NO_SUB:1250]`.
+    Attack,
+    /// Arguments: prepare time, recover time
+    ///
+    /// How long the previous attack takes to prepare and recover from.
+    AttackPrepareAndRecover,
+    /// The previous attack can be used in addition to other attacks.
+    AttackFlagIndependentMultiattack,
+    /// The previous attack can be used to catch an incoming projectile or attack.
+    AttackFlagCatchAttack,
+    /// The previous attack is a poor choice when attacking several times in a row.
+    AttackFlagBadMultiattack,
+
+    // # Ammo Tokens #
+    /// Arguments: ammo class
+    ///
+    /// The class of the ammo (or siege ammo), which matches the second argument of `RANGED` on the weapon.
+    Class,
+
+    // # Armor and Clothing Tokens #
+    /// Arguments: level
+    ///
+    /// The armor level of the item. Armor with level 0 is clothing.
+    ArmorLevel,
+    /// Arguments: percent
+    ///
+    /// The percentage of the body part covered by the item.
+    Coverage,
+    /// Arguments: layer
+    ///
+    /// The layer the item is worn on: `UNDER`, `OVER`, `ARMOR` or `COVER`.
+    Layer,
+    /// Arguments: size
+    ///
+    /// How much space the item takes up when worn on its layer.
+    LayerSize,
+    /// Arguments: size
+    ///
+    /// How much space the item allows for items worn beneath it.
+    LayerPermit,
+    /// Arguments: number of body parts or `MAX`
+    ///
+    /// How far the item extends up the body (for gloves and shoes) or from the lower body (for helms and pants).
+    UpStep,
+    /// Arguments: number of body parts or `MAX`
+    ///
+    /// How far the armor extends up the upper body.
+    UpperBodyStep,
+    /// Arguments: number of body parts or `MAX`
+    ///
+    /// How far the armor extends down the lower body.
+    LowerBodyStep,
+    /// Arguments: percent
+    ///
+    /// The chance for a shield to block an attack.
+    BlockChance,
+    /// The item is made from shaped material (e.g. a breastplate), so it can be made of metal, wood, bone or shell.
+    Shaped,
+    /// The item can be made from bars.
+    Barred,
+    /// The item can be made from scales.
+    Scaled,
+    /// The item can be made from leather.
+    Leather,
+    /// The item is chain armor, which can be made from metal.
+    Chain,
+    /// The item can be made from soft materials like cloth.
+    Soft,
+    /// The item can be made from hard materials like metal or wood.
+    Hard,
+    /// The item can be made from metal.
+    Metal,
+    /// Armor made of metal uses the armor levels of metal armor.
+    MetalArmorLevels,
+    /// The item uses the elasticity of its material for all layers when made of chain.
+    StructuralElasticityChainAll,
+    /// The item uses the elasticity of its material for all layers when made of metal chain.
+    StructuralElasticityChainMetal,
+    /// The item uses the elasticity of its material when made of woven thread.
+    StructuralElasticityWovenThread,
+
+    // # Tool, Toy and Trap Component Tokens #
+    /// Arguments: tool use
+    ///
+    /// A use of the tool, e.g. `MEAT_CARVING` or `LIQUID_CONTAINER`. Tools can have several uses.
+    ToolUse,
+    /// Arguments: capacity
+    ///
+    /// How much the tool can hold, if it is a container.
+    ContainerCapacity,
+    /// Arguments: number of hits
+    ///
+    /// How many times a trap component hits when the trap is triggered.
+    Hits,
+    /// The item can be made from hard materials (used by toys and tools).
+    HardMat,
+    /// The item can be made from wood.
+    Wood,
+    /// The tool can be made from metal.
+    MetalMat,
+    /// The tool can be made from wood.
+    WoodMat,
+    /// The tool can be made from stone.
+    StoneMat,
+    /// The tool can be made from glass.
+    GlassMat,
+    /// The tool can be made from ceramic.
+    CeramicMat,
+    /// The tool can be made from bone.
+    BoneMat,
+    /// The tool can be made from shell.
+    ShellMat,
+    /// The tool can be made from leather.
+    LeatherMat,
+    /// The tool can be made from soft materials like cloth.
+    SoftMat,
+    /// The tool can be made from sheets like paper or parchment.
+    SheetMat,
+    /// The item can be made from stone.
+    CanStone,
+    /// The trap component is a screw, so it can be used to make screw pumps.
+    IsScrew,
+    /// The trap component is a spike, so it can be used in spike traps.
+    IsSpike,
+    /// The tool is furniture, so it is placed like a building.
+    Furniture,
+    /// The tool is not finished when it is made, e.g. an instrument piece.
+    IncompleteItem,
+    /// The tool tile is shown inverted.
+    InvertedTile,
+    /// The tool can't be made with the standard job.
+    NoDefaultJob,
+    /// The item can't be decorated.
+    Unimprovable,
+    /// The item doesn't get the improvements it normally would.
+    NoDefaultImprovements,
+
+    /// An unknown token.
+    #[default]
+    Unknown,
+}
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum ArmorLayer {
+    /// Worn under other clothing, e.g. a shirt.
+    Under,
+    /// Worn over the under layer, e.g. a dress.
+    Over,
+    /// Armor, e.g. a breastplate.
+    Armor,
+    /// Worn over everything else, e.g. a cloak.
+    Cover,
+    /// The item has no layer.
+    #[default]
+    Unknown,
+}
+
+impl ArmorLayer {
+    pub fn is_default(&self) -> bool {
+        self == &ArmorLayer::Unknown
+    }
+}
+
+impl std::fmt::Display for ItemToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod name_generator;
+pub mod parts_of_speech;
+pub mod phf_table;
+pub mod symbol;
+pub mod tokens;
+pub mod translation;
+pub mod word;
@@ -0,0 +1,259 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{entity::raw::Entity, object_types::ObjectType, raws::RawObject};
+
+use super::{symbol::Symbol, tokens::WordToken, translation::Translation, word::Word};
+
+/// A name made by a `NameGenerator`, in the entity's language and in English, e.g. `Kogsakezum`
+/// and `Abbeybraid`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct GeneratedName {
+    /// The identifiers of the words the name is made from
+    words: Vec<String>,
+    native: String,
+    english: String,
+}
+
+impl GeneratedName {
+    pub fn get_words(&self) -> &[String] {
+        self.words.as_slice()
+    }
+    pub fn get_native(&self) -> &str {
+        &self.native
+    }
+    pub fn get_english(&self) -> &str {
+        &self.english
+    }
+}
+
+/// Generates compound names (a front word and a rear word) for entities, from the parsed `Word`,
+/// `Translation` and `Symbol` raws.
+///
+/// The words come from the symbols the entity selects for the kind of name (with `SELECT_SYMBOL`),
+/// minus the words in the symbols it culls (with `CULL_SYMBOL`). If the entity doesn't select any
+/// symbols for the kind of name, all words are used. The names are translated with the entity's
+/// `TRANSLATION`; a word which isn't in the translation keeps its English form.
+///
+/// The same raws, entity, kind of name and seed always give the same names.
+pub struct NameGenerator<'a> {
+    /// The words by identifier, sorted so the generated names don't depend on parse order
+    words: BTreeMap<&'a str, &'a Word>,
+    translations: HashMap<&'a str, &'a Translation>,
+    symbols: HashMap<&'a str, &'a Symbol>,
+}
+
+impl<'a> NameGenerator<'a> {
+    /// Create a name generator from the parsed raws. If there are several words, translations or
+    /// symbols with the same identifier, the last one parsed is used (so a mod can replace them).
+    pub fn new(all_raws: &'a [Box<dyn RawObject>]) -> Self {
+        let mut words = BTreeMap::new();
+        let mut translations = HashMap::new();
+        let mut symbols = HashMap::new();
+
+        for raw in all_raws {
+            match raw.get_type() {
+                ObjectType::Word => {
+                    if let Some(word) = raw.as_any().downcast_ref::<Word>() {
+                        words.insert(word.get_identifier(), word);
+                    }
+                }
+                ObjectType::Translation => {
+                    if let Some(translation) = raw.as_any().downcast_ref::<Translation>() {
+                        translations.insert(translation.get_identifier(), translation);
+                    }
+                }
+                ObjectType::Symbol => {
+                    if let Some(symbol) = raw.as_any().downcast_ref::<Symbol>() {
+                        symbols.insert(symbol.get_identifier(), symbol);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            words,
+            translations,
+            symbols,
+        }
+    }
+
+    /// Generate names for an entity.
+    ///
+    /// Arguments:
+    ///
+    /// * `entity`: The entity the names are for, which gives the symbols and the translation
+    /// * `name_type`: The kind of name, as used in `SELECT_SYMBOL`, e.g. `WALL` or `TEMPLE`
+    /// * `seed`: The seed for picking the words
+    /// * `count`: How many names to generate
+    ///
+    /// Returns:
+    ///
+    /// The names, which is empty if there aren't any words which can be used at the front and rear
+    /// of a compound word.
+    pub fn generate(
+        &self,
+        entity: &Entity,
+        name_type: &str,
+        seed: u64,
+        count: usize,
+    ) -> Vec<GeneratedName> {
+        let words = self.word_pool(entity, name_type);
+        let front_forms: Vec<(&Word, &str)> = words.iter().filter_map(|w| front_form(w)).collect();
+        let rear_forms: Vec<(&Word, &str)> = words.iter().filter_map(|w| rear_form(w)).collect();
+
+        if front_forms.is_empty() || rear_forms.is_empty() {
+            log::debug!(
+                "NameGenerator::generate: no compound words for {} {name_type}",
+                entity.get_identifier()
+            );
+            return Vec::new();
+        }
+
+        let translation = self.translations.get(entity.get_translation());
+        let translate = |word: &Word, english: &str| -> String {
+            translation
+                .and_then(|t| t.translate(word.get_identifier()))
+                .unwrap_or(english)
+                .to_string()
+        };
+
+        let mut rng = SplitMix64::new(seed);
+        (0..count)
+            .map(|_| {
+                let (front, front_english) = front_forms[rng.below(front_forms.len())];
+                // Avoid using the same word twice, unless it's the only one there is
+                let other_rear_forms: Vec<&(&Word, &str)> = rear_forms
+                    .iter()
+                    .filter(|(w, _)| w.get_identifier() != front.get_identifier())
+                    .collect();
+                let (rear, rear_english) = if other_rear_forms.is_empty() {
+                    rear_forms[rng.below(rear_forms.len())]
+                } else {
+                    *other_rear_forms[rng.below(other_rear_forms.len())]
+                };
+                GeneratedName {
+                    words: vec![
+                        String::from(front.get_identifier()),
+                        String::from(rear.get_identifier()),
+                    ],
+                    native: capitalize(&format!(
+                        "{}{}",
+                        translate(front, front_english),
+                        translate(rear, rear_english)
+                    )),
+                    english: capitalize(&format!("{front_english}{rear_english}")),
+                }
+            })
+            .collect()
+    }
+
+    /// The words an entity can use for the kind of name, sorted by identifier.
+    fn word_pool(&self, entity: &Entity, name_type: &str) -> Vec<&'a Word> {
+        let selected = self.symbol_words(entity.get_select_symbols(), name_type);
+        let culled = self.symbol_words(entity.get_cull_symbols(), name_type);
+
+        self.words
+            .iter()
+            .filter(|(identifier, _)| selected.is_empty() || selected.contains(*identifier))
+            .filter(|(identifier, _)| !culled.contains(*identifier))
+            .map(|(_, word)| *word)
+            .collect()
+    }
+
+    /// The words in the symbols which apply to the kind of name. A symbol applies if it is for
+    /// `ALL` names or for this kind of name, or for the `REMAINING` names when none are for this
+    /// kind of name.
+    fn symbol_words(&self, entries: &[(String, String)], name_type: &str) -> HashSet<&'a str> {
+        let has_name_type = entries.iter().any(|(n, _)| n == name_type);
+        entries
+            .iter()
+            .filter(|(n, _)| n == name_type || n == "ALL" || (!has_name_type && n == "REMAINING"))
+            .filter_map(|(_, symbol)| self.symbols.get(symbol.as_str()))
+            .flat_map(|symbol| symbol.get_words().iter().map(String::as_str))
+            .collect()
+    }
+}
+
+/// The English form of the word to use at the front of a compound word, if it can be used there.
+fn front_form(word: &Word) -> Option<(&Word, &str)> {
+    if let Some(prefix) = word
+        .get_prefix()
+        .filter(|p| p.get_usages().contains(&WordToken::FrontCompoundPrefix))
+    {
+        return Some((word, prefix.get_prefix()));
+    }
+    if let Some(noun) = word.get_noun() {
+        if noun
+            .get_usages()
+            .contains(&WordToken::FrontCompoundNounSingular)
+        {
+            return Some((word, noun.get_singular()));
+        }
+        if noun
+            .get_usages()
+            .contains(&WordToken::FrontCompoundNounPlural)
+        {
+            return Some((word, noun.get_plural()));
+        }
+    }
+    word.get_adjective()
+        .filter(|a| a.get_usages().contains(&WordToken::FrontCompoundAdjective))
+        .map(|a| (word, a.get_form()))
+}
+
+/// The English form of the word to use at the rear of a compound word, if it can be used there.
+fn rear_form(word: &Word) -> Option<(&Word, &str)> {
+    if let Some(noun) = word.get_noun() {
+        if noun
+            .get_usages()
+            .contains(&WordToken::RearCompoundNounSingular)
+        {
+            return Some((word, noun.get_singular()));
+        }
+        if noun
+            .get_usages()
+            .contains(&WordToken::RearCompoundNounPlural)
+        {
+            return Some((word, noun.get_plural()));
+        }
+    }
+    word.get_adjective()
+        .filter(|a| a.get_usages().contains(&WordToken::RearCompoundAdjective))
+        .map(|a| (word, a.get_form()))
+}
+
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// A small seeded random number generator (`SplitMix64`), so that names are reproducible without
+/// depending on a random number crate.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    /// A number from 0 up to (but not including) `n`.
+    fn below(&mut self, n: usize) -> usize {
+        let n = u64::try_from(n).unwrap_or(u64::MAX);
+        usize::try_from(self.next_u64() % n).unwrap_or_default()
+    }
+}
@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::serializer_helper;
+
+use super::tokens::WordToken;
+
+/// The noun form of a word, from a token like `[NOUN:abbey:abbeys]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Noun {
+    singular: String,
+    plural: String,
+    /// Where the noun can be used in names, e.g. `FrontCompoundNounSingular`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    usages: Vec<WordToken>,
+}
+
+impl Noun {
+    pub fn from_value(value: &str) -> Self {
+        let mut split = value.split(':');
+        Self {
+            singular: String::from(split.next().unwrap_or_default()),
+            plural: String::from(split.next().unwrap_or_default()),
+            usages: Vec::new(),
+        }
+    }
+    pub fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    pub fn get_plural(&self) -> &str {
+        &self.plural
+    }
+    pub fn get_usages(&self) -> &[WordToken] {
+        self.usages.as_slice()
+    }
+    pub fn add_usage(&mut self, usage: &WordToken) {
+        self.usages.push(usage.clone());
+    }
+}
+
+/// The verb forms of a word, from a token like `[VERB:abbey:abbeys:abbeyed:abbeyed:abbeying]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Verb {
+    present_first: String,
+    present_third: String,
+    preterite: String,
+    past_participle: String,
+    present_participle: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    usages: Vec<WordToken>,
+}
+
+impl Verb {
+    pub fn from_value(value: &str) -> Self {
+        let mut split = value.split(':');
+        Self {
+            present_first: String::from(split.next().unwrap_or_default()),
+            present_third: String::from(split.next().unwrap_or_default()),
+            preterite: String::from(split.next().unwrap_or_default()),
+            past_participle: String::from(split.next().unwrap_or_default()),
+            present_participle: String::from(split.next().unwrap_or_default()),
+            usages: Vec::new(),
+        }
+    }
+    pub fn get_present_first(&self) -> &str {
+        &self.present_first
+    }
+    pub fn get_present_third(&self) -> &str {
+        &self.present_third
+    }
+    pub fn get_preterite(&self) -> &str {
+        &self.preterite
+    }
+    pub fn get_past_participle(&self) -> &str {
+        &self.past_participle
+    }
+    pub fn get_present_participle(&self) -> &str {
+        &self.present_participle
+    }
+    pub fn get_usages(&self) -> &[WordToken] {
+        self.usages.as_slice()
+    }
+    pub fn add_usage(&mut self, usage: &WordToken) {
+        self.usages.push(usage.clone());
+    }
+}
+
+/// The adjective form of a word, from a token like `[ADJ:peaceful]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Adjective {
+    form: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    distance: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    usages: Vec<WordToken>,
+}
+
+impl Adjective {
+    pub fn from_value(value: &str) -> Self {
+        Self {
+            form: String::from(value),
+            ..Self::default()
+        }
+    }
+    pub fn get_form(&self) -> &str {
+        &self.form
+    }
+    pub fn get_distance(&self) -> u32 {
+        self.distance
+    }
+    pub fn set_distance(&mut self, distance: u32) {
+        self.distance = distance;
+    }
+    pub fn get_usages(&self) -> &[WordToken] {
+        self.usages.as_slice()
+    }
+    pub fn add_usage(&mut self, usage: &WordToken) {
+        self.usages.push(usage.clone());
+    }
+}
+
+/// The prefix form of a word, from a token like `[PREFIX:abbey]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Prefix {
+    prefix: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    usages: Vec<WordToken>,
+}
+
+impl Prefix {
+    pub fn from_value(value: &str) -> Self {
+        Self {
+            prefix: String::from(value),
+            usages: Vec::new(),
+        }
+    }
+    pub fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+    pub fn get_usages(&self) -> &[WordToken] {
+        self.usages.as_slice()
+    }
+    pub fn add_usage(&mut self, usage: &WordToken) {
+        self.usages.push(usage.clone());
+    }
+}
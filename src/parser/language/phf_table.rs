@@ -0,0 +1,23 @@
+use super::tokens::WordToken;
+
+pub static WORD_TOKENS: phf::Map<&'static str, WordToken> = phf::phf_map! {
+    "NOUN" => WordToken::Noun,
+    "VERB" => WordToken::Verb,
+    "ADJ" => WordToken::Adjective,
+    "PREFIX" => WordToken::Prefix,
+    "ADJ_DIST" => WordToken::AdjectiveDistance,
+    "FRONT_COMPOUND_NOUN_SING" => WordToken::FrontCompoundNounSingular,
+    "FRONT_COMPOUND_NOUN_PLUR" => WordToken::FrontCompoundNounPlural,
+    "REAR_COMPOUND_NOUN_SING" => WordToken::RearCompoundNounSingular,
+    "REAR_COMPOUND_NOUN_PLUR" => WordToken::RearCompoundNounPlural,
+    "THE_NOUN_SING" => WordToken::TheNounSingular,
+    "THE_NOUN_PLUR" => WordToken::TheNounPlural,
+    "OF_NOUN_SING" => WordToken::OfNounSingular,
+    "OF_NOUN_PLUR" => WordToken::OfNounPlural,
+    "FRONT_COMPOUND_ADJ" => WordToken::FrontCompoundAdjective,
+    "REAR_COMPOUND_ADJ" => WordToken::RearCompoundAdjective,
+    "THE_COMPOUND_ADJ" => WordToken::TheCompoundAdjective,
+    "FRONT_COMPOUND_PREFIX" => WordToken::FrontCompoundPrefix,
+    "THE_COMPOUND_PREFIX" => WordToken::TheCompoundPrefix,
+    "STANDARD_VERB" => WordToken::StandardVerb,
+};
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+/// A language symbol from an `[OBJECT:LANGUAGE]` raw file, e.g. `[SYMBOL:PEACE]`, which groups
+/// words with `[S_WORD:...]` tokens.
+///
+/// Entities pick the words used in their names by selecting and culling symbols with
+/// `[SELECT_SYMBOL:ALL:PEACE]` and `[CULL_SYMBOL:ALL:DOMESTIC]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Symbol {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    /// The identifiers of the words in the symbol.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    words: Vec<String>,
+}
+
+impl Symbol {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Symbol),
+            ..Self::default()
+        }
+    }
+    pub fn get_words(&self) -> &[String] {
+        self.words.as_slice()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for Symbol {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::Symbol
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        if key != "S_WORD" {
+            log::debug!(
+                "Symbol::parse_tag: Unknown symbol tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown symbol tag {key}"),
+            );
+            return;
+        }
+
+        self.words.push(String::from(value));
+    }
+}
+
+impl Searchable for Symbol {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.extend(self.words.iter().cloned());
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum WordToken {
+    /// Arguments: singular, plural
+    ///
+    /// The noun form of the word. The usage tokens which follow it apply to the noun.
+    Noun,
+    /// Arguments: present (first person), present (third person), preterite, past participle,
+    /// present participle
+    ///
+    /// The verb forms of the word.
+    Verb,
+    /// Arguments: adjective
+    ///
+    /// The adjective form of the word. The usage tokens which follow it apply to the adjective.
+    Adjective,
+    /// Arguments: prefix
+    ///
+    /// The prefix form of the word. The usage tokens which follow it apply to the prefix.
+    Prefix,
+    /// Arguments: distance
+    ///
+    /// How far from the noun the adjective is placed when there are several adjectives.
+    AdjectiveDistance,
+    /// The singular noun can be used at the front of a compound word.
+    FrontCompoundNounSingular,
+    /// The plural noun can be used at the front of a compound word.
+    FrontCompoundNounPlural,
+    /// The singular noun can be used at the rear of a compound word.
+    RearCompoundNounSingular,
+    /// The plural noun can be used at the rear of a compound word.
+    RearCompoundNounPlural,
+    /// The singular noun can be used in "the X".
+    TheNounSingular,
+    /// The plural noun can be used in "the X".
+    TheNounPlural,
+    /// The singular noun can be used in "of X".
+    OfNounSingular,
+    /// The plural noun can be used in "of X".
+    OfNounPlural,
+    /// The adjective can be used at the front of a compound word.
+    FrontCompoundAdjective,
+    /// The adjective can be used at the rear of a compound word.
+    RearCompoundAdjective,
+    /// The adjective can be used in "the X".
+    TheCompoundAdjective,
+    /// The prefix can be used at the front of a compound word.
+    FrontCompoundPrefix,
+    /// The prefix can be used in "the X".
+    TheCompoundPrefix,
+    /// The verb can be used in names.
+    StandardVerb,
+    #[default]
+    Unknown,
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+/// A translation from an `[OBJECT:LANGUAGE]` raw file, e.g. `[TRANSLATION:DWARF]`, which maps
+/// words to the language with `[T_WORD:ABBEY:kogsak]` tokens.
+///
+/// Entities pick their language with `[TRANSLATION:DWARF]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Translation {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    /// The translated form of each word, as (word identifier, translation).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    words: Vec<(String, String)>,
+}
+
+impl Translation {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Translation),
+            ..Self::default()
+        }
+    }
+    pub fn get_words(&self) -> &[(String, String)] {
+        self.words.as_slice()
+    }
+    /// Returns the translated form of the word with the given identifier, if it has one.
+    pub fn translate(&self, word: &str) -> Option<&str> {
+        self.words
+            .iter()
+            .rev()
+            .find(|(w, _)| w == word)
+            .map(|(_, translation)| translation.as_str())
+    }
+}
+
+#[typetag::serde]
+impl RawObject for Translation {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::Translation
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        if key != "T_WORD" {
+            log::debug!(
+                "Translation::parse_tag: Unknown translation tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown translation tag {key}"),
+            );
+            return;
+        }
+
+        let (word, translation) = value.split_once(':').unwrap_or((value, ""));
+        self.words
+            .push((String::from(word), String::from(translation)));
+    }
+}
+
+impl Searchable for Translation {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.extend(
+            self.words
+                .iter()
+                .map(|(_, translation)| translation.clone()),
+        );
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{
+    parts_of_speech::{Adjective, Noun, Prefix, Verb},
+    phf_table::WORD_TOKENS,
+    tokens::WordToken,
+};
+
+/// A word from an `[OBJECT:LANGUAGE]` raw file, e.g. `[WORD:ABBEY]`, with its English forms.
+///
+/// The word's identifier is what translations (and symbols) refer to.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Word {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    noun: Option<Noun>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verb: Option<Verb>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    adjective: Option<Adjective>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prefix: Option<Prefix>,
+}
+
+impl Word {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Word),
+            ..Self::default()
+        }
+    }
+    pub fn get_noun(&self) -> Option<&Noun> {
+        self.noun.as_ref()
+    }
+    pub fn get_verb(&self) -> Option<&Verb> {
+        self.verb.as_ref()
+    }
+    pub fn get_adjective(&self) -> Option<&Adjective> {
+        self.adjective.as_ref()
+    }
+    pub fn get_prefix(&self) -> Option<&Prefix> {
+        self.prefix.as_ref()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for Word {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::Word
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = WORD_TOKENS.get(key) else {
+            log::debug!(
+                "Word::parse_tag: Unknown word tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(DiagnosticSeverity::Info, &format!("Unknown word tag {key}"));
+            return;
+        };
+
+        match tag {
+            WordToken::Noun => {
+                self.noun = Some(Noun::from_value(value));
+            }
+            WordToken::Verb => {
+                self.verb = Some(Verb::from_value(value));
+            }
+            WordToken::Adjective => {
+                self.adjective = Some(Adjective::from_value(value));
+            }
+            WordToken::Prefix => {
+                self.prefix = Some(Prefix::from_value(value));
+            }
+            WordToken::AdjectiveDistance => {
+                if let Some(adjective) = self.adjective.as_mut() {
+                    adjective.set_distance(value.parse().unwrap_or_default());
+                }
+            }
+            WordToken::FrontCompoundNounSingular
+            | WordToken::FrontCompoundNounPlural
+            | WordToken::RearCompoundNounSingular
+            | WordToken::RearCompoundNounPlural
+            | WordToken::TheNounSingular
+            | WordToken::TheNounPlural
+            | WordToken::OfNounSingular
+            | WordToken::OfNounPlural => {
+                if let Some(noun) = self.noun.as_mut() {
+                    noun.add_usage(tag);
+                }
+            }
+            WordToken::FrontCompoundAdjective
+            | WordToken::RearCompoundAdjective
+            | WordToken::TheCompoundAdjective => {
+                if let Some(adjective) = self.adjective.as_mut() {
+                    adjective.add_usage(tag);
+                }
+            }
+            WordToken::FrontCompoundPrefix | WordToken::TheCompoundPrefix => {
+                if let Some(prefix) = self.prefix.as_mut() {
+                    prefix.add_usage(tag);
+                }
+            }
+            WordToken::StandardVerb => {
+                if let Some(verb) = self.verb.as_mut() {
+                    verb.add_usage(tag);
+                }
+            }
+            WordToken::Unknown => {}
+        }
+    }
+}
+
+impl Searchable for Word {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        if let Some(noun) = &self.noun {
+            vec.push(String::from(noun.get_singular()));
+            vec.push(String::from(noun.get_plural()));
+        }
+        if let Some(verb) = &self.verb {
+            vec.push(String::from(verb.get_present_first()));
+        }
+        if let Some(adjective) = &self.adjective {
+            vec.push(String::from(adjective.get_form()));
+        }
+
+        clean_search_vec(vec.as_slice())
+    }
+}
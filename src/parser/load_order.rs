@@ -0,0 +1,122 @@
+use super::{
+    diagnostics::{self, DiagnosticSeverity},
+    module_info_file::ModuleInfoFile,
+};
+
+/// Work out the order Dwarf Fortress loads the modules in, and return the indices of `modules` in
+/// that order.
+///
+/// With no `explicit_order`, the modules keep the order they are given in (which should be the
+/// order they were found in: vanilla, then installed mods, then mods), except that each module is
+/// moved after the modules in its `REQUIRES_ID_BEFORE_ME` tokens and before the modules in its
+/// `REQUIRES_ID_AFTER_ME` tokens. Requirements on modules which aren't in `modules` are ignored. If
+/// the requirements form a cycle, the modules in the cycle are loaded in the order they were given
+/// in, and a warning is reported.
+///
+/// With an `explicit_order` (a list of module identifiers, e.g. from a world's list of mods), the
+/// modules in the list are loaded in that order, followed by any other modules in the order worked
+/// out as above.
+///
+/// Arguments:
+///
+/// * `modules`: The info.txt files of the modules, in the order they were found
+/// * `explicit_order`: Module identifiers in the order they should be loaded, or empty
+///
+/// Returns:
+///
+/// The indices of `modules`, in load order.
+pub fn compute_load_order(modules: &[ModuleInfoFile], explicit_order: &[String]) -> Vec<usize> {
+    let required_order = order_by_requirements(modules);
+
+    if explicit_order.is_empty() {
+        return required_order;
+    }
+
+    let mut load_order: Vec<usize> = Vec::with_capacity(modules.len());
+    for identifier in explicit_order {
+        for &index in &required_order {
+            if &modules[index].get_identifier() == identifier && !load_order.contains(&index) {
+                load_order.push(index);
+            }
+        }
+    }
+    let unlisted: Vec<usize> = required_order
+        .into_iter()
+        .filter(|i| !load_order.contains(i))
+        .collect();
+    if !unlisted.is_empty() {
+        log::debug!(
+            "compute_load_order: {} modules aren't in the explicit load order, loading them last",
+            unlisted.len()
+        );
+    }
+    load_order.extend(unlisted);
+
+    load_order
+}
+
+/// Order the modules by their `REQUIRES_ID_BEFORE_ME` and `REQUIRES_ID_AFTER_ME` tokens, keeping
+/// the given order wherever the tokens allow it.
+fn order_by_requirements(modules: &[ModuleInfoFile]) -> Vec<usize> {
+    // loaded_before[i] is the modules which have to be loaded before module i
+    let mut loaded_before: Vec<Vec<usize>> = vec![Vec::new(); modules.len()];
+    let indices_of = |identifier: &str| -> Vec<usize> {
+        modules
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| m.get_identifier() == identifier)
+            .map(|(i, _)| i)
+            .collect()
+    };
+
+    for (index, module) in modules.iter().enumerate() {
+        for identifier in module.get_requires_ids_before() {
+            loaded_before[index].extend(indices_of(identifier));
+        }
+        for identifier in module.get_requires_ids_after() {
+            for later in indices_of(identifier) {
+                loaded_before[later].push(index);
+            }
+        }
+    }
+
+    let mut load_order: Vec<usize> = Vec::with_capacity(modules.len());
+    let mut loaded = vec![false; modules.len()];
+
+    while load_order.len() < modules.len() {
+        // Load the first module (in the given order) which has nothing left to wait for
+        let next = (0..modules.len()).find(|&i| {
+            !loaded[i]
+                && loaded_before[i]
+                    .iter()
+                    .all(|&before| before == i || loaded[before])
+        });
+
+        if let Some(index) = next {
+            loaded[index] = true;
+            load_order.push(index);
+            continue;
+        }
+
+        // Everything left is waiting on something else which is left, so there is a cycle
+        let remaining: Vec<usize> = (0..modules.len()).filter(|&i| !loaded[i]).collect();
+        let identifiers: Vec<String> = remaining
+            .iter()
+            .map(|&i| modules[i].get_identifier())
+            .collect();
+        log::warn!(
+            "compute_load_order: circular load order requirements between {}",
+            identifiers.join(", ")
+        );
+        diagnostics::report(
+            DiagnosticSeverity::Warning,
+            &format!(
+                "Circular load order requirements between modules {}",
+                identifiers.join(", ")
+            ),
+        );
+        load_order.extend(remaining);
+    }
+
+    load_order
+}
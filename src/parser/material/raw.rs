@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::parser::{
     color::Color,
     creature_effect::phf_table::CREATURE_EFFECT_TOKENS,
+    diagnostics::{self, DiagnosticSeverity},
     material::phf_table::MATERIAL_PROPERTY_TOKENS,
     material_mechanics::MaterialMechanics,
     names::StateName,
@@ -11,6 +12,7 @@ use crate::parser::{
     syndrome::{phf_table::SYNDROME_TOKEN, raw::Syndrome},
     temperature::Temperatures,
     tile::Tile,
+    writer::{token_key, write_tags, RawWriter, ToRawText},
 };
 
 use super::{
@@ -19,10 +21,11 @@ use super::{
 };
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Material {
     /// The type of the material is also the trigger to start tracking a material
     #[serde(skip_serializing_if = "MaterialType::is_default")]
@@ -105,13 +108,42 @@ pub struct Material {
     item_symbol: String,
 }
 
-impl Material {
-    pub fn new() -> Material {
-        Material {
+impl Default for Material {
+    fn default() -> Self {
+        Self {
+            material_type: MaterialType::default(),
+            name: String::new(),
+            fuel_type: FuelType::default(),
+            creature_identifier: String::new(),
+            plant_identifier: String::new(),
+            is_local_material: false,
+            reagent_identifier: String::new(),
+            reaction_product_identifier: String::new(),
+            template_identifier: String::new(),
+            usage: Vec::new(),
             value: 1,
-            ..Material::default()
+            color: Color::default(),
+            state_names: StateName::default(),
+            state_adjectives: StateName::default(),
+            state_colors: StateName::default(),
+            temperatures: Temperatures::default(),
+            properties: Vec::new(),
+            syndromes: Vec::new(),
+            mechanical_properties: MaterialMechanics::default(),
+            liquid_density: 0,
+            molar_mass: 0,
+            build_color: Color::default(),
+            display_color: Color::default(),
+            tile: Tile::default(),
+            item_symbol: String::new(),
         }
     }
+}
+
+impl Material {
+    pub fn new() -> Material {
+        Material::default()
+    }
     /// This may not be correct. This should be for `\[USE_MATERIAL:XX:XX\]` but couldn't find an example for Plant.
     pub fn use_material_from_value(value: &str) -> Material {
         // Start defining a new material with a name and properties of another local material
@@ -141,6 +173,229 @@ impl Material {
             ..Material::new()
         }
     }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_material_type(&self) -> &MaterialType {
+        &self.material_type
+    }
+    pub fn is_local_material(&self) -> bool {
+        self.is_local_material
+    }
+    pub fn get_state_colors(&self) -> &StateName {
+        &self.state_colors
+    }
+    /// Returns the identifier of the material template this material uses, or an empty string if it
+    /// doesn't use one.
+    pub fn get_template_identifier(&self) -> &str {
+        &self.template_identifier
+    }
+    /// Apply a material template to this material. The properties of the template are used as the
+    /// base, and any properties set on this material are applied on top of them.
+    ///
+    /// Arguments:
+    ///
+    /// * `template`: The material from the `MaterialTemplate` this material uses
+    pub fn apply_material_template(&mut self, template: &Material) {
+        let mut combined_material = template.clone();
+        combined_material.overwrite_material(self);
+        *self = combined_material;
+    }
+    /// Overwrite the properties of self with any properties that are set in other.
+    #[allow(clippy::too_many_lines)]
+    pub fn overwrite_material(&mut self, other: &Material) {
+        // Include any usage tags, properties and syndromes from other that aren't in self
+        for usage in &other.usage {
+            if !self.usage.contains(usage) {
+                self.usage.push(usage.clone());
+            }
+        }
+        for property in &other.properties {
+            if !self.properties.contains(property) {
+                self.properties.push(property.clone());
+            }
+        }
+        self.syndromes.extend(other.syndromes.iter().cloned());
+
+        // For any of the other's values that are not default, overwrite self's values
+        if !other.material_type.is_default() {
+            self.material_type.clone_from(&other.material_type);
+        }
+        if !other.name.is_empty() {
+            self.name.clone_from(&other.name);
+        }
+        if !other.fuel_type.is_default() {
+            self.fuel_type.clone_from(&other.fuel_type);
+        }
+        if !other.creature_identifier.is_empty() {
+            self.creature_identifier
+                .clone_from(&other.creature_identifier);
+        }
+        if !other.plant_identifier.is_empty() {
+            self.plant_identifier.clone_from(&other.plant_identifier);
+        }
+        if other.is_local_material {
+            self.is_local_material = true;
+        }
+        if !other.reagent_identifier.is_empty() {
+            self.reagent_identifier
+                .clone_from(&other.reagent_identifier);
+        }
+        if !other.reaction_product_identifier.is_empty() {
+            self.reaction_product_identifier
+                .clone_from(&other.reaction_product_identifier);
+        }
+        if !other.template_identifier.is_empty() {
+            self.template_identifier
+                .clone_from(&other.template_identifier);
+        }
+        // A value of 1 is the default for a material (and 0 is unset)
+        if other.value > 1 {
+            self.value = other.value;
+        }
+        if !other.color.is_default() {
+            self.color.clone_from(&other.color);
+        }
+        self.state_names.overwrite_state_name(&other.state_names);
+        self.state_adjectives
+            .overwrite_state_name(&other.state_adjectives);
+        self.state_colors.overwrite_state_name(&other.state_colors);
+        self.temperatures
+            .overwrite_temperatures(&other.temperatures);
+        self.mechanical_properties
+            .overwrite_mechanics(&other.mechanical_properties);
+        if other.liquid_density != 0 {
+            self.liquid_density = other.liquid_density;
+        }
+        if other.molar_mass != 0 {
+            self.molar_mass = other.molar_mass;
+        }
+        if !other.build_color.is_default() {
+            self.build_color.clone_from(&other.build_color);
+        }
+        if !other.display_color.is_default() {
+            self.display_color.clone_from(&other.display_color);
+        }
+        if !other.tile.is_default() {
+            self.tile.clone_from(&other.tile);
+        }
+        if !other.item_symbol.is_empty() {
+            self.item_symbol.clone_from(&other.item_symbol);
+        }
+    }
+    /// Returns the material as a material reference, e.g. `CREATURE_MAT:DWARF:SKIN` or
+    /// `LOCAL_PLANT_MAT:LEAF`. This is the reverse of `from_value`.
+    pub fn to_value(&self) -> String {
+        let material_type =
+            token_key(&MATERIAL_TYPE_TOKENS, &self.material_type).unwrap_or_default();
+        let parts = match self.material_type {
+            MaterialType::Coal => {
+                vec![token_key(&FUEL_TYPE_TOKENS, &self.fuel_type).unwrap_or_default()]
+            }
+            MaterialType::CreatureMaterial => {
+                vec![self.creature_identifier.as_str(), self.name.as_str()]
+            }
+            MaterialType::PlantMaterial => vec![self.plant_identifier.as_str(), self.name.as_str()],
+            MaterialType::GetMaterialFromReagent => vec![
+                self.reagent_identifier.as_str(),
+                self.reaction_product_identifier.as_str(),
+            ],
+            _ => vec![self.name.as_str()],
+        };
+
+        let mut value = String::from(material_type);
+        for part in parts.into_iter().filter(|part| !part.is_empty()) {
+            value.push(':');
+            value.push_str(part);
+        }
+        value
+    }
+    /// Write the properties of the material (everything but its `USE_MATERIAL_TEMPLATE`), one token
+    /// per line. Only the syndrome tokens the library keeps are written for each syndrome; their
+    /// creature effects are not written.
+    pub fn write_properties(&self, writer: &mut RawWriter) {
+        let tokens = &MATERIAL_PROPERTY_TOKENS;
+
+        let states = [
+            (MaterialProperty::StateName, &self.state_names),
+            (MaterialProperty::StateAdjective, &self.state_adjectives),
+            (MaterialProperty::StateColor, &self.state_colors),
+        ];
+        for (tag, state_name) in states {
+            let values = [
+                ("SOLID", state_name.get_solid()),
+                ("LIQUID", state_name.get_liquid()),
+                ("GAS", state_name.get_gas()),
+            ];
+            for (state, value) in values {
+                if !value.is_empty() {
+                    writer.token_for(tokens, &tag, format!("{state}:{value}"));
+                }
+            }
+        }
+        if self.value != 1 {
+            writer.token_for(tokens, &MaterialProperty::MaterialValue, self.value);
+        }
+        let colors = [
+            (MaterialProperty::BasicColor, &self.color),
+            (MaterialProperty::BuildColor, &self.build_color),
+            (MaterialProperty::DisplayColor, &self.display_color),
+        ];
+        for (tag, color) in colors {
+            if !color.is_default() {
+                writer.token_for(tokens, &tag, color.to_value());
+            }
+        }
+        for (tag, value) in self.temperatures.as_properties() {
+            writer.token_for(tokens, &tag, value);
+        }
+        for (tag, value) in self.mechanical_properties.as_properties() {
+            writer.token_for(tokens, &tag, value);
+        }
+        if self.liquid_density != 0 {
+            writer.token_for(
+                tokens,
+                &MaterialProperty::LiquidDensity,
+                self.liquid_density,
+            );
+        }
+        if self.molar_mass != 0 {
+            writer.token_for(tokens, &MaterialProperty::MolarMass, self.molar_mass);
+        }
+        if !self.tile.get_character().is_empty() {
+            writer.token_for(tokens, &MaterialProperty::Tile, self.tile.get_character());
+        }
+        if !self.tile.get_color().is_default() {
+            writer.token_for(
+                tokens,
+                &MaterialProperty::TileColor,
+                self.tile.get_color().to_value(),
+            );
+        }
+        if !self.item_symbol.is_empty() {
+            writer.token_for(tokens, &MaterialProperty::ItemSymbol, &self.item_symbol);
+        }
+        if !self.reaction_product_identifier.is_empty()
+            && self.material_type != MaterialType::GetMaterialFromReagent
+        {
+            writer.token_for(
+                tokens,
+                &MaterialProperty::MaterialReactionProduct,
+                &self.reaction_product_identifier,
+            );
+        }
+        // The other properties are kept as they were in the raws, e.g. `REACTION_CLASS:FAT`
+        for property in &self.properties {
+            let (key, value) = property.split_once(':').unwrap_or((property, ""));
+            writer.token(key, value);
+        }
+
+        write_tags(writer, &MATERIAL_USAGE_TOKENS, &self.usage);
+
+        for syndrome in &self.syndromes {
+            syndrome.write_raw_text(writer);
+        }
+    }
     pub fn basic_material_from_value(value: &str) -> Material {
         Material::from_value(value)
     }
@@ -387,6 +642,26 @@ impl Material {
             "Material::parse_tag() was provided a key that was not recognized: {}",
             key
         );
+        diagnostics::report(
+            DiagnosticSeverity::Warning,
+            &format!("Unknown material tag {key}"),
+        );
+    }
+}
+
+impl ToRawText for Material {
+    /// Write the `[USE_MATERIAL_TEMPLATE:...]` token (if the material uses a template) and the
+    /// properties of the material. The token which starts the material (which depends on where it
+    /// is defined) is not written.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        if !self.template_identifier.is_empty() {
+            writer.token_for(
+                &MATERIAL_PROPERTY_TOKENS,
+                &MaterialProperty::UseMaterialTemplate,
+                &self.template_identifier,
+            );
+        }
+        self.write_properties(writer);
     }
 }
 
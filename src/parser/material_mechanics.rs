@@ -1,11 +1,16 @@
-use crate::parser::{material::tokens::MaterialProperty, serializer_helper};
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    material::tokens::MaterialProperty,
+    serializer_helper,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct MaterialMechanics {
     #[serde(skip_serializing_if = "MechanicalProperties::is_empty")]
     impact: MechanicalProperties,
@@ -28,10 +33,11 @@ pub struct MaterialMechanics {
 }
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct MechanicalProperties {
     #[serde(rename = "yield")]
     yield_stress: i32,
@@ -55,6 +61,18 @@ impl MechanicalProperties {
     pub fn set_elasticity(&mut self, value: i32) {
         self.elasticity = value;
     }
+    /// Overwrite any values in self with the values which are set in other.
+    pub fn overwrite_properties(&mut self, other: &MechanicalProperties) {
+        if other.yield_stress != 0 {
+            self.yield_stress = other.yield_stress;
+        }
+        if other.fracture != 0 {
+            self.fracture = other.fracture;
+        }
+        if other.elasticity != 0 {
+            self.elasticity = other.elasticity;
+        }
+    }
 }
 
 impl MaterialMechanics {
@@ -69,6 +87,85 @@ impl MaterialMechanics {
             && self.shear.is_empty()
             && self.bending.is_empty()
     }
+    /// Overwrite any values in self with the values which are set in other.
+    pub fn overwrite_mechanics(&mut self, other: &MaterialMechanics) {
+        self.impact.overwrite_properties(&other.impact);
+        self.compressive.overwrite_properties(&other.compressive);
+        self.tensile.overwrite_properties(&other.tensile);
+        self.torsion.overwrite_properties(&other.torsion);
+        self.shear.overwrite_properties(&other.shear);
+        self.bending.overwrite_properties(&other.bending);
+        if other.max_edge != 0 {
+            self.max_edge = other.max_edge;
+        }
+        if other.solid_density != 0 {
+            self.solid_density = other.solid_density;
+        }
+    }
+    /// Returns the mechanical properties which are set, with the material property token for each.
+    pub fn as_properties(&self) -> Vec<(MaterialProperty, i32)> {
+        let properties = [
+            (
+                [
+                    MaterialProperty::ImpactYield,
+                    MaterialProperty::ImpactFracture,
+                    MaterialProperty::ImpactElasticity,
+                ],
+                &self.impact,
+            ),
+            (
+                [
+                    MaterialProperty::CompressiveYield,
+                    MaterialProperty::CompressiveFracture,
+                    MaterialProperty::CompressiveElasticity,
+                ],
+                &self.compressive,
+            ),
+            (
+                [
+                    MaterialProperty::TensileYield,
+                    MaterialProperty::TensileFracture,
+                    MaterialProperty::TensileElasticity,
+                ],
+                &self.tensile,
+            ),
+            (
+                [
+                    MaterialProperty::TorsionYield,
+                    MaterialProperty::TorsionFracture,
+                    MaterialProperty::TorsionElasticity,
+                ],
+                &self.torsion,
+            ),
+            (
+                [
+                    MaterialProperty::ShearYield,
+                    MaterialProperty::ShearFracture,
+                    MaterialProperty::ShearElasticity,
+                ],
+                &self.shear,
+            ),
+            (
+                [
+                    MaterialProperty::BendingYield,
+                    MaterialProperty::BendingFracture,
+                    MaterialProperty::BendingElasticity,
+                ],
+                &self.bending,
+            ),
+        ];
+
+        let mut vec = Vec::new();
+        for ([yield_stress, fracture, elasticity], mechanics) in properties {
+            vec.push((yield_stress, mechanics.yield_stress));
+            vec.push((fracture, mechanics.fracture));
+            vec.push((elasticity, mechanics.elasticity));
+        }
+        vec.push((MaterialProperty::MaxEdge, self.max_edge));
+        vec.push((MaterialProperty::SolidDensity, self.solid_density));
+        vec.retain(|(_, value)| *value != 0);
+        vec
+    }
     pub fn parse_tag(&mut self, key: &MaterialProperty, value: &str) {
         match key {
             MaterialProperty::ImpactYield => {
@@ -133,6 +230,10 @@ impl MaterialMechanics {
 
             _ => {
                 log::warn!("Unhandled material mechanics token: '{:?}'", key);
+                diagnostics::report(
+                    DiagnosticSeverity::Warning,
+                    &format!("Unhandled material mechanics tag {key:?}"),
+                );
             }
         }
     }
@@ -6,14 +6,17 @@ use crate::parser::{
     object_types::ObjectType,
     raws::{RawMetadata, RawObject},
     searchable::{clean_search_vec, Searchable},
+    serializer_helper,
 };
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct MaterialTemplate {
     identifier: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
     metadata: RawMetadata,
     object_id: String,
     material: Material,
@@ -23,6 +26,9 @@ impl MaterialTemplate {
     pub fn empty() -> MaterialTemplate {
         MaterialTemplate::default()
     }
+    pub fn get_material(&self) -> &Material {
+        &self.material
+    }
     pub fn new(identifier: &str, metadata: &RawMetadata) -> MaterialTemplate {
         MaterialTemplate {
             identifier: String::from(identifier),
@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Milkable {
     material: String,
     frequency: u32,
@@ -21,6 +22,10 @@ impl Milkable {
         }
         Milkable::default()
     }
+    /// The value of a `MILKABLE` token, the material and frequency (the reverse of `from_value`).
+    pub fn to_value(&self) -> String {
+        format!("{}:{}", self.material, self.frequency)
+    }
     pub fn is_default(&self) -> bool {
         self.material.is_empty() && self.frequency == 0
     }
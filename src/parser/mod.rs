@@ -1,50 +1,210 @@
-use std::path::Path;
+use std::{io::Read, path::Path};
 
-use crate::{options::ParserOptions, parser::module_info_file::ModuleInfoFile};
+use crate::{
+    errors::ParserError,
+    options::ParserOptions,
+    parser::{diagnostics::DiagnosticSeverity, module_info_file::ModuleInfoFile},
+};
 
 pub mod biome;
+pub mod body;
+pub mod body_detail_plan;
 pub mod body_size;
+pub mod building;
+pub mod cache;
 pub mod color;
+pub mod conflicts;
 pub mod creature;
 pub mod creature_caste;
 pub mod creature_effect;
 pub mod creature_variation;
+pub mod cut_object;
+pub mod descriptor;
+pub mod diagnostics;
+pub mod document;
 pub mod entity;
 pub mod graphics;
 pub mod helpers;
 pub mod inorganic;
+pub mod interaction;
+pub mod item;
+pub mod language;
+pub mod load_order;
 pub mod material;
 pub mod material_mechanics;
 pub mod material_template;
 pub mod milkable;
 pub mod module_info_file;
+pub mod module_versions;
+pub mod music;
 pub mod names;
 pub mod object_types;
+pub mod palette;
 pub mod plant;
 pub mod plant_growth;
 pub mod position;
 pub mod ranges;
 pub mod raw_locations;
 pub mod raws;
+pub mod reaction;
 mod reader;
 mod refs;
 pub mod searchable;
 pub mod seed_material;
 pub mod select_creature;
+pub mod select_object;
 pub mod serializer_helper;
 pub mod shrub;
+pub mod sound;
 pub mod syndrome;
 pub mod temperature;
+pub mod text_set;
 pub mod tile;
+pub mod tissue_template;
 pub mod tree;
+pub mod writer;
 
 pub fn parse_info_file_from_file_path<P: AsRef<Path>>(raw_file_path: &P) -> ModuleInfoFile {
     ModuleInfoFile::parse(&raw_file_path.as_ref())
 }
 
+/// Parse the info.txt file at the given path.
+///
+/// # Errors
+///
+/// Returns a `ParserError` if the info.txt file is missing or can't be read.
+pub fn try_parse_info_file_from_file_path<P: AsRef<Path>>(
+    raw_file_path: &P,
+) -> Result<ModuleInfoFile, ParserError> {
+    ModuleInfoFile::try_parse(&raw_file_path.as_ref())
+}
+
 pub fn parse_raws_from_single_file<P: AsRef<Path>>(
     entry_path: &P,
     options: &ParserOptions,
 ) -> Vec<Box<dyn raws::RawObject>> {
+    match try_parse_raws_from_single_file(entry_path, options) {
+        Ok(raws) => raws,
+        Err(e) => {
+            log::error!("parse_raws_from_single_file: {e}");
+            diagnostics::set_file(&entry_path.as_ref().display().to_string());
+            diagnostics::report(DiagnosticSeverity::Error, &e.to_string());
+            Vec::new()
+        }
+    }
+}
+
+/// Parse the raws from a single raw file.
+///
+/// # Errors
+///
+/// Returns a `ParserError` if the file can't be read or decoded, or if its `[OBJECT]` type is
+/// unknown or inconsistent.
+pub fn try_parse_raws_from_single_file<P: AsRef<Path>>(
+    entry_path: &P,
+    options: &ParserOptions,
+) -> Result<Vec<Box<dyn raws::RawObject>>, ParserError> {
     reader::parse_file::parse_raw_file(entry_path, options)
 }
+
+/// Parse the raws from a single raw file which belongs to an already parsed module. This is the
+/// same as `parse_raws_from_single_file`, but doesn't read the module's info.txt file again.
+pub fn parse_raws_from_single_file_with_info<P: AsRef<Path>>(
+    entry_path: &P,
+    module_info_file: &ModuleInfoFile,
+    options: &ParserOptions,
+) -> Vec<Box<dyn raws::RawObject>> {
+    match try_parse_raws_from_single_file_with_info(entry_path, module_info_file, options) {
+        Ok(raws) => raws,
+        Err(e) => {
+            log::error!("parse_raws_from_single_file_with_info: {e}");
+            diagnostics::set_file(&entry_path.as_ref().display().to_string());
+            diagnostics::report(DiagnosticSeverity::Error, &e.to_string());
+            Vec::new()
+        }
+    }
+}
+
+/// Parse the raws from a single raw file which belongs to an already parsed module, returning the
+/// error instead of logging it (see `parse_raws_from_single_file_with_info`).
+///
+/// # Errors
+///
+/// Returns a `ParserError` if the file can't be read or decoded, or if its `[OBJECT]` type is
+/// unknown or inconsistent.
+pub fn try_parse_raws_from_single_file_with_info<P: AsRef<Path>>(
+    entry_path: &P,
+    module_info_file: &ModuleInfoFile,
+    options: &ParserOptions,
+) -> Result<Vec<Box<dyn raws::RawObject>>, ParserError> {
+    reader::parse_file::parse_raw_file_with_info(entry_path, module_info_file, options)
+}
+
+/// Parse the raws from the text of a raw file which isn't on disk (e.g. raws pasted into a form, or
+/// stored in a database). The text should have the raw file's identifier on its first line and an
+/// `[OBJECT:...]` token, the same as a raw file.
+///
+/// Arguments:
+///
+/// * `raw_text`: The text of the raw file
+/// * `source_name`: A name for the raw file (e.g. `pasted_creature.txt`). It is used in place of the
+///   raw file path in the raws' metadata, in diagnostics and in errors.
+/// * `module_info_file`: The module the raws belong to (see `ModuleInfoFile::synthetic`)
+/// * `options`: The parser options. Only `raws_to_parse` and `attach_metadata_to_raws` are used.
+///
+/// # Errors
+///
+/// Returns a `ParserError` if the `[OBJECT]` type is unknown or inconsistent.
+pub fn try_parse_raws_from_str(
+    raw_text: &str,
+    source_name: &str,
+    module_info_file: &ModuleInfoFile,
+    options: &ParserOptions,
+) -> Result<Vec<Box<dyn raws::RawObject>>, ParserError> {
+    reader::parse_file::parse_raw_text_with_info(raw_text, &source_name, module_info_file, options)
+}
+
+/// Parse the raws from the bytes of a raw file which isn't on disk. The bytes are decoded with the
+/// encoding used by Dwarf Fortress (or as UTF-8, if they start with a UTF-8 byte order mark).
+///
+/// See `try_parse_raws_from_str` for the arguments.
+///
+/// # Errors
+///
+/// * `ParserError::Encoding` if the bytes can't be decoded (with the `source_name` as the path)
+/// * A `ParserError` if the `[OBJECT]` type is unknown or inconsistent
+pub fn try_parse_raws_from_bytes(
+    raw_bytes: &[u8],
+    source_name: &str,
+    module_info_file: &ModuleInfoFile,
+    options: &ParserOptions,
+) -> Result<Vec<Box<dyn raws::RawObject>>, ParserError> {
+    let raw_text = reader::header::decode_raw_bytes(raw_bytes, &source_name)?;
+    try_parse_raws_from_str(&raw_text, source_name, module_info_file, options)
+}
+
+/// Parse the raws from a reader with the bytes of a raw file (e.g. a network stream or a blob from a
+/// database). The reader is read to the end, and decoded as for `try_parse_raws_from_bytes`.
+///
+/// See `try_parse_raws_from_str` for the arguments.
+///
+/// # Errors
+///
+/// * `ParserError::Io` if the reader can't be read (with the `source_name` as the path)
+/// * `ParserError::Encoding` if the bytes can't be decoded (with the `source_name` as the path)
+/// * A `ParserError` if the `[OBJECT]` type is unknown or inconsistent
+pub fn try_parse_raws_from_reader<R: Read>(
+    mut reader: R,
+    source_name: &str,
+    module_info_file: &ModuleInfoFile,
+    options: &ParserOptions,
+) -> Result<Vec<Box<dyn raws::RawObject>>, ParserError> {
+    let mut raw_bytes = Vec::new();
+    reader
+        .read_to_end(&mut raw_bytes)
+        .map_err(|source| ParserError::Io {
+            path: source_name.into(),
+            source,
+        })?;
+    try_parse_raws_from_bytes(&raw_bytes, source_name, module_info_file, options)
+}
@@ -1,25 +1,20 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    path::Path,
-};
+use std::path::Path;
 
-use encoding_rs_io::DecodeReaderBytesBuilder;
 use serde::{Deserialize, Serialize};
 use slug::slugify;
 
-use crate::{parser::refs::NON_DIGIT_RE, util::get_parent_dir_name};
+use crate::{errors::ParserError, parser::refs::NON_DIGIT_RE, util::get_parent_dir_name};
 
 use super::{
-    raw_locations::RawModuleLocation,
-    refs::{DF_ENCODING, RAW_TOKEN_RE},
+    raw_locations::RawModuleLocation, reader::header::decode_raw_bytes, refs::RAW_TOKEN_RE,
 };
 
 // Struct for info about a raw module
 #[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[serde(default)]
 pub struct ModuleInfoFile {
     identifier: String,
     object_id: String,
@@ -53,6 +48,10 @@ pub struct ModuleInfoFile {
     #[serde(skip_serializing_if = "String::is_empty")]
     steam_changelog: String,
     steam_file_id: u64,
+    /// The position of the module in the load order (see `compute_load_order`). This is 0 unless
+    /// the module was parsed along with other modules.
+    #[serde(skip)]
+    load_order: usize,
 }
 
 impl ModuleInfoFile {
@@ -68,6 +67,20 @@ impl ModuleInfoFile {
     pub fn empty() -> Self {
         ModuleInfoFile::default()
     }
+    /// A module for raws which don't come from a module on disk (e.g. raws pasted into a form, or
+    /// stored in a database), to use with `try_parse_raws_from_str` and friends. The object ids of
+    /// the raws are built from the module's identifier, and the raws' metadata has the `name`.
+    ///
+    /// Arguments:
+    ///
+    /// * `identifier`: The identifier of the module
+    /// * `name`: The name of the module
+    pub fn synthetic(identifier: &str, name: &str) -> Self {
+        ModuleInfoFile {
+            name: String::from(name),
+            ..ModuleInfoFile::new(identifier, RawModuleLocation::Unknown, identifier)
+        }
+    }
     pub fn from_raw_file_path<P: AsRef<Path>>(full_path: &P) -> Self {
         // Take the full path for the raw file and navigate up to the parent directory
         // e.g from `data/vanilla/vanilla_creatures/objects/creature_standard.txt` to `data/vanilla/vanilla_creatures`
@@ -83,45 +96,72 @@ impl ModuleInfoFile {
         let info_file_path = Path::new(parent_directory.as_str()).join("info.txt");
         Self::parse(&info_file_path)
     }
-    #[allow(clippy::too_many_lines)]
+    /// Parse the info.txt file at the given path. If there is an error reading the file, it is
+    /// logged and an empty `ModuleInfoFile` is returned. Use `try_parse` to handle the error instead.
     pub fn parse<P: AsRef<Path>>(info_file_path: &P) -> ModuleInfoFile {
+        match Self::try_parse(info_file_path) {
+            Ok(info_file) => info_file,
+            Err(e) => {
+                log::error!("DFInfoFile - {e}");
+                ModuleInfoFile::empty()
+            }
+        }
+    }
+    /// Parse the info.txt file at the given path.
+    ///
+    /// # Errors
+    ///
+    /// * `ParserError::MissingInfoFile` if there is no file at the path
+    /// * `ParserError::Io` if the file can't be read
+    /// * `ParserError::Encoding` if the file can't be decoded
+    pub fn try_parse<P: AsRef<Path>>(info_file_path: &P) -> Result<ModuleInfoFile, ParserError> {
         let parent_dir = get_parent_dir_name(info_file_path);
         let location = RawModuleLocation::from_info_text_file_path(info_file_path);
 
-        let file = match File::open(info_file_path) {
-            Ok(f) => f,
-            Err(e) => {
-                log::error!(
-                    "DFInfoFile - Error opening raw file for parsing in \"{}\"\n{:?}",
-                    parent_dir,
-                    e
-                );
-                return ModuleInfoFile::empty();
-            }
-        };
+        if !info_file_path.as_ref().exists() {
+            return Err(ParserError::MissingInfoFile(
+                info_file_path.as_ref().to_path_buf(),
+            ));
+        }
+
+        let bytes = std::fs::read(info_file_path).map_err(|source| ParserError::Io {
+            path: info_file_path.as_ref().to_path_buf(),
+            source,
+        })?;
 
-        let decoding_reader = DecodeReaderBytesBuilder::new()
-            .encoding(Some(*DF_ENCODING))
-            .build(file);
-        let reader = BufReader::new(decoding_reader);
+        let info_text = decode_raw_bytes(&bytes, info_file_path)?;
+        let info_file_data = Self::from_info_text(&info_text, location, &parent_dir);
 
+        // Check for 'unknown' identifier and try to provide any extra info
+        if info_file_data.get_identifier() == "unknown" {
+            log::error!(
+                "Failure parsing proper info from {}",
+                info_file_path.as_ref().display()
+            );
+        }
+
+        Ok(info_file_data)
+    }
+    /// Parse the text of an info.txt file which isn't on disk (e.g. one stored in a database).
+    ///
+    /// Arguments:
+    ///
+    /// * `info_text`: The text of the info.txt file
+    /// * `location`: The location the module is treated as being in
+    /// * `parent_directory`: The name of the module's directory, e.g. `vanilla_creatures`
+    #[allow(clippy::too_many_lines)]
+    pub fn from_info_text(
+        info_text: &str,
+        location: RawModuleLocation,
+        parent_directory: &str,
+    ) -> ModuleInfoFile {
         // info.txt details
         let mut caller = String::from("DFInfoFile");
-        let mut info_file_data: ModuleInfoFile = ModuleInfoFile::new("", location, &parent_dir);
+        let mut info_file_data: ModuleInfoFile =
+            ModuleInfoFile::new("", location, parent_directory);
 
-        for (index, line) in reader.lines().enumerate() {
-            if line.is_err() {
-                log::error!("{} - Error processing {:?}:{}", caller, parent_dir, index);
-                continue;
-            }
-            let line = match line {
-                Ok(l) => l,
-                Err(e) => {
-                    log::error!("{} - Line-reading error\n{:?}", caller, e);
-                    continue;
-                }
-            };
-            for cap in RAW_TOKEN_RE.captures_iter(&line) {
+        for line in info_text.lines() {
+            for cap in RAW_TOKEN_RE.captures_iter(line) {
                 let captured_key = match cap.get(2) {
                     Some(v) => v.as_str(),
                     _ => {
@@ -146,7 +186,8 @@ impl ModuleInfoFile {
                     // SECTION FOR MATCHING info.txt DATA
                     "ID" => {
                         // the [ID:identifier] tag should be the top of the info.txt file
-                        info_file_data = ModuleInfoFile::new(captured_value, location, &parent_dir);
+                        info_file_data =
+                            ModuleInfoFile::new(captured_value, location, parent_directory);
                         caller = format!("DFInfoFile ({})", &captured_value);
                     }
                     "NUMERIC_VERSION" => match captured_value.parse() {
@@ -266,7 +307,7 @@ impl ModuleInfoFile {
                             log::debug!(
                                 "{} - 'STEAM_FILE_ID' should be integer {}",
                                 caller,
-                                parent_dir
+                                parent_directory
                             );
                             // match on \D to replace any non-digit characters with empty string
                             let digits_only =
@@ -294,14 +335,6 @@ impl ModuleInfoFile {
             info_file_data.name = info_file_data.get_identifier();
         }
 
-        // Check for 'unknown' identifier and try to provide any extra info
-        if info_file_data.get_identifier() == "unknown" {
-            log::error!(
-                "Failure parsing proper info from {}",
-                info_file_path.as_ref().display()
-            );
-        }
-
         info_file_data
     }
 
@@ -320,7 +353,30 @@ impl ModuleInfoFile {
     pub fn get_version(&self) -> String {
         String::from(&self.displayed_version)
     }
+    pub fn get_numeric_version(&self) -> u32 {
+        self.numeric_version
+    }
     pub fn get_parent_directory(&self) -> String {
         String::from(&self.parent_directory)
     }
+    pub fn get_requires_ids(&self) -> &[String] {
+        self.requires_ids.as_slice()
+    }
+    pub fn get_conflicts_with_ids(&self) -> &[String] {
+        self.conflicts_with_ids.as_slice()
+    }
+    /// The modules which have to be loaded before this one (from `REQUIRES_ID_BEFORE_ME`).
+    pub fn get_requires_ids_before(&self) -> &[String] {
+        self.requires_ids_before.as_slice()
+    }
+    /// The modules which have to be loaded after this one (from `REQUIRES_ID_AFTER_ME`).
+    pub fn get_requires_ids_after(&self) -> &[String] {
+        self.requires_ids_after.as_slice()
+    }
+    pub fn get_load_order(&self) -> usize {
+        self.load_order
+    }
+    pub fn set_load_order(&mut self, load_order: usize) {
+        self.load_order = load_order;
+    }
 }
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::options::ModuleVersionPolicy;
+
+use super::{
+    diagnostics::{self, DiagnosticSeverity},
+    module_info_file::ModuleInfoFile,
+    raw_locations::RawModuleLocation,
+};
+
+/// A module which wasn't parsed because another version of it was kept (see
+/// `ModuleVersionPolicy`).
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DroppedModule {
+    identifier: String,
+    numeric_version: u32,
+    displayed_version: String,
+    location: RawModuleLocation,
+    /// The name of the module's directory, e.g. `mymod (1.2)`
+    parent_directory: String,
+    /// The numeric version of the module which was kept instead
+    kept_numeric_version: u32,
+}
+
+impl DroppedModule {
+    fn new(module: &ModuleInfoFile, kept_numeric_version: u32) -> Self {
+        Self {
+            identifier: module.get_identifier(),
+            numeric_version: module.get_numeric_version(),
+            displayed_version: module.get_version(),
+            location: module.get_location(),
+            parent_directory: module.get_parent_directory(),
+            kept_numeric_version,
+        }
+    }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn get_numeric_version(&self) -> u32 {
+        self.numeric_version
+    }
+    pub fn get_displayed_version(&self) -> &str {
+        &self.displayed_version
+    }
+    pub fn get_location(&self) -> RawModuleLocation {
+        self.location
+    }
+    pub fn get_parent_directory(&self) -> &str {
+        &self.parent_directory
+    }
+    pub fn get_kept_numeric_version(&self) -> u32 {
+        self.kept_numeric_version
+    }
+}
+
+/// Pick which versions of each module to parse, following the `policy`.
+///
+/// With `ModuleVersionPolicy::Newest` (or for a module which isn't pinned), the module with the
+/// highest `NUMERIC_VERSION` is kept. With `ModuleVersionPolicy::Pinned`, the pinned version of a
+/// module is kept, or the newest one (with a warning) if the pinned version wasn't found. A pinned
+/// module which wasn't found at all is also warned about. If the same version of a module is found
+/// more than once (e.g. in both `vanilla` and `installed_mods`), only the first one is kept.
+///
+/// Arguments:
+///
+/// * `modules`: The info.txt files of the modules, in the order they were found
+/// * `policy`: Which versions of each module to keep
+///
+/// Returns:
+///
+/// The indices of the modules to keep (in the order they were given in), and the modules which were
+/// dropped.
+pub fn select_module_versions(
+    modules: &[ModuleInfoFile],
+    policy: &ModuleVersionPolicy,
+) -> (Vec<usize>, Vec<DroppedModule>) {
+    let pinned_versions = match policy {
+        ModuleVersionPolicy::All => return ((0..modules.len()).collect(), Vec::new()),
+        ModuleVersionPolicy::Newest => HashMap::new(),
+        ModuleVersionPolicy::Pinned(pinned_versions) => pinned_versions.clone(),
+    };
+
+    // The index of the module to keep for each identifier
+    let mut kept_by_identifier: HashMap<String, usize> = HashMap::new();
+    for (index, module) in modules.iter().enumerate() {
+        let identifier = module.get_identifier();
+        let Some(&kept) = kept_by_identifier.get(&identifier) else {
+            kept_by_identifier.insert(identifier, index);
+            continue;
+        };
+
+        let kept_version = modules[kept].get_numeric_version();
+        let version = module.get_numeric_version();
+        let replace = match pinned_versions.get(&identifier) {
+            Some(&pinned) if kept_version == pinned => false,
+            Some(&pinned) if version == pinned => true,
+            _ => version > kept_version,
+        };
+        if replace {
+            kept_by_identifier.insert(identifier, index);
+        }
+    }
+
+    for (identifier, &pinned) in &pinned_versions {
+        if let Some(&kept) = kept_by_identifier.get(identifier) {
+            if modules[kept].get_numeric_version() != pinned {
+                log::warn!(
+                    "select_module_versions: pinned version {pinned} of {identifier} wasn't found"
+                );
+                diagnostics::report(
+                    DiagnosticSeverity::Warning,
+                    &format!(
+                        "Pinned version {pinned} of module {identifier} was not found, using version {}",
+                        modules[kept].get_numeric_version()
+                    ),
+                );
+            }
+        } else {
+            log::warn!("select_module_versions: pinned module {identifier} wasn't found");
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Pinned module {identifier} (version {pinned}) was not found"),
+            );
+        }
+    }
+
+    let mut kept_modules: Vec<usize> = Vec::new();
+    let mut dropped_modules: Vec<DroppedModule> = Vec::new();
+    for (index, module) in modules.iter().enumerate() {
+        let kept = kept_by_identifier
+            .get(&module.get_identifier())
+            .copied()
+            .unwrap_or(index);
+        if kept == index {
+            kept_modules.push(index);
+        } else {
+            log::warn!(
+                "select_module_versions: not parsing {} v{} ({}), v{} is parsed instead",
+                module.get_identifier(),
+                module.get_version(),
+                module.get_parent_directory(),
+                modules[kept].get_version()
+            );
+            dropped_modules.push(DroppedModule::new(
+                module,
+                modules[kept].get_numeric_version(),
+            ));
+        }
+    }
+
+    (kept_modules, dropped_modules)
+}
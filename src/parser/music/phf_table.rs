@@ -0,0 +1,6 @@
+use super::tokens::MusicToken;
+
+pub static MUSIC_TOKENS: phf::Map<&'static str, MusicToken> = phf::phf_map! {
+    "FILE" => MusicToken::File,
+    "CONDITION" => MusicToken::Condition,
+};
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{phf_table::MUSIC_TOKENS, tokens::MusicToken};
+
+/// A piece of music from an `[OBJECT:MUSIC]` raw file, e.g. `[MUSIC:MAIN_THEME]`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Music {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    /// The files the music is played from, relative to the module.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    files: Vec<String>,
+    /// The conditions for playing the music, e.g. `FORT_SEASON:SPRING`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    conditions: Vec<String>,
+}
+
+impl Music {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Music),
+            ..Self::default()
+        }
+    }
+    pub fn get_files(&self) -> &[String] {
+        self.files.as_slice()
+    }
+    pub fn get_conditions(&self) -> &[String] {
+        self.conditions.as_slice()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for Music {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::Music
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = MUSIC_TOKENS.get(key) else {
+            log::debug!(
+                "Music::parse_tag: Unknown music tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown music tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            MusicToken::File => self.files.push(String::from(value)),
+            MusicToken::Condition => self.conditions.push(String::from(value)),
+            MusicToken::Unknown => {}
+        }
+    }
+}
+
+impl Searchable for Music {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.extend(self.files.iter().cloned());
+
+        clean_search_vec(vec.as_slice())
+    }
+}
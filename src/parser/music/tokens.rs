@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum MusicToken {
+    /// Arguments: file path
+    ///
+    /// A file the music is played from, e.g. `music/main_theme.ogg`. There can be several.
+    File,
+    /// Arguments: condition
+    ///
+    /// When the music is played. There can be several.
+    Condition,
+    #[default]
+    Unknown,
+}
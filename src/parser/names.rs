@@ -2,9 +2,10 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Name {
     singular: String,
     plural: String,
@@ -26,6 +27,11 @@ impl Name {
             adjective: String::from(adjective_name),
         }
     }
+    /// The value of a token for the name, e.g. `dwarf:dwarves:dwarven` (the reverse of
+    /// `from_value`).
+    pub fn to_value(&self) -> String {
+        format!("{}:{}:{}", self.singular, self.plural, self.adjective)
+    }
     pub fn is_empty(&self) -> bool {
         self.singular.is_empty() && self.plural.is_empty() && self.adjective.is_empty()
     }
@@ -63,9 +69,10 @@ impl Name {
 }
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct SingPlurName {
     singular: String,
     plural: String,
@@ -89,9 +96,19 @@ impl SingPlurName {
             plural: String::from(plural_name),
         }
     }
+    /// The value of a token for the name, e.g. `puppy:puppies` (the reverse of `from_value`).
+    pub fn to_value(&self) -> String {
+        format!("{}:{}", self.singular, self.plural)
+    }
     pub fn is_empty(&self) -> bool {
         self.singular.is_empty() && self.plural.is_empty()
     }
+    pub fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    pub fn get_plural(&self) -> &str {
+        &self.plural
+    }
     pub fn as_vec(&self) -> Vec<String> {
         let mut vec = Vec::new();
         if !self.singular.is_empty() {
@@ -105,9 +122,10 @@ impl SingPlurName {
 }
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct StateName {
     solid: String,
     liquid: String,
@@ -118,6 +136,15 @@ impl StateName {
     pub fn is_empty(&self) -> bool {
         self.solid.is_empty() && self.liquid.is_empty() && self.gas.is_empty()
     }
+    pub fn get_solid(&self) -> &str {
+        &self.solid
+    }
+    pub fn get_liquid(&self) -> &str {
+        &self.liquid
+    }
+    pub fn get_gas(&self) -> &str {
+        &self.gas
+    }
     pub fn set_solid(&mut self, name: &str) {
         self.solid = String::from(name);
     }
@@ -127,6 +154,18 @@ impl StateName {
     pub fn set_gas(&mut self, name: &str) {
         self.gas = String::from(name);
     }
+    /// Overwrite any state in self with the states which are set in other.
+    pub fn overwrite_state_name(&mut self, other: &StateName) {
+        if !other.solid.is_empty() {
+            self.solid.clone_from(&other.solid);
+        }
+        if !other.liquid.is_empty() {
+            self.liquid.clone_from(&other.liquid);
+        }
+        if !other.gas.is_empty() {
+            self.gas.clone_from(&other.gas);
+        }
+    }
     pub fn add_from_value(&mut self, value: &str) {
         // Split the value into a descriptor and value
         let split = value.split(':').collect::<Vec<&str>>();
@@ -46,8 +46,8 @@ pub static OBJECT_TOKENS: phf::Map<&'static str, ObjectType> = phf::phf_map! {
 };
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum ObjectType {
     Creature,
     Inorganic,
@@ -93,6 +93,18 @@ pub enum ObjectType {
     Unknown,
     SelectCreature,
     CreatureCaste,
+    /// A `[WORD:...]` from an `[OBJECT:LANGUAGE]` file
+    Word,
+    /// A `[SYMBOL:...]` from an `[OBJECT:LANGUAGE]` file
+    Symbol,
+    /// A `[SELECT_PLANT:...]` patch from an `[OBJECT:PLANT]` file
+    SelectPlant,
+    /// A `[SELECT_INORGANIC:...]` patch from an `[OBJECT:INORGANIC]` file
+    SelectInorganic,
+    /// A `[CUT_CREATURE:...]` from an `[OBJECT:CREATURE]` file
+    CutCreature,
+    /// A `[CUT_INORGANIC:...]` from an `[OBJECT:INORGANIC]` file
+    CutInorganic,
 }
 
 impl Display for ObjectType {
@@ -0,0 +1,3 @@
+pub mod phf_table;
+pub mod raw;
+pub mod tokens;
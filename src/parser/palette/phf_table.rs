@@ -0,0 +1,7 @@
+use super::tokens::PaletteToken;
+
+pub static PALETTE_TOKENS: phf::Map<&'static str, PaletteToken> = phf::phf_map! {
+    "FILE" => PaletteToken::File,
+    "DEFAULT_ROW" => PaletteToken::DefaultRow,
+    "ROW" => PaletteToken::Row,
+};
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{phf_table::PALETTE_TOKENS, tokens::PaletteToken};
+
+/// A palette from an `[OBJECT:PALETTE]` raw file, e.g. `[PALETTE:WOOD]`.
+///
+/// Graphics which swap colors (e.g. `[TOOL_GRAPHICS_WOOD:1:ITEM_BOOKCASE:0:0]`) pick a row of the
+/// palette image by its index, which is the sprite's `color_pallet_swap`.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Palette {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "String::is_empty")]
+    file: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    default_row: u32,
+    /// The color table, as (name, row).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rows: Vec<(String, u32)>,
+}
+
+impl Palette {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Palette),
+            ..Self::default()
+        }
+    }
+    pub fn get_file(&self) -> &str {
+        &self.file
+    }
+    pub fn get_rows(&self) -> &[(String, u32)] {
+        self.rows.as_slice()
+    }
+    /// Returns the row of the palette for the name, or the default row if it isn't in the color
+    /// table.
+    pub fn get_row(&self, name: &str) -> u32 {
+        self.rows
+            .iter()
+            .find(|(n, _)| n == name)
+            .map_or(self.default_row, |(_, row)| *row)
+    }
+    /// Returns the names in the color table which use the row, e.g. to look up the
+    /// `color_pallet_swap` of a sprite.
+    pub fn get_row_names(&self, row: u32) -> Vec<&str> {
+        self.rows
+            .iter()
+            .filter(|(_, r)| *r == row)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for Palette {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::Palette
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = PALETTE_TOKENS.get(key) else {
+            log::debug!(
+                "Palette::parse_tag: Unknown palette tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown palette tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            PaletteToken::File => self.file = String::from(value),
+            PaletteToken::DefaultRow => self.default_row = value.parse().unwrap_or_default(),
+            PaletteToken::Row => {
+                let (name, row) = value.rsplit_once(':').unwrap_or((value, ""));
+                self.rows
+                    .push((String::from(name), row.parse().unwrap_or_default()));
+            }
+            PaletteToken::Unknown => {}
+        }
+    }
+}
+
+impl Searchable for Palette {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.push(self.file.clone());
+        vec.extend(self.rows.iter().map(|(name, _)| name.clone()));
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum PaletteToken {
+    /// Arguments: file path
+    ///
+    /// The image with the palette's colors, one row of colors per entry.
+    File,
+    /// Arguments: row
+    ///
+    /// The row used for anything which isn't in the palette's color table.
+    DefaultRow,
+    /// Arguments: name, row
+    ///
+    /// An entry of the color table, which maps a name (e.g. a color or material) to a row of the
+    /// palette image, e.g. `[ROW:AMETHYST:5]`.
+    Row,
+    #[default]
+    Unknown,
+}
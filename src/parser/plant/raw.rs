@@ -1,11 +1,15 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use slug::slugify;
 
 use crate::parser::{
     biome::{phf_map::BIOME_TOKENS, tokens::Biome},
+    diagnostics::{self, DiagnosticSeverity},
     material::{
         phf_table::{MATERIAL_PROPERTY_TOKENS, MATERIAL_USAGE_TOKENS},
         raw::Material,
+        tokens::MaterialType,
     },
     names::Name,
     object_types::ObjectType,
@@ -20,15 +24,17 @@ use crate::parser::{
     serializer_helper,
     shrub::{phf_table::SHRUB_TOKENS, raw::Shrub},
     tree::{phf_table::TREE_TOKENS, raw::Tree},
+    writer::{token_key, write_tags, RawWriter, ToRawText},
 };
 
 use super::{phf_table::PLANT_TOKENS, tokens::PlantTag};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Plant {
     /// Common Raw file Things
     #[serde(skip_serializing_if = "RawMetadata::is_hidden")]
@@ -68,18 +74,34 @@ pub struct Plant {
     materials: Vec<Material>,
 }
 
-impl Plant {
-    pub fn empty() -> Plant {
-        Plant {
+impl Default for Plant {
+    fn default() -> Self {
+        Self {
+            metadata: RawMetadata::default(),
+            identifier: String::new(),
+            object_id: String::new(),
+            name: Name::default(),
+            pref_strings: Vec::new(),
+            tags: Vec::new(),
+            underground_depth: [0, 0],
             frequency: 50,
-            ..Plant::default()
+            biomes: Vec::new(),
+            growths: Vec::new(),
+            tree_details: None,
+            shrub_details: None,
+            materials: Vec::new(),
         }
     }
+}
+
+impl Plant {
+    pub fn empty() -> Plant {
+        Plant::default()
+    }
     pub fn new(identifier: &str, metadata: &RawMetadata) -> Plant {
         Plant {
             identifier: String::from(identifier),
             metadata: metadata.clone(),
-            frequency: 50,
             object_id: format!(
                 "{}-{}-{}",
                 metadata.get_raw_identifier(),
@@ -92,6 +114,38 @@ impl Plant {
     pub fn get_biomes(&self) -> Vec<Biome> {
         self.biomes.clone()
     }
+    /// Apply the material templates used by this plant's materials. A plant material can also be
+    /// based on one of the plant's own materials (with `USE_MATERIAL`), which is used when there is
+    /// no template with that identifier.
+    ///
+    /// Arguments:
+    ///
+    /// * `templates`: The material of each `MaterialTemplate`, by template identifier.
+    ///
+    /// Returns:
+    ///
+    /// true if any of the materials were changed.
+    pub fn apply_material_templates(&mut self, templates: &HashMap<String, Material>) -> bool {
+        let mut changed = false;
+        for index in 0..self.materials.len() {
+            let template_identifier = self.materials[index].get_template_identifier();
+            if template_identifier.is_empty() {
+                continue;
+            }
+            let template = templates.get(template_identifier).cloned().or_else(|| {
+                // Look for a local material defined before this one
+                self.materials[..index]
+                    .iter()
+                    .find(|m| m.get_name() == template_identifier)
+                    .cloned()
+            });
+            if let Some(template) = template {
+                self.materials[index].apply_material_template(&template);
+                changed = true;
+            }
+        }
+        changed
+    }
 }
 
 #[typetag::serde]
@@ -126,10 +180,7 @@ impl RawObject for Plant {
         }
 
         if TREE_TOKENS.contains_key(key) {
-            if self.tree_details.is_none() {
-                self.tree_details = Some(Tree::new(value));
-            }
-            let tree = self.tree_details.as_mut().unwrap();
+            let tree = self.tree_details.get_or_insert_with(|| Tree::new(value));
             tree.parse_tag(key, value);
             return;
         }
@@ -167,6 +218,10 @@ impl RawObject for Plant {
 
         if !PLANT_TOKENS.contains_key(key) {
             log::debug!("PlantParsing: Unknown tag {} with value {}", key, value);
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown plant tag {key}"),
+            );
             return;
         }
 
@@ -200,6 +255,10 @@ impl RawObject for Plant {
                         "PlantParsing: called `Option::unwrap()` on a `None` value for presumed biome: {}",
                         value
                     );
+                    diagnostics::report(
+                        DiagnosticSeverity::Warning,
+                        &format!("Unknown biome {value}"),
+                    );
                     return;
                 };
                 self.biomes.push(biome.clone());
@@ -236,6 +295,66 @@ impl RawObject for Plant {
     }
 }
 
+impl ToRawText for Plant {
+    /// Write the plant as raw text. Materials defined with `USE_MATERIAL` are written with
+    /// `USE_MATERIAL_TEMPLATE`, which is parsed the same way.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &PLANT_TOKENS;
+        writer.begin_object("PLANT", &self.identifier);
+
+        if !self.name.is_empty() {
+            writer.token_for(tokens, &PlantTag::AllNames, self.name.to_value());
+        }
+        for pref_string in &self.pref_strings {
+            writer.token_for(tokens, &PlantTag::PrefString, pref_string);
+        }
+        for biome in &self.biomes {
+            if let Some(biome) = token_key(&BIOME_TOKENS, biome) {
+                writer.token_for(tokens, &PlantTag::Biome, biome);
+            }
+        }
+        let [min, max] = self.underground_depth;
+        if min != 0 || max != 0 {
+            writer.token_for(tokens, &PlantTag::UndergroundDepth, format!("{min}:{max}"));
+        }
+        if self.frequency != 50 {
+            writer.token_for(tokens, &PlantTag::Frequency, self.frequency);
+        }
+        write_tags(writer, tokens, &self.tags);
+
+        for material in &self.materials {
+            if material.is_local_material()
+                && material.get_material_type() == &MaterialType::default()
+            {
+                writer.token_for(
+                    tokens,
+                    &PlantTag::UseMaterialTemplate,
+                    format!(
+                        "{}:{}",
+                        material.get_name(),
+                        material.get_template_identifier()
+                    ),
+                );
+            } else {
+                writer.token_for(tokens, &PlantTag::Material, material.to_value());
+            }
+            writer.indent();
+            material.write_properties(writer);
+            writer.outdent();
+        }
+
+        if let Some(shrub) = &self.shrub_details {
+            shrub.write_raw_text(writer);
+        }
+        if let Some(tree) = &self.tree_details {
+            tree.write_raw_text(writer);
+        }
+        for growth in &self.growths {
+            growth.write_raw_text(writer);
+        }
+    }
+}
+
 impl Searchable for Plant {
     fn get_search_vec(&self) -> Vec<String> {
         let mut vec = Vec::new();
@@ -1,19 +1,22 @@
 use serde::{Deserialize, Serialize};
 
+use crate::parser::diagnostics::{self, DiagnosticSeverity};
 use crate::parser::searchable::clean_search_vec;
 use crate::parser::serializer_helper;
+use crate::parser::writer::{token_key, write_tags, RawWriter, ToRawText};
 use crate::parser::{names::SingPlurName, searchable::Searchable};
 
 use super::{
-    phf_table::{GROWTH_TOKENS, PLANT_PART_TOKENS},
+    phf_table::{GROWTH_TOKENS, GROWTH_TYPE_TOKENS, PLANT_PART_TOKENS},
     tokens::{GrowthTag, GrowthType, PlantPart},
 };
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct PlantGrowth {
     /// Plant growths are not given an identifier, since they are just supporting
     /// data for the plant definition. They are defined instead by the type of growth.
@@ -52,10 +55,29 @@ pub struct PlantGrowth {
     tags: Vec<GrowthTag>,
 }
 
+impl Default for PlantGrowth {
+    fn default() -> Self {
+        Self {
+            growth_type: GrowthType::default(),
+            name: SingPlurName::default(),
+            item: String::new(),
+            host_tiles: Vec::new(),
+            trunk_height_percentage: [0, -1],
+            density: 0,
+            print: String::new(),
+            timing: [0, 403_200],
+            tags: Vec::new(),
+        }
+    }
+}
+
 impl PlantGrowth {
     pub fn new(growth_type: GrowthType) -> PlantGrowth {
         PlantGrowth {
             growth_type,
+            // Left unset until the TRUNK_HEIGHT_PERCENTAGE and TIMING tokens are parsed
+            trunk_height_percentage: [0, 0],
+            timing: [0, 0],
             ..PlantGrowth::default()
         }
     }
@@ -65,6 +87,10 @@ impl PlantGrowth {
                 "PlantGrowthParsing: called `Option::unwrap()` on a `None` value for presumed caste tag: {}",
                 key
             );
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Unknown plant growth tag {key}"),
+            );
             return;
         };
 
@@ -155,6 +181,52 @@ impl PlantGrowth {
     }
 }
 
+impl ToRawText for PlantGrowth {
+    /// Write the `[GROWTH:...]` token and the tokens of the growth.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &GROWTH_TOKENS;
+        writer.token_for(
+            tokens,
+            &GrowthTag::Growth,
+            token_key(&GROWTH_TYPE_TOKENS, &self.growth_type).unwrap_or_default(),
+        );
+        writer.indent();
+
+        if !self.name.is_empty() {
+            writer.token_for(tokens, &GrowthTag::GrowthName, self.name.to_value());
+        }
+        if !self.item.is_empty() {
+            writer.token_for(tokens, &GrowthTag::GrowthItem, &self.item);
+        }
+        for host_tile in &self.host_tiles {
+            if let Some(part) = token_key(&PLANT_PART_TOKENS, host_tile) {
+                writer.token_for(tokens, &GrowthTag::GrowthHostTile, part);
+            }
+        }
+        let [percentage, direction] = self.trunk_height_percentage;
+        if percentage != 0 || direction != 0 {
+            writer.token_for(
+                tokens,
+                &GrowthTag::GrowthTrunkHeightPercent,
+                format!("{percentage}:{direction}"),
+            );
+        }
+        if self.density != 0 {
+            writer.token_for(tokens, &GrowthTag::GrowthDensity, self.density);
+        }
+        let [start, end] = self.timing;
+        if start != 0 || end != 0 {
+            writer.token_for(tokens, &GrowthTag::GrowthTiming, format!("{start}:{end}"));
+        }
+        if !self.print.is_empty() {
+            writer.token_for(tokens, &GrowthTag::GrowthPrint, &self.print);
+        }
+        write_tags(writer, tokens, &self.tags);
+
+        writer.outdent();
+    }
+}
+
 impl Searchable for PlantGrowth {
     fn get_search_vec(&self) -> Vec<String> {
         let mut vec = Vec::new();
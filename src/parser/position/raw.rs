@@ -1,13 +1,19 @@
 use serde::{Deserialize, Serialize};
 
-use crate::parser::{color::Color, names::SingPlurName, serializer_helper};
+use crate::parser::{
+    color::Color,
+    names::SingPlurName,
+    serializer_helper,
+    writer::{write_tags, RawWriter, ToRawText},
+};
 
-use super::tokens::PositionToken;
+use super::{phf_table::POSITION_TOKENS, tokens::PositionToken};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Position {
     identifier: String,
 
@@ -90,6 +96,9 @@ impl Position {
             ..Default::default()
         }
     }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
     pub fn parse_tag(&mut self, key: &PositionToken, value: &str) {
         match key {
             PositionToken::AllowedClass => self.allowed_classes.push(value.to_string()),
@@ -142,3 +151,80 @@ impl Position {
         }
     }
 }
+
+impl ToRawText for Position {
+    /// Write the tokens of the position. The `[POSITION:...]` token is written by the entity.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &POSITION_TOKENS;
+
+        let names = [
+            (PositionToken::Name, &self.name),
+            (PositionToken::NameMale, &self.name_male),
+            (PositionToken::NameFemale, &self.name_female),
+            (PositionToken::Spouse, &self.spouse),
+            (PositionToken::SpouseMale, &self.spouse_male),
+            (PositionToken::SpouseFemale, &self.spouse_female),
+        ];
+        for (tag, name) in names {
+            if !name.is_empty() {
+                writer.token_for(tokens, &tag, name.to_value());
+            }
+        }
+        let values = [
+            (PositionToken::AppointedBy, &self.appointed_by),
+            (PositionToken::Commander, &self.commander),
+            (PositionToken::ExecutionSkill, &self.execution_skill),
+            (PositionToken::Gender, &self.gender),
+            (PositionToken::LandName, &self.land_name),
+            (PositionToken::ReplacedBy, &self.replaced_by),
+            (PositionToken::Squad, &self.squad),
+            (PositionToken::Succession, &self.succession),
+        ];
+        for (tag, value) in values {
+            if !value.is_empty() {
+                writer.token_for(tokens, &tag, value);
+            }
+        }
+        let lists = [
+            (PositionToken::AllowedClass, &self.allowed_classes),
+            (PositionToken::AllowedCreature, &self.allowed_creatures),
+            (PositionToken::RejectedClass, &self.rejected_classes),
+            (PositionToken::RejectedCreature, &self.rejected_creatures),
+            (PositionToken::Responsibility, &self.responsibilities),
+        ];
+        for (tag, list) in lists {
+            for value in list {
+                writer.token_for(tokens, &tag, value);
+            }
+        }
+        let numbers = [
+            (PositionToken::DemandMax, self.demand_max),
+            (PositionToken::LandHolder, self.land_holder),
+            (PositionToken::MandateMax, self.mandate_max),
+            (PositionToken::RequiredBedroom, self.required_bedroom),
+            (PositionToken::RequiredBoxes, self.required_boxes),
+            (PositionToken::RequiredCabinets, self.required_cabinets),
+            (PositionToken::RequiredDining, self.required_dining),
+            (PositionToken::RequiredOffice, self.required_office),
+            (PositionToken::RequiredRacks, self.required_racks),
+            (PositionToken::RequiredStands, self.required_stands),
+            (PositionToken::RequiredTomb, self.required_tomb),
+            (PositionToken::RequiresPopulation, self.requires_population),
+        ];
+        for (tag, number) in numbers {
+            if number != 0 {
+                writer.token_for(tokens, &tag, number);
+            }
+        }
+        if self.number != 0 {
+            writer.token_for(tokens, &PositionToken::Number, self.number);
+        }
+        if self.precedence != 0 {
+            writer.token_for(tokens, &PositionToken::Precedence, self.precedence);
+        }
+        if !self.color.is_default() {
+            writer.token_for(tokens, &PositionToken::Color, self.color.to_value());
+        }
+        write_tags(writer, tokens, &self.tags);
+    }
+}
@@ -1,5 +1,7 @@
 use std::num::ParseIntError;
 
+use crate::parser::diagnostics::{self, DiagnosticSeverity};
+
 /// It takes a slice of strings, parses the first two strings as unsigned 16-bit integers, and returns a
 /// two-element array of unsigned 16-bit integers
 ///
@@ -19,6 +21,10 @@ pub fn parse_min_max_range_from_vec(split: &[&str]) -> Result<[u16; 2], ParseInt
         Ok(n) => n,
         Err(e) => {
             log::error!("min_value parsing error\n{:?}", e);
+            diagnostics::report(
+                DiagnosticSeverity::Error,
+                &format!("Unable to parse minimum value: {e}"),
+            );
             return Err(e);
         }
     };
@@ -26,6 +32,10 @@ pub fn parse_min_max_range_from_vec(split: &[&str]) -> Result<[u16; 2], ParseInt
         Ok(n) => n,
         Err(e) => {
             log::error!("max_value parsing error\n{:?}", e);
+            diagnostics::report(
+                DiagnosticSeverity::Error,
+                &format!("Unable to parse maximum value: {e}"),
+            );
             return Err(e);
         }
     };
@@ -11,7 +11,7 @@ use super::{
 /// to provide a common interface for all raw objects, so that they can be
 /// stored in a single vector. It also provides a common interface for parsing.
 #[typetag::serde(tag = "type")]
-pub trait RawObject: RawObjectToAny + Send + Sync + Searchable {
+pub trait RawObject: RawObjectToAny + RawObjectClone + Send + Sync + Searchable {
     /// Get the metadata for the raw.
     fn get_metadata(&self) -> &RawMetadata;
     /// Get the identifier of the raw.
@@ -55,6 +55,24 @@ impl<T: 'static> RawObjectToAny for T {
     }
 }
 
+/// The `RawObjectClone` trait is implemented by all raw objects. This trait is
+/// used to clone a boxed raw object without downcasting it to its type first.
+pub trait RawObjectClone {
+    fn clone_box(&self) -> Box<dyn RawObject>;
+}
+
+impl<T: RawObject + Clone> RawObjectClone for T {
+    fn clone_box(&self) -> Box<dyn RawObject> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn RawObject> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
 /// The `RawMetadata` struct represents metadata about a raw module in Rust, including its name,
 /// version, file path, identifier, object type, module location, and visibility status.
 ///
@@ -75,13 +93,17 @@ impl<T: 'static> RawObjectToAny for T {
 ///     - `RawModuleLocation::Mods`: The raw module is located in the `mods` folder.
 ///     - `RawModuleLocation::Vanilla`: The raw module is located in the `vanilla` folder.
 ///
+/// * `module_load_order`: The position of the owning raw module in the load order, which decides
+///   which module's `SELECT_*`, `CUT_*` and `COPY_TAGS_FROM` win. It is 0 when a single module or
+///   raw file is parsed.
 /// * `hidden`: The `hidden` property is a boolean value that indicates whether the raw metadata should
 /// be hidden or not when exporting. By default, it is set to `true`, meaning that the raw metadata will
 /// be hidden unless specified in the `ParsingOptions` struct.
-#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[serde(default)]
 pub struct RawMetadata {
     // The name of the raw module the raw is from.
     module_name: String,
@@ -97,12 +119,30 @@ pub struct RawMetadata {
     // The location of the owning raw module
     // i.e. installed_mods, mods, or vanilla
     raw_module_location: RawModuleLocation,
+    // The position of the owning raw module in the load order
+    #[serde(default)]
+    module_load_order: usize,
     // Optionally hide or unhide from exporting
-    // By default will be hidden
-    #[serde(skip)]
+    // By default will be hidden, but metadata which was exported (and is being read back) isn't
+    #[serde(skip_serializing, default)]
     hidden: bool,
 }
 
+impl Default for RawMetadata {
+    fn default() -> Self {
+        Self {
+            module_name: String::new(),
+            module_version: String::new(),
+            raw_file_path: String::new(),
+            raw_identifier: String::new(),
+            object_type: ObjectType::default(),
+            raw_module_location: RawModuleLocation::default(),
+            module_load_order: 0,
+            hidden: true,
+        }
+    }
+}
+
 impl RawMetadata {
     pub fn new<P: AsRef<Path>>(
         module_info: &ModuleInfoFile,
@@ -118,6 +158,7 @@ impl RawMetadata {
             raw_identifier: String::from(raw_identifier),
             object_type: object_type.clone(),
             raw_module_location: module_info.get_location(),
+            module_load_order: module_info.get_load_order(),
             hidden: !attach_metadata_to_raws,
         }
     }
@@ -141,6 +182,10 @@ impl RawMetadata {
     pub fn get_module_version(&self) -> &str {
         &self.module_version
     }
+    /// Get the type of the raws in the raw file the raw is from.
+    pub fn get_object_type(&self) -> &ObjectType {
+        &self.object_type
+    }
     /// Get the full path to the raw file the raw is from.
     pub fn get_raw_file_path(&self) -> &str {
         &self.raw_file_path
@@ -149,4 +194,9 @@ impl RawMetadata {
     pub fn get_location(&self) -> &RawModuleLocation {
         &self.raw_module_location
     }
+    /// Get the position of the owning raw module in the load order. Objects from modules later in
+    /// the load order are applied after (and so win over) objects from earlier modules.
+    pub fn get_module_load_order(&self) -> usize {
+        self.module_load_order
+    }
 }
@@ -0,0 +1,5 @@
+pub mod phf_table;
+pub mod product;
+pub mod raw;
+pub mod reagent;
+pub mod tokens;
@@ -0,0 +1,65 @@
+use super::tokens::{ProductToken, ReactionToken, ReagentToken};
+
+pub static REACTION_TOKENS: phf::Map<&'static str, ReactionToken> = phf::phf_map! {
+    "NAME" => ReactionToken::Name,
+    "BUILDING" => ReactionToken::Building,
+    "SKILL" => ReactionToken::Skill,
+    "CATEGORY" => ReactionToken::Category,
+    "DESCRIPTION" => ReactionToken::Description,
+    "REAGENT" => ReactionToken::Reagent,
+    "PRODUCT" => ReactionToken::Product,
+    "IMPROVEMENT" => ReactionToken::Improvement,
+    "AUTOMATIC" => ReactionToken::Automatic,
+    "FUEL" => ReactionToken::Fuel,
+    "ADVENTURE_MODE_ENABLED" => ReactionToken::AdventureModeEnabled,
+    "MAX_MULTIPLIER" => ReactionToken::MaxMultiplier,
+    "SKILL_IP" => ReactionToken::SkillIp,
+    "SKILL_ROLL_RANGE" => ReactionToken::SkillRollRange,
+    "COAL_QUANTITY" => ReactionToken::CoalQuantity,
+};
+
+pub static REAGENT_TOKENS: phf::Map<&'static str, ReagentToken> = phf::phf_map! {
+    "HAS_MATERIAL_REACTION_PRODUCT" => ReagentToken::HasMaterialReactionProduct,
+    "HAS_ITEM_REACTION_PRODUCT" => ReagentToken::HasItemReactionProduct,
+    "REACTION_CLASS" => ReagentToken::ReactionClass,
+    "CONTAINS" => ReagentToken::Contains,
+    "HAS_TOOL_USE" => ReagentToken::HasToolUse,
+    "MIN_DIMENSION" => ReagentToken::MinDimension,
+    "METAL_ORE" => ReagentToken::MetalOre,
+    "PRESERVE_REAGENT" => ReagentToken::PreserveReagent,
+    "UNROTTEN" => ReagentToken::Unrotten,
+    "USE_BODY_COMPONENT" => ReagentToken::UseBodyComponent,
+    "EMPTY" => ReagentToken::Empty,
+    "NOT_CONTAIN_BARREL_ITEM" => ReagentToken::NotContainBarrelItem,
+    "DOES_NOT_DETERMINE_PRODUCT_AMOUNT" => ReagentToken::DoesNotDetermineProductAmount,
+    "DOES_NOT_ABSORB" => ReagentToken::DoesNotAbsorb,
+    "NOT_ENGRAVED" => ReagentToken::NotEngraved,
+    "NOT_IMPROVED" => ReagentToken::NotImproved,
+    "NOT_WEB" => ReagentToken::NotWeb,
+    "WEB_ONLY" => ReagentToken::WebOnly,
+    "CONTAINS_LIQUID" => ReagentToken::ContainsLiquid,
+    "ANY_WEAPON_MATERIAL" => ReagentToken::AnyWeaponMaterial,
+    "ANY_PLANT_MATERIAL" => ReagentToken::AnyPlantMaterial,
+    "ANY_SILK_MATERIAL" => ReagentToken::AnySilkMaterial,
+    "ANY_YARN_MATERIAL" => ReagentToken::AnyYarnMaterial,
+    "ANY_SOAP_MATERIAL" => ReagentToken::AnySoapMaterial,
+    "ANY_TOOTH_MATERIAL" => ReagentToken::AnyToothMaterial,
+    "ANY_HORN_MATERIAL" => ReagentToken::AnyHornMaterial,
+    "ANY_PEARL_MATERIAL" => ReagentToken::AnyPearlMaterial,
+    "ANY_SHELL_MATERIAL" => ReagentToken::AnyShellMaterial,
+    "ANY_LEATHER_MATERIAL" => ReagentToken::AnyLeatherMaterial,
+    "ANY_BONE_MATERIAL" => ReagentToken::AnyBoneMaterial,
+    "ANY_STRAND_TISSUE" => ReagentToken::AnyStrandTissue,
+    "ANY_ANIMAL_MATERIAL" => ReagentToken::AnyAnimalMaterial,
+    "CAN_USE_DYE" => ReagentToken::CanUseDye,
+};
+
+pub static PRODUCT_TOKENS: phf::Map<&'static str, ProductToken> = phf::phf_map! {
+    "PRODUCT_TO_CONTAINER" => ProductToken::ProductToContainer,
+    "PRODUCT_DIMENSION" => ProductToken::ProductDimension,
+    "PRODUCT_TOKEN" => ProductToken::ProductToken,
+    "PRODUCT_PASTE" => ProductToken::ProductPaste,
+    "PRODUCT_PRESSED" => ProductToken::ProductPressed,
+    "FORCE_EDGE" => ProductToken::ForceEdge,
+    "TRANSFER_ARTIFACT_STATUS" => ProductToken::TransferArtifactStatus,
+};
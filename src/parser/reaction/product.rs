@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    material::raw::Material,
+    serializer_helper,
+};
+
+use super::{phf_table::PRODUCT_TOKENS, reagent::parse_item_and_material, tokens::ProductToken};
+
+/// An output of a reaction, from a token like `[PRODUCT:100:1:BAR:NONE:INORGANIC:STEEL]` and
+/// the tokens which follow it.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Product {
+    /// The chance (out of 100) that the product is made.
+    probability: u32,
+    quantity: u32,
+    /// The item type, e.g. `BAR`.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    item_type: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    item_subtype: String,
+    /// The material of the product. For many reactions, this is `GET_MATERIAL_FROM_REAGENT`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    material: Option<Material>,
+
+    #[serde(skip_serializing_if = "String::is_empty")]
+    to_container: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    dimension: u32,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    token: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<ProductToken>,
+}
+
+impl Product {
+    /// Create a product from the value of a `PRODUCT` token.
+    ///
+    /// Arguments:
+    ///
+    /// * `value`: The value of the token, e.g. `100:1:BAR:NONE:INORGANIC:STEEL`
+    pub fn from_value(value: &str) -> Self {
+        let parts: Vec<&str> = value.split(':').collect();
+        let (item_type, item_subtype, material) =
+            parse_item_and_material(parts.get(2..).unwrap_or_default());
+
+        Self {
+            probability: parts.first().unwrap_or(&"").parse().unwrap_or_default(),
+            quantity: parts.get(1).unwrap_or(&"").parse().unwrap_or_default(),
+            item_type,
+            item_subtype,
+            material,
+            ..Self::default()
+        }
+    }
+    pub fn get_item_type(&self) -> &str {
+        &self.item_type
+    }
+    pub fn get_material(&self) -> Option<&Material> {
+        self.material.as_ref()
+    }
+    /// Returns true if the key is a token which can follow a `PRODUCT`.
+    pub fn is_product_token(key: &str) -> bool {
+        PRODUCT_TOKENS.contains_key(key)
+    }
+    pub fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = PRODUCT_TOKENS.get(key) else {
+            log::debug!("Product::parse_tag: Unknown product tag {key}");
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown product tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            ProductToken::ProductToContainer => {
+                self.to_container = String::from(value);
+            }
+            ProductToken::ProductDimension => {
+                self.dimension = value.parse().unwrap_or_default();
+            }
+            ProductToken::ProductToken => {
+                self.token = String::from(value);
+            }
+            _ => {
+                self.tags.push(tag.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{
+    phf_table::REACTION_TOKENS, product::Product, reagent::Reagent, tokens::ReactionToken,
+};
+
+/// A `[REACTION:...]` raw, which turns reagents into products at a building, e.g.
+/// `TAN_A_HIDE` at the tanner's workshop.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Reaction {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "String::is_empty")]
+    name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    description: String,
+    /// The identifiers of the buildings which can perform the reaction, e.g. `TANNER`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    buildings: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    skill: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    category: String,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    reagents: Vec<Reagent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    products: Vec<Product>,
+    /// The values of the `IMPROVEMENT` tokens, e.g. `100:A:COVERED:GET_MATERIAL_FROM_REAGENT:B:NONE`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    improvements: Vec<String>,
+
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    max_multiplier: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    skill_ip: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    skill_roll_range: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    coal_quantity: u32,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<ReactionToken>,
+}
+
+impl Reaction {
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::Reaction),
+            ..Self::default()
+        }
+    }
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn get_reagents(&self) -> &[Reagent] {
+        self.reagents.as_slice()
+    }
+    pub fn get_products(&self) -> &[Product] {
+        self.products.as_slice()
+    }
+    pub fn get_buildings(&self) -> &[String] {
+        self.buildings.as_slice()
+    }
+    pub fn is_automatic(&self) -> bool {
+        self.tags.contains(&ReactionToken::Automatic)
+    }
+}
+
+#[typetag::serde]
+impl RawObject for Reaction {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        if self.name.is_empty() {
+            return &self.identifier;
+        }
+        &self.name
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::Reaction
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = REACTION_TOKENS.get(key) else {
+            // Anything else belongs to the last product or reagent
+            if Product::is_product_token(key) {
+                if let Some(product) = self.products.last_mut() {
+                    product.parse_tag(key, value);
+                    return;
+                }
+            } else if Reagent::is_reagent_token(key) {
+                if let Some(reagent) = self.reagents.last_mut() {
+                    reagent.parse_tag(key, value);
+                    return;
+                }
+            }
+            log::debug!(
+                "Reaction::parse_tag: Unknown reaction tag {} in {}",
+                key,
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown reaction tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            ReactionToken::Name => {
+                self.name = String::from(value);
+            }
+            ReactionToken::Description => {
+                self.description = String::from(value);
+            }
+            ReactionToken::Building => {
+                // The second argument is the hotkey, which we don't keep
+                let building = value.split(':').next().unwrap_or_default();
+                self.buildings.push(String::from(building));
+            }
+            ReactionToken::Skill => {
+                self.skill = String::from(value);
+            }
+            ReactionToken::Category => {
+                self.category = String::from(value);
+            }
+            ReactionToken::Reagent => {
+                self.reagents.push(Reagent::from_value(value));
+            }
+            ReactionToken::Product => {
+                self.products.push(Product::from_value(value));
+            }
+            ReactionToken::Improvement => {
+                self.improvements.push(String::from(value));
+            }
+            ReactionToken::MaxMultiplier => {
+                self.max_multiplier = value.parse().unwrap_or_default();
+            }
+            ReactionToken::SkillIp => {
+                self.skill_ip = value.parse().unwrap_or_default();
+            }
+            ReactionToken::SkillRollRange => {
+                self.skill_roll_range = value.parse().unwrap_or_default();
+            }
+            ReactionToken::CoalQuantity => {
+                self.coal_quantity = value.parse().unwrap_or_default();
+            }
+            _ => {
+                self.tags.push(tag.clone());
+            }
+        }
+    }
+}
+
+impl Searchable for Reaction {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.push(self.name.clone());
+        vec.push(format!("{:?}", self.get_type()));
+        vec.extend(self.buildings.iter().cloned());
+        vec.push(self.skill.clone());
+        vec.push(self.category.clone());
+        vec.extend(
+            self.reagents
+                .iter()
+                .map(|reagent| String::from(reagent.get_item_type())),
+        );
+        vec.extend(
+            self.products
+                .iter()
+                .map(|product| String::from(product.get_item_type())),
+        );
+        vec.extend(self.tags.iter().map(|tag| format!("{tag:?}")));
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    material::{phf_table::MATERIAL_USAGE_TOKENS, raw::Material, tokens::MaterialUsage},
+    serializer_helper,
+};
+
+use super::{phf_table::REAGENT_TOKENS, tokens::ReagentToken};
+
+/// An input to a reaction, from a token like `[REAGENT:A:1:BAR:NONE:INORGANIC:IRON]` and the
+/// tokens which follow it.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Reagent {
+    /// The identifier used by products and other reagents to refer to this reagent, e.g. `A`.
+    identifier: String,
+    quantity: u32,
+    /// The item type, e.g. `BAR`. Empty if any item can be used.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    item_type: String,
+    /// The item subtype, e.g. `ITEM_WEAPON_SWORD_SHORT`. Empty if any subtype can be used.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    item_subtype: String,
+    /// The material the reagent must be made of, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    material: Option<Material>,
+
+    #[serde(skip_serializing_if = "String::is_empty")]
+    reaction_class: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    has_material_reaction_product: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    has_item_reaction_product: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    has_tool_use: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    metal_ore: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    contains: Vec<String>,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    min_dimension: u32,
+
+    /// Material flags the reagent's material must have, e.g. `[BONE]`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    material_usage: Vec<MaterialUsage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<ReagentToken>,
+}
+
+impl Reagent {
+    /// Create a reagent from the value of a `REAGENT` token.
+    ///
+    /// Arguments:
+    ///
+    /// * `value`: The value of the token, e.g. `A:1:BAR:NONE:INORGANIC:IRON`
+    pub fn from_value(value: &str) -> Self {
+        let parts: Vec<&str> = value.split(':').collect();
+        let (item_type, item_subtype, material) =
+            parse_item_and_material(parts.get(2..).unwrap_or_default());
+
+        Self {
+            identifier: String::from(*parts.first().unwrap_or(&"")),
+            quantity: parts.get(1).unwrap_or(&"").parse().unwrap_or_default(),
+            item_type,
+            item_subtype,
+            material,
+            ..Self::default()
+        }
+    }
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn get_item_type(&self) -> &str {
+        &self.item_type
+    }
+    pub fn get_material(&self) -> Option<&Material> {
+        self.material.as_ref()
+    }
+    /// Returns true if the key is a token which can follow a `REAGENT`.
+    pub fn is_reagent_token(key: &str) -> bool {
+        REAGENT_TOKENS.contains_key(key) || MATERIAL_USAGE_TOKENS.contains_key(key)
+    }
+    pub fn parse_tag(&mut self, key: &str, value: &str) {
+        if let Some(usage) = MATERIAL_USAGE_TOKENS.get(key) {
+            self.material_usage.push(usage.clone());
+            return;
+        }
+
+        let Some(tag) = REAGENT_TOKENS.get(key) else {
+            log::debug!("Reagent::parse_tag: Unknown reagent tag {key}");
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown reagent tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            ReagentToken::HasMaterialReactionProduct => {
+                self.has_material_reaction_product = String::from(value);
+            }
+            ReagentToken::HasItemReactionProduct => {
+                self.has_item_reaction_product = String::from(value);
+            }
+            ReagentToken::ReactionClass => {
+                self.reaction_class = String::from(value);
+            }
+            ReagentToken::Contains => {
+                self.contains.push(String::from(value));
+            }
+            ReagentToken::HasToolUse => {
+                self.has_tool_use = String::from(value);
+            }
+            ReagentToken::MetalOre => {
+                self.metal_ore = String::from(value);
+            }
+            ReagentToken::MinDimension => {
+                self.min_dimension = value.parse().unwrap_or_default();
+            }
+            _ => {
+                self.tags.push(tag.clone());
+            }
+        }
+    }
+}
+
+/// Split the item and material tokens at the end of a `REAGENT` or `PRODUCT` token.
+///
+/// The item token is two parts (e.g. `BAR:NONE`), except for `GET_ITEM_DATA_FROM_REAGENT:A:NONE`,
+/// whose subtype is kept as `A:NONE`. The rest is the material token, which is `None` for
+/// `NONE:NONE`.
+///
+/// Returns:
+///
+/// The item type, the item subtype, and the material. A `NONE` item type or subtype is returned
+/// as an empty string.
+pub fn parse_item_and_material(parts: &[&str]) -> (String, String, Option<Material>) {
+    let item_type = *parts.first().unwrap_or(&"");
+    let item_parts = if item_type == "GET_ITEM_DATA_FROM_REAGENT" {
+        3
+    } else {
+        2
+    };
+    let item_subtype = parts.get(1..item_parts).unwrap_or_default().join(":");
+    let material = parts.get(item_parts..).unwrap_or_default().join(":");
+
+    let material = if material.is_empty() || material.split(':').all(|part| part == "NONE") {
+        None
+    } else {
+        Some(Material::from_value(&material))
+    };
+
+    let clean = |part: &str| {
+        if part == "NONE" {
+            String::new()
+        } else {
+            String::from(part)
+        }
+    };
+
+    (clean(item_type), clean(&item_subtype), material)
+}
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum ReactionToken {
+    /// Arguments: name
+    ///
+    /// The name of the reaction, as shown in the building's menu.
+    Name,
+    /// Arguments: building, hotkey
+    ///
+    /// A building which can perform the reaction, e.g. `[BUILDING:TANNER:CUSTOM_T]`. A reaction can have several buildings.
+    Building,
+    /// Arguments: skill
+    ///
+    /// The skill used (and trained) by the reaction.
+    Skill,
+    /// Arguments: category
+    ///
+    /// The category (sub-menu) the reaction is shown in within the building.
+    Category,
+    /// Arguments: description
+    ///
+    /// A description of the reaction, shown in the building's menu.
+    Description,
+    /// Arguments: reagent identifier, quantity, item token, material token
+    ///
+    /// An input to the reaction, e.g. `[REAGENT:A:1:BAR:NONE:INORGANIC:IRON]`. The tokens which follow it apply to this reagent.
+    Reagent,
+    /// Arguments: probability, quantity, item token, material token
+    ///
+    /// An output of the reaction, e.g. `[PRODUCT:100:1:BAR:NONE:INORGANIC:STEEL]`. The tokens which follow it apply to this product.
+    Product,
+    /// Arguments: probability, reagent identifier, improvement type, material token
+    ///
+    /// Decorates a reagent with an improvement, e.g. `[IMPROVEMENT:100:A:COVERED:GET_MATERIAL_FROM_REAGENT:B:NONE]`.
+    Improvement,
+    /// The reaction is queued automatically when its reagents are available.
+    Automatic,
+    /// The reaction needs fuel (or magma) to be performed.
+    Fuel,
+    /// The reaction can be performed in adventure mode.
+    AdventureModeEnabled,
+    /// Arguments: multiplier
+    ///
+    /// The maximum number of times the reaction can be repeated in a single job.
+    MaxMultiplier,
+    /// Arguments: amount
+    ///
+    /// The amount of experience given for the skill. Defaults to the amount for the products.
+    SkillIp,
+    /// Arguments: roll range
+    ///
+    /// Adjusts how much the skill level affects the quality of the products.
+    SkillRollRange,
+    /// Arguments: quantity
+    ///
+    /// The quantity of coal (or other fuel) used by the reaction.
+    CoalQuantity,
+    /// An unknown token.
+    #[default]
+    Unknown,
+}
+
+/// Tokens which follow a `REAGENT` and apply to it. Material flags (e.g. `[BONE]`) are parsed as
+/// `MaterialUsage` instead.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum ReagentToken {
+    /// Arguments: reaction product identifier
+    ///
+    /// The reagent must have a material with this `MATERIAL_REACTION_PRODUCT`, e.g. `TAN_MAT`.
+    HasMaterialReactionProduct,
+    /// Arguments: reaction product identifier
+    ///
+    /// The reagent must have an `ITEM_REACTION_PRODUCT` with this identifier.
+    HasItemReactionProduct,
+    /// Arguments: reaction class
+    ///
+    /// The reagent must be made of a material with this `REACTION_CLASS`.
+    ReactionClass,
+    /// Arguments: reagent identifier
+    ///
+    /// The reagent must contain the other reagent, e.g. a barrel containing a liquid.
+    Contains,
+    /// Arguments: tool use
+    ///
+    /// The reagent must be a tool with this `TOOL_USE`.
+    HasToolUse,
+    /// Arguments: dimension
+    ///
+    /// The reagent must have at least this dimension, e.g. 150 for a full bar.
+    MinDimension,
+    /// Arguments: metal
+    ///
+    /// The reagent must be an ore of this metal.
+    MetalOre,
+    /// The reagent is not used up by the reaction.
+    PreserveReagent,
+    /// The reagent must not be rotten.
+    Unrotten,
+    /// The reagent must be a body part (e.g. a hide).
+    UseBodyComponent,
+    /// The reagent must be empty.
+    Empty,
+    /// The reagent can't be a barrel which holds an item.
+    NotContainBarrelItem,
+    /// The reagent doesn't affect how many products are made.
+    DoesNotDetermineProductAmount,
+    /// The reagent doesn't need to be reachable from the building.
+    DoesNotAbsorb,
+    /// The reagent must not be engraved.
+    NotEngraved,
+    /// The reagent must not be improved.
+    NotImproved,
+    /// The reagent must not be a web.
+    NotWeb,
+    /// The reagent must be a web.
+    WebOnly,
+    /// The reagent must be a container which can hold liquid.
+    ContainsLiquid,
+    /// The reagent must be made of a metal which can be made into weapons.
+    AnyWeaponMaterial,
+    /// The reagent must be made of any plant material.
+    AnyPlantMaterial,
+    /// The reagent must be made of any silk material.
+    AnySilkMaterial,
+    /// The reagent must be made of any yarn material.
+    AnyYarnMaterial,
+    /// The reagent must be made of any soap material.
+    AnySoapMaterial,
+    /// The reagent must be made of any tooth material.
+    AnyToothMaterial,
+    /// The reagent must be made of any horn material.
+    AnyHornMaterial,
+    /// The reagent must be made of any pearl material.
+    AnyPearlMaterial,
+    /// The reagent must be made of any shell material.
+    AnyShellMaterial,
+    /// The reagent must be made of any leather material.
+    AnyLeatherMaterial,
+    /// The reagent must be made of any bone material.
+    AnyBoneMaterial,
+    /// The reagent must be made of any strand tissue (e.g. hair or wool).
+    AnyStrandTissue,
+    /// The reagent must be made of any material which came from a creature.
+    AnyAnimalMaterial,
+    /// The reagent must be able to hold a dye.
+    CanUseDye,
+    /// An unknown token.
+    #[default]
+    Unknown,
+}
+
+/// Tokens which follow a `PRODUCT` and apply to it.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum ProductToken {
+    /// Arguments: reagent identifier
+    ///
+    /// The product is placed into the container reagent.
+    ProductToContainer,
+    /// Arguments: dimension
+    ///
+    /// The dimension of the product, e.g. 150 for a full bar.
+    ProductDimension,
+    /// Arguments: token
+    ///
+    /// An identifier for the product, which other tokens can refer to.
+    ProductToken,
+    /// The product is made as a paste.
+    ProductPaste,
+    /// The product is made as a pressed cake.
+    ProductPressed,
+    /// The product is made with an edge, even if it normally wouldn't have one.
+    ForceEdge,
+    /// The artifact status of the reagent is transferred to the product.
+    TransferArtifactStatus,
+    /// An unknown token.
+    #[default]
+    Unknown,
+}
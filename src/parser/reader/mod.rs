@@ -1,3 +1,3 @@
-mod header;
+pub(crate) mod header;
 pub mod parsable_types;
 pub mod parse_file;
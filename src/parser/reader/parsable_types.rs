@@ -1,6 +1,6 @@
 use crate::parser::object_types::ObjectType;
 
-pub const PARSABLE_OBJECT_TYPES: [&ObjectType; 8] = [
+pub const PARSABLE_OBJECT_TYPES: [&ObjectType; 23] = [
     &ObjectType::Creature,
     &ObjectType::Plant,
     &ObjectType::Inorganic,
@@ -9,4 +9,19 @@ pub const PARSABLE_OBJECT_TYPES: [&ObjectType; 8] = [
     &ObjectType::Entity,
     &ObjectType::MaterialTemplate,
     &ObjectType::CreatureVariation,
+    &ObjectType::Item,
+    &ObjectType::Reaction,
+    &ObjectType::Building,
+    &ObjectType::Body,
+    &ObjectType::BodyDetailPlan,
+    &ObjectType::TissueTemplate,
+    &ObjectType::Language,
+    &ObjectType::Interaction,
+    &ObjectType::DescriptorColor,
+    &ObjectType::DescriptorPattern,
+    &ObjectType::DescriptorShape,
+    &ObjectType::Palette,
+    &ObjectType::Music,
+    &ObjectType::Sound,
+    &ObjectType::TextSet,
 ];
@@ -1,85 +1,109 @@
-use encoding_rs_io::DecodeReaderBytesBuilder;
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    path::Path,
-};
+use std::path::Path;
 
 use crate::{
+    errors::ParserError,
     options::ParserOptions,
     parser::{
+        body::raw::Body,
+        body_detail_plan::raw::BodyDetailPlan,
+        building::raw::Building,
         creature::raw::Creature,
+        creature_variation::raw::CreatureVariation,
+        cut_object::raw::CutObject,
+        descriptor::{color::DescriptorColor, pattern::DescriptorPattern, shape::DescriptorShape},
+        diagnostics,
+        document::RawDocument,
         entity::raw::Entity,
         graphics::{
             phf_table::GRAPHIC_TYPE_TAGS, raw::Graphic, tile_page::TilePage, tokens::GraphicType,
         },
         inorganic::raw::Inorganic,
+        interaction::raw::Interaction,
+        item::raw::Item,
+        language::{symbol::Symbol, translation::Translation, word::Word},
         material_template::raw::MaterialTemplate,
         module_info_file::ModuleInfoFile,
+        music::raw::Music,
         object_types::{ObjectType, OBJECT_TOKENS},
+        palette::raw::Palette,
         plant::raw::Plant,
         raws::{RawMetadata, RawObject},
+        reaction::raw::Reaction,
         reader::parsable_types::PARSABLE_OBJECT_TYPES,
-        refs::{DF_ENCODING, RAW_TOKEN_RE},
         select_creature::raw::SelectCreature,
+        select_object::raw::SelectObject,
+        sound::raw::Sound,
+        text_set::raw::TextSet,
+        tissue_template::raw::TissueTemplate,
     },
 };
 
-use super::header::read_raw_file_type;
+use super::header::read_raw_file_text;
 
 pub fn parse_raw_file<P: AsRef<Path>>(
     raw_file_path: &P,
     options: &ParserOptions,
-) -> Vec<Box<dyn RawObject>> {
+) -> Result<Vec<Box<dyn RawObject>>, ParserError> {
     let mod_info_file = ModuleInfoFile::from_raw_file_path(raw_file_path);
 
     parse_raw_file_with_info(raw_file_path, &mod_info_file, options)
 }
 
-#[allow(clippy::too_many_lines)]
 pub fn parse_raw_file_with_info<P: AsRef<Path>>(
     raw_file_path: &P,
     mod_info_file: &ModuleInfoFile,
     options: &ParserOptions,
-) -> Vec<Box<dyn RawObject>> {
-    let mut created_raws: Vec<Box<dyn RawObject>> = Vec::new();
-
-    let file = match File::open(raw_file_path) {
-        Ok(f) => f,
-        Err(e) => {
-            log::error!(
-                "parse_raw_file_with_info: Error opening raw file for parsing!\n{:?}",
-                e
-            );
-            return created_raws;
-        }
-    };
+) -> Result<Vec<Box<dyn RawObject>>, ParserError> {
+    let raw_text = read_raw_file_text(raw_file_path)?;
 
-    let decoding_reader = DecodeReaderBytesBuilder::new()
-        .encoding(Some(*DF_ENCODING))
-        .build(file);
-    let reader = BufReader::new(decoding_reader);
-    let mut started = false;
-    let mut raw_filename = String::new();
-
-    let mut temp_creature = Creature::empty();
-    let mut temp_select_creature = SelectCreature::empty();
-    let mut temp_plant = Plant::empty();
-    let mut temp_inorganic = Inorganic::empty();
-    let mut temp_graphic = Graphic::empty();
-    let mut temp_material_template = MaterialTemplate::empty();
-    let mut temp_entity = Entity::empty();
-
-    let mut last_parsed_type = ObjectType::Unknown;
-    let mut last_graphic_type = GraphicType::Unknown;
-    let mut temp_tile_page = TilePage::empty();
+    parse_raw_text_with_info(&raw_text, raw_file_path, mod_info_file, options)
+}
+
+/// Parse the raws from the (already decoded) text of a raw file.
+///
+/// Arguments:
+///
+/// * `raw_text`: The text of the raw file
+/// * `raw_file_path`: The path of the raw file, or a name for it if it didn't come from a file. It is
+///   used in the raws' metadata, in diagnostics and in errors.
+/// * `mod_info_file`: The module the raw file belongs to
+/// * `options`: The parser options
+///
+/// # Errors
+///
+/// * `ParserError::UnknownObjectType` if the \[OBJECT\] type is unknown
+/// * `ParserError::ObjectTypeMismatch` if there is more than one \[OBJECT\] type
+pub fn parse_raw_text_with_info<P: AsRef<Path>>(
+    raw_text: &str,
+    raw_file_path: &P,
+    mod_info_file: &ModuleInfoFile,
+    options: &ParserOptions,
+) -> Result<Vec<Box<dyn RawObject>>, ParserError> {
+    diagnostics::set_file(&raw_file_path.as_ref().display().to_string());
 
     // Metadata
-    let object_type = read_raw_file_type(raw_file_path);
-    let mut raw_metadata = RawMetadata::new(
+    let document = RawDocument::parse(raw_text);
+    let object_type = if let Some(token) = document.get_object_token() {
+        OBJECT_TOKENS
+            .get(token.get_value())
+            .cloned()
+            .ok_or_else(|| ParserError::UnknownObjectType {
+                object_type: token.get_value().to_uppercase(),
+                path: raw_file_path.as_ref().to_path_buf(),
+            })?
+    } else {
+        // Not finding an \[OBJECT\] tag means the raw file is invalid
+        log::warn!(
+            "parse_raw_file_with_info: no [OBJECT] tag in {}",
+            raw_file_path.as_ref().display()
+        );
+        ObjectType::Unknown
+    };
+    let raw_filename = document.get_raw_identifier();
+    let raw_metadata = RawMetadata::new(
         mod_info_file,
         &object_type,
-        raw_filename.as_str(),
+        raw_filename,
         &raw_file_path,
         options.attach_metadata_to_raws,
     );
@@ -90,7 +114,7 @@ pub fn parse_raw_file_with_info<P: AsRef<Path>>(
             "parse_raw_file_with_info: Quitting early because object type {:?} is not included in options!",
             object_type
         );
-        return created_raws;
+        return Ok(Vec::new());
     }
 
     // If the type of object is not in our known_list, we should quit here
@@ -99,312 +123,501 @@ pub fn parse_raw_file_with_info<P: AsRef<Path>>(
             "parse_raw_file_with_info: Quitting early because object type {:?} is not parsable!",
             object_type
         );
-        return created_raws;
+        return Ok(Vec::new());
     }
 
-    for (index, line) in reader.lines().enumerate() {
-        if line.is_err() {
-            log::error!(
-                "parse_raw_file_with_info: Error processing {}:{}",
-                raw_file_path.as_ref().display(),
-                index
-            );
-            continue;
+    let mut reader = RawFileReader::new(object_type, raw_metadata);
+    for token in document.tokens() {
+        let captured_key = token.get_key();
+        let captured_value = token.get_value();
+
+        log::trace!("parse_raw_file_with_info: Key: {captured_key} Value: {captured_value}");
+        diagnostics::set_line(token.get_span().get_line());
+        diagnostics::set_token(token.get_text());
+
+        if captured_key == "OBJECT" {
+            check_object_type(&reader.object_type, captured_value, raw_file_path.as_ref())?;
+        } else {
+            reader.read_token(captured_key, captured_value);
         }
-        let line = match line {
-            Ok(l) => l,
-            Err(e) => {
-                log::error!("parse_raw_file_with_info: Line-reading error\n{:?}", e);
-                continue;
-            }
-        };
+    }
+    let created_raws = reader.finish();
+
+    log::debug!(
+        "parse_raw_file_with_info: Parsed {} raws from {}",
+        created_raws.len(),
+        raw_filename
+    );
+
+    Ok(created_raws)
+}
+
+/// Check that an \[OBJECT\] token matches the object type of the raw file.
+fn check_object_type(
+    object_type: &ObjectType,
+    captured_value: &str,
+    raw_file_path: &Path,
+) -> Result<(), ParserError> {
+    let Some(captured_object_type) = OBJECT_TOKENS.get(captured_value) else {
+        // We don't know what this object is, so we can't parse it.
+        return Err(ParserError::UnknownObjectType {
+            object_type: captured_value.to_uppercase(),
+            path: raw_file_path.to_path_buf(),
+        });
+    };
+    if object_type != captured_object_type {
+        return Err(ParserError::ObjectTypeMismatch {
+            expected: object_type.clone(),
+            found: captured_value.to_uppercase(),
+            path: raw_file_path.to_path_buf(),
+        });
+    }
+    Ok(())
+}
+
+/// Finish `current`, adding it to the created raws unless nothing was read into it, and start
+/// reading `next` in its place.
+fn start_object<T: RawObject>(
+    created_raws: &mut Vec<Box<dyn RawObject>>,
+    current: &mut T,
+    next: T,
+) {
+    diagnostics::set_object(next.get_identifier());
+    let finished = std::mem::replace(current, next);
+    if !finished.is_empty() {
+        created_raws.push(Box::new(finished));
+    }
+}
 
-        if index == 0 {
-            raw_filename = String::from(&line);
-            raw_metadata = RawMetadata::new(
-                mod_info_file,
-                &object_type,
-                raw_filename.as_str(),
-                &raw_file_path,
-                options.attach_metadata_to_raws,
-            );
-            continue;
+/// The objects being read from a raw file.
+///
+/// Each kind of object is kept until the next one of the same kind starts (or the file ends), and
+/// is then added to the created raws. Tokens that don't start an object are tags for the object
+/// that was started last.
+struct RawFileReader {
+    object_type: ObjectType,
+    raw_metadata: RawMetadata,
+    created_raws: Vec<Box<dyn RawObject>>,
+    last_parsed_type: ObjectType,
+    last_graphic_type: GraphicType,
+
+    creature: Creature,
+    select_creature: SelectCreature,
+    plant: Plant,
+    inorganic: Inorganic,
+    select_object: SelectObject,
+    graphic: Graphic,
+    tile_page: TilePage,
+    material_template: MaterialTemplate,
+    entity: Entity,
+    creature_variation: CreatureVariation,
+    item: Item,
+    reaction: Reaction,
+    building: Building,
+    body: Body,
+    body_detail_plan: BodyDetailPlan,
+    tissue_template: TissueTemplate,
+    word: Word,
+    translation: Translation,
+    symbol: Symbol,
+    interaction: Interaction,
+    descriptor_color: DescriptorColor,
+    descriptor_pattern: DescriptorPattern,
+    descriptor_shape: DescriptorShape,
+    palette: Palette,
+    music: Music,
+    sound: Sound,
+    text_set: TextSet,
+}
+
+impl RawFileReader {
+    fn new(object_type: ObjectType, raw_metadata: RawMetadata) -> Self {
+        Self {
+            object_type,
+            raw_metadata,
+            created_raws: Vec::new(),
+            last_parsed_type: ObjectType::Unknown,
+            last_graphic_type: GraphicType::Unknown,
+            creature: Creature::empty(),
+            select_creature: SelectCreature::empty(),
+            plant: Plant::empty(),
+            inorganic: Inorganic::empty(),
+            select_object: SelectObject::empty(),
+            graphic: Graphic::empty(),
+            tile_page: TilePage::empty(),
+            material_template: MaterialTemplate::empty(),
+            entity: Entity::empty(),
+            creature_variation: CreatureVariation::empty(),
+            item: Item::empty(),
+            reaction: Reaction::empty(),
+            building: Building::empty(),
+            body: Body::empty(),
+            body_detail_plan: BodyDetailPlan::empty(),
+            tissue_template: TissueTemplate::empty(),
+            word: Word::empty(),
+            translation: Translation::empty(),
+            symbol: Symbol::empty(),
+            interaction: Interaction::empty(),
+            descriptor_color: DescriptorColor::empty(),
+            descriptor_pattern: DescriptorPattern::empty(),
+            descriptor_shape: DescriptorShape::empty(),
+            palette: Palette::empty(),
+            music: Music::empty(),
+            sound: Sound::empty(),
+            text_set: TextSet::empty(),
         }
-        for cap in RAW_TOKEN_RE.captures_iter(&line) {
-            let captured_key = match cap.get(2) {
-                Some(v) => v.as_str(),
-                _ => {
-                    continue;
-                }
-            };
-            let captured_value = match cap.get(3) {
-                Some(v) => v.as_str(),
-                _ => {
-                    continue;
-                }
-            };
-
-            log::trace!(
-                "parse_raw_file_with_info: Key: {} Value: {}",
-                captured_key,
-                captured_value
-            );
-
-            match captured_key {
-                "OBJECT" => {
-                    if !OBJECT_TOKENS.contains_key(captured_value) {
-                        // We don't know what this object is, so we can't parse it.
-                        // We should log this as an error.
-                        log::error!(
-                            "parse_raw_file_with_info: Unknown object type: {} Raw: {}",
-                            captured_value.to_uppercase(),
-                            raw_filename
-                        );
-                        return created_raws;
-                    }
-                    // Check of object_type matches the captured_value as ObjectType.
-                    // If it doesn't, we should log this as an error.
-                    if &object_type != OBJECT_TOKENS.get(captured_value).unwrap() {
-                        log::error!(
-                            "parse_raw_file_with_info: Object type mismatch: {} != {}",
-                            object_type,
-                            captured_value.to_uppercase()
-                        );
-                        return created_raws;
-                    }
-                }
-                "CREATURE" => {
-                    // The entity object has a CREATURE tag
-                    if started && last_parsed_type == ObjectType::Entity {
-                        // We need to let the entity parse this tag.
-                        temp_entity.parse_tag(captured_key, captured_value);
-                        // leave before adding a fake new creature
-                        continue;
-                    }
-
-                    if started
-                        && (last_parsed_type == ObjectType::Creature
-                            || last_parsed_type == ObjectType::CreatureCaste)
-                    {
-                        // We need to add the creature to the list.
-                        created_raws.push(Box::new(temp_creature.clone()));
-                    } else {
-                        started = true;
-                    }
-                    // We haven't started a creature yet, so we need to start one.
-                    temp_creature = Creature::new(captured_value, &raw_metadata.clone());
-                    last_parsed_type = ObjectType::Creature;
-                }
-                "SELECT_CREATURE" => {
-                    if started && last_parsed_type == ObjectType::SelectCreature {
-                        // We need to add the creature to the list.
-                        created_raws.push(Box::new(temp_select_creature.clone()));
-                    } else {
-                        started = true;
-                    }
-                    // We haven't started a creature yet, so we need to start one.
-                    temp_select_creature =
-                        SelectCreature::new(captured_value, &raw_metadata.clone());
-                    last_parsed_type = ObjectType::SelectCreature;
-                }
-                "CASTE" => {
-                    if object_type != ObjectType::Creature
-                        && object_type != ObjectType::Entity
-                        && object_type != ObjectType::Graphics
-                    {
-                        // Currently unhandled outside of these configurations.
-                        continue;
-                    }
-                    // Starting a new caste (in creature), so we can just add a caste to the last creature we started.
-                    if started
-                        && (last_parsed_type == ObjectType::CreatureCaste
-                            || last_parsed_type == ObjectType::Creature)
-                    {
-                        // We have a creature, so we can add a caste to it.
-                        // First we have to cast the dyn RawObject to a DFCreature.
-                        temp_creature.add_caste(captured_value);
-                    }
-                    last_parsed_type = ObjectType::CreatureCaste;
-                }
-                "SELECT_CASTE" => {
-                    // Starting a new caste (in creature), so we can just add a caste to the last creature we started.
-                    if started {
-                        // We have a creature, so we can add a caste to it.
-                        // First we have to cast the dyn RawObject to a DFCreature.
-                        temp_creature.select_caste(captured_value);
-                    }
-                }
-                "PLANT" => {
-                    // Starting a new plant, so we can just add a plant to the list.
-                    if started {
-                        // We need to add the plant to the list.
-                        created_raws.push(Box::new(temp_plant.clone()));
-                    } else {
-                        started = true;
-                    }
-                    // We haven't started a plant yet, so we need to start one.
-                    temp_plant = Plant::new(captured_value, &raw_metadata.clone());
-                    last_parsed_type = ObjectType::Plant;
-                }
-                "INORGANIC" | "SELECT_INORGANIC" => {
-                    if started {
-                        // We've already started a raw, so we need to finish it.
-                        // This is a new creature, so we need to finish the old one.
-                        created_raws.push(Box::new(temp_inorganic.clone()));
-                    } else {
-                        started = true;
-                    }
-                    temp_inorganic = Inorganic::new(captured_value, &raw_metadata.clone());
-                    last_parsed_type = ObjectType::Inorganic;
-                }
-                "MATERIAL_TEMPLATE" => {
-                    // Starting a new material template, so we can just add a material template to the list.
-                    if started {
-                        // We need to add the material template to the list.
-                        created_raws.push(Box::new(temp_material_template.clone()));
-                    } else {
-                        started = true;
-                    }
-                    // We haven't started a material template yet, so we need to start one.
-                    temp_material_template =
-                        MaterialTemplate::new(captured_value, &raw_metadata.clone());
-                    last_parsed_type = ObjectType::MaterialTemplate;
-                }
-                "CREATURE_GRAPHICS"
-                | "CREATURE_CASTE_GRAPHICS"
-                | "TILE_GRAPHICS"
-                | "PLANT_GRAPHICS" => {
-                    // Starting a new graphic, so we can just add a graphic to the list.
-                    if started {
-                        // We need to add the graphic to the list.
-                        created_raws.push(Box::new(temp_graphic.clone()));
-                    } else {
-                        started = true;
-                    }
-                    // We haven't started a graphic yet, so we need to start one.
-
-                    last_parsed_type = ObjectType::Graphics;
-                    last_graphic_type = *GRAPHIC_TYPE_TAGS
-                        .get(captured_key)
-                        .unwrap_or(&GraphicType::Unknown);
-
-                    temp_graphic =
-                        Graphic::new(captured_value, &raw_metadata.clone(), last_graphic_type);
-                }
-                "TILE_PAGE" => {
-                    if started {
-                        // We need to add the tile page to the list.
-                        created_raws.push(Box::new(temp_tile_page.clone()));
-                    } else {
-                        started = true;
-                    }
-                    // We haven't started a tile page yet, so we need to start one.
-                    temp_tile_page = TilePage::new(captured_value, &raw_metadata.clone());
-                    last_parsed_type = ObjectType::TilePage;
-                }
-                "ENTITY" => {
-                    // Starting a new entity, so we can just add an entity to the list.
-                    if started {
-                        // We need to add the entity to the list.
-                        created_raws.push(Box::new(temp_entity.clone()));
-                    } else {
-                        started = true;
-                    }
-                    // We haven't started an entity yet, so we need to start one.
-                    temp_entity = Entity::new(captured_value, &raw_metadata.clone());
-                    last_parsed_type = ObjectType::Entity;
+    }
+
+    /// Read a token, which either starts an object of the file's object type or is a tag for the
+    /// object that was started last.
+    fn read_token(&mut self, key: &str, value: &str) {
+        let started_object = match self.object_type {
+            ObjectType::Creature => self.read_creature_token(key, value),
+            ObjectType::Plant => self.read_plant_token(key, value),
+            ObjectType::Inorganic => self.read_inorganic_token(key, value),
+            ObjectType::Graphics | ObjectType::TilePage => self.read_graphics_token(key, value),
+            ObjectType::Item => self.read_item_token(key, value),
+            ObjectType::Building => self.read_building_token(key, value),
+            ObjectType::Language => self.read_language_token(key, value),
+            _ => self.read_object_token(key, value),
+        };
+        if !started_object {
+            self.parse_tag(key, value);
+        }
+    }
+
+    /// Creature files have creatures and their castes, and the patches and cuts for creatures
+    /// defined elsewhere.
+    fn read_creature_token(&mut self, key: &str, value: &str) -> bool {
+        match key {
+            "CREATURE" => {
+                // A SELECT_CREATURE or CUT_CREATURE may have come between the creatures, so
+                // finish the previous creature whatever was parsed last.
+                let creature = Creature::new(value, &self.raw_metadata);
+                start_object(&mut self.created_raws, &mut self.creature, creature);
+                self.last_parsed_type = ObjectType::Creature;
+            }
+            "SELECT_CREATURE" => {
+                let select_creature = SelectCreature::new(value, &self.raw_metadata);
+                start_object(
+                    &mut self.created_raws,
+                    &mut self.select_creature,
+                    select_creature,
+                );
+                self.last_parsed_type = ObjectType::SelectCreature;
+            }
+            "CUT_CREATURE" => self.add_cut_object(value, ObjectType::CutCreature),
+            "CASTE" | "SELECT_CASTE" if self.last_parsed_type == ObjectType::SelectCreature => {
+                // Castes in a SELECT_CREATURE are part of the patch, not of the creature which was
+                // parsed before it.
+                self.select_creature.parse_tag(key, value);
+            }
+            "CASTE" => {
+                // Starting a new caste, so we can just add a caste to the last creature we started
+                // (unless it is saving the tokens after a creature variation for later).
+                if (self.last_parsed_type == ObjectType::CreatureCaste
+                    || self.last_parsed_type == ObjectType::Creature)
+                    && !self.creature.save_tag_after_creature_variation(key, value)
+                {
+                    self.creature.add_caste(value);
                 }
-                _ => {
-                    // This should be a tag for the current object.
-                    // We should check if we have a current object, and if we do, we should add the tag to it.
-                    // If we haven't started yet, we should do nothing.
-                    if started {
-                        match last_parsed_type {
-                            ObjectType::Creature | ObjectType::CreatureCaste => {
-                                // We have a creature, so we can add a tag to it.
-                                temp_creature.parse_tag(captured_key, captured_value);
-                            }
-                            ObjectType::SelectCreature => {
-                                // We have a creature, so we can add a tag to it.
-                                temp_select_creature.parse_tag(captured_key, captured_value);
-                            }
-                            ObjectType::Plant => {
-                                // We have a plant, so we can add a tag to it.
-                                temp_plant.parse_tag(captured_key, captured_value);
-                            }
-                            ObjectType::Inorganic => {
-                                // We have an inorganic, so we can add a tag to it.
-                                temp_inorganic.parse_tag(captured_key, captured_value);
-                            }
-                            ObjectType::MaterialTemplate => {
-                                // We have a material template, so we can add a tag to it.
-                                temp_material_template.parse_tag(captured_key, captured_value);
-                            }
-                            ObjectType::Graphics => {
-                                // We have a graphic, so we can add a tag to it.
-                                if temp_graphic.get_graphic_type() == GraphicType::Tile {
-                                    // Update graphic type (every line should have a graphic type tag)
-                                    last_graphic_type = *GRAPHIC_TYPE_TAGS
-                                        .get(captured_key)
-                                        .unwrap_or(&GraphicType::Unknown);
-                                }
-
-                                temp_graphic.parse_sprite_from_tag(
-                                    captured_key,
-                                    captured_value,
-                                    last_graphic_type,
-                                );
-                            }
-                            ObjectType::TilePage => {
-                                // We have a tile page, so we can add a tag to it.
-                                temp_tile_page.parse_tag(captured_key, captured_value);
-                            }
-                            ObjectType::Entity => {
-                                // We have an entity, so we can add a tag to it.
-                                temp_entity.parse_tag(captured_key, captured_value);
-                            }
-                            _ => {
-                                // We don't have a known raw yet. So do nothing.
-                            }
-                        }
-                    }
+                self.last_parsed_type = ObjectType::CreatureCaste;
+            }
+            "SELECT_CASTE" => {
+                if !self.creature.save_tag_after_creature_variation(key, value) {
+                    self.creature.select_caste(value);
                 }
             }
+            _ => return false,
         }
+        true
     }
-    if started {
-        // If we did indeed start capture, we need to complete the final raw by adding it to the list
-        if !temp_creature.is_empty() {
-            created_raws.push(Box::new(temp_creature.clone()));
-        }
-        if !temp_select_creature.is_empty() {
-            created_raws.push(Box::new(temp_select_creature.clone()));
-        }
-        if !temp_plant.is_empty() {
-            created_raws.push(Box::new(temp_plant.clone()));
+
+    /// Plant files have plants and the patches for plants defined elsewhere.
+    fn read_plant_token(&mut self, key: &str, value: &str) -> bool {
+        match key {
+            "PLANT" => {
+                let plant = Plant::new(value, &self.raw_metadata);
+                start_object(&mut self.created_raws, &mut self.plant, plant);
+                self.last_parsed_type = ObjectType::Plant;
+            }
+            "SELECT_PLANT" => self.start_select_object(value, ObjectType::SelectPlant),
+            _ => return false,
         }
-        if !temp_inorganic.is_empty() {
-            created_raws.push(Box::new(temp_inorganic.clone()));
+        true
+    }
+
+    /// Inorganic files have inorganics, and the patches and cuts for inorganics defined elsewhere.
+    fn read_inorganic_token(&mut self, key: &str, value: &str) -> bool {
+        match key {
+            "INORGANIC" => {
+                let inorganic = Inorganic::new(value, &self.raw_metadata);
+                start_object(&mut self.created_raws, &mut self.inorganic, inorganic);
+                self.last_parsed_type = ObjectType::Inorganic;
+            }
+            "SELECT_INORGANIC" => self.start_select_object(value, ObjectType::SelectInorganic),
+            "CUT_INORGANIC" => self.add_cut_object(value, ObjectType::CutInorganic),
+            _ => return false,
         }
-        if !temp_material_template.is_empty() {
-            created_raws.push(Box::new(temp_material_template.clone()));
+        true
+    }
+
+    /// A patch to a plant or inorganic defined elsewhere, applied after parsing.
+    fn start_select_object(&mut self, value: &str, select_type: ObjectType) {
+        let select_object = SelectObject::new(value, &self.raw_metadata, select_type.clone());
+        start_object(
+            &mut self.created_raws,
+            &mut self.select_object,
+            select_object,
+        );
+        self.last_parsed_type = select_type;
+    }
+
+    /// A cut has no tags, so it can be added to the list right away.
+    fn add_cut_object(&mut self, value: &str, cut_type: ObjectType) {
+        diagnostics::set_object(value);
+        self.created_raws.push(Box::new(CutObject::new(
+            value,
+            &self.raw_metadata,
+            cut_type.clone(),
+        )));
+        self.last_parsed_type = cut_type;
+    }
+
+    /// Graphics files have tile pages and the graphics of the different kinds of objects.
+    fn read_graphics_token(&mut self, key: &str, value: &str) -> bool {
+        match key {
+            "CREATURE_GRAPHICS"
+            | "CREATURE_CASTE_GRAPHICS"
+            | "TILE_GRAPHICS"
+            | "PLANT_GRAPHICS"
+            | "CUSTOM_WORKSHOP_GRAPHICS" => {
+                self.last_graphic_type =
+                    *GRAPHIC_TYPE_TAGS.get(key).unwrap_or(&GraphicType::Unknown);
+                let graphic = Graphic::new(value, &self.raw_metadata, self.last_graphic_type);
+                start_object(&mut self.created_raws, &mut self.graphic, graphic);
+                self.last_parsed_type = ObjectType::Graphics;
+            }
+            "TILE_PAGE" => {
+                let tile_page = TilePage::new(value, &self.raw_metadata);
+                start_object(&mut self.created_raws, &mut self.tile_page, tile_page);
+                self.last_parsed_type = ObjectType::TilePage;
+            }
+            _ => return false,
         }
-        if !temp_graphic.is_empty() {
-            created_raws.push(Box::new(temp_graphic.clone()));
+        true
+    }
+
+    /// Item files are all \[OBJECT:ITEM\], so the kind of item comes from the token.
+    fn read_item_token(&mut self, key: &str, value: &str) -> bool {
+        if !key.starts_with("ITEM_") {
+            return false;
         }
-        if !temp_tile_page.is_empty() {
-            created_raws.push(Box::new(temp_tile_page.clone()));
+        let Some(item_type) = OBJECT_TOKENS.get(key).cloned() else {
+            return false;
+        };
+        let item = Item::new(value, &self.raw_metadata, item_type);
+        start_object(&mut self.created_raws, &mut self.item, item);
+        self.last_parsed_type = ObjectType::Item;
+        true
+    }
+
+    /// Building files are all \[OBJECT:BUILDING\], so the kind of building comes from the token.
+    fn read_building_token(&mut self, key: &str, value: &str) -> bool {
+        let building_type = match key {
+            "BUILDING_WORKSHOP" => ObjectType::BuildingWorkshop,
+            "BUILDING_FURNACE" => ObjectType::BuildingFurnace,
+            _ => return false,
+        };
+        let building = Building::new(value, &self.raw_metadata, building_type);
+        start_object(&mut self.created_raws, &mut self.building, building);
+        self.last_parsed_type = ObjectType::Building;
+        true
+    }
+
+    /// Words, translations and symbols can share a language file, so each keeps its own object
+    /// and is added when the next of the same kind starts.
+    fn read_language_token(&mut self, key: &str, value: &str) -> bool {
+        match key {
+            "WORD" => {
+                let word = Word::new(value, &self.raw_metadata);
+                start_object(&mut self.created_raws, &mut self.word, word);
+                self.last_parsed_type = ObjectType::Word;
+            }
+            "TRANSLATION" => {
+                let translation = Translation::new(value, &self.raw_metadata);
+                start_object(&mut self.created_raws, &mut self.translation, translation);
+                self.last_parsed_type = ObjectType::Translation;
+            }
+            "SYMBOL" => {
+                let symbol = Symbol::new(value, &self.raw_metadata);
+                start_object(&mut self.created_raws, &mut self.symbol, symbol);
+                self.last_parsed_type = ObjectType::Symbol;
+            }
+            _ => return false,
         }
-        if !temp_entity.is_empty() {
-            created_raws.push(Box::new(temp_entity.clone()));
+        true
+    }
+
+    /// The other files have one kind of object, of the file's object type.
+    fn read_object_token(&mut self, key: &str, value: &str) -> bool {
+        let metadata = &self.raw_metadata;
+        let created_raws = &mut self.created_raws;
+        match (&self.object_type, key) {
+            (ObjectType::Entity, "ENTITY") => {
+                start_object(created_raws, &mut self.entity, Entity::new(value, metadata));
+            }
+            (ObjectType::MaterialTemplate, "MATERIAL_TEMPLATE") => {
+                let material_template = MaterialTemplate::new(value, metadata);
+                start_object(created_raws, &mut self.material_template, material_template);
+            }
+            (ObjectType::CreatureVariation, "CREATURE_VARIATION") => {
+                let creature_variation = CreatureVariation::new(value, metadata);
+                start_object(
+                    created_raws,
+                    &mut self.creature_variation,
+                    creature_variation,
+                );
+            }
+            (ObjectType::Reaction, "REACTION") => {
+                start_object(
+                    created_raws,
+                    &mut self.reaction,
+                    Reaction::new(value, metadata),
+                );
+            }
+            (ObjectType::Body, "BODY") => {
+                start_object(created_raws, &mut self.body, Body::new(value, metadata));
+            }
+            (ObjectType::BodyDetailPlan, "BODY_DETAIL_PLAN") => {
+                let body_detail_plan = BodyDetailPlan::new(value, metadata);
+                start_object(created_raws, &mut self.body_detail_plan, body_detail_plan);
+            }
+            (ObjectType::TissueTemplate, "TISSUE_TEMPLATE") => {
+                let tissue_template = TissueTemplate::new(value, metadata);
+                start_object(created_raws, &mut self.tissue_template, tissue_template);
+            }
+            (ObjectType::Interaction, "INTERACTION") => {
+                let interaction = Interaction::new(value, metadata);
+                start_object(created_raws, &mut self.interaction, interaction);
+            }
+            (ObjectType::DescriptorColor, "COLOR") => {
+                let color = DescriptorColor::new(value, metadata);
+                start_object(created_raws, &mut self.descriptor_color, color);
+            }
+            (ObjectType::DescriptorPattern, "COLOR_PATTERN") => {
+                let pattern = DescriptorPattern::new(value, metadata);
+                start_object(created_raws, &mut self.descriptor_pattern, pattern);
+            }
+            (ObjectType::DescriptorShape, "SHAPE") => {
+                let shape = DescriptorShape::new(value, metadata);
+                start_object(created_raws, &mut self.descriptor_shape, shape);
+            }
+            (ObjectType::Palette, "PALETTE") => {
+                start_object(
+                    created_raws,
+                    &mut self.palette,
+                    Palette::new(value, metadata),
+                );
+            }
+            (ObjectType::Music, "MUSIC") => {
+                start_object(created_raws, &mut self.music, Music::new(value, metadata));
+            }
+            (ObjectType::Sound, "SOUND") => {
+                start_object(created_raws, &mut self.sound, Sound::new(value, metadata));
+            }
+            (ObjectType::TextSet, "TEXT_SET") => {
+                start_object(
+                    created_raws,
+                    &mut self.text_set,
+                    TextSet::new(value, metadata),
+                );
+            }
+            _ => return false,
         }
+        self.last_parsed_type = self.object_type.clone();
+        true
     }
 
-    log::debug!(
-        "parse_raw_file_with_info: Parsed {} raws from {}",
-        created_raws.len(),
-        raw_filename
-    );
+    /// Add a tag to the object that was started last. Tags before the first object are ignored.
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        match self.last_parsed_type {
+            ObjectType::Creature | ObjectType::CreatureCaste => self.creature.parse_tag(key, value),
+            ObjectType::SelectCreature => self.select_creature.parse_tag(key, value),
+            ObjectType::Plant => self.plant.parse_tag(key, value),
+            ObjectType::Inorganic => self.inorganic.parse_tag(key, value),
+            ObjectType::SelectPlant | ObjectType::SelectInorganic => {
+                self.select_object.parse_tag(key, value);
+            }
+            ObjectType::MaterialTemplate => self.material_template.parse_tag(key, value),
+            ObjectType::Graphics => {
+                if self.graphic.get_graphic_type() == GraphicType::Tile {
+                    // Update graphic type (every line should have a graphic type tag)
+                    self.last_graphic_type =
+                        *GRAPHIC_TYPE_TAGS.get(key).unwrap_or(&GraphicType::Unknown);
+                }
+                self.graphic
+                    .parse_sprite_from_tag(key, value, self.last_graphic_type);
+            }
+            ObjectType::TilePage => self.tile_page.parse_tag(key, value),
+            ObjectType::Entity => self.entity.parse_tag(key, value),
+            ObjectType::CreatureVariation => self.creature_variation.parse_tag(key, value),
+            ObjectType::Item => self.item.parse_tag(key, value),
+            ObjectType::Reaction => self.reaction.parse_tag(key, value),
+            ObjectType::Building => self.building.parse_tag(key, value),
+            ObjectType::Body => self.body.parse_tag(key, value),
+            ObjectType::BodyDetailPlan => self.body_detail_plan.parse_tag(key, value),
+            ObjectType::TissueTemplate => self.tissue_template.parse_tag(key, value),
+            ObjectType::Word => self.word.parse_tag(key, value),
+            ObjectType::Translation => self.translation.parse_tag(key, value),
+            ObjectType::Symbol => self.symbol.parse_tag(key, value),
+            ObjectType::Interaction => self.interaction.parse_tag(key, value),
+            ObjectType::DescriptorColor => self.descriptor_color.parse_tag(key, value),
+            ObjectType::DescriptorPattern => self.descriptor_pattern.parse_tag(key, value),
+            ObjectType::DescriptorShape => self.descriptor_shape.parse_tag(key, value),
+            ObjectType::Palette => self.palette.parse_tag(key, value),
+            ObjectType::Music => self.music.parse_tag(key, value),
+            ObjectType::Sound => self.sound.parse_tag(key, value),
+            ObjectType::TextSet => self.text_set.parse_tag(key, value),
+            _ => {
+                // We don't have a known raw yet. So do nothing.
+            }
+        }
+    }
 
-    created_raws
+    /// Finish the objects that are still being read, and return all the raws from the file.
+    fn finish(mut self) -> Vec<Box<dyn RawObject>> {
+        let unfinished: [Box<dyn RawObject>; 27] = [
+            Box::new(self.creature),
+            Box::new(self.select_creature),
+            Box::new(self.plant),
+            Box::new(self.inorganic),
+            Box::new(self.select_object),
+            Box::new(self.material_template),
+            Box::new(self.graphic),
+            Box::new(self.tile_page),
+            Box::new(self.entity),
+            Box::new(self.creature_variation),
+            Box::new(self.item),
+            Box::new(self.reaction),
+            Box::new(self.building),
+            Box::new(self.body),
+            Box::new(self.body_detail_plan),
+            Box::new(self.tissue_template),
+            Box::new(self.word),
+            Box::new(self.translation),
+            Box::new(self.symbol),
+            Box::new(self.interaction),
+            Box::new(self.descriptor_color),
+            Box::new(self.descriptor_pattern),
+            Box::new(self.descriptor_shape),
+            Box::new(self.palette),
+            Box::new(self.music),
+            Box::new(self.sound),
+            Box::new(self.text_set),
+        ];
+        self.created_raws
+            .extend(unfinished.into_iter().filter(|raw| !raw.is_empty()));
+        self.created_raws
+    }
 }
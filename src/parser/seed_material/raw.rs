@@ -3,10 +3,11 @@ use serde::{Deserialize, Serialize};
 use crate::parser::{color::Color, names::SingPlurName};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct SeedMaterial {
     name: SingPlurName,
     color: Color,
@@ -17,6 +18,15 @@ impl SeedMaterial {
     pub fn is_empty(&self) -> bool {
         self.name.is_empty() && self.color.is_default() && self.material.is_empty()
     }
+    /// The value of a `[SEED:...]` token for the seed (the reverse of `from_value`).
+    pub fn to_value(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.name.to_value(),
+            self.color.to_value(),
+            self.material
+        )
+    }
     pub fn from_value(value: &str) -> SeedMaterial {
         // Example seed tag:
         // [SEED:apricot pit:apricot pits:6:0:0:LOCAL_PLANT_MAT:SEED]
@@ -9,9 +9,10 @@ use crate::parser::{
 };
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct SelectCreature {
     #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
     metadata: RawMetadata,
@@ -37,6 +38,10 @@ impl SelectCreature {
     pub fn empty() -> Self {
         Self::default()
     }
+    /// Returns the tags to apply to the creature, as `KEY:VALUE`.
+    pub fn get_tags(&self) -> &[String] {
+        self.tags.as_slice()
+    }
 }
 
 #[typetag::serde]
@@ -51,7 +56,7 @@ impl RawObject for SelectCreature {
         &self.identifier
     }
     fn is_empty(&self) -> bool {
-        false
+        self.identifier.is_empty()
     }
     fn get_type(&self) -> &ObjectType {
         &ObjectType::SelectCreature
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+/// A patch to an object defined elsewhere, from a `[SELECT_PLANT:...]` or `[SELECT_INORGANIC:...]`
+/// token. The tags after the token are kept as they are, and are parsed by the target object when
+/// the patch is applied (see `apply_select_objects`).
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SelectObject {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+    /// Either `SelectPlant` or `SelectInorganic`
+    object_type: ObjectType,
+
+    /// The tags to apply to the target, as (key, value)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<(String, String)>,
+}
+
+impl SelectObject {
+    pub fn new(identifier: &str, metadata: &RawMetadata, object_type: ObjectType) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &object_type),
+            object_type,
+            ..Self::default()
+        }
+    }
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn get_tags(&self) -> &[(String, String)] {
+        self.tags.as_slice()
+    }
+    /// Returns the type of object the patch applies to, e.g. `Plant` for a `SELECT_PLANT`.
+    pub fn get_target_type(&self) -> ObjectType {
+        match self.object_type {
+            ObjectType::SelectCreature => ObjectType::Creature,
+            ObjectType::SelectPlant => ObjectType::Plant,
+            ObjectType::SelectInorganic => ObjectType::Inorganic,
+            _ => ObjectType::Unknown,
+        }
+    }
+}
+
+#[typetag::serde]
+impl RawObject for SelectObject {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &self.object_type
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        self.tags.push((String::from(key), String::from(value)));
+    }
+}
+
+impl Searchable for SelectObject {
+    fn get_search_vec(&self) -> Vec<String> {
+        let vec = vec![
+            self.get_identifier().to_string(),
+            format!("{:?}", self.get_type()),
+        ];
+
+        clean_search_vec(vec.as_slice())
+    }
+}
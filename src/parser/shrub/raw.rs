@@ -1,16 +1,19 @@
 use serde::{Deserialize, Serialize};
 
 use crate::parser::color::Color;
+use crate::parser::diagnostics::{self, DiagnosticSeverity};
 use crate::parser::seed_material::raw::SeedMaterial;
 use crate::parser::serializer_helper;
+use crate::parser::writer::{RawWriter, ToRawText};
 
 use super::phf_table::SHRUB_TOKENS;
 use super::tokens::{SeasonToken, ShrubToken};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Shrub {
     /// Allows the plant to grow in farm plots during the given season.
     /// If the plant is a surface plant, allows it to grow in the wild during this season; wild surface plants without
@@ -92,23 +95,45 @@ pub struct Shrub {
     extract_barrel: String,
 }
 
-impl Shrub {
-    pub fn new() -> Shrub {
-        Shrub {
+impl Default for Shrub {
+    fn default() -> Self {
+        Self {
+            growing_season: Vec::new(),
             grow_duration: 300,
+            value: 0,
             picked_tile: 231,
             dead_picked_tile: 169,
             shrub_tile: 34,
             dead_shrub_tile: 34,
             cluster_size: 5,
+            picked_color: Color::default(),
+            dead_picked_color: Color::default(),
+            shrub_color: Color::default(),
+            dead_shrub_color: Color::default(),
             shrub_drown_level: 4,
-            ..Shrub::default()
+            drink: String::new(),
+            mill: String::new(),
+            thread: String::new(),
+            seed: SeedMaterial::default(),
+            extract_still_vial: String::new(),
+            extract_vial: String::new(),
+            extract_barrel: String::new(),
         }
     }
+}
+
+impl Shrub {
+    pub fn new() -> Shrub {
+        Shrub::default()
+    }
     #[allow(clippy::too_many_lines)]
     pub fn parse_tag(&mut self, key: &str, value: &str) {
         let Some(tag) = SHRUB_TOKENS.get(key) else {
             log::warn!("Unknown shrub token: {}", key);
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Unknown shrub tag {key}"),
+            );
             return;
         };
 
@@ -232,7 +257,84 @@ impl Shrub {
             }
             ShrubToken::Unknown => {
                 log::warn!("Unknown shrub token: {}", key);
+                diagnostics::report(
+                    DiagnosticSeverity::Warning,
+                    &format!("Unknown shrub tag {key}"),
+                );
             }
         }
     }
 }
+
+impl ToRawText for Shrub {
+    /// Write the shrub tokens of a plant. Values which are the same as the defaults are not
+    /// written.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &SHRUB_TOKENS;
+
+        for season in &self.growing_season {
+            let tag = match season {
+                SeasonToken::Spring => ShrubToken::Spring,
+                SeasonToken::Summer => ShrubToken::Summer,
+                SeasonToken::Autumn => ShrubToken::Autumn,
+                SeasonToken::Winter => ShrubToken::Winter,
+                SeasonToken::Unknown => continue,
+            };
+            writer.token_for(tokens, &tag, "");
+        }
+        let values = [
+            (ShrubToken::GrowDuration, self.grow_duration, 300),
+            (ShrubToken::Value, self.value, 0),
+            (ShrubToken::PickedTile, u32::from(self.picked_tile), 231),
+            (
+                ShrubToken::DeadPickedTile,
+                u32::from(self.dead_picked_tile),
+                169,
+            ),
+            (ShrubToken::ShrubTile, u32::from(self.shrub_tile), 34),
+            (
+                ShrubToken::DeadShrubTile,
+                u32::from(self.dead_shrub_tile),
+                34,
+            ),
+            (ShrubToken::ClusterSize, self.cluster_size, 5),
+            (
+                ShrubToken::ShrubDrownLevel,
+                u32::from(self.shrub_drown_level),
+                4,
+            ),
+        ];
+        for (tag, value, default) in values {
+            if value != default {
+                writer.token_for(tokens, &tag, value);
+            }
+        }
+        let colors = [
+            (ShrubToken::PickedColor, &self.picked_color),
+            (ShrubToken::DeadPickedColor, &self.dead_picked_color),
+            (ShrubToken::ShrubColor, &self.shrub_color),
+            (ShrubToken::DeadShrubColor, &self.dead_shrub_color),
+        ];
+        for (tag, color) in colors {
+            if !color.is_default() {
+                writer.token_for(tokens, &tag, color.to_value());
+            }
+        }
+        let products = [
+            (ShrubToken::Drink, &self.drink),
+            (ShrubToken::Mill, &self.mill),
+            (ShrubToken::Thread, &self.thread),
+            (ShrubToken::ExtractStillVial, &self.extract_still_vial),
+            (ShrubToken::ExtractVial, &self.extract_vial),
+            (ShrubToken::ExtractBarrel, &self.extract_barrel),
+        ];
+        for (tag, product) in products {
+            if !product.is_empty() {
+                writer.token_for(tokens, &tag, product);
+            }
+        }
+        if !self.seed.is_empty() {
+            writer.token_for(tokens, &ShrubToken::Seed, self.seed.to_value());
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use super::tokens::SoundToken;
+
+pub static SOUND_TOKENS: phf::Map<&'static str, SoundToken> = phf::phf_map! {
+    "FILE" => SoundToken::File,
+    "CONDITION" => SoundToken::Condition,
+};
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum SoundToken {
+    /// Arguments: file path
+    ///
+    /// A file the sound is played from, e.g. `sound/anvil.ogg`. There can be several.
+    File,
+    /// Arguments: condition
+    ///
+    /// When the sound is played. There can be several.
+    Condition,
+    #[default]
+    Unknown,
+}
@@ -2,16 +2,19 @@ use serde::{Deserialize, Serialize};
 
 use crate::parser::{
     creature_effect::phf_table::CREATURE_EFFECT_TOKENS,
+    diagnostics::{self, DiagnosticSeverity},
     searchable::{clean_search_vec, Searchable},
     serializer_helper,
+    writer::{write_tags, RawWriter, ToRawText},
 };
 
 use super::{phf_table::SYNDROME_TOKEN, tokens::SyndromeToken};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Syndrome {
     /// Seen the \[SYN_IDENTIFIER:INEBRIATION\] tag in material_templates.txt
     #[serde(skip_serializing_if = "String::is_empty")]
@@ -98,6 +101,10 @@ impl Syndrome {
 
             SyndromeToken::Unknown => {
                 log::warn!("Unknown syndrome token: {}", key);
+                diagnostics::report(
+                    DiagnosticSeverity::Warning,
+                    &format!("Unknown syndrome tag {key}"),
+                );
             }
             SyndromeToken::Class => self.classes.push(String::from(value)),
             SyndromeToken::NoHospital => self.tags.push(SyndromeToken::NoHospital),
@@ -105,6 +112,57 @@ impl Syndrome {
     }
 }
 
+impl ToRawText for Syndrome {
+    /// Write the `[SYNDROME]` token and the syndrome tokens which follow it. The creature effects
+    /// of the syndrome are not kept by the library, so they are not written.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        writer.tag("SYNDROME");
+        writer.indent();
+
+        let tokens = &SYNDROME_TOKEN;
+        if !self.name.is_empty() {
+            writer.token_for(tokens, &SyndromeToken::Name, &self.name);
+        }
+        if !self.identifier.is_empty() {
+            writer.token_for(tokens, &SyndromeToken::Identifier, &self.identifier);
+        }
+        for class in &self.classes {
+            writer.token_for(tokens, &SyndromeToken::Class, class);
+        }
+        for affected_class in &self.affected_classes {
+            writer.token_for(tokens, &SyndromeToken::AffectedClass, affected_class);
+        }
+        for immune_class in &self.immune_classes {
+            writer.token_for(tokens, &SyndromeToken::ImmuneClass, immune_class);
+        }
+        for (creature, caste) in &self.affected_creatures {
+            writer.token_for(
+                tokens,
+                &SyndromeToken::AffectedCreature,
+                format!("{creature}:{caste}"),
+            );
+        }
+        for (creature, caste) in &self.immune_creatures {
+            writer.token_for(
+                tokens,
+                &SyndromeToken::ImmuneCreature,
+                format!("{creature}:{caste}"),
+            );
+        }
+        let [min, max] = self.concentration_added;
+        if min != 0 || max != 0 {
+            writer.token_for(
+                tokens,
+                &SyndromeToken::ConcentrationAdded,
+                format!("{min}:{max}"),
+            );
+        }
+        write_tags(writer, tokens, &self.tags);
+
+        writer.outdent();
+    }
+}
+
 impl Searchable for Syndrome {
     fn get_search_vec(&self) -> Vec<String> {
         let mut vec = Vec::new();
@@ -1,10 +1,11 @@
-use crate::parser::serializer_helper;
+use crate::parser::{material::tokens::MaterialProperty, serializer_helper};
 use serde::{Deserialize, Serialize};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Temperatures {
     /// This determines how long it takes the material to heat up or cool down.
     /// A material with a high specific heat capacity will hold more heat and affect its surroundings more
@@ -50,6 +51,30 @@ impl Temperatures {
             && self.cold_damage_point == 0
             && self.material_fixed_temperature == 0
     }
+    /// Overwrite any temperature in self with the temperatures which are set in other.
+    pub fn overwrite_temperatures(&mut self, other: &Temperatures) {
+        if other.specific_heat != 0 {
+            self.specific_heat = other.specific_heat;
+        }
+        if other.ignition_point != 0 {
+            self.ignition_point = other.ignition_point;
+        }
+        if other.melting_point != 0 {
+            self.melting_point = other.melting_point;
+        }
+        if other.boiling_point != 0 {
+            self.boiling_point = other.boiling_point;
+        }
+        if other.heat_damage_point != 0 {
+            self.heat_damage_point = other.heat_damage_point;
+        }
+        if other.cold_damage_point != 0 {
+            self.cold_damage_point = other.cold_damage_point;
+        }
+        if other.material_fixed_temperature != 0 {
+            self.material_fixed_temperature = other.material_fixed_temperature;
+        }
+    }
     pub fn update_specific_heat(&mut self, value: u32) {
         self.specific_heat = value;
     }
@@ -71,4 +96,22 @@ impl Temperatures {
     pub fn update_material_fixed_temperature(&mut self, value: u32) {
         self.material_fixed_temperature = value;
     }
+    /// Returns the temperatures which are set, with the material property token for each.
+    pub fn as_properties(&self) -> Vec<(MaterialProperty, u32)> {
+        [
+            (MaterialProperty::SpecificHeat, self.specific_heat),
+            (MaterialProperty::IgnitionPoint, self.ignition_point),
+            (MaterialProperty::MeltingPoint, self.melting_point),
+            (MaterialProperty::BoilingPoint, self.boiling_point),
+            (MaterialProperty::HeatDamagePoint, self.heat_damage_point),
+            (MaterialProperty::ColdDamagePoint, self.cold_damage_point),
+            (
+                MaterialProperty::MaterialFixedTemperature,
+                self.material_fixed_temperature,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, value)| *value != 0)
+        .collect()
+    }
 }
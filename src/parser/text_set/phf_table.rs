@@ -0,0 +1,5 @@
+use super::tokens::TextSetToken;
+
+pub static TEXT_SET_TOKENS: phf::Map<&'static str, TextSetToken> = phf::phf_map! {
+    "TEXT" => TextSetToken::Text,
+};
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{phf_table::TEXT_SET_TOKENS, tokens::TextSetToken};
+
+/// A set of text lines from an `[OBJECT:TEXT_SET]` raw file, e.g. `[TEXT_SET:GREETINGS]`, which
+/// the game picks lines from.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct TextSet {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    lines: Vec<String>,
+}
+
+impl TextSet {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(metadata, identifier, &ObjectType::TextSet),
+            ..Self::default()
+        }
+    }
+    pub fn get_lines(&self) -> &[String] {
+        self.lines.as_slice()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for TextSet {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        &self.identifier
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::TextSet
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = TEXT_SET_TOKENS.get(key) else {
+            log::debug!(
+                "TextSet::parse_tag: Unknown text set tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown text set tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            TextSetToken::Text => self.lines.push(String::from(value)),
+            TextSetToken::Unknown => {}
+        }
+    }
+}
+
+impl Searchable for TextSet {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.extend(self.lines.iter().cloned());
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum TextSetToken {
+    /// Arguments: text
+    ///
+    /// A line of text in the set. The text can contain colons.
+    Text,
+    #[default]
+    Unknown,
+}
@@ -1,12 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use super::color::Color;
+use super::{color::Color, writer::RawWriter};
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 #[allow(clippy::module_name_repetitions)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Tile {
     character: String,
     #[serde(skip_serializing_if = "String::is_empty")]
@@ -35,6 +36,41 @@ impl Tile {
     pub fn set_glow_character(&mut self, character: &str) {
         self.glow_character = String::from(character);
     }
+    pub fn get_character(&self) -> &str {
+        &self.character
+    }
+    pub fn get_alt_character(&self) -> &str {
+        &self.alt_character
+    }
+    pub fn get_color(&self) -> &Color {
+        &self.color
+    }
+    pub fn get_glow_character(&self) -> &str {
+        &self.glow_character
+    }
+    pub fn get_glow_color(&self) -> &Color {
+        &self.glow_color
+    }
+    /// Write a token for each part of the tile which is set.
+    ///
+    /// Arguments:
+    ///
+    /// * `writer`: The writer to write the tokens to
+    /// * `keys`: The keys of the tokens for the character, alternate character, color, glow
+    ///   character and glow color (in that order), e.g. `CREATURE_TILE`, `ALTTILE`, `COLOR`,
+    ///   `GLOWTILE` and `GLOWCOLOR` for a creature
+    pub fn write_tokens(&self, writer: &mut RawWriter, keys: [&str; 5]) {
+        let [character, alt_character, color, glow_character, glow_color] = keys;
+        writer.token_if_set(character, &self.character);
+        writer.token_if_set(alt_character, &self.alt_character);
+        if !self.color.is_default() {
+            writer.token(color, self.color.to_value());
+        }
+        writer.token_if_set(glow_character, &self.glow_character);
+        if !self.glow_color.is_default() {
+            writer.token(glow_color, self.glow_color.to_value());
+        }
+    }
     pub fn is_default(&self) -> bool {
         self.character.is_empty()
             && self.alt_character.is_empty()
@@ -0,0 +1,4 @@
+pub mod phf_table;
+pub mod raw;
+pub mod template_use;
+pub mod tokens;
@@ -0,0 +1,30 @@
+use super::tokens::TissueToken;
+
+pub static TISSUE_TOKENS: phf::Map<&'static str, TissueToken> = phf::phf_map! {
+    "TISSUE_NAME" => TissueToken::Name,
+    "TISSUE_MATERIAL" => TissueToken::Material,
+    "TISSUE_MAT_STATE" => TissueToken::MaterialState,
+    "RELATIVE_THICKNESS" => TissueToken::RelativeThickness,
+    "HEALING_RATE" => TissueToken::HealingRate,
+    "VASCULAR" => TissueToken::Vascular,
+    "PAIN_RECEPTORS" => TissueToken::PainReceptors,
+    "TISSUE_SHAPE" => TissueToken::Shape,
+    "INSULATION" => TissueToken::Insulation,
+    "SUBORDINATE_TO_TISSUE" => TissueToken::SubordinateToTissue,
+    "TISSUE_STYLE_UNIT_ID" => TissueToken::StyleUnitId,
+    "ARTERIES" => TissueToken::Arteries,
+    "CONNECTS" => TissueToken::Connects,
+    "CONNECTIVE_TISSUE_ANCHOR" => TissueToken::ConnectiveTissueAnchor,
+    "COSMETIC" => TissueToken::Cosmetic,
+    "FUNCTIONAL" => TissueToken::Functional,
+    "MAJOR_ARTERIES" => TissueToken::MajorArteries,
+    "NERVOUS" => TissueToken::Nervous,
+    "SCARS" => TissueToken::Scars,
+    "SETTABLE" => TissueToken::Settable,
+    "SPLINTABLE" => TissueToken::Splintable,
+    "STRUCTURAL" => TissueToken::Structural,
+    "STYLEABLE" => TissueToken::Styleable,
+    "THICKENS_ON_ENERGY_STORAGE" => TissueToken::ThickensOnEnergyStorage,
+    "THICKENS_ON_STRENGTH" => TissueToken::ThickensOnStrength,
+    "TISSUE_LEAKS" => TissueToken::TissueLeaks,
+};
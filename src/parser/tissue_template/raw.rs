@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{
+    diagnostics::{self, DiagnosticSeverity},
+    helpers::object_id::build_object_id_from_pieces,
+    names::SingPlurName,
+    object_types::ObjectType,
+    raws::{RawMetadata, RawObject},
+    searchable::{clean_search_vec, Searchable},
+    serializer_helper,
+};
+
+use super::{phf_table::TISSUE_TOKENS, tokens::TissueToken};
+
+/// A tissue template from an `[OBJECT:TISSUE_TEMPLATE]` raw file, e.g.
+/// `[TISSUE_TEMPLATE:SKIN_TEMPLATE]`.
+///
+/// Creatures give a name to a tissue made from a template with `[USE_TISSUE_TEMPLATE:SKIN:SKIN_TEMPLATE]`,
+/// and that name is used in the tissue layers of their body parts.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct TissueTemplate {
+    #[serde(skip_serializing_if = "serializer_helper::is_metadata_hidden")]
+    metadata: RawMetadata,
+    identifier: String,
+    object_id: String,
+
+    #[serde(skip_serializing_if = "SingPlurName::is_empty")]
+    name: SingPlurName,
+    /// The material of the tissue, e.g. `LOCAL_CREATURE_MAT:SKIN`.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    material: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    material_state: String,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    relative_thickness: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    healing_rate: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    vascular: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    pain_receptors: u32,
+    #[serde(skip_serializing_if = "serializer_helper::is_zero")]
+    insulation: u32,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    shape: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    subordinate_to_tissue: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    style_unit_id: String,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<TissueToken>,
+}
+
+impl TissueTemplate {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    pub fn new(identifier: &str, metadata: &RawMetadata) -> Self {
+        Self {
+            identifier: String::from(identifier),
+            metadata: metadata.clone(),
+            object_id: build_object_id_from_pieces(
+                metadata,
+                identifier,
+                &ObjectType::TissueTemplate,
+            ),
+            ..Self::default()
+        }
+    }
+    pub fn get_material(&self) -> &str {
+        &self.material
+    }
+    pub fn get_relative_thickness(&self) -> u32 {
+        self.relative_thickness
+    }
+    pub fn get_tags(&self) -> &[TissueToken] {
+        self.tags.as_slice()
+    }
+}
+
+#[typetag::serde]
+impl RawObject for TissueTemplate {
+    fn get_metadata(&self) -> &RawMetadata {
+        &self.metadata
+    }
+    fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    fn get_name(&self) -> &str {
+        if self.name.is_empty() {
+            return &self.identifier;
+        }
+        self.name.get_singular()
+    }
+    fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+    fn get_type(&self) -> &ObjectType {
+        &ObjectType::TissueTemplate
+    }
+    fn get_object_id(&self) -> &str {
+        self.object_id.as_str()
+    }
+    fn parse_tag(&mut self, key: &str, value: &str) {
+        let Some(tag) = TISSUE_TOKENS.get(key) else {
+            log::debug!(
+                "TissueTemplate::parse_tag: Unknown tissue tag {key} in {}",
+                self.identifier
+            );
+            diagnostics::report(
+                DiagnosticSeverity::Info,
+                &format!("Unknown tissue tag {key}"),
+            );
+            return;
+        };
+
+        match tag {
+            TissueToken::Name => {
+                self.name = SingPlurName::from_value(value);
+            }
+            TissueToken::Material => {
+                self.material = String::from(value);
+            }
+            TissueToken::MaterialState => {
+                self.material_state = String::from(value);
+            }
+            TissueToken::RelativeThickness => {
+                self.relative_thickness = value.parse().unwrap_or_default();
+            }
+            TissueToken::HealingRate => {
+                self.healing_rate = value.parse().unwrap_or_default();
+            }
+            TissueToken::Vascular => {
+                self.vascular = value.parse().unwrap_or_default();
+            }
+            TissueToken::PainReceptors => {
+                self.pain_receptors = value.parse().unwrap_or_default();
+            }
+            TissueToken::Insulation => {
+                self.insulation = value.parse().unwrap_or_default();
+            }
+            TissueToken::Shape => {
+                self.shape = String::from(value);
+            }
+            TissueToken::SubordinateToTissue => {
+                self.subordinate_to_tissue = String::from(value);
+            }
+            TissueToken::StyleUnitId => {
+                self.style_unit_id = String::from(value);
+            }
+            _ => {
+                self.tags.push(tag.clone());
+            }
+        }
+    }
+}
+
+impl Searchable for TissueTemplate {
+    fn get_search_vec(&self) -> Vec<String> {
+        let mut vec = Vec::new();
+
+        vec.push(self.identifier.clone());
+        vec.extend(self.name.as_vec());
+        vec.push(self.material.clone());
+        vec.extend(self.tags.iter().map(|tag| format!("{tag:?}")));
+
+        clean_search_vec(vec.as_slice())
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+/// A named use of a template, e.g. `[USE_TISSUE_TEMPLATE:SKIN:SKIN_TEMPLATE]` or
+/// `[ADD_MATERIAL:SKIN:SKIN_TEMPLATE]`. The name is what the rest of the creature uses to refer to it.
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct TemplateUse {
+    name: String,
+    template: String,
+}
+
+impl TemplateUse {
+    /// Create a template use from a token value like `SKIN:SKIN_TEMPLATE`.
+    pub fn from_value(value: &str) -> Self {
+        let (name, template) = value.split_once(':').unwrap_or((value, ""));
+        Self {
+            name: String::from(name),
+            template: String::from(template),
+        }
+    }
+    /// The value of the token for the template use, e.g. `SKIN:SKIN_TEMPLATE` (the reverse of
+    /// `from_value`).
+    pub fn to_value(&self) -> String {
+        format!("{}:{}", self.name, self.template)
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_template(&self) -> &str {
+        &self.template
+    }
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ts_rs::TS)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum TissueToken {
+    /// Arguments: name, plural name
+    ///
+    /// The name of the tissue. The plural name can be `NP` if the tissue has no plural.
+    Name,
+    /// Arguments: material token
+    ///
+    /// The material the tissue is made of, e.g. `[TISSUE_MATERIAL:LOCAL_CREATURE_MAT:SKIN]`.
+    Material,
+    /// Arguments: state
+    ///
+    /// The state of the tissue's material, e.g. `SOLID`.
+    MaterialState,
+    /// Arguments: thickness
+    ///
+    /// The thickness of the tissue relative to the other layers of a body part.
+    RelativeThickness,
+    /// Arguments: rate
+    ///
+    /// How quickly the tissue heals. Lower is faster.
+    HealingRate,
+    /// Arguments: value
+    ///
+    /// How much the tissue bleeds when damaged.
+    Vascular,
+    /// Arguments: value
+    ///
+    /// How much pain the tissue feels when damaged.
+    PainReceptors,
+    /// Arguments: shape
+    ///
+    /// The shape of the tissue, e.g. `LAYER`, `STRANDS` or `SCALES`.
+    Shape,
+    /// Arguments: value
+    ///
+    /// How well the tissue insulates the body.
+    Insulation,
+    /// Arguments: tissue
+    ///
+    /// The tissue is removed if the given tissue is removed.
+    SubordinateToTissue,
+    /// Arguments: identifier
+    ///
+    /// The style unit of the tissue, used for hair styling.
+    StyleUnitId,
+    /// The tissue contains arteries.
+    Arteries,
+    /// The tissue connects the body part to its parent.
+    Connects,
+    /// The tissue anchors connective tissue.
+    ConnectiveTissueAnchor,
+    /// The tissue is cosmetic and has no function.
+    Cosmetic,
+    /// The tissue has a function, and the body part stops working if it is destroyed.
+    Functional,
+    /// The tissue contains major arteries.
+    MajorArteries,
+    /// The tissue is nervous tissue.
+    Nervous,
+    /// The tissue scars when healed.
+    Scars,
+    /// The tissue can be set if broken.
+    Settable,
+    /// The tissue can be splinted if broken.
+    Splintable,
+    /// The tissue is structural, and the body part falls apart if it is destroyed.
+    Structural,
+    /// The tissue can be styled, like hair.
+    Styleable,
+    /// The tissue thickens when the creature gains energy storage (fat).
+    ThickensOnEnergyStorage,
+    /// The tissue thickens when the creature gains strength.
+    ThickensOnStrength,
+    /// The tissue leaks out when damaged.
+    TissueLeaks,
+    #[default]
+    Unknown,
+}
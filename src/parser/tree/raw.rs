@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-use crate::parser::{color::Color, names::Name, serializer_helper};
+use crate::parser::{
+    color::Color,
+    diagnostics::{self, DiagnosticSeverity},
+    names::Name,
+    serializer_helper,
+    writer::{write_tags, RawWriter, ToRawText},
+};
 
 use super::{
     phf_table::TREE_TOKENS,
@@ -8,9 +14,10 @@ use super::{
 };
 
 #[derive(ts_rs::TS)]
-#[ts(export)]
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+#[serde(default)]
 pub struct Tree {
     /// Tree will yield logs made of that material. Instead, if it's `[TREE:NONE]`, no logs will result.
     /// Materials are typically found in other raws..
@@ -114,17 +121,49 @@ pub struct Tree {
     tags: Vec<TreeToken>,
 }
 
-impl Tree {
-    pub fn new(material: &str) -> Self {
+impl Default for Tree {
+    fn default() -> Self {
         Self {
-            material: material.to_string(),
+            material: String::new(),
+            trunk_name: Name::default(),
             max_trunk_height: 1,
             max_trunk_diameter: 1,
             trunk_period: 1,
             trunk_width_period: 1,
+            branch_name: Name::default(),
+            branch_density: 0,
+            branch_radius: 0,
+            heavy_branches_name: Name::default(),
+            heavy_branch_density: 0,
+            heavy_branch_radius: 0,
+            trunk_branching: 0,
+            root_name: Name::default(),
+            root_density: 0,
+            root_radius: 0,
+            twigs_name: Name::default(),
+            twigs_placement: Vec::new(),
+            cap_name: Name::default(),
             cap_period: 1,
+            cap_radius: 0,
+            tree_tile: String::new(),
+            dead_tree_tile: String::new(),
+            sapling_tile: String::new(),
+            dead_sapling_tile: String::new(),
+            tree_color: Color::default(),
+            dead_tree_color: Color::default(),
+            sapling_color: Color::default(),
+            dead_sapling_color: Color::default(),
             sapling_drown_level: 4,
             tree_drown_level: 7,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl Tree {
+    pub fn new(material: &str) -> Self {
+        Self {
+            material: material.to_string(),
             twigs_placement: vec![TwigPlacement::SideBranches, TwigPlacement::AboveBranches],
             ..Default::default()
         }
@@ -136,6 +175,10 @@ impl Tree {
                 "TreeParsing: called `Option::unwrap()` on a `None` value for presumed tree tag: {}",
                 key
             );
+            diagnostics::report(
+                DiagnosticSeverity::Warning,
+                &format!("Unknown tree tag {key}"),
+            );
             return;
         };
 
@@ -397,3 +440,87 @@ impl Tree {
         }
     }
 }
+
+impl ToRawText for Tree {
+    /// Write the `[TREE:...]` token and the tree tokens of a plant. Values which are the same as
+    /// the defaults are not written.
+    fn write_raw_text(&self, writer: &mut RawWriter) {
+        let tokens = &TREE_TOKENS;
+        writer.token_for(tokens, &TreeToken::Tree, &self.material);
+
+        let names = [
+            (TreeToken::TrunkName, &self.trunk_name),
+            (TreeToken::BranchName, &self.branch_name),
+            (TreeToken::HeavyBranchesName, &self.heavy_branches_name),
+            (TreeToken::RootName, &self.root_name),
+            (TreeToken::TwigsName, &self.twigs_name),
+            (TreeToken::CapName, &self.cap_name),
+        ];
+        for (tag, name) in names {
+            if !name.is_empty() {
+                writer.token_for(tokens, &tag, name.to_value());
+            }
+        }
+        let values = [
+            (TreeToken::MaxTrunkHeight, self.max_trunk_height, 1),
+            (TreeToken::MaxTrunkDiameter, self.max_trunk_diameter, 1),
+            (TreeToken::TrunkPeriod, self.trunk_period, 1),
+            (TreeToken::TrunkWidthPeriod, self.trunk_width_period, 1),
+            (TreeToken::BranchDensity, self.branch_density, 0),
+            (TreeToken::BranchRadius, self.branch_radius, 0),
+            (TreeToken::HeavyBranchDensity, self.heavy_branch_density, 0),
+            (TreeToken::HeavyBranchRadius, self.heavy_branch_radius, 0),
+            (TreeToken::TrunkBranching, self.trunk_branching, 0),
+            (TreeToken::RootDensity, self.root_density, 0),
+            (TreeToken::RootRadius, self.root_radius, 0),
+            (TreeToken::CapPeriod, self.cap_period, 1),
+            (TreeToken::CapRadius, self.cap_radius, 0),
+            (TreeToken::SaplingDrownLevel, self.sapling_drown_level, 4),
+            (TreeToken::TreeDrownLevel, self.tree_drown_level, 7),
+        ];
+        for (tag, value, default) in values {
+            if value != default {
+                writer.token_for(tokens, &tag, value);
+            }
+        }
+        // Every tree starts with twigs on the side of and above its branches
+        for placement in self.twigs_placement.iter().skip(2) {
+            let tag = match placement {
+                TwigPlacement::SideBranches => TreeToken::TwigsSideBranches,
+                TwigPlacement::AboveBranches => TreeToken::TwigsAboveBranches,
+                TwigPlacement::BelowBranches => TreeToken::TwigsBelowBranches,
+                TwigPlacement::SideHeavyBranches => TreeToken::TwigsSideHeavyBranches,
+                TwigPlacement::AboveHeavyBranches => TreeToken::TwigsAboveHeavyBranches,
+                TwigPlacement::BelowHeavyBranches => TreeToken::TwigsBelowHeavyBranches,
+                TwigPlacement::SideTrunk => TreeToken::TwigsSideTrunk,
+                TwigPlacement::AboveTrunk => TreeToken::TwigsAboveTrunk,
+                TwigPlacement::BelowTrunk => TreeToken::TwigsBelowTrunk,
+                TwigPlacement::Unknown => continue,
+            };
+            writer.token_for(tokens, &tag, "");
+        }
+        let tiles = [
+            (TreeToken::TreeTile, &self.tree_tile),
+            (TreeToken::DeadTreeTile, &self.dead_tree_tile),
+            (TreeToken::SaplingTile, &self.sapling_tile),
+            (TreeToken::DeadSaplingTile, &self.dead_sapling_tile),
+        ];
+        for (tag, tile) in tiles {
+            if !tile.is_empty() {
+                writer.token_for(tokens, &tag, tile);
+            }
+        }
+        let colors = [
+            (TreeToken::TreeColor, &self.tree_color),
+            (TreeToken::DeadTreeColor, &self.dead_tree_color),
+            (TreeToken::SaplingColor, &self.sapling_color),
+            (TreeToken::DeadSaplingColor, &self.dead_sapling_color),
+        ];
+        for (tag, color) in colors {
+            if !color.is_default() {
+                writer.token_for(tokens, &tag, color.to_value());
+            }
+        }
+        write_tags(writer, tokens, &self.tags);
+    }
+}
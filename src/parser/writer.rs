@@ -0,0 +1,373 @@
+use std::{fmt::Display, path::Path};
+
+use crate::errors::ParserError;
+
+use super::{
+    creature::raw::Creature,
+    entity::raw::Entity,
+    graphics::{raw::Graphic, tile_page::TilePage},
+    inorganic::raw::Inorganic,
+    object_types::{ObjectType, OBJECT_TOKENS},
+    plant::raw::Plant,
+    raws::RawObject,
+    refs::DF_ENCODING,
+};
+
+/// Builds the text of a raw file, one token per line.
+///
+/// Object tokens (e.g. `[CREATURE:DWARF]`) start at the beginning of a line, and the tokens of
+/// the object are indented below them. Anything that groups tokens within an object (e.g. a
+/// `[CASTE:FEMALE]` or a material) indents the tokens which follow it by another level.
+///
+/// ```
+/// use dfraw_json_parser::parser::writer::RawWriter;
+///
+/// let mut writer = RawWriter::new();
+/// writer.begin_object("CREATURE", "CAT");
+/// writer.token("NAME", "cat:cats:feline");
+/// writer.tag("PET");
+///
+/// assert_eq!(writer.finish(), "\n[CREATURE:CAT]\n\t[NAME:cat:cats:feline]\n\t[PET]\n");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RawWriter {
+    text: String,
+    indent: usize,
+}
+
+impl RawWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Write the start of a raw file: the identifier of the file on the first line, and the
+    /// `[OBJECT:...]` token for the type of the objects in it.
+    ///
+    /// Arguments:
+    ///
+    /// * `raw_identifier`: The identifier of the raw file, which is also its file name (without
+    ///   the `.txt`), e.g. `creature_domestic`
+    /// * `object_type`: The type of the objects in the file
+    pub fn write_header(&mut self, raw_identifier: &str, object_type: &ObjectType) {
+        self.text.push_str(raw_identifier);
+        self.text.push_str("\n\n");
+        self.indent = 0;
+        self.token("OBJECT", object_type_key(object_type));
+    }
+    /// Start a new object with its `[KEY:IDENTIFIER]` token. The tokens written after it are
+    /// indented once.
+    pub fn begin_object(&mut self, key: &str, identifier: &str) {
+        self.text.push('\n');
+        self.indent = 0;
+        self.token(key, identifier);
+        self.indent = 1;
+    }
+    /// Write a token without a value, e.g. `[PET]`.
+    pub fn tag(&mut self, key: &str) {
+        self.token(key, "");
+    }
+    /// Write a token with a value, e.g. `[NAME:cat:cats:feline]`. If the value is empty, only the
+    /// key is written.
+    pub fn token<V: Display>(&mut self, key: &str, value: V) {
+        for _ in 0..self.indent {
+            self.text.push('\t');
+        }
+        let value = value.to_string();
+        self.text.push('[');
+        self.text.push_str(key);
+        if !value.is_empty() {
+            self.text.push(':');
+            self.text.push_str(&value);
+        }
+        self.text.push_str("]\n");
+    }
+    /// Write a token for a tag, with the key for the tag from the token table (see `token_key`).
+    /// Nothing is written if the tag isn't in the table.
+    pub fn token_for<T: PartialEq, V: Display>(
+        &mut self,
+        tokens: &'static phf::Map<&'static str, T>,
+        tag: &T,
+        value: V,
+    ) {
+        if let Some(key) = token_key(tokens, tag) {
+            self.token(key, value);
+        }
+    }
+    /// Write a token if the value isn't empty.
+    pub fn token_if_set(&mut self, key: &str, value: &str) {
+        if !value.is_empty() {
+            self.token(key, value);
+        }
+    }
+    /// Write a token for each of the values, e.g. a `[PREFSTRING:...]` for each pref string.
+    pub fn tokens<V: Display>(&mut self, key: &str, values: &[V]) {
+        for value in values {
+            self.token(key, value);
+        }
+    }
+    /// Write tokens which were kept as they were parsed, as key and value (see `ToRawText`).
+    pub fn raw_tokens(&mut self, raw_tokens: &[(String, String)]) {
+        for (key, value) in raw_tokens {
+            self.token(key, value);
+        }
+    }
+    /// Indent the tokens written after this by another level.
+    pub fn indent(&mut self) {
+        self.indent += 1;
+    }
+    /// Go back to the previous level of indentation.
+    pub fn outdent(&mut self) {
+        self.indent = self.indent.saturating_sub(1);
+    }
+    /// Returns the text written so far.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+    /// Returns the text of the raw file.
+    pub fn finish(self) -> String {
+        self.text
+    }
+}
+
+/// Implemented by the raws (and the parts of raws) which can be written back out as raw text.
+///
+/// The tokens are written from the fields of the raw, so they may be in a different order (and
+/// grouped differently) than in the raw file the raw was parsed from. Creatures and castes keep
+/// the tokens they can't write from their fields (tokens the library doesn't recognize, or doesn't
+/// keep the value of) as raw tokens, which are written back as they were, in the order they were
+/// parsed. The other types only write what they keep.
+pub trait ToRawText {
+    /// Write the tokens for this to the writer. Objects start with their own `[KEY:IDENTIFIER]`
+    /// token.
+    fn write_raw_text(&self, writer: &mut RawWriter);
+    /// Returns the tokens for this as raw text.
+    fn to_raw_text(&self) -> String {
+        let mut writer = RawWriter::new();
+        self.write_raw_text(&mut writer);
+        writer.finish()
+    }
+}
+
+/// Find the key of a tag in one of the token tables, e.g. the `"BIOME"` for `CreatureTag::Biome`
+/// in `CREATURE_TOKENS`. If more than one key is for the tag, the first one in the table is used.
+///
+/// Returns:
+///
+/// The key for the tag, or `None` if the tag isn't in the table.
+pub fn token_key<T: PartialEq>(
+    tokens: &'static phf::Map<&'static str, T>,
+    tag: &T,
+) -> Option<&'static str> {
+    tokens
+        .entries()
+        .find_map(|(key, value)| (value == tag).then_some(*key))
+}
+
+/// Find the key of a tag in one of the token tables (like `token_key`), preferring the keys which
+/// match the predicate when more than one key is for the tag.
+pub fn preferred_token_key<T: PartialEq>(
+    tokens: &'static phf::Map<&'static str, T>,
+    tag: &T,
+    prefer: impl Fn(&str) -> bool,
+) -> Option<&'static str> {
+    let mut fallback = None;
+    for (key, value) in tokens.entries() {
+        if value != tag {
+            continue;
+        }
+        if prefer(key) {
+            return Some(key);
+        }
+        fallback = fallback.or(Some(*key));
+    }
+    fallback
+}
+
+/// Write a token for each tag in the list, with the key for the tag from the token table. Tags
+/// which aren't in the table are skipped.
+pub fn write_tags<T: PartialEq>(
+    writer: &mut RawWriter,
+    tokens: &'static phf::Map<&'static str, T>,
+    tags: &[T],
+) {
+    for tag in tags {
+        if let Some(key) = token_key(tokens, tag) {
+            writer.tag(key);
+        }
+    }
+}
+
+/// Write a token for each tag in the list (like `write_tags`), except for the tags which are also in
+/// the raw tokens. Those tags had a value which isn't kept, so they are written with their value by
+/// `RawWriter::raw_tokens` instead.
+pub fn write_tags_without_raw_tokens<T: PartialEq>(
+    writer: &mut RawWriter,
+    tokens: &'static phf::Map<&'static str, T>,
+    tags: &[T],
+    raw_tokens: &[(String, String)],
+) {
+    for tag in tags {
+        let is_raw_token = raw_tokens
+            .iter()
+            .any(|(key, _)| tokens.get(key.as_str()) == Some(tag));
+        if is_raw_token {
+            continue;
+        }
+        if let Some(key) = token_key(tokens, tag) {
+            writer.tag(key);
+        }
+    }
+}
+
+/// Remove the raw tokens with the `key`, as done by `CV_REMOVE_TAG`. If a `value` is given, only the
+/// tokens with that value (or whose value starts with it) are removed.
+///
+/// Returns:
+///
+/// true if any raw tokens were removed.
+pub fn remove_raw_tokens(raw_tokens: &mut Vec<(String, String)>, key: &str, value: &str) -> bool {
+    let count = raw_tokens.len();
+    raw_tokens.retain(|(k, v)| {
+        k != key || !(value.is_empty() || v == value || v.starts_with(&format!("{value}:")))
+    });
+    raw_tokens.len() != count
+}
+
+/// Replace the `target` with the `replacement` in the values of the raw tokens with the `key`, as
+/// done by `CV_CONVERT_TAG`.
+///
+/// Returns:
+///
+/// true if any of the raw tokens have the `key`.
+pub fn convert_raw_tokens(
+    raw_tokens: &mut [(String, String)],
+    key: &str,
+    target: &str,
+    replacement: &str,
+) -> bool {
+    let mut found = false;
+    for (_, v) in raw_tokens.iter_mut().filter(|(k, _)| k == key) {
+        *v = v.replace(target, replacement);
+        found = true;
+    }
+    found
+}
+
+/// The key used in the `[OBJECT:...]` token for an object type. Tile pages are in `GRAPHICS`
+/// files, so they use the `GRAPHICS` key.
+pub fn object_type_key(object_type: &ObjectType) -> &'static str {
+    if object_type == &ObjectType::TilePage {
+        return "GRAPHICS";
+    }
+    token_key(&OBJECT_TOKENS, object_type).unwrap_or("UNKNOWN")
+}
+
+/// Get the raw as something which can be written as raw text, if it's one of the types which
+/// can be written.
+///
+/// Supported types: `Creature`, `Plant`, `Inorganic`, `Entity`, `Graphic` and `TilePage`.
+pub fn as_raw_text(raw: &dyn RawObject) -> Option<&dyn ToRawText> {
+    let any = raw.as_any();
+    match raw.get_type() {
+        ObjectType::Creature => any.downcast_ref::<Creature>().map(|r| r as &dyn ToRawText),
+        ObjectType::Plant => any.downcast_ref::<Plant>().map(|r| r as &dyn ToRawText),
+        ObjectType::Inorganic => any.downcast_ref::<Inorganic>().map(|r| r as &dyn ToRawText),
+        ObjectType::Entity => any.downcast_ref::<Entity>().map(|r| r as &dyn ToRawText),
+        ObjectType::Graphics => any.downcast_ref::<Graphic>().map(|r| r as &dyn ToRawText),
+        ObjectType::TilePage => any.downcast_ref::<TilePage>().map(|r| r as &dyn ToRawText),
+        _ => None,
+    }
+}
+
+/// Write the raws as the text of a raw file, with the file header and `[OBJECT:...]` token
+/// followed by each raw in order.
+///
+/// All the raws must go in the same type of raw file (graphics and tile pages both go in
+/// `GRAPHICS` files). The text can be parsed again with `parser::try_parse_raws_from_str`, or
+/// written to a file with `write_raw_file`.
+///
+/// Arguments:
+///
+/// * `raw_identifier`: The identifier of the raw file (its file name without the `.txt`)
+/// * `raws`: The raws to write
+///
+/// Returns:
+///
+/// The text of the raw file.
+///
+/// # Errors
+///
+/// * `ParserError::UnsupportedObjectType` if one of the raws can't be written as raw text
+/// * `ParserError::ObjectTypeMismatch` if the raws don't all go in the same type of raw file
+pub fn raws_to_raw_text(
+    raw_identifier: &str,
+    raws: &[Box<dyn RawObject>],
+) -> Result<String, ParserError> {
+    let object_type = raws
+        .first()
+        .map_or(ObjectType::Unknown, |raw| raw.get_type().clone());
+
+    let mut writer = RawWriter::new();
+    writer.write_header(raw_identifier, &object_type);
+    for raw in raws {
+        if object_type_key(raw.get_type()) != object_type_key(&object_type) {
+            return Err(ParserError::ObjectTypeMismatch {
+                expected: object_type,
+                found: String::from(object_type_key(raw.get_type())),
+                path: Path::new(raw_identifier).with_extension("txt"),
+            });
+        }
+        let Some(raw_text) = as_raw_text(raw.as_ref()) else {
+            return Err(ParserError::UnsupportedObjectType(raw.get_type().clone()));
+        };
+        raw_text.write_raw_text(&mut writer);
+    }
+    Ok(writer.finish())
+}
+
+/// Write the raws to a raw file, as done by `raws_to_raw_text`. The identifier of the raw file is
+/// taken from the file name, and the text is encoded with the encoding used by Dwarf Fortress.
+///
+/// Arguments:
+///
+/// * `raw_file_path`: The path of the raw file to write, e.g. `objects/creature_my_mod.txt`
+/// * `raws`: The raws to write
+///
+/// # Errors
+///
+/// * `ParserError::UnsupportedObjectType` if one of the raws can't be written as raw text
+/// * `ParserError::ObjectTypeMismatch` if the raws don't all go in the same type of raw file
+/// * `ParserError::Encoding` if the text has characters the encoding used by Dwarf Fortress doesn't
+///   have (nothing is written)
+/// * `ParserError::Io` if the file can't be written
+pub fn write_raw_file<P: AsRef<Path>>(
+    raw_file_path: &P,
+    raws: &[Box<dyn RawObject>],
+) -> Result<(), ParserError> {
+    let raw_file_path = raw_file_path.as_ref();
+    let raw_identifier = raw_file_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let text = raws_to_raw_text(&raw_identifier, raws)?;
+
+    let (bytes, _encoding, had_unmappable) = DF_ENCODING.encode(&text);
+    if had_unmappable {
+        // Report the first line with a character the encoding doesn't have
+        let line = text
+            .lines()
+            .position(|line| DF_ENCODING.encode(line).2)
+            .unwrap_or_default();
+        return Err(ParserError::Encoding {
+            path: raw_file_path.to_path_buf(),
+            line: line + 1,
+            source: std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{} can't encode the text", DF_ENCODING.name()),
+            ),
+        });
+    }
+    std::fs::write(raw_file_path, bytes).map_err(|source| ParserError::Io {
+        path: raw_file_path.to_path_buf(),
+        source,
+    })
+}
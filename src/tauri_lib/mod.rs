@@ -28,11 +28,36 @@ pub fn parse(
     options: &crate::options::ParserOptions,
     window: tauri::Window,
 ) -> Vec<Box<dyn crate::parser::raws::RawObject>> {
+    match try_parse(options, window) {
+        Ok(result) => result,
+        Err(e) => {
+            log::error!("Unable to parse: {e}\nProvided options:\n{options:#?}");
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(feature = "tauri")]
+/// Parse a directory of raws while emitting `PROGRESS` events to the supplied window, returning
+/// any error instead of logging it.
+///
+/// Properties:
+///
+/// * `options`: The `ParserOptions` to use for parsing.
+/// * `window`: A `tauri::Window` to emit `PROGRESS` events to.
+///
+/// # Errors
+///
+/// The same errors as `crate::try_parse`.
+pub fn try_parse(
+    options: &crate::options::ParserOptions,
+    window: tauri::Window,
+) -> Result<Vec<Box<dyn crate::parser::raws::RawObject>>, crate::errors::ParserError> {
     // setup progress helper
     let mut progress_helper = structs::ProgressHelper::with_tauri_window(window);
     progress_helper.update_current_task("Parsing all raws in dwarf fortress directory.");
 
-    let result = with_progress::parse(options, &mut progress_helper);
+    let result = with_progress::try_parse(options, &mut progress_helper);
     progress_helper.send_final("Parsing completed.");
 
     result
@@ -56,11 +81,36 @@ pub fn parse_to_json_vec(
     options: &crate::options::ParserOptions,
     window: tauri::Window,
 ) -> Vec<String> {
+    match try_parse_to_json_vec(options, window) {
+        Ok(result) => result,
+        Err(e) => {
+            log::error!("Unable to parse: {e}\nProvided options:\n{options:#?}");
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(feature = "tauri")]
+/// Parse a directory of raws into JSON strings while emitting `PROGRESS` events to the supplied
+/// window, returning any error instead of logging it.
+///
+/// Arguments:
+///
+/// * `options`: A reference to a `ParserOptions` struct from the `crate::options` module.
+/// * `window`: A `tauri::Window` to emit `PROGRESS` events to.
+///
+/// # Errors
+///
+/// The same errors as `crate::try_parse_to_json`.
+pub fn try_parse_to_json_vec(
+    options: &crate::options::ParserOptions,
+    window: tauri::Window,
+) -> Result<Vec<String>, crate::errors::ParserError> {
     // setup progress helper
     let mut progress_helper = structs::ProgressHelper::with_tauri_window(window);
     progress_helper.update_current_task("Parsing all raws in dwarf fortress directory.");
 
-    let result = with_progress::parse_to_json_vec(options, &mut progress_helper);
+    let result = with_progress::try_parse_to_json_vec(options, &mut progress_helper);
     progress_helper.send_final("Parsing completed.");
 
     result
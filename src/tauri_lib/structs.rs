@@ -5,7 +5,7 @@ use tauri::Manager;
 #[derive(Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 #[derive(ts_rs::TS)]
-#[ts(export)]
+#[cfg_attr(feature = "export-bindings", ts(export))]
 /// It's a struct to represent the progress of the current job. This is emitted back to the Tauri app using the `PROGRESS` event.
 ///
 /// Properties:
@@ -3,15 +3,15 @@ extern crate tauri;
 #[cfg(feature = "tauri")]
 use super::structs::ProgressHelper;
 #[cfg(feature = "tauri")]
+use crate::errors::ParserError;
+#[cfg(feature = "tauri")]
 use crate::parser;
 #[cfg(feature = "tauri")]
-use crate::parser::raws::RawObject;
+use crate::parser::cache::RawFileCache;
 #[cfg(feature = "tauri")]
-use crate::util;
+use crate::parser::raws::RawObject;
 #[cfg(feature = "tauri")]
 use std::path::Path;
-#[cfg(feature = "tauri")]
-use walkdir::DirEntry;
 
 #[cfg(feature = "tauri")]
 /// Parse a directory of raws, and return a JSON string of the parsed raws. While parsing, this will
@@ -28,139 +28,36 @@ use walkdir::DirEntry;
 /// Returns:
 ///
 /// A (large) JSON string with details on all raws in the game path.
-#[allow(clippy::too_many_lines)]
-pub fn parse(
+///
+/// # Errors
+///
+/// The same errors as `crate::try_parse`.
+pub fn try_parse(
     options: &crate::options::ParserOptions,
     progress_helper: &mut ProgressHelper,
-) -> Vec<Box<dyn RawObject>> {
+) -> Result<Vec<Box<dyn RawObject>>, ParserError> {
     // Guard against invalid path
-    if !crate::util::options_has_valid_paths(options) {
-        log::error!(
-            "Returning early for bad path. Provided options:\n{:#?}",
-            options
-        );
-        return Vec::new();
-    }
+    crate::util::validate_options_paths(options)?;
     let target_path = Path::new(&options.target_path);
-    let mut results: Vec<Box<dyn RawObject>> = Vec::new();
-
-    match options.job {
-        crate::options::ParsingJob::All => {
-            // Set file paths for each location
-            let data_path = target_path.join("data");
-            let vanilla_path = data_path.join("vanilla");
-            let installed_mods_path = data_path.join("installed_mods");
-            let workshop_mods_path = target_path.join("mods");
-
-            // Parse each location
-            if options
-                .locations_to_parse
-                .contains(&parser::raw_locations::RawModuleLocation::Vanilla)
-            {
-                results.extend(parse_location(&vanilla_path, options, progress_helper));
-            }
-            if options
-                .locations_to_parse
-                .contains(&parser::raw_locations::RawModuleLocation::InstalledMods)
-            {
-                results.extend(parse_location(
-                    &installed_mods_path,
-                    options,
-                    progress_helper,
-                ));
-            }
-            if options
-                .locations_to_parse
-                .contains(&parser::raw_locations::RawModuleLocation::Mods)
-            {
-                results.extend(parse_location(
-                    &workshop_mods_path,
-                    options,
-                    progress_helper,
-                ));
-            }
-        }
-        crate::options::ParsingJob::SingleLocation => {
-            // Set the file path for the chosen location
-            let location_path = if let Some(location) = options.locations_to_parse.first() {
-                match location {
-                    parser::raw_locations::RawModuleLocation::Vanilla => {
-                        target_path.join("data").join("vanilla")
-                    }
-                    parser::raw_locations::RawModuleLocation::InstalledMods => {
-                        target_path.join("data").join("installed_mods")
-                    }
-                    parser::raw_locations::RawModuleLocation::Mods => target_path.join("mods"),
-                    parser::raw_locations::RawModuleLocation::Unknown => {
-                        log::error!(
-                            "parse: Unknown location provided to parse! Provided options:\n{:#?}",
-                            options
-                        );
-                        return Vec::new();
-                    }
-                }
-            } else {
-                log::error!(
-                    "parse: No location provided to parse! Provided options:\n{:#?}",
-                    options
-                );
-                return Vec::new();
-            };
-
-            // Parse the location
-            results.extend(parse_location(&location_path, options, progress_helper));
-        }
-        crate::options::ParsingJob::SingleModule => {
-            // The provided path should be a module directory
-
-            // Check for info.txt
-            let info_txt_path = target_path.join("info.txt");
-            if !info_txt_path.exists() {
-                let dir_name = target_path.file_name().unwrap_or_default();
-                let dir_name_str = dir_name.to_str().unwrap_or("");
-
-                if !(dir_name_str.eq("mod_upload")
-                    || dir_name_str.eq("examples and notes")
-                    || dir_name_str.eq("interaction examples"))
-                {
-                    log::error!(
-                        "parse: No info.txt as expected in {:?}. Is this DF 50.xx? Provided options:\n{:#?}",
-                        target_path.file_name().unwrap_or_default(),
-                        options
-                    );
-                }
 
-                return Vec::new();
-            }
-
-            results.extend(parse_module(&target_path, options, progress_helper));
-        }
-        crate::options::ParsingJob::AllModuleInfoFiles
-        | crate::options::ParsingJob::SingleModuleInfoFile => {
-            log::warn!(
-                "parse: Unable to parse info.txt files in this dispatch. Provided options:\n{:#?}",
-                options
-            );
-            return Vec::new();
-        }
-        crate::options::ParsingJob::SingleRaw => {
-            // The provided path should be a raw file directly
-            results.extend(parser::parse_raws_from_single_file(&target_path, options));
-        }
-    }
+    let mut results = if let crate::options::ParsingJob::SingleRaw = options.job {
+        // The provided path should be a raw file directly
+        parser::try_parse_raws_from_single_file(&target_path, options)?
+    } else {
+        parse_modules(
+            crate::modules_for_job(target_path, options)?,
+            options,
+            progress_helper,
+        )
+    };
 
-    // Absorb select_creature
-    parser::helpers::absorb_select_creature::absorb_select_creature(&mut results);
-    // Apply copy_tags_from
-    if !options.skip_apply_copy_tags_from {
-        parser::helpers::apply_copy_from::apply_copy_tags_from(&mut results);
-    }
+    crate::resolve_raws(&mut results, options);
 
-    results
+    Ok(results)
 }
 
 #[cfg(feature = "tauri")]
-/// The function `parse_to_json_vec` takes in options and a progress helper, parses the options, and
+/// The function `try_parse_to_json_vec` takes in options and a progress helper, parses the options, and
 /// returns a vector of JSON strings.
 ///
 /// Arguments:
@@ -172,83 +69,89 @@ pub fn parse(
 /// Returns:
 ///
 /// a vector of JSON strings
-pub fn parse_to_json_vec(
+///
+/// # Errors
+///
+/// The same errors as `crate::try_parse_to_json`.
+pub fn try_parse_to_json_vec(
     options: &crate::options::ParserOptions,
     progress_helper: &mut ProgressHelper,
-) -> Vec<String> {
-    let raws = parse(options, progress_helper);
-    let mut results = Vec::new();
-
-    for raw in raws {
-        results.push(serde_json::to_string(&raw).unwrap_or_default());
-    }
-
-    results
+) -> Result<Vec<String>, ParserError> {
+    try_parse(options, progress_helper)?
+        .iter()
+        .map(|raw| serde_json::to_string(raw).map_err(ParserError::from))
+        .collect()
 }
 
 #[cfg(feature = "tauri")]
-/// Parses the raws in the provided location path, and returns a vector of boxed dynamic raw objects.
+/// Parses the raws in the provided module directories, in load order, and returns a vector of boxed
+/// dynamic raw objects.
 ///
 /// This is meant to be a private function, because the main entry point should be `parse`.
 ///
 /// # Arguments
 ///
-/// * `location_path` - A reference to the path to parse.
+/// * `modules` - The module paths and their info, in load order.
 /// * `options` - A reference to a `ParserOptions` struct that contains the parsing options.
 /// * `progress` - A reference to a `ProgressHelper` struct that contains the progress information.
 ///
 /// # Returns
 ///
 /// A vector of boxed dynamic raw objects.
-fn parse_location<P: AsRef<Path>>(
-    location_path: &P,
+fn parse_modules(
+    modules: Vec<(
+        std::path::PathBuf,
+        crate::parser::module_info_file::ModuleInfoFile,
+    )>,
     options: &crate::options::ParserOptions,
     progress_helper: &mut ProgressHelper,
 ) -> Vec<Box<dyn RawObject>> {
-    use crate::parser::raw_locations::RawModuleLocation;
-
     let mut results: Vec<Box<dyn RawObject>> = Vec::new();
-    let module_location = RawModuleLocation::from_path(&location_path);
-    let location_path: std::path::PathBuf = location_path.as_ref().to_path_buf();
-    // Get a list of all subdirectories in the location
-    let raw_modules_in_location: Vec<DirEntry> =
-        util::subdirectories(location_path).unwrap_or_default();
-    log::info!(
-        "parse_location: Found {} raw modules in {:?}",
-        raw_modules_in_location.len(),
-        module_location,
-    );
-
-    progress_helper.update_current_location(format!("{module_location:?}").as_str());
+    log::info!("parse_modules: Parsing {} raw modules", modules.len());
 
     // Calculate total number of modules we will parse:
-    progress_helper.add_steps(raw_modules_in_location.len());
-    progress_helper.update_current_task(format!("Parsing raws in {module_location:?}").as_str());
-
-    // Loop over each module and parse it
-    for raw_module in raw_modules_in_location {
-        let module = parse_module(&raw_module.path(), options, progress_helper);
+    progress_helper.add_steps(modules.len());
+
+    let cache = (!options.cache_path.as_os_str().is_empty())
+        .then(|| RawFileCache::load(&options.cache_path, options));
+
+    // Loop over each module (in load order) and parse it
+    for (module_path, module_info_file) in modules {
+        let module_location = module_info_file.get_location();
+        progress_helper.update_current_location(format!("{module_location:?}").as_str());
+        progress_helper
+            .update_current_task(format!("Parsing raws in {module_location:?}").as_str());
+        let module = parse_module(
+            &module_path,
+            &module_info_file,
+            options,
+            cache.as_ref(),
+            progress_helper,
+        );
         results.extend(module);
     }
 
+    if let Some(cache) = cache {
+        if let Err(e) = cache.save() {
+            log::warn!("Unable to save the raw file cache: {e}");
+            crate::parser::diagnostics::report(
+                crate::parser::diagnostics::DiagnosticSeverity::Warning,
+                &format!("Unable to save the raw file cache: {e}"),
+            );
+        }
+    }
+
     results
 }
 
 #[cfg(feature = "tauri")]
 fn parse_module<P: AsRef<Path>>(
     module_path: &P,
+    module_info_file: &crate::parser::module_info_file::ModuleInfoFile,
     options: &crate::options::ParserOptions,
+    cache: Option<&RawFileCache>,
     progress_helper: &mut ProgressHelper,
 ) -> Vec<Box<dyn RawObject>> {
-    // Get information from the module info file
-    let module_info_file_path = module_path.as_ref().join("info.txt");
-    let module_info_file = crate::parse_module_info_file_direct(&module_info_file_path);
-
-    log::info!(
-        "parse_module: Parsing raws for {} v{}",
-        module_info_file.get_identifier(),
-        module_info_file.get_version(),
-    );
     progress_helper.update_current_module(
         format!(
             "{} v{}",
@@ -258,98 +161,16 @@ fn parse_module<P: AsRef<Path>>(
         .as_str(),
     );
 
-    // Get a list of all raw files in the module
-    let objects_path = module_path.as_ref().join("objects");
-    let graphics_path = module_path.as_ref().join("graphics");
-
-    let mut parse_objects = true;
-    let mut parse_graphics = true;
-
-    if !objects_path.exists() {
-        log::warn!(
-            "parse_module: No objects directory found in {:?}",
-            module_path.as_ref().file_name().unwrap_or_default(),
-        );
-        parse_objects = false;
-    }
-
-    if parse_objects && !objects_path.is_dir() {
-        log::warn!(
-            "parse_module: Objects directory in {:?} is not a directory",
-            module_path.as_ref().file_name().unwrap_or_default(),
-        );
-        parse_objects = false;
-    }
-
-    if !graphics_path.exists() {
-        log::debug!(
-            "parse_module: No graphics directory found in {:?}",
-            module_path.as_ref().file_name().unwrap_or_default(),
-        );
-        parse_graphics = false;
-    }
-
-    if parse_graphics && !graphics_path.is_dir() {
-        log::warn!(
-            "parse_module: Graphics directory in {:?} is not a directory",
-            module_path.as_ref().file_name().unwrap_or_default(),
-        );
-        parse_graphics = false;
-    }
-
-    // Exit early if nothing to parse
-    if !parse_graphics && !parse_objects {
-        return Vec::new();
-    }
+    let raw_file_paths = crate::module_raw_files(module_path, module_info_file);
+    progress_helper.add_steps(raw_file_paths.len());
 
     let mut results = Vec::new();
-
-    // Parse the objects
-    if parse_objects {
-        for entry in walkdir::WalkDir::new(objects_path)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-        {
-            if entry.file_type().is_file() {
-                let file_path = entry.path();
-                let file_name = file_path.file_name().unwrap_or_default();
-                let file_name_str = file_name.to_str().unwrap_or_default();
-
-                if std::path::Path::new(file_name_str)
-                    .extension()
-                    .map_or(false, |ext| ext.eq_ignore_ascii_case("txt"))
-                {
-                    progress_helper.add_steps(1);
-                    progress_helper.send_update(file_name_str);
-                    results.extend(parser::parse_raws_from_single_file(&file_path, options));
-                    progress_helper.add_to_running_total(results.len());
-                }
-            }
-        }
-    }
-
-    // Parse the graphics
-    if parse_graphics {
-        for entry in walkdir::WalkDir::new(graphics_path)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-        {
-            if entry.file_type().is_file() {
-                let file_path = entry.path();
-                let file_name = file_path.file_name().unwrap_or_default();
-                let file_name_str = file_name.to_str().unwrap_or_default();
-
-                if std::path::Path::new(file_name_str)
-                    .extension()
-                    .map_or(false, |ext| ext.eq_ignore_ascii_case("txt"))
-                {
-                    progress_helper.add_steps(1);
-                    progress_helper.send_update(file_name_str);
-                    results.extend(parser::parse_raws_from_single_file(&file_path, options));
-                    progress_helper.add_to_running_total(results.len());
-                }
-            }
-        }
+    for file_path in raw_file_paths {
+        let file_name = file_path.file_name().unwrap_or_default().to_string_lossy();
+        progress_helper.send_update(&file_name);
+        let raws = crate::parse_module_raw_file(&file_path, module_info_file, options, cache);
+        progress_helper.add_to_running_total(raws.len());
+        results.extend(raws);
     }
 
     results
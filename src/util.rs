@@ -7,6 +7,7 @@ use std::{
 use walkdir::WalkDir;
 
 use crate::{
+    errors::ParserError,
     options::{ParserOptions, ParsingJob},
     parser::{
         creature::raw::Creature, object_types::ObjectType, raws::RawObject,
@@ -14,6 +15,9 @@ use crate::{
     },
 };
 
+#[cfg(feature = "rayon")]
+use crate::parser::diagnostics::{self, ParseReport};
+
 /// Get a vec of subdirectories for a given directory
 ///
 /// Using the `WalkDir` crate:
@@ -111,7 +115,27 @@ pub fn path_from_game_directory<P: AsRef<Path>>(game_path: &P) -> Result<PathBuf
 ///
 /// * `parsed_raws_string_vec`: String
 /// * `out_filepath`: Path
+#[allow(clippy::ptr_arg)]
 pub fn write_json_string_vec_to_file<P: AsRef<Path>>(strings_vec: &Vec<String>, out_filepath: &P) {
+    if let Err(e) = try_write_json_string_vec_to_file(strings_vec, out_filepath) {
+        log::error!("write_json_string_vec_to_file: {e}");
+    }
+}
+
+/// Save a slice of strings to a file as a JSON array.
+///
+/// Arguments:
+///
+/// * `strings_vec`: The JSON strings to write
+/// * `out_filepath`: Path to the file to write
+///
+/// # Errors
+///
+/// Returns `ParserError::Io` if the file can't be created or written to.
+pub fn try_write_json_string_vec_to_file<P: AsRef<Path>>(
+    strings_vec: &[String],
+    out_filepath: &P,
+) -> Result<(), ParserError> {
     log::info!(
         "write_json_string_vec_to_file: Writing {} strings to file {:?}",
         strings_vec.len(),
@@ -120,92 +144,117 @@ pub fn write_json_string_vec_to_file<P: AsRef<Path>>(strings_vec: &Vec<String>,
 
     if strings_vec.is_empty() {
         log::warn!("write_json_string_vec_to_file: Provided string vector is empty!");
-        return;
+        return Ok(());
     }
 
-    let out_file = match File::create(out_filepath) {
-        Ok(f) => f,
-        Err(e) => {
-            log::error!(
-                "write_json_string_vec_to_file: Unable to open {} for writing \n{:?}",
-                out_filepath.as_ref().display(),
-                e
-            );
-            return;
-        }
+    let io_error = |source: std::io::Error| ParserError::Io {
+        path: out_filepath.as_ref().to_path_buf(),
+        source,
     };
 
+    let out_file = File::create(out_filepath).map_err(io_error)?;
     let mut stream = BufWriter::new(out_file);
-    let write_error = &format!(
-        "write_json_string_vec_to_file: Unable to write to {}",
-        out_filepath.as_ref().to_string_lossy()
-    );
 
     if strings_vec.len() == 1 {
-        match writeln!(stream, "{}", strings_vec.first().unwrap_or(&String::new())) {
-            Ok(_x) => (),
-            Err(e) => {
-                log::error!("write_json_string_vec_to_file: {}\n{:?}", write_error, e);
-                return;
-            }
-        };
-        match stream.flush() {
-            Ok(_x) => (),
-            Err(e) => {
-                log::error!("write_json_string_vec_to_file: {}\n{:?}", write_error, e);
-            }
-        };
-        return;
+        writeln!(stream, "{}", strings_vec.first().unwrap_or(&String::new())).map_err(io_error)?;
+        stream.flush().map_err(io_error)?;
+        return Ok(());
     }
 
-    let strings_vec = strings_vec.iter();
     // Write the first value with an open bracket '[' at the beginning
     // Write all next values with a comma ',' in front
     // Finish with a closing bracket ']'
-    for (i, string) in strings_vec.enumerate() {
+    for (i, string) in strings_vec.iter().enumerate() {
         match i {
-            0 => match write!(stream, "[{string}") {
-                Ok(_x) => (),
-                Err(e) => {
-                    log::error!("write_json_string_vec_to_file: {}\n{:?}", write_error, e);
-                    return;
-                }
-            },
-            _ => match write!(stream, ",{string}") {
-                Ok(_x) => (),
-                Err(e) => {
-                    log::error!("write_json_string_vec_to_file: {}\n{:?}", write_error, e);
-                    return;
-                }
-            },
+            0 => write!(stream, "[{string}").map_err(io_error)?,
+            _ => write!(stream, ",{string}").map_err(io_error)?,
         }
     }
 
-    match writeln!(stream, "]") {
-        Ok(_x) => (),
-        Err(e) => {
-            log::error!("write_json_string_vec_to_file: {}\n{:?}", write_error, e);
-            return;
-        }
-    };
+    writeln!(stream, "]").map_err(io_error)?;
+    stream.flush().map_err(io_error)?;
+
+    Ok(())
+}
+
+/// Writes values to a `Write` as a JSON array, one value at a time, so the JSON for all the values
+/// never has to be in memory at once.
+///
+/// The array is written as `[value,value,...]` followed by a newline. Call `finish` after the last
+/// value to close the array (an array with no values is written as `[]`).
+pub struct JsonArrayWriter<W: Write> {
+    writer: W,
+    count: usize,
+}
+
+impl<W: Write> JsonArrayWriter<W> {
+    /// Start writing a JSON array to `writer`. Nothing is written until the first value (or
+    /// `finish`).
+    pub fn new(writer: W) -> Self {
+        Self { writer, count: 0 }
+    }
+
+    /// Write a value to the array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParserError::Serialization` if the value can't be serialized or written.
+    pub fn write<T: serde::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ParserError> {
+        let separator: &[u8] = if self.count == 0 { b"[" } else { b"," };
+        self.writer
+            .write_all(separator)
+            .map_err(serde_json::Error::io)?;
+        serde_json::to_writer(&mut self.writer, value)?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// The number of values written so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Close the array and flush the writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParserError::Serialization` if the writer can't be written to or flushed.
+    pub fn finish(mut self) -> Result<W, ParserError> {
+        let end: &[u8] = if self.count == 0 { b"[]\n" } else { b"]\n" };
+        self.writer.write_all(end).map_err(serde_json::Error::io)?;
+        self.writer.flush().map_err(serde_json::Error::io)?;
+        Ok(self.writer)
+    }
+}
 
-    match stream.flush() {
-        Ok(_x) => (),
+pub fn options_has_valid_paths(options: &ParserOptions) -> bool {
+    match validate_options_paths(options) {
+        Ok(()) => true,
         Err(e) => {
-            log::error!("write_json_string_vec_to_file: {}\n{:?}", write_error, e);
+            log::error!("options_has_valid_paths: {e}");
+            false
         }
-    };
+    }
 }
 
-pub fn options_has_valid_paths(options: &ParserOptions) -> bool {
+/// Check that the paths in the `ParserOptions` are usable for the chosen `ParsingJob`.
+///
+/// Arguments:
+///
+/// * `options`: The `ParserOptions` to check
+///
+/// # Errors
+///
+/// Returns `ParserError::InvalidPath` if the target path doesn't exist, is a file when a directory
+/// is needed, or if the output path is not a file when writing output to a file.
+pub fn validate_options_paths(options: &ParserOptions) -> Result<(), ParserError> {
     let target_path = &options.target_path;
     // Guard against invalid path
     if !target_path.exists() {
-        log::error!(
-            "write_json_string_vec_to_file: Provided path for parsing doesn't exist!\n{}",
-            target_path.display()
-        );
-        return false;
+        return Err(ParserError::InvalidPath {
+            path: target_path.clone(),
+            reason: String::from("provided path for parsing doesn't exist"),
+        });
     }
     if (options.job == ParsingJob::All
         || options.job == ParsingJob::SingleModule
@@ -213,37 +262,29 @@ pub fn options_has_valid_paths(options: &ParserOptions) -> bool {
         || options.job == ParsingJob::AllModuleInfoFiles)
         && target_path.is_file()
     {
-        log::error!(
-            "write_json_string_vec_to_file: Target path needs to be a directory for parsing {:?}\n{}",
-            options.job,
-            target_path.display()
-        );
-        return false;
+        return Err(ParserError::InvalidPath {
+            path: target_path.clone(),
+            reason: format!(
+                "target path needs to be a directory for parsing {:?}",
+                options.job
+            ),
+        });
     }
 
     // Exit early if we aren't writing to a file.
     if !options.output_to_file {
-        return true;
+        return Ok(());
     }
 
     let output_path = &options.output_path;
-    // Guard against invalid path
-    if !target_path.exists() {
-        log::error!(
-            "write_json_string_vec_to_file: Provided path for parsing doesn't exist!\n{}",
-            output_path.display()
-        );
-        return false;
-    }
     // Output path needs to be a file (always)
     if !output_path.is_file() {
-        log::error!(
-            "write_json_string_vec_to_file: Output path needs to be a file\n{}",
-            output_path.display()
-        );
-        return false;
+        return Err(ParserError::InvalidPath {
+            path: output_path.clone(),
+            reason: String::from("output path needs to be a file"),
+        });
     }
-    true
+    Ok(())
 }
 
 /// The function `raws_to_string` converts a vector of raw objects into a JSON string representation.
@@ -308,3 +349,49 @@ pub fn get_only_select_creatures_from_raws(all_raws: &[Box<dyn RawObject>]) -> V
         .map(|r| r.unwrap_or(&SelectCreature::default()).clone())
         .collect::<Vec<SelectCreature>>()
 }
+
+/// The function `map_in_order` calls `f` on each of the `items` and returns the results in the same
+/// order as the items.
+///
+/// With the "rayon" feature the items are processed in parallel. The diagnostics reported while
+/// processing each item are collected on the worker thread and added to this thread's diagnostics
+/// in the order of the items, so the `ParseReport` is the same as for a sequential parse.
+///
+/// Arguments:
+///
+/// * `items`: The items to process
+/// * `f`: The function to call on each item
+///
+/// Returns:
+///
+/// The results of `f`, in the order of `items`.
+pub(crate) fn map_in_order<T: Sync, R: Send>(
+    items: &[T],
+    f: impl Fn(&T) -> R + Sync + Send,
+) -> Vec<R> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        if !diagnostics::is_capturing() {
+            return items.par_iter().map(f).collect();
+        }
+
+        // `collect` keeps the order of the items, whichever thread finishes first
+        let results: Vec<(R, ParseReport)> = items
+            .par_iter()
+            .map(|item| diagnostics::capture(|| f(item)))
+            .collect();
+
+        results
+            .into_iter()
+            .map(|(result, report)| {
+                diagnostics::merge(report);
+                result
+            })
+            .collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    items.iter().map(f).collect()
+}
@@ -0,0 +1,199 @@
+//! The raw file cache in `ParserOptions::cache_path`. Whether a raw file came from the cache is
+//! checked by changing the raws stored in the cache file: a cached raw file gives back the changed
+//! raws, while a raw file which is parsed again gives back what is in the file.
+
+mod common;
+
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use dfraw_json_parser::{
+    options::{ParserOptions, ParsingJob},
+    parser::object_types::ObjectType,
+};
+use serde_json::Value;
+
+const CREATURE_FILE: &str =
+    "creature_cache_test\n\n[OBJECT:CREATURE]\n\n[CREATURE:TOAD]\n\t[NAME:toad:toads:toad]\n";
+
+/// The name the creature gets in the cache file, which is only parsed if the cache is used.
+const CACHED_NAME: &str = "cached toad";
+
+struct CacheTest {
+    module_path: PathBuf,
+    cache_path: PathBuf,
+}
+
+impl CacheTest {
+    fn new(name: &str) -> Self {
+        let dir = common::empty_temp_dir(name);
+        let test = Self {
+            module_path: dir.join("cache_test"),
+            cache_path: dir.join("cache.json"),
+        };
+        test.write_info_file(1);
+        common::write_file(&test.raw_file_path(), CREATURE_FILE);
+        test
+    }
+
+    fn raw_file_path(&self) -> PathBuf {
+        self.module_path
+            .join("objects")
+            .join("creature_cache_test.txt")
+    }
+
+    fn write_info_file(&self, version: u32) {
+        common::write_file(
+            &self.module_path.join("info.txt"),
+            format!("[ID:cache_test]\n[NUMERIC_VERSION:{version}]\n[DISPLAYED_VERSION:{version}]\n[NAME:Cache Test]\n"),
+        );
+    }
+
+    fn options(&self) -> ParserOptions {
+        let mut options = ParserOptions::new(&self.module_path);
+        options.set_job(ParsingJob::SingleModule);
+        options.set_raws_to_parse(vec![ObjectType::Creature]);
+        options.set_cache_path(&self.cache_path);
+        options
+    }
+
+    /// Parse the module, returning the name of the creature.
+    fn parse_name(&self, options: &ParserOptions) -> String {
+        let raws = dfraw_json_parser::parse(options);
+        assert_eq!(raws.len(), 1, "the module has one creature");
+        raws[0].get_name().to_string()
+    }
+
+    fn read_cache(&self) -> Value {
+        let text = std::fs::read_to_string(&self.cache_path).expect("the cache file is written");
+        serde_json::from_str(&text).expect("the cache file is JSON")
+    }
+
+    fn write_cache(&self, cache: &Value) {
+        common::write_file(
+            &self.cache_path,
+            serde_json::to_string(cache).expect("the cache serializes"),
+        );
+    }
+
+    /// Parse the module to fill the cache, then change the creature's name in the cache file.
+    fn fill_cache(&self) {
+        assert_eq!(self.parse_name(&self.options()), "toad");
+        let mut cache = self.read_cache();
+        let files = cache["files"]
+            .as_object_mut()
+            .expect("the files are cached");
+        assert_eq!(files.len(), 1, "{files:?}");
+        for file in files.values_mut() {
+            file["raws"][0]["name"]["singular"] = Value::from(CACHED_NAME);
+        }
+        self.write_cache(&cache);
+    }
+}
+
+fn set_modified(path: &Path, modified: SystemTime) {
+    File::options()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.set_modified(modified))
+        .expect("the modification time can be set");
+}
+
+fn modified(path: &Path) -> SystemTime {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .expect("the modification time can be read")
+}
+
+#[test]
+fn unchanged_raw_files_come_from_the_cache() {
+    let test = CacheTest::new("cache_hit");
+    test.fill_cache();
+
+    assert_eq!(test.parse_name(&test.options()), CACHED_NAME);
+}
+
+#[test]
+fn raw_files_which_change_size_are_parsed_again() {
+    let test = CacheTest::new("cache_size_changed");
+    test.fill_cache();
+    common::write_file(
+        &test.raw_file_path(),
+        CREATURE_FILE.replace("toad:toads:toad", "frog:frogs:frog prince"),
+    );
+
+    assert_eq!(test.parse_name(&test.options()), "frog");
+}
+
+#[test]
+fn raw_files_which_are_only_touched_come_from_the_cache() {
+    let test = CacheTest::new("cache_touched");
+    test.fill_cache();
+    let path = test.raw_file_path();
+    set_modified(&path, modified(&path) + Duration::from_secs(60));
+
+    assert_eq!(test.parse_name(&test.options()), CACHED_NAME);
+}
+
+#[test]
+fn raw_files_which_change_contents_are_parsed_again() {
+    let test = CacheTest::new("cache_contents_changed");
+    test.fill_cache();
+    let path = test.raw_file_path();
+    let cached_modified = modified(&path);
+    // The same size, so only the hash of the contents shows the change
+    common::write_file(&path, CREATURE_FILE.replace("toad", "frog"));
+    set_modified(&path, cached_modified + Duration::from_secs(60));
+
+    assert_eq!(test.parse_name(&test.options()), "frog");
+}
+
+#[test]
+fn raw_files_of_another_module_version_are_parsed_again() {
+    let test = CacheTest::new("cache_module_version");
+    test.fill_cache();
+    test.write_info_file(2);
+
+    assert_eq!(test.parse_name(&test.options()), "toad");
+}
+
+#[test]
+fn caches_from_other_versions_of_the_library_are_ignored() {
+    let test = CacheTest::new("cache_parser_version");
+    test.fill_cache();
+    let mut cache = test.read_cache();
+    assert_eq!(cache["parserVersion"], env!("CARGO_PKG_VERSION"));
+    cache["parserVersion"] = Value::from("0.0.1");
+    test.write_cache(&cache);
+
+    assert_eq!(test.parse_name(&test.options()), "toad");
+    assert_eq!(
+        test.read_cache()["parserVersion"],
+        env!("CARGO_PKG_VERSION")
+    );
+}
+
+#[test]
+fn caches_for_other_raws_to_parse_are_ignored() {
+    let test = CacheTest::new("cache_options");
+    test.fill_cache();
+    let mut options = test.options();
+    options.set_raws_to_parse(vec![ObjectType::Creature, ObjectType::Plant]);
+
+    assert_eq!(test.parse_name(&options), "toad");
+}
+
+#[test]
+fn options_applied_after_parsing_use_the_cache() {
+    let test = CacheTest::new("cache_other_options");
+    test.fill_cache();
+    let mut options = test.options();
+    options.attach_metadata_to_raws();
+    options.skip_apply_creature_variations();
+    options.set_module_load_order(vec![String::from("cache_test")]);
+
+    assert_eq!(test.parse_name(&options), CACHED_NAME);
+}
@@ -0,0 +1,254 @@
+//! Helpers shared by the integration tests.
+//!
+//! The fixture corpus in `tests/fixtures` is laid out like a Dwarf Fortress install, with the
+//! fixture modules in `data/vanilla`, so it can be parsed with the same options as a real install.
+
+// Each test binary uses some of the helpers.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+use dfraw_json_parser::{
+    options::{ParserOptions, ParsingJob},
+    parser::{
+        diagnostics::ParseReport, module_info_file::ModuleInfoFile, object_types::ObjectType,
+        raws::RawObject, try_parse_raws_from_str,
+    },
+};
+use serde_json::Value;
+
+/// Every type of raw the parser reads. The fixture corpus has at least one raw of each.
+pub const PARSABLE_OBJECT_TYPES: [ObjectType; 23] = [
+    ObjectType::Creature,
+    ObjectType::Plant,
+    ObjectType::Inorganic,
+    ObjectType::Graphics,
+    ObjectType::TilePage,
+    ObjectType::Entity,
+    ObjectType::MaterialTemplate,
+    ObjectType::CreatureVariation,
+    ObjectType::Item,
+    ObjectType::Reaction,
+    ObjectType::Building,
+    ObjectType::Body,
+    ObjectType::BodyDetailPlan,
+    ObjectType::TissueTemplate,
+    ObjectType::Language,
+    ObjectType::Interaction,
+    ObjectType::DescriptorColor,
+    ObjectType::DescriptorPattern,
+    ObjectType::DescriptorShape,
+    ObjectType::Palette,
+    ObjectType::Music,
+    ObjectType::Sound,
+    ObjectType::TextSet,
+];
+
+/// Stands in for the path of the fixture directory in snapshots, so they don't depend on where
+/// the repository is checked out.
+const FIXTURES_PLACEHOLDER: &str = "$FIXTURES";
+
+/// The fixture "game directory".
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+}
+
+/// The directory with the golden JSON snapshots.
+pub fn snapshots_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+}
+
+/// An empty directory for a test to write files in, under cargo's temporary directory for the
+/// integration tests. Anything left in it by an earlier run is removed.
+pub fn empty_temp_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir).expect("the old temporary directory can be removed");
+    }
+    std::fs::create_dir_all(&dir).expect("the temporary directory can be created");
+    dir
+}
+
+/// Write `contents` to the file at `path`, creating the directories it is in.
+pub fn write_file<C: AsRef<[u8]>>(path: &Path, contents: C) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).expect("the directory can be created");
+    }
+    std::fs::write(path, contents).expect("the file can be written");
+}
+
+/// Options which parse every type of raw in the fixture corpus.
+pub fn fixture_options() -> ParserOptions {
+    let mut options = ParserOptions::new(fixtures_dir());
+    options.set_raws_to_parse(PARSABLE_OBJECT_TYPES.to_vec());
+    options
+}
+
+/// The raw files in the fixture corpus, sorted by path.
+pub fn fixture_raw_files() -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = walkdir::WalkDir::new(fixtures_dir().join("data"))
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "txt")
+                && path.file_name().is_some_and(|name| name != "info.txt")
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// The module the raws parsed from text in the tests belong to.
+pub fn test_module() -> ModuleInfoFile {
+    ModuleInfoFile::synthetic("test_module", "Test Module")
+}
+
+/// Parse the text of a raw file (with the raw file's identifier on its first line) as part of
+/// `module`.
+pub fn parse_str_in_module(
+    raw_text: &str,
+    module: &ModuleInfoFile,
+    options: &ParserOptions,
+) -> Vec<Box<dyn RawObject>> {
+    let raw_identifier = raw_text.lines().next().unwrap_or_default();
+    try_parse_raws_from_str(raw_text, raw_identifier, module, options)
+        .expect("the raw text has a valid object type")
+}
+
+/// Parse the text of a raw file in the test module, with the fixture options.
+pub fn parse_str(raw_text: &str) -> Vec<Box<dyn RawObject>> {
+    parse_str_in_module(raw_text, &test_module(), &fixture_options())
+}
+
+/// Parse the texts of raw files in the test module, and resolve the raws from all of them together
+/// (as `parse` does for the raws of a module).
+pub fn parse_and_resolve(raw_texts: &[&str]) -> Vec<Box<dyn RawObject>> {
+    let options = fixture_options();
+    let mut raws: Vec<Box<dyn RawObject>> = raw_texts
+        .iter()
+        .flat_map(|raw_text| parse_str_in_module(raw_text, &test_module(), &options))
+        .collect();
+    dfraw_json_parser::resolve_raws(&mut raws, &options);
+    raws
+}
+
+/// Parse the text of a raw file as the only raw file of a module on disk (in a temporary
+/// directory called `name`), returning the raws with the diagnostics reported for them.
+pub fn parse_module_with_report(
+    name: &str,
+    raw_text: &str,
+) -> (Vec<Box<dyn RawObject>>, ParseReport) {
+    let module_path = empty_temp_dir(name);
+    write_file(
+        &module_path.join("info.txt"),
+        "[ID:test_module]\n[NUMERIC_VERSION:1]\n[DISPLAYED_VERSION:1.0]\n[NAME:Test Module]\n",
+    );
+    let raw_identifier = raw_text.lines().next().unwrap_or_default();
+    write_file(
+        &module_path
+            .join("objects")
+            .join(format!("{raw_identifier}.txt")),
+        raw_text,
+    );
+    let mut options = ParserOptions::new(&module_path);
+    options.set_job(ParsingJob::SingleModule);
+    options.set_raws_to_parse(PARSABLE_OBJECT_TYPES.to_vec());
+    dfraw_json_parser::parse_with_report(&options)
+}
+
+/// Serialize a raw to JSON, with the path of the fixture directory replaced by a placeholder.
+pub fn raw_to_json(raw: &dyn RawObject) -> Value {
+    let value = serde_json::to_value(raw).expect("raws serialize to JSON");
+    replace_fixture_paths(value)
+}
+
+/// Replace the path of the fixture directory in `text` with a placeholder.
+pub fn replace_fixture_path(text: &str) -> String {
+    text.replace(&*fixtures_dir().to_string_lossy(), FIXTURES_PLACEHOLDER)
+}
+
+fn replace_fixture_paths(value: Value) -> Value {
+    match value {
+        Value::String(s) => Value::String(replace_fixture_path(&s)),
+        Value::Array(values) => {
+            Value::Array(values.into_iter().map(replace_fixture_paths).collect())
+        }
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, replace_fixture_paths(value)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+/// Remove the keys which depend on where a raw was parsed from (its object ID and metadata), so
+/// the same raw parsed from different places compares equal.
+pub fn strip_source(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("objectId");
+            map.remove("metadata");
+            for value in map.values_mut() {
+                strip_source(value);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                strip_source(value);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Describe the differences between two JSON values, one line per field which is missing, added
+/// or changed. The lines start with the path to the field (e.g. `castes[0].tags`).
+pub fn json_differences(expected: &Value, actual: &Value) -> Vec<String> {
+    let mut differences = Vec::new();
+    collect_differences("", expected, actual, &mut differences);
+    differences
+}
+
+fn collect_differences(path: &str, expected: &Value, actual: &Value, out: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected_value) in expected {
+                let field = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                match actual.get(key) {
+                    Some(actual_value) => {
+                        collect_differences(&field, expected_value, actual_value, out);
+                    }
+                    None => out.push(format!("{field}: lost (was {expected_value})")),
+                }
+            }
+            for (key, actual_value) in actual {
+                if !expected.contains_key(key) {
+                    let field = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    out.push(format!("{field}: added ({actual_value})"));
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            for (i, (expected_value, actual_value)) in expected.iter().zip(actual).enumerate() {
+                collect_differences(&format!("{path}[{i}]"), expected_value, actual_value, out);
+            }
+        }
+        _ if expected != actual => out.push(format!("{path}: {expected} != {actual}")),
+        _ => {}
+    }
+}
@@ -0,0 +1,195 @@
+//! Conflicts between modules, found with `find_conflicts` and `detect_conflicts`.
+
+mod common;
+
+use dfraw_json_parser::parser::{
+    conflicts::find_conflicts,
+    creature::raw::Creature,
+    creature_caste::raw::Caste,
+    module_info_file::ModuleInfoFile,
+    object_types::ObjectType,
+    raw_locations::RawModuleLocation,
+    raws::{RawMetadata, RawObject},
+    select_creature::raw::SelectCreature,
+    select_object::raw::SelectObject,
+};
+
+/// A mod with the given identifier and extra info.txt tokens, at `load_order`.
+fn test_module(identifier: &str, info_tokens: &str, load_order: usize) -> ModuleInfoFile {
+    let info_text = format!(
+        "[ID:{identifier}]\n[NUMERIC_VERSION:1]\n[DISPLAYED_VERSION:1.0]\n[NAME:{identifier}]\n{info_tokens}"
+    );
+    let mut module =
+        ModuleInfoFile::from_info_text(&info_text, RawModuleLocation::Mods, identifier);
+    module.set_load_order(load_order);
+    module
+}
+
+#[test]
+fn declared_conflicts_and_missing_requirements() {
+    let modules = [
+        test_module(
+            "first_mod",
+            "[REQUIRES_ID:second_mod]\n[REQUIRES_ID:missing_mod]\n[CONFLICTS_WITH_ID:third_mod]\n[CONFLICTS_WITH_ID:absent_mod]\n",
+            0,
+        ),
+        test_module("second_mod", "", 1),
+        test_module("third_mod", "", 2),
+    ];
+    let report = find_conflicts(&modules, &[]);
+
+    let conflicts = report.get_declared_conflicts();
+    assert_eq!(conflicts.len(), 1, "{conflicts:?}");
+    assert_eq!(conflicts[0].get_module().get_identifier(), "first_mod");
+    assert_eq!(
+        conflicts[0].get_conflicts_with().get_identifier(),
+        "third_mod"
+    );
+
+    let missing = report.get_missing_requirements();
+    assert_eq!(missing.len(), 1, "{missing:?}");
+    assert_eq!(missing[0].get_module().get_identifier(), "first_mod");
+    assert_eq!(missing[0].get_required_identifier(), "missing_mod");
+
+    assert!(report.get_duplicate_objects().is_empty());
+    assert!(report.get_overlapping_patches().is_empty());
+}
+
+#[test]
+fn objects_defined_by_more_than_one_module() {
+    let options = common::fixture_options();
+    let first = test_module("first_mod", "", 0);
+    let second = test_module("second_mod", "", 1);
+    let mut raws = common::parse_str_in_module(
+        "conflict_test\n\n[OBJECT:CREATURE]\n\n[CREATURE:DWARF]\n[CREATURE:ELF]\n[CREATURE:ELF]\n",
+        &first,
+        &options,
+    );
+    raws.extend(common::parse_str_in_module(
+        "conflict_test\n\n[OBJECT:CREATURE]\n\n[CREATURE:DWARF]\n[SELECT_CREATURE:ELF]\n\t[FLIER]\n",
+        &second,
+        &options,
+    ));
+    let report = find_conflicts(&[first, second], &raws);
+
+    // ELF is defined twice by the same module (and patched by the other), which isn't a duplicate
+    let duplicates = report.get_duplicate_objects();
+    assert_eq!(duplicates.len(), 1, "{duplicates:?}");
+    assert_eq!(duplicates[0].get_object_type(), &ObjectType::Creature);
+    assert_eq!(duplicates[0].get_identifier(), "DWARF");
+    let modules: Vec<&str> = duplicates[0]
+        .get_definitions()
+        .iter()
+        .map(|definition| definition.get_module().get_identifier())
+        .collect();
+    assert_eq!(modules, ["first_mod", "second_mod"]);
+}
+
+#[test]
+fn objects_patched_by_more_than_one_module() {
+    let options = common::fixture_options();
+    let first = test_module("first_mod", "", 0);
+    let second = test_module("second_mod", "", 1);
+    let mut raws = common::parse_str_in_module(
+        "conflict_test\n\n[OBJECT:CREATURE]\n\n[SELECT_CREATURE:TOAD]\n\t[LARGE_ROAMING]\n\t[FLIER]\n",
+        &first,
+        &options,
+    );
+    raws.extend(common::parse_str_in_module(
+        "conflict_test\n\n[OBJECT:CREATURE]\n\n[SELECT_CREATURE:TOAD]\n\t[LARGE_ROAMING]\n",
+        &second,
+        &options,
+    ));
+    raws.extend(common::parse_str_in_module(
+        "conflict_test\n\n[OBJECT:PLANT]\n\n[SELECT_PLANT:TOAD]\n\t[WET]\n",
+        &second,
+        &options,
+    ));
+    let report = find_conflicts(&[first, second], &raws);
+
+    // The plant patch has a different target type, so it doesn't overlap the creature patches
+    let overlaps = report.get_overlapping_patches();
+    assert_eq!(overlaps.len(), 1, "{overlaps:?}");
+    assert_eq!(overlaps[0].get_target_type(), &ObjectType::Creature);
+    assert_eq!(overlaps[0].get_identifier(), "TOAD");
+    assert_eq!(overlaps[0].get_patches().len(), 2);
+    assert_eq!(overlaps[0].get_shared_tokens(), ["LARGE_ROAMING"]);
+    assert!(report.get_duplicate_objects().is_empty());
+}
+
+#[test]
+fn castes_in_a_patch_belong_to_the_patch() {
+    let raws = common::parse_str(
+        "conflict_test\n\n[OBJECT:CREATURE]\n\n[CREATURE:DWARF]\n\t[CASTE:MALE]\n\t\t[MALE]\n\
+         [SELECT_CREATURE:TOAD]\n\t[CASTE:FEMALE]\n\t\t[FLIER]\n\t[SELECT_CASTE:ALL]\n\t\t[LARGE_ROAMING]\n",
+    );
+
+    let dwarf = raws
+        .iter()
+        .find_map(|raw| raw.as_any().downcast_ref::<Creature>())
+        .expect("the creature is parsed");
+    let castes: Vec<&str> = dwarf
+        .get_castes()
+        .iter()
+        .map(Caste::get_identifier)
+        .collect();
+    assert_eq!(castes, ["ALL", "MALE"]);
+    let dwarf = common::raw_to_json(dwarf);
+    assert!(dwarf.get("tags").is_none(), "{dwarf}");
+    assert_eq!(
+        dwarf["castes"][1]["tags"],
+        serde_json::json!(["Male"]),
+        "{dwarf}"
+    );
+
+    let patch = raws
+        .iter()
+        .find_map(|raw| raw.as_any().downcast_ref::<SelectCreature>())
+        .expect("the patch is parsed");
+    assert_eq!(
+        patch.get_tags(),
+        [
+            "CASTE:FEMALE",
+            "FLIER:",
+            "SELECT_CASTE:ALL",
+            "LARGE_ROAMING:"
+        ]
+    );
+}
+
+#[test]
+fn select_creature_and_select_object_patches_overlap() {
+    let options = common::fixture_options();
+    let first = test_module("first_mod", "", 0);
+    let second = test_module("second_mod", "", 1);
+    let mut raws = common::parse_str_in_module(
+        "conflict_test\n\n[OBJECT:CREATURE]\n\n[SELECT_CREATURE:TOAD]\n\t[FLIER]\n\t[NAME:toad:toads:toad]\n",
+        &first,
+        &options,
+    );
+    let metadata = RawMetadata::new(
+        &second,
+        &ObjectType::Creature,
+        "conflict_test",
+        &"conflict_test",
+        false,
+    );
+    let mut patch = SelectObject::new("TOAD", &metadata, ObjectType::SelectCreature);
+    patch.parse_tag("FLIER", "");
+    patch.parse_tag("LARGE_ROAMING", "");
+    raws.push(Box::new(patch));
+    let report = find_conflicts(&[first, second], &raws);
+
+    let overlaps = report.get_overlapping_patches();
+    assert_eq!(overlaps.len(), 1, "{overlaps:?}");
+    assert_eq!(overlaps[0].get_target_type(), &ObjectType::Creature);
+    assert_eq!(overlaps[0].get_identifier(), "TOAD");
+    assert_eq!(overlaps[0].get_patches().len(), 2);
+    assert_eq!(overlaps[0].get_shared_tokens(), ["FLIER"]);
+}
+
+#[test]
+fn fixture_modules_have_no_conflicts() {
+    let report = dfraw_json_parser::detect_conflicts(&common::fixture_options());
+    assert!(report.is_empty(), "{report:#?}");
+}
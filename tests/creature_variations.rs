@@ -0,0 +1,158 @@
+//! Creature variations applied to creatures with `APPLY_CREATURE_VARIATION` and
+//! `APPLY_CURRENT_CREATURE_VARIATION`.
+
+mod common;
+
+use serde_json::Value;
+
+const VARIATION_TEXT: &str = "c_variation_giant\n\n[OBJECT:CREATURE_VARIATION]\n\n\
+    [CREATURE_VARIATION:GIANT]\n\t[CV_ADD_TAG:FREQUENCY:5]\n\t[CV_ADD_TAG:BIOME:MOUNTAIN]\n";
+
+/// Parse the creature text alongside the GIANT variation, resolve them and return the creature
+/// as JSON.
+fn resolved_creature(creature_text: &str) -> Value {
+    let raws = common::parse_and_resolve(&[VARIATION_TEXT, creature_text]);
+    let creature = raws
+        .iter()
+        .find(|raw| raw.get_identifier() == "GIANT_TOAD")
+        .expect("the creature is in the raws");
+    common::raw_to_json(creature.as_ref())
+}
+
+#[test]
+fn variation_overrides_the_tags_before_it() {
+    let creature = resolved_creature(
+        "creature_giant\n\n[OBJECT:CREATURE]\n\n[CREATURE:GIANT_TOAD]\n\t[FREQUENCY:20]\n\
+         \t[APPLY_CREATURE_VARIATION:GIANT]\n\t[APPLY_CURRENT_CREATURE_VARIATION]\n",
+    );
+    assert_eq!(creature["frequency"], 5, "{creature}");
+    assert!(
+        creature.get("applyCreatureVariation").is_none(),
+        "{creature}"
+    );
+}
+
+#[test]
+fn tags_after_apply_current_override_the_variation() {
+    let creature = resolved_creature(
+        "creature_giant\n\n[OBJECT:CREATURE]\n\n[CREATURE:GIANT_TOAD]\n\t[FREQUENCY:20]\n\
+         \t[APPLY_CREATURE_VARIATION:GIANT]\n\t[APPLY_CURRENT_CREATURE_VARIATION]\n\
+         \t[FREQUENCY:30]\n\t[CASTE:FEMALE]\n\t\t[FEMALE]\n",
+    );
+    assert_eq!(creature["frequency"], 30, "{creature}");
+    assert_eq!(
+        creature["biomes"],
+        serde_json::json!(["Mountain"]),
+        "{creature}"
+    );
+    // The variation selects the ALL caste before the FEMALE caste is added after it
+    assert_eq!(creature["castes"][1]["identifier"], "FEMALE", "{creature}");
+    assert_eq!(
+        creature["castes"][1]["tags"],
+        serde_json::json!(["Female"]),
+        "{creature}"
+    );
+    assert!(
+        creature.get("tagsAfterCreatureVariation").is_none(),
+        "{creature}"
+    );
+}
+
+#[test]
+fn tags_after_apply_current_are_kept_without_resolving() {
+    let raws = common::parse_str(
+        "creature_giant\n\n[OBJECT:CREATURE]\n\n[CREATURE:GIANT_TOAD]\n\
+         \t[APPLY_CREATURE_VARIATION:GIANT]\n\t[APPLY_CURRENT_CREATURE_VARIATION]\n\
+         \t[FREQUENCY:30]\n\t[SELECT_CASTE:ALL]\n",
+    );
+    let creature = common::raw_to_json(raws[0].as_ref());
+    assert_eq!(
+        creature["tagsAfterCreatureVariation"],
+        serde_json::json!([["FREQUENCY", "30"], ["SELECT_CASTE", "ALL"]]),
+        "{creature}"
+    );
+}
+
+#[test]
+fn unconvertible_tags_are_reported() {
+    let (raws, report) = dfraw_json_parser::parse_with_report(&common::fixture_options());
+    assert!(!raws.is_empty());
+    let diagnostic = report
+        .get_diagnostics()
+        .iter()
+        .find(|diagnostic| diagnostic.get_message().starts_with("CV_CONVERT_TAG"))
+        .expect("the unconvertible tag is reported");
+    assert_eq!(diagnostic.get_object_identifier(), "GIANT");
+    assert_eq!(diagnostic.get_raw_token(), "[CVCT_MASTER:NAME]");
+    assert!(
+        diagnostic.get_file_path().ends_with("c_variation_test.txt"),
+        "{diagnostic:?}"
+    );
+}
+
+const RULES_VARIATION_TEXT: &str = "c_variation_rules\n\n[OBJECT:CREATURE_VARIATION]\n\n\
+    [CREATURE_VARIATION:RULES]\n\
+    \t[CV_REMOVE_TAG:FLIER]\n\
+    \t[CV_NEW_CTAG:2:BIG:FREQUENCY:7]\n\
+    \t[CV_NEW_CTAG:1:BIG:LARGE_ROAMING]\n\
+    \t[CV_ADD_TAG:NAME:!ARG1:!ARG10:!ARG11]\n";
+
+/// Apply the RULES variation to a creature with the `args`, and return the creature as JSON.
+fn creature_with_rules(args: &str) -> Value {
+    let creature_text = format!(
+        "creature_rules\n\n[OBJECT:CREATURE]\n\n[CREATURE:RULED_TOAD]\n\t[FREQUENCY:20]\n\
+         \t[CASTE:FEMALE]\n\t\t[FEMALE]\n\t\t[FLIER]\n\t[CASTE:MALE]\n\t\t[MALE]\n\t\t[FLIER]\n\
+         \t[SELECT_CASTE:ALL]\n\t[APPLY_CREATURE_VARIATION:RULES:{args}]\n\
+         \t[APPLY_CURRENT_CREATURE_VARIATION]\n",
+    );
+    let raws = common::parse_and_resolve(&[RULES_VARIATION_TEXT, &creature_text]);
+    let creature = raws
+        .iter()
+        .find(|raw| raw.get_identifier() == "RULED_TOAD")
+        .expect("the creature is in the raws");
+    common::raw_to_json(creature.as_ref())
+}
+
+#[test]
+fn removed_tags_are_removed_from_every_caste() {
+    let creature = creature_with_rules("one:big:3:4:5:6:7:8:9:ten:eleven");
+    for (caste, tags) in [("FEMALE", ["Female"]), ("MALE", ["Male"])] {
+        let caste = creature["castes"]
+            .as_array()
+            .and_then(|castes| castes.iter().find(|c| c["identifier"] == caste))
+            .unwrap_or_else(|| panic!("the {caste} caste is kept: {creature}"));
+        assert_eq!(caste["tags"], serde_json::json!(tags), "{creature}");
+    }
+}
+
+#[test]
+fn conditional_rules_check_the_argument_at_their_index() {
+    // The second argument is BIG (in any case), the first isn't
+    let creature = creature_with_rules("one:big:3:4:5:6:7:8:9:ten:eleven");
+    assert_eq!(creature["frequency"], 7, "{creature}");
+    assert!(
+        !creature["tags"]
+            .as_array()
+            .is_some_and(|tags| tags.contains(&Value::from("LargeRoaming"))),
+        "{creature}"
+    );
+
+    let creature = creature_with_rules("BIG:small:3:4:5:6:7:8:9:ten:eleven");
+    assert_eq!(creature["frequency"], 20, "{creature}");
+    assert!(
+        creature["tags"]
+            .as_array()
+            .is_some_and(|tags| tags.contains(&Value::from("LargeRoaming"))),
+        "{creature}"
+    );
+}
+
+#[test]
+fn arguments_past_the_ninth_are_substituted_whole() {
+    // !ARG10 and !ARG11 are replaced by the tenth and eleventh arguments, not by the first one
+    // followed by "0" or "1"
+    let creature = creature_with_rules("one:big:3:4:5:6:7:8:9:ten:eleven");
+    assert_eq!(creature["name"]["singular"], "one", "{creature}");
+    assert_eq!(creature["name"]["plural"], "ten", "{creature}");
+    assert_eq!(creature["name"]["adjective"], "eleven", "{creature}");
+}
@@ -0,0 +1,124 @@
+//! Tokenizing raw files into a lossless `RawDocument`.
+
+mod common;
+
+use dfraw_json_parser::parser::{
+    document::{RawDocument, RawElementRef},
+    object_types::ObjectType,
+};
+
+/// A creature file with comments, stray text between and after tokens, an unterminated `[`, a
+/// Windows line ending, characters outside of ASCII and no line ending at the end.
+const MESSY_RAW_TEXT: &str = "creature_messy\r\n\
+    \n\
+    A comment before the object type [OBJECT:CREATURE] and after it\n\
+    \n\
+    [CREATURE:TOAD] the toad\n\
+    \t[NAME:toad:toads:toad]stray[PETVALUE:10]\n\
+    \t[DESCRIPTION:A toad with an unterminated [ bracket\n\
+    \t[CASTE:FEMALE]\t\n\
+    \t\t[FEMALE]\n\
+    (remarqué) [CREATURE:CRAPAUD]\n\
+    \t[NAME:crapaud:crapauds:crapaud] fin";
+
+#[test]
+fn source_is_given_back_byte_for_byte() {
+    let document = RawDocument::parse(MESSY_RAW_TEXT);
+    assert_eq!(document.to_source(), MESSY_RAW_TEXT);
+    assert_eq!(document.get_raw_identifier(), "creature_messy");
+    assert_eq!(document.get_object_type(), &ObjectType::Creature);
+
+    let identifiers: Vec<&str> = document
+        .get_objects()
+        .iter()
+        .map(|object| object.get_identifier())
+        .collect();
+    assert_eq!(identifiers, ["TOAD", "CRAPAUD"]);
+}
+
+#[test]
+fn fixture_raw_files_are_given_back_byte_for_byte() {
+    for raw_file in common::fixture_raw_files() {
+        let source = std::fs::read(&raw_file).expect("the fixture can be read");
+        let document = RawDocument::from_file(&raw_file).expect("the fixture can be tokenized");
+        let (text, _, _) = encoding_rs::WINDOWS_1252.decode(&source);
+        assert_eq!(document.to_source(), text, "{}", raw_file.display());
+    }
+}
+
+#[test]
+fn spans_point_at_the_text_of_each_element() {
+    let document = RawDocument::parse(MESSY_RAW_TEXT);
+
+    let header = document.get_header().get_span();
+    assert_eq!(
+        &MESSY_RAW_TEXT[header.get_start()..header.get_end()],
+        document.get_header().get_text()
+    );
+
+    // The elements cover the whole text after the header line, without gaps or overlaps
+    let mut end = header.get_end();
+    for element in document.elements() {
+        let span = element.get_span();
+        assert_eq!(span.get_start(), end, "{element:?}");
+        assert_eq!(
+            &MESSY_RAW_TEXT[span.get_start()..span.get_end()],
+            element.get_text()
+        );
+        // The line and column are where the element starts
+        let before = &MESSY_RAW_TEXT[..span.get_start()];
+        assert_eq!(
+            span.get_line(),
+            before.matches('\n').count() + 1,
+            "{element:?}"
+        );
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        assert_eq!(
+            span.get_column(),
+            MESSY_RAW_TEXT[line_start..span.get_start()].chars().count() + 1,
+            "{element:?}"
+        );
+        end = span.get_end();
+    }
+    assert_eq!(end, MESSY_RAW_TEXT.len());
+}
+
+#[test]
+fn tokens_are_found_as_the_parser_finds_them() {
+    let document = RawDocument::parse(MESSY_RAW_TEXT);
+    let tokens: Vec<(&str, &str, usize, usize)> = document
+        .tokens()
+        .map(|token| {
+            let span = token.get_span();
+            (
+                token.get_key(),
+                token.get_value(),
+                span.get_line(),
+                span.get_column(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            ("OBJECT", "CREATURE", 3, 34),
+            ("CREATURE", "TOAD", 5, 1),
+            ("NAME", "toad:toads:toad", 6, 2),
+            ("PETVALUE", "10", 6, 29),
+            ("CASTE", "FEMALE", 8, 2),
+            ("FEMALE", "", 9, 3),
+            ("CREATURE", "CRAPAUD", 10, 12),
+            ("NAME", "crapaud:crapauds:crapaud", 11, 2),
+        ]
+    );
+
+    // The unterminated token is kept as text, in the object it is in
+    let toad = &document.get_objects()[0];
+    assert!(toad.get_elements().iter().all(|element| element
+        .as_token()
+        .map_or(true, |token| token.get_key() != "DESCRIPTION")));
+    assert!(document.elements().any(|element| matches!(
+        element,
+        RawElementRef::Element(element) if element.get_text().contains("unterminated [ bracket")
+    )));
+}
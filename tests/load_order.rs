@@ -0,0 +1,140 @@
+//! Working out the order modules are loaded in with `compute_load_order`.
+
+mod common;
+
+use dfraw_json_parser::{
+    options::ParserOptions,
+    parser::{
+        diagnostics::DiagnosticSeverity, load_order::compute_load_order,
+        module_info_file::ModuleInfoFile, object_types::ObjectType,
+        raw_locations::RawModuleLocation,
+    },
+};
+
+fn info_text(identifier: &str, info_tokens: &str) -> String {
+    format!(
+        "[ID:{identifier}]\n[NUMERIC_VERSION:1]\n[DISPLAYED_VERSION:1.0]\n[NAME:{identifier}]\n{info_tokens}"
+    )
+}
+
+fn module(identifier: &str, info_tokens: &str) -> ModuleInfoFile {
+    ModuleInfoFile::from_info_text(
+        &info_text(identifier, info_tokens),
+        RawModuleLocation::Mods,
+        identifier,
+    )
+}
+
+/// The identifiers of `modules` in the order `compute_load_order` loads them in.
+fn load_order_of(modules: &[ModuleInfoFile], explicit_order: &[&str]) -> Vec<String> {
+    let explicit_order: Vec<String> = explicit_order.iter().map(|&s| String::from(s)).collect();
+    compute_load_order(modules, &explicit_order)
+        .into_iter()
+        .map(|index| modules[index].get_identifier())
+        .collect()
+}
+
+#[test]
+fn modules_keep_the_order_they_were_found_in() {
+    let modules = [
+        module("first", ""),
+        module("second", ""),
+        module("third", ""),
+    ];
+    assert_eq!(load_order_of(&modules, &[]), ["first", "second", "third"]);
+}
+
+#[test]
+fn explicit_order_comes_first() {
+    let modules = [
+        module("first", ""),
+        module("second", ""),
+        module("third", ""),
+        module("fourth", ""),
+    ];
+    // Modules which aren't in the explicit order are loaded after it, in the order they were found
+    // in, and identifiers which match no module are skipped
+    assert_eq!(
+        load_order_of(&modules, &["third", "not_installed", "first"]),
+        ["third", "first", "second", "fourth"]
+    );
+}
+
+#[test]
+fn modules_are_moved_after_the_modules_they_require() {
+    let modules = [
+        // Needs `second` loaded before it
+        module("first", "[REQUIRES_ID_BEFORE_ME:second]\n"),
+        module("second", ""),
+        module("third", ""),
+        // Needs to be loaded before `third`
+        module("fourth", "[REQUIRES_ID_AFTER_ME:third]\n"),
+    ];
+    assert_eq!(
+        load_order_of(&modules, &[]),
+        ["second", "first", "fourth", "third"]
+    );
+}
+
+#[test]
+fn requirements_on_missing_modules_are_ignored() {
+    let modules = [
+        module("first", "[REQUIRES_ID_BEFORE_ME:not_installed]\n"),
+        module("second", "[REQUIRES_ID_AFTER_ME:also_not_installed]\n"),
+    ];
+    assert_eq!(load_order_of(&modules, &[]), ["first", "second"]);
+}
+
+#[test]
+fn cyclic_requirements_keep_the_order_they_were_found_in() {
+    let modules = [
+        module("first", ""),
+        module("second", "[REQUIRES_ID_BEFORE_ME:third]\n"),
+        module("third", "[REQUIRES_ID_BEFORE_ME:second]\n"),
+        module("fourth", "[REQUIRES_ID_AFTER_ME:first]\n"),
+    ];
+    // `fourth` can still be moved before `first`, but the cycle is loaded as found
+    assert_eq!(
+        load_order_of(&modules, &[]),
+        ["fourth", "first", "second", "third"]
+    );
+}
+
+#[test]
+fn cyclic_requirements_are_reported() {
+    let df_path = common::empty_temp_dir("load_order_cycle");
+    for (identifier, info_tokens) in [
+        ("cycle_a", "[REQUIRES_ID_BEFORE_ME:cycle_b]\n"),
+        ("cycle_b", "[REQUIRES_ID_BEFORE_ME:cycle_a]\n"),
+    ] {
+        let module_path = df_path.join("mods").join(identifier);
+        common::write_file(
+            &module_path.join("info.txt"),
+            info_text(identifier, info_tokens),
+        );
+        common::write_file(
+            &module_path.join("objects").join("creature_cycle.txt"),
+            format!("creature_cycle\n\n[OBJECT:CREATURE]\n\n[CREATURE:{identifier}]\n"),
+        );
+    }
+
+    let mut options = ParserOptions::new(&df_path);
+    options.set_locations_to_parse(vec![RawModuleLocation::Mods]);
+    options.set_raws_to_parse(vec![ObjectType::Creature]);
+    let (raws, report) = dfraw_json_parser::parse_with_report(&options);
+
+    assert_eq!(raws.len(), 2);
+    let warnings: Vec<&str> = report
+        .with_severity(DiagnosticSeverity::Warning)
+        .into_iter()
+        .map(|diagnostic| diagnostic.get_message())
+        .collect();
+    assert_eq!(
+        warnings
+            .iter()
+            .filter(|message| message.starts_with("Circular load order requirements between"))
+            .count(),
+        1,
+        "{warnings:?}"
+    );
+}
@@ -0,0 +1,142 @@
+//! Malformed raws which used to crash the parser. A broken mod should only ever produce
+//! diagnostics, so each of these has to parse (and resolve) without panicking.
+
+mod common;
+
+use dfraw_json_parser::parser::{
+    creature::raw::Creature,
+    creature_caste::raw::Caste,
+    diagnostics::{DiagnosticSeverity, ParseReport},
+};
+
+/// The messages of the warnings in `report`.
+fn warnings(report: &ParseReport) -> Vec<&str> {
+    report
+        .with_severity(DiagnosticSeverity::Warning)
+        .into_iter()
+        .map(|diagnostic| diagnostic.get_message())
+        .collect()
+}
+
+#[test]
+fn building_rows_out_of_range() {
+    let (raws, report) = common::parse_module_with_report(
+        "malformed_building_rows",
+        "malformed\n\n[OBJECT:BUILDING]\n\n[BUILDING_WORKSHOP:HUGE]\n\t[DIM:1:1]\n\
+         \t[BLOCK:18446744073709551615:0]\n\t[BLOCK:4000000000:0]\n\
+         \t[TILE:3:4000000000:0]\n\t[COLOR:3:18446744073709551615:MAT]\n",
+    );
+    assert_eq!(raws.len(), 1);
+    // Every row is out of range, so none of them are kept
+    let json = common::raw_to_json(raws[0].as_ref());
+    assert!(
+        json.get("blocks")
+            .and_then(serde_json::Value::as_array)
+            .map_or(true, Vec::is_empty),
+        "{json}"
+    );
+    for stage in json
+        .get("stages")
+        .and_then(serde_json::Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        for rows in ["tiles", "colors"] {
+            assert!(
+                stage
+                    .get(rows)
+                    .and_then(serde_json::Value::as_array)
+                    .map_or(true, Vec::is_empty),
+                "{json}"
+            );
+        }
+    }
+    let warnings = warnings(&report);
+    assert_eq!(
+        warnings
+            .iter()
+            .filter(|message| message.starts_with("Building row")
+                && message.ends_with("is out of range (1 to 31)"))
+            .count(),
+        4,
+        "{warnings:?}"
+    );
+}
+
+#[test]
+fn layer_conditions_without_a_layer() {
+    let (raws, report) = common::parse_module_with_report(
+        "malformed_layer_conditions",
+        "malformed\n\n[OBJECT:GRAPHICS]\n\n[CREATURE_GRAPHICS:CAT]\n\t[LAYER_GROUP]\n\
+         \t\t[CONDITION_NOT_CHILD]\n\t\t[LAYER:BODY:NO_SUCH_PAGE]\n\t\t[CONDITION_BP:BY_CATEGORY:BODY]\n",
+    );
+    assert_eq!(raws.len(), 1);
+    // The layer is missing its tile position, so it isn't parsed and there is never a layer for
+    // the conditions to be attached to
+    let json = common::raw_to_json(raws[0].as_ref());
+    assert!(
+        json.get("layers")
+            .and_then(serde_json::Value::as_array)
+            .map_or(true, Vec::is_empty),
+        "{json}"
+    );
+    let warnings = warnings(&report);
+    for condition in ["CONDITION_NOT_CHILD", "CONDITION_BP"] {
+        assert!(
+            warnings.contains(
+                &format!("Layer condition {condition} has no layer to apply to").as_str()
+            ),
+            "{warnings:?}"
+        );
+    }
+}
+
+#[test]
+fn creature_caste_tag_before_any_caste() {
+    let raws = common::parse_and_resolve(&[
+        "malformed\n\n[OBJECT:CREATURE]\n\n[CREATURE:TOAD]\n\t[PETVALUE:10]\n\t[CASTE:FEMALE]\n",
+    ]);
+    assert_eq!(raws.len(), 1);
+    let creature = raws[0]
+        .as_any()
+        .downcast_ref::<Creature>()
+        .expect("the raw is a creature");
+    // The tag gets an ALL caste, since there was no caste to add it to
+    let castes: Vec<&str> = creature
+        .get_castes()
+        .iter()
+        .map(Caste::get_identifier)
+        .collect();
+    assert_eq!(castes, ["ALL", "FEMALE"]);
+}
+
+#[test]
+fn layer_before_any_layer_set() {
+    let raws = common::parse_and_resolve(&[
+        "malformed\n\n[OBJECT:GRAPHICS]\n\n[CREATURE_GRAPHICS:CAT]\n\
+         \t[LAYER:BODY:CREATURES_DOMESTIC:0:1]\n\t\t[CONDITION_NOT_CHILD]\n",
+    ]);
+    assert_eq!(raws.len(), 1);
+    let json = common::raw_to_json(raws[0].as_ref());
+    assert_eq!(json["layers"][0][0], "default", "{json}");
+    assert_eq!(
+        json["layers"][0][1].as_array().map(Vec::len),
+        Some(1),
+        "{json}"
+    );
+    assert_eq!(
+        json["layers"][0][1][0]["conditions"],
+        serde_json::json!([["NotChild", ""]]),
+        "{json}"
+    );
+}
+
+#[test]
+fn plant_tree_tag_before_tree_details() {
+    let raws = common::parse_and_resolve(&[
+        "malformed\n\n[OBJECT:PLANT]\n\n[PLANT:OAK]\n\t[TRUNK_PERIOD:10]\n\t[TREE:LOCAL_PLANT_MAT:WOOD]\n",
+    ]);
+    assert_eq!(raws.len(), 1);
+    let json = common::raw_to_json(raws[0].as_ref());
+    assert!(json.get("treeDetails").is_some(), "{json}");
+}
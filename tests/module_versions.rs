@@ -0,0 +1,103 @@
+//! Picking which versions of each module to parse with `select_module_versions`.
+
+use std::collections::HashMap;
+
+use dfraw_json_parser::{
+    options::ModuleVersionPolicy,
+    parser::{
+        module_info_file::ModuleInfoFile,
+        module_versions::{select_module_versions, DroppedModule},
+        raw_locations::RawModuleLocation,
+    },
+};
+
+fn module(identifier: &str, version: u32, location: RawModuleLocation) -> ModuleInfoFile {
+    ModuleInfoFile::from_info_text(
+        &format!(
+            "[ID:{identifier}]\n[NUMERIC_VERSION:{version}]\n[DISPLAYED_VERSION:{version}.0]\n[NAME:{identifier}]\n"
+        ),
+        location,
+        &format!("{identifier} ({version})"),
+    )
+}
+
+/// Two versions of `my_mod` in `installed_mods`, a newer one in `mods`, and `other_mod` once.
+fn modules() -> Vec<ModuleInfoFile> {
+    vec![
+        module("my_mod", 1, RawModuleLocation::InstalledMods),
+        module("other_mod", 4, RawModuleLocation::InstalledMods),
+        module("my_mod", 2, RawModuleLocation::InstalledMods),
+        module("my_mod", 3, RawModuleLocation::Mods),
+    ]
+}
+
+/// The identifiers and versions of the dropped modules, and the versions kept instead.
+fn dropped_versions(dropped: &[DroppedModule]) -> Vec<(&str, u32, u32)> {
+    dropped
+        .iter()
+        .map(|module| {
+            (
+                module.get_identifier(),
+                module.get_numeric_version(),
+                module.get_kept_numeric_version(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn all_versions_are_kept() {
+    let (kept, dropped) = select_module_versions(&modules(), &ModuleVersionPolicy::All);
+    assert_eq!(kept, [0, 1, 2, 3]);
+    assert!(dropped.is_empty(), "{dropped:?}");
+}
+
+#[test]
+fn newest_version_is_kept() {
+    let (kept, dropped) = select_module_versions(&modules(), &ModuleVersionPolicy::Newest);
+    assert_eq!(kept, [1, 3]);
+    assert_eq!(
+        dropped_versions(&dropped),
+        [("my_mod", 1, 3), ("my_mod", 2, 3)]
+    );
+    assert_eq!(dropped[0].get_location(), RawModuleLocation::InstalledMods);
+    assert_eq!(dropped[0].get_parent_directory(), "my_mod (1)");
+    assert_eq!(dropped[0].get_displayed_version(), "1.0");
+}
+
+#[test]
+fn pinned_version_is_kept() {
+    let policy = ModuleVersionPolicy::Pinned(HashMap::from([(String::from("my_mod"), 2)]));
+    let (kept, dropped) = select_module_versions(&modules(), &policy);
+    assert_eq!(kept, [1, 2]);
+    assert_eq!(
+        dropped_versions(&dropped),
+        [("my_mod", 1, 2), ("my_mod", 3, 2)]
+    );
+}
+
+#[test]
+fn pin_which_matches_no_version_keeps_the_newest() {
+    let policy = ModuleVersionPolicy::Pinned(HashMap::from([
+        (String::from("my_mod"), 7),
+        (String::from("missing_mod"), 1),
+    ]));
+    let (kept, dropped) = select_module_versions(&modules(), &policy);
+    assert_eq!(kept, [1, 3]);
+    assert_eq!(
+        dropped_versions(&dropped),
+        [("my_mod", 1, 3), ("my_mod", 2, 3)]
+    );
+}
+
+#[test]
+fn same_version_found_twice_keeps_the_first() {
+    let modules = [
+        module("my_mod", 1, RawModuleLocation::Vanilla),
+        module("my_mod", 1, RawModuleLocation::InstalledMods),
+    ];
+    let (kept, dropped) = select_module_versions(&modules, &ModuleVersionPolicy::Newest);
+    assert_eq!(kept, [0]);
+    assert_eq!(dropped_versions(&dropped), [("my_mod", 1, 1)]);
+    assert_eq!(dropped[0].get_location(), RawModuleLocation::InstalledMods);
+}
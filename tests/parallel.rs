@@ -0,0 +1,57 @@
+//! Parsing in parallel with the "rayon" feature gives the same raws and diagnostics, in the same
+//! order, as a sequential parse. Run with `cargo test --features rayon`.
+#![cfg(feature = "rayon")]
+
+mod common;
+
+use dfraw_json_parser::parser::{diagnostics::Diagnostic, raws::RawObject};
+use serde_json::Value;
+
+fn to_json(raws: &[Box<dyn RawObject>]) -> Vec<Value> {
+    raws.iter()
+        .map(|raw| common::raw_to_json(raw.as_ref()))
+        .collect()
+}
+
+fn parse_on_threads(num_threads: usize) -> (Vec<Value>, Vec<Diagnostic>) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .expect("the thread pool can be built");
+    let (raws, report) =
+        pool.install(|| dfraw_json_parser::parse_with_report(&common::fixture_options()));
+    (to_json(&raws), report.get_diagnostics().to_vec())
+}
+
+#[test]
+fn parallel_parse_matches_sequential_parse() {
+    // `parse_iter` parses the raw files one after another, even with the "rayon" feature
+    let options = common::fixture_options();
+    let mut sequential_raws: Vec<Box<dyn RawObject>> = dfraw_json_parser::parse_iter(&options)
+        .collect::<Result<_, _>>()
+        .expect("the fixtures parse");
+    dfraw_json_parser::resolve_raws(&mut sequential_raws, &options);
+    let sequential_raws = to_json(&sequential_raws);
+    assert!(!sequential_raws.is_empty(), "the fixtures have raws");
+
+    // The diagnostics of a parse on a single thread, which finishes the raw files in order
+    let (raws, sequential_diagnostics) = parse_on_threads(1);
+    assert!(
+        raws == sequential_raws,
+        "the raws differ on a single thread"
+    );
+    assert!(
+        !sequential_diagnostics.is_empty(),
+        "the fixtures report diagnostics"
+    );
+
+    // A few times, since the threads finish the raw files in a different order each time
+    for _ in 0..4 {
+        let (raws, diagnostics) = parse_on_threads(8);
+        assert!(
+            raws == sequential_raws,
+            "the raws are in a different order (or differ) when parsed in parallel"
+        );
+        assert_eq!(diagnostics, sequential_diagnostics);
+    }
+}
@@ -0,0 +1,52 @@
+//! Raw metadata is hidden from the exported JSON unless `attach_metadata_to_raws` is set. Raws
+//! deserialized from JSON without metadata get the default metadata, which has to stay hidden so
+//! exporting them again doesn't add an empty `metadata` object to each raw.
+
+mod common;
+
+use dfraw_json_parser::{
+    options::ParserOptions,
+    parser::raws::{RawMetadata, RawObject},
+};
+
+const RAW_TEXT: &str =
+    "metadata_test\n\n[OBJECT:CREATURE]\n\n[CREATURE:TOAD]\n\t[NAME:toad:toads:toad]\n";
+const MATERIAL_TEMPLATE_TEXT: &str =
+    "metadata_test\n\n[OBJECT:MATERIAL_TEMPLATE]\n\n[MATERIAL_TEMPLATE:STONE_TEMPLATE]\n\t[STATE_NAME_ADJ:ALL_SOLID:stone]\n";
+
+#[test]
+fn default_metadata_is_hidden() {
+    assert!(RawMetadata::default().is_hidden());
+}
+
+#[test]
+fn hidden_metadata_stays_hidden_after_deserializing() {
+    let options = ParserOptions::default();
+    for raw_text in [RAW_TEXT, MATERIAL_TEMPLATE_TEXT] {
+        let raws = common::parse_str_in_module(raw_text, &common::test_module(), &options);
+        assert_eq!(raws.len(), 1);
+        let json = serde_json::to_value(&raws[0]).expect("raws serialize");
+        assert!(json.get("metadata").is_none(), "{json}");
+
+        let deserialized: Box<dyn RawObject> =
+            serde_json::from_value(json).expect("raws deserialize");
+        let json = serde_json::to_value(&deserialized).expect("raws serialize");
+        assert!(json.get("metadata").is_none(), "{json}");
+    }
+}
+
+#[test]
+fn attached_metadata_is_kept_after_deserializing() {
+    let mut options = ParserOptions::default();
+    options.attach_metadata_to_raws();
+    let raws = common::parse_str_in_module(RAW_TEXT, &common::test_module(), &options);
+    let json = serde_json::to_value(&raws[0]).expect("raws serialize");
+    assert!(json.get("metadata").is_some(), "{json}");
+
+    let deserialized: Box<dyn RawObject> =
+        serde_json::from_value(json.clone()).expect("raws deserialize");
+    assert_eq!(
+        serde_json::to_value(&deserialized).expect("raws serialize"),
+        json
+    );
+}
@@ -0,0 +1,128 @@
+//! Parsing raws which aren't on disk, from bytes and from readers.
+
+mod common;
+
+use std::io::Read;
+
+use dfraw_json_parser::{
+    parser::{try_parse_raws_from_bytes, try_parse_raws_from_reader},
+    ParserError,
+};
+
+const RAW_TEXT: &str =
+    "creature_bytes\n\n[OBJECT:CREATURE]\n\n[CREATURE:TOAD]\n\t[NAME:crapaud:crapauds:crapaud \u{e9}l\u{e9}gant]\n";
+
+/// The name of the (only) parsed raw, as JSON.
+fn parsed_name(raw_bytes: &[u8]) -> serde_json::Value {
+    let raws = try_parse_raws_from_bytes(
+        raw_bytes,
+        "creature_bytes.txt",
+        &common::test_module(),
+        &common::fixture_options(),
+    )
+    .expect("the bytes parse");
+    assert_eq!(raws.len(), 1);
+    common::raw_to_json(raws[0].as_ref())["name"].clone()
+}
+
+#[test]
+fn bytes_are_decoded_with_the_game_encoding() {
+    let (game_bytes, _, _) = encoding_rs::WINDOWS_1252.encode(RAW_TEXT);
+    assert_ne!(game_bytes.as_ref(), RAW_TEXT.as_bytes());
+
+    let name = parsed_name(&game_bytes);
+    assert_eq!(
+        name,
+        common::raw_to_json(common::parse_str(RAW_TEXT)[0].as_ref())["name"]
+    );
+    assert_eq!(name["singular"], "crapaud", "{name}");
+    assert_eq!(name["plural"], "crapauds", "{name}");
+    assert_eq!(name["adjective"], "crapaud \u{e9}l\u{e9}gant", "{name}");
+}
+
+#[test]
+fn bytes_with_a_utf8_byte_order_mark_are_decoded_as_utf8() {
+    let utf8_bytes = [b"\xEF\xBB\xBF".as_slice(), RAW_TEXT.as_bytes()].concat();
+    let name = parsed_name(&utf8_bytes);
+    assert_eq!(name["adjective"], "crapaud \u{e9}l\u{e9}gant", "{name}");
+}
+
+#[test]
+fn bytes_which_cant_be_decoded_are_an_error() {
+    // A byte order mark says the text is UTF-8, but line 6 isn't
+    let (game_bytes, _, _) = encoding_rs::WINDOWS_1252.encode(RAW_TEXT);
+    let bytes = [b"\xEF\xBB\xBF".as_slice(), &game_bytes].concat();
+
+    let result = try_parse_raws_from_bytes(
+        &bytes,
+        "creature_bytes.txt",
+        &common::test_module(),
+        &common::fixture_options(),
+    );
+    match result {
+        Err(ParserError::Encoding { path, line, .. }) => {
+            assert_eq!(path.to_string_lossy(), "creature_bytes.txt");
+            assert_eq!(line, 6);
+        }
+        Err(e) => panic!("expected an encoding error, got {e}"),
+        Ok(raws) => panic!("expected an encoding error, parsed {} raws", raws.len()),
+    }
+}
+
+#[test]
+fn reader_is_parsed_the_same_as_its_bytes() {
+    let (game_bytes, _, _) = encoding_rs::WINDOWS_1252.encode(RAW_TEXT);
+    let raws = try_parse_raws_from_reader(
+        game_bytes.as_ref(),
+        "creature_bytes.txt",
+        &common::test_module(),
+        &common::fixture_options(),
+    )
+    .expect("the reader parses");
+    assert_eq!(raws.len(), 1);
+    assert_eq!(
+        common::raw_to_json(raws[0].as_ref())["name"],
+        parsed_name(&game_bytes)
+    );
+}
+
+/// A reader which fails after giving the first part of the text.
+struct FailingReader<'a> {
+    remaining: &'a [u8],
+}
+
+impl Read for FailingReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.remaining.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "the connection was reset",
+            ));
+        }
+        let length = self.remaining.len().min(buf.len());
+        buf[..length].copy_from_slice(&self.remaining[..length]);
+        self.remaining = &self.remaining[length..];
+        Ok(length)
+    }
+}
+
+#[test]
+fn reader_errors_are_returned() {
+    let reader = FailingReader {
+        remaining: &RAW_TEXT.as_bytes()[..20],
+    };
+    let result = try_parse_raws_from_reader(
+        reader,
+        "creature_stream",
+        &common::test_module(),
+        &common::fixture_options(),
+    );
+    match result {
+        Err(ParserError::Io { path, source }) => {
+            assert_eq!(path.to_string_lossy(), "creature_stream");
+            assert_eq!(source.kind(), std::io::ErrorKind::ConnectionReset);
+        }
+        Err(e) => panic!("expected an IO error, got {e}"),
+        Ok(raws) => panic!("expected an IO error, parsed {} raws", raws.len()),
+    }
+}
@@ -0,0 +1,300 @@
+//! Round trips of the parsed raws, which flag any field that is lost along the way:
+//!
+//! * parse → serialize to JSON → deserialize → serialize, which catches fields whose
+//!   `skip_serializing_if` helper skips a value that doesn't deserialize back to the same value
+//! * parse → write as raw text → parse, for the types `parser::writer` can write
+//! * parse vanilla creature text → write as raw text, comparing the tokens, which catches tokens
+//!   the writer loses (or changes) even when the parsed creature doesn't keep them
+//! * parse → clone the boxed raw, which the resolution passes do to every raw
+
+mod common;
+
+use std::{collections::BTreeMap, path::Path};
+
+use dfraw_json_parser::parser::{
+    creature::phf_table::CREATURE_TOKENS,
+    creature_caste::phf_table::CASTE_TOKENS,
+    document::RawDocument,
+    raws::RawObject,
+    try_parse_raws_from_single_file,
+    writer::{as_raw_text, raws_to_raw_text, write_raw_file},
+};
+use dfraw_json_parser::ParserError;
+use serde_json::Value;
+
+/// Serialize the raw, deserialize it and serialize it again, returning the differences between
+/// the two serializations.
+fn json_round_trip(raw: &dyn RawObject) -> Vec<String> {
+    let json = common::raw_to_json(raw);
+    let deserialized: Box<dyn RawObject> = match serde_json::from_value(json.clone()) {
+        Ok(raw) => raw,
+        Err(e) => return vec![format!("unable to deserialize: {e}")],
+    };
+    common::json_differences(&json, &common::raw_to_json(deserialized.as_ref()))
+}
+
+/// Write the raws as raw text and parse the text again, returning the differences between the
+/// raws (other than their object IDs and metadata).
+fn raw_text_round_trip(
+    raw_identifier: &str,
+    raws: &[Box<dyn RawObject>],
+    dir: &Path,
+) -> Vec<String> {
+    let text = match raws_to_raw_text(raw_identifier, raws) {
+        Ok(text) => text,
+        Err(e) => return vec![format!("unable to write: {e}")],
+    };
+    let reparsed = common::parse_str(&text);
+
+    let mut differences = Vec::new();
+    if reparsed.len() != raws.len() {
+        differences.push(format!(
+            "wrote {} raws but parsed {}:\n{text}",
+            raws.len(),
+            reparsed.len()
+        ));
+    }
+    for (raw, again) in raws.iter().zip(&reparsed) {
+        let mut expected = common::raw_to_json(raw.as_ref());
+        let mut actual = common::raw_to_json(again.as_ref());
+        common::strip_source(&mut expected);
+        common::strip_source(&mut actual);
+        resolve_tile_page_file(&mut actual, dir);
+        for difference in common::json_differences(&expected, &actual) {
+            differences.push(format!("{}: {difference}", raw.get_identifier()));
+        }
+    }
+    differences
+}
+
+/// Tile page files are written relative to the raw file, and text parsed without a path keeps
+/// them relative, so resolve them against the directory the original raw file was in.
+fn resolve_tile_page_file(value: &mut Value, dir: &Path) {
+    if value.get("type").and_then(Value::as_str) != Some("TilePage") {
+        return;
+    }
+    if let Some(Value::String(file)) = value.get("file") {
+        let resolved = common::replace_fixture_path(&dir.join(file).to_string_lossy());
+        value["file"] = Value::String(resolved);
+    }
+}
+
+/// A creature with `token` on the creature (or on its caste, for caste tokens).
+fn token_test_creature(token: &str, is_caste_token: bool) -> String {
+    let token_indent = if is_caste_token { "\t\t" } else { "\t" };
+    format!(
+        "creature_token_test\n\n[OBJECT:CREATURE]\n\n[CREATURE:TOKEN_TEST]\n\t[CASTE:FEMALE]\n{token_indent}[{token}]\n"
+    )
+}
+
+/// The tokens of each object in the raw text, as key and value. The tokens of each caste are
+/// listed separately (the creature's own tokens with the `ALL` caste), and sorted, since the
+/// writer groups the tokens differently than the raw files do.
+fn object_tokens(raw_text: &str) -> BTreeMap<String, Vec<(String, String)>> {
+    let document = RawDocument::parse(raw_text);
+    let mut objects = BTreeMap::new();
+    for object in document.get_objects() {
+        let mut caste = "ALL";
+        for token in object.tokens() {
+            if matches!(token.get_key(), "CASTE" | "SELECT_CASTE") {
+                caste = token.get_value();
+            }
+            objects
+                .entry(format!("{}:{caste}", object.get_identifier()))
+                .or_insert_with(Vec::new)
+                .push((token.get_key().to_string(), token.get_value().to_string()));
+        }
+    }
+    for tokens in objects.values_mut() {
+        tokens.sort();
+    }
+    objects
+}
+
+#[test]
+fn fixtures_survive_json_round_trip() {
+    let raws = dfraw_json_parser::parse(&common::fixture_options());
+    let failures: Vec<String> = raws
+        .iter()
+        .flat_map(|raw| {
+            json_round_trip(raw.as_ref())
+                .into_iter()
+                .map(|difference| format!("{}: {difference}", raw.get_object_id()))
+        })
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "fields changed in the JSON round trip:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn fixtures_survive_cloning() {
+    let raws = dfraw_json_parser::parse(&common::fixture_options());
+    let failures: Vec<String> = raws
+        .iter()
+        .flat_map(|raw| {
+            let clone = raw.clone();
+            common::json_differences(
+                &common::raw_to_json(raw.as_ref()),
+                &common::raw_to_json(clone.as_ref()),
+            )
+            .into_iter()
+            .map(|difference| format!("{}: {difference}", raw.get_object_id()))
+        })
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "fields changed when cloning:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn fixtures_survive_raw_text_round_trip() {
+    let options = common::fixture_options();
+    let mut failures = Vec::new();
+    let mut written = 0;
+    for path in common::fixture_raw_files() {
+        let raws = try_parse_raws_from_single_file(&path, &options).expect("fixtures parse");
+        let raws: Vec<Box<dyn RawObject>> = raws
+            .into_iter()
+            .filter(|raw| as_raw_text(raw.as_ref()).is_some())
+            .collect();
+        if raws.is_empty() {
+            continue;
+        }
+        written += raws.len();
+        let raw_identifier = raws[0].get_metadata().get_raw_identifier().to_string();
+        let dir = path.parent().expect("fixtures are in a directory");
+        for difference in raw_text_round_trip(&raw_identifier, &raws, dir) {
+            failures.push(format!("{}: {difference}", path.display()));
+        }
+    }
+    assert!(
+        written > 0,
+        "none of the fixtures could be written as raw text"
+    );
+    assert!(
+        failures.is_empty(),
+        "fields changed in the raw text round trip:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn every_creature_token_survives_round_trip() {
+    let tokens = CREATURE_TOKENS
+        .keys()
+        .map(|key| (*key, false))
+        .chain(CASTE_TOKENS.keys().map(|key| (*key, true)));
+
+    let mut failures = Vec::new();
+    for (token, is_caste_token) in tokens {
+        // As a flag, and with a value (which most tokens need).
+        for token in [String::from(token), format!("{token}:1")] {
+            let text = token_test_creature(&token, is_caste_token);
+            let raws = common::parse_str(&text);
+            assert_eq!(raws.len(), 1, "[{token}] didn't parse as one creature");
+            for raw in &raws {
+                for difference in json_round_trip(raw.as_ref()) {
+                    failures.push(format!("[{token}] JSON: {difference}"));
+                }
+            }
+            for difference in raw_text_round_trip("creature_token_test", &raws, Path::new("")) {
+                failures.push(format!("[{token}] raw text: {difference}"));
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "creature tokens changed in a round trip:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn vanilla_creature_tokens_survive_raw_text_round_trip() {
+    let path = common::fixtures_dir()
+        .join("raw_text")
+        .join("creature_vanilla.txt");
+    let text = std::fs::read_to_string(&path).expect("the fixture can be read");
+    let raws = common::parse_str(&text);
+    assert_eq!(raws.len(), 2, "the fixture has two creatures");
+
+    let written = raws_to_raw_text("creature_vanilla", &raws).expect("the creatures are written");
+    let mut failures = Vec::new();
+    let expected = object_tokens(&text);
+    let actual = object_tokens(&written);
+    for (object, expected_tokens) in &expected {
+        let actual_tokens = actual.get(object).cloned().unwrap_or_default();
+        for token in expected_tokens {
+            if !actual_tokens.contains(token) {
+                failures.push(format!("{object}: lost [{}:{}]", token.0, token.1));
+            }
+        }
+        for token in &actual_tokens {
+            if !expected_tokens.contains(token) {
+                failures.push(format!("{object}: added [{}:{}]", token.0, token.1));
+            }
+        }
+    }
+    for object in actual
+        .keys()
+        .filter(|object| !expected.contains_key(*object))
+    {
+        failures.push(format!("{object}: added"));
+    }
+    assert!(
+        failures.is_empty(),
+        "tokens changed when writing the vanilla creatures:\n{}\n\n{written}",
+        failures.join("\n")
+    );
+
+    for difference in raw_text_round_trip("creature_vanilla", &raws, Path::new("")) {
+        failures.push(difference);
+    }
+    assert!(
+        failures.is_empty(),
+        "fields changed in the raw text round trip:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn raw_files_are_written_in_the_game_encoding() {
+    let dir = common::empty_temp_dir("write_raw_file");
+    let raws = common::parse_str(&token_test_creature("NAME:pâté:pâtés:pâté", false));
+    let path = dir.join("creature_token_test.txt");
+    write_raw_file(&path, &raws).expect("the creature is written");
+
+    let bytes = std::fs::read(&path).expect("the written file can be read");
+    assert!(
+        bytes.windows(2).any(|pair| pair == [b't', 0xe9]),
+        "the name isn't written in latin1"
+    );
+    let reparsed = try_parse_raws_from_single_file(&path, &common::fixture_options())
+        .expect("the written file parses");
+    assert_eq!(reparsed[0].get_name(), "pâté");
+}
+
+#[test]
+fn raws_the_game_encoding_cant_hold_are_not_written() {
+    let dir = common::empty_temp_dir("write_raw_file_unencodable");
+    let raws = common::parse_str(&token_test_creature("NAME:toad🐸:toads:toad", false));
+    let path = dir.join("creature_token_test.txt");
+    let result = write_raw_file(&path, &raws);
+
+    let text = raws_to_raw_text("creature_token_test", &raws).expect("the creature is written");
+    let name_line = text
+        .lines()
+        .position(|line| line.contains("NAME:"))
+        .expect("the name is written")
+        + 1;
+    assert!(
+        matches!(result, Err(ParserError::Encoding { line, .. }) if line == name_line),
+        "{result:?}"
+    );
+    assert!(!path.exists(), "nothing is written");
+}
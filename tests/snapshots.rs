@@ -0,0 +1,105 @@
+//! Golden JSON snapshots of what `parse` makes of the fixture corpus.
+//!
+//! There is one snapshot per raw file, in the same place under `tests/snapshots` as the raw file
+//! is under `tests/fixtures/data/vanilla`. After an intended change to the parsed output,
+//! regenerate them with:
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test --test snapshots
+//! ```
+//!
+//! and review the changes to the snapshots like any other change.
+
+mod common;
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+#[test]
+fn fixtures_have_every_parsable_type() {
+    let raws = dfraw_json_parser::parse(&common::fixture_options());
+    for object_type in &common::PARSABLE_OBJECT_TYPES {
+        assert!(
+            raws.iter().any(|raw| raw.get_type() == object_type
+                || raw.get_metadata().get_object_type() == object_type),
+            "the fixture corpus has no {object_type:?} raws"
+        );
+    }
+}
+
+#[test]
+fn parse_matches_snapshots() {
+    let raws = dfraw_json_parser::parse(&common::fixture_options());
+    assert!(
+        !raws.is_empty(),
+        "nothing was parsed from the fixture corpus"
+    );
+
+    // Group the raws by the raw file they came from, keeping the order they were parsed in.
+    let modules_dir = common::fixtures_dir().join("data").join("vanilla");
+    let mut parsed: BTreeMap<PathBuf, Vec<Value>> = BTreeMap::new();
+    for raw in &raws {
+        let raw_file_path = Path::new(raw.get_metadata().get_raw_file_path());
+        let snapshot = raw_file_path
+            .strip_prefix(&modules_dir)
+            .expect("the raws are from the fixture modules")
+            .with_extension("json");
+        parsed
+            .entry(snapshot)
+            .or_default()
+            .push(common::raw_to_json(raw.as_ref()));
+    }
+
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let mut failures = Vec::new();
+    for (snapshot, values) in &parsed {
+        let path = common::snapshots_dir().join(snapshot);
+        let snapshot = snapshot.display();
+        let actual = Value::Array(values.clone());
+        if update {
+            std::fs::create_dir_all(path.parent().expect("snapshots are in a module directory"))
+                .expect("create the snapshot directory");
+            let mut json = serde_json::to_string_pretty(&actual).expect("serialize the snapshot");
+            json.push('\n');
+            std::fs::write(&path, json).expect("write the snapshot");
+            continue;
+        }
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            failures.push(format!(
+                "{snapshot}: no snapshot (run with UPDATE_SNAPSHOTS=1)"
+            ));
+            continue;
+        };
+        let expected: Value = serde_json::from_str(&text).expect("snapshots are valid JSON");
+        for difference in common::json_differences(&expected, &actual) {
+            failures.push(format!("{snapshot}: {difference}"));
+        }
+    }
+
+    // Snapshots of raw files which are no longer in the corpus (or no longer have any raws).
+    if !update {
+        for entry in walkdir::WalkDir::new(common::snapshots_dir())
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+        {
+            let snapshot = entry
+                .path()
+                .strip_prefix(common::snapshots_dir())
+                .expect("the snapshot is in the snapshot directory");
+            if !parsed.contains_key(snapshot) {
+                failures.push(format!("{}: no raws were parsed", snapshot.display()));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "parsed raws don't match the snapshots:\n{}",
+        failures.join("\n")
+    );
+}
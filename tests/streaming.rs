@@ -0,0 +1,83 @@
+//! Parsing the raws one raw file at a time with `parse_iter`.
+
+mod common;
+
+use std::path::Path;
+
+use dfraw_json_parser::{
+    options::{ParserOptions, ParsingJob},
+    parser::object_types::ObjectType,
+    ParserError,
+};
+
+/// A module with two creature files, and a file between them with an `[OBJECT:...]` type the
+/// parser doesn't know.
+fn write_module(module_path: &Path) {
+    common::write_file(
+        &module_path.join("info.txt"),
+        "[ID:streaming]\n[NUMERIC_VERSION:1]\n[DISPLAYED_VERSION:1.0]\n[NAME:Streaming]\n",
+    );
+    let objects = module_path.join("objects");
+    common::write_file(
+        &objects.join("creature_a.txt"),
+        "creature_a\n\n[OBJECT:CREATURE]\n\n[CREATURE:FIRST]\n[CREATURE:SECOND]\n",
+    );
+    common::write_file(
+        &objects.join("creature_b.txt"),
+        "creature_b\n\n[OBJECT:NOT_A_TYPE]\n\n[CREATURE:LOST]\n",
+    );
+    common::write_file(
+        &objects.join("creature_c.txt"),
+        "creature_c\n\n[OBJECT:CREATURE]\n\n[CREATURE:THIRD]\n",
+    );
+}
+
+fn module_options(module_path: &Path) -> ParserOptions {
+    let mut options = ParserOptions::new(module_path);
+    options.set_job(ParsingJob::SingleModule);
+    options.set_raws_to_parse(vec![ObjectType::Creature]);
+    options
+}
+
+#[test]
+fn raw_file_errors_are_yielded_and_parsing_goes_on() {
+    let module_path = common::empty_temp_dir("streaming_errors");
+    write_module(&module_path);
+    let options = module_options(&module_path);
+
+    let results: Vec<_> = dfraw_json_parser::parse_iter(&options).collect();
+    let errors: Vec<&ParserError> = results.iter().filter_map(|r| r.as_ref().err()).collect();
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        matches!(
+            errors[0],
+            ParserError::UnknownObjectType { object_type, .. } if object_type == "NOT_A_TYPE"
+        ),
+        "{errors:?}"
+    );
+    // The files are parsed in the order they are found in, but all of them are parsed
+    let mut identifiers: Vec<&str> = results
+        .iter()
+        .filter_map(|r| r.as_ref().ok())
+        .map(|raw| raw.get_identifier())
+        .collect();
+    identifiers.sort_unstable();
+    assert_eq!(identifiers, ["FIRST", "SECOND", "THIRD"]);
+}
+
+#[test]
+fn streamed_raws_match_parsed_raws() {
+    let module_path = common::empty_temp_dir("streaming_matches_parse");
+    write_module(&module_path);
+    let options = module_options(&module_path);
+
+    let streamed: Vec<String> = dfraw_json_parser::parse_iter(&options)
+        .filter_map(Result::ok)
+        .map(|raw| raw.get_object_id().to_string())
+        .collect();
+    let parsed: Vec<String> = dfraw_json_parser::parse(&options)
+        .iter()
+        .map(|raw| raw.get_object_id().to_string())
+        .collect();
+    assert_eq!(streamed, parsed);
+}
@@ -0,0 +1,149 @@
+//! Parsing the raws a world uses. The game keeps a world's module list in its binary save data, so
+//! the caller supplies the modules (as the load order) and their versions (as pinned versions).
+
+mod common;
+
+use std::{collections::HashMap, path::Path};
+
+use dfraw_json_parser::{
+    options::{ModuleVersionPolicy, ParserOptions},
+    parser::{
+        diagnostics::DiagnosticSeverity, object_types::ObjectType, raw_locations::RawModuleLocation,
+    },
+};
+
+fn write_module(module_path: &Path, identifier: &str, version: u32, raw_text: &str) {
+    common::write_file(
+        &module_path.join("info.txt"),
+        format!(
+            "[ID:{identifier}]\n[NUMERIC_VERSION:{version}]\n[DISPLAYED_VERSION:{version}.0]\n[NAME:{identifier}]\n"
+        ),
+    );
+    common::write_file(
+        &module_path.join("objects").join("creature_world_test.txt"),
+        format!("creature_world_test\n\n[OBJECT:CREATURE]\n\n{raw_text}"),
+    );
+}
+
+/// A dwarf fortress directory with a world which uses version 1 of `my_mod`, while version 2 of it
+/// is also installed (for another world).
+fn write_game_dir(df_path: &Path) {
+    write_module(
+        &df_path
+            .join("data")
+            .join("vanilla")
+            .join("vanilla_creatures"),
+        "vanilla_creatures",
+        5001,
+        "[CREATURE:TOAD]\n\t[NAME:toad:toads:toad]\n",
+    );
+    let installed_mods = df_path.join("data").join("installed_mods");
+    write_module(
+        &installed_mods.join("my_mod (1)"),
+        "my_mod",
+        1,
+        "[SELECT_CREATURE:TOAD]\n\t[NAME:old toad:old toads:old toad]\n[CREATURE:OLD_BEAST]\n",
+    );
+    write_module(
+        &installed_mods.join("my_mod (2)"),
+        "my_mod",
+        2,
+        "[SELECT_CREATURE:TOAD]\n\t[NAME:new toad:new toads:new toad]\n[CREATURE:NEW_BEAST]\n",
+    );
+    let save_path = df_path.join("save").join("region1");
+    common::write_file(&save_path.join("world.sav"), [0x1b, 0x0a, 0x00, 0x00, 0x01]);
+    common::write_file(&save_path.join("world.dat"), [0x00, 0x00, 0x00, 0x00]);
+}
+
+/// The options to parse the game directory with the world's load order, and `my_mod` pinned to
+/// `my_mod_version`.
+fn world_options(df_path: &Path, my_mod_version: u32) -> ParserOptions {
+    let mut options = ParserOptions::new(df_path);
+    options.set_raws_to_parse(vec![ObjectType::Creature]);
+    options.set_locations_to_parse(vec![
+        RawModuleLocation::Vanilla,
+        RawModuleLocation::InstalledMods,
+    ]);
+    options.set_module_load_order(vec![
+        String::from("vanilla_creatures"),
+        String::from("my_mod"),
+    ]);
+    options.set_module_version_policy(ModuleVersionPolicy::Pinned(HashMap::from([(
+        String::from("my_mod"),
+        my_mod_version,
+    )])));
+    options
+}
+
+#[test]
+fn world_modules_are_parsed_in_the_world_load_order_and_versions() {
+    let df_path = common::empty_temp_dir("world_raws");
+    write_game_dir(&df_path);
+
+    let options = world_options(&df_path, 1);
+    let (raws, report) = dfraw_json_parser::parse_with_report(&options);
+
+    let mut identifiers: Vec<&str> = raws.iter().map(|raw| raw.get_identifier()).collect();
+    identifiers.sort_unstable();
+    assert_eq!(identifiers, ["OLD_BEAST", "TOAD"]);
+    let toad = raws
+        .iter()
+        .find(|raw| raw.get_identifier() == "TOAD")
+        .expect("the toad is parsed");
+    let toad = serde_json::to_value(toad).expect("raws serialize");
+    assert_eq!(
+        toad["selectCreatureVariation"][0]["tags"],
+        serde_json::json!(["NAME:old toad:old toads:old toad"]),
+        "{toad}"
+    );
+
+    let dropped = report.get_dropped_modules();
+    assert_eq!(dropped.len(), 1, "{dropped:?}");
+    assert_eq!(dropped[0].get_identifier(), "my_mod");
+    assert_eq!(dropped[0].get_numeric_version(), 2);
+}
+
+#[test]
+fn dropped_modules_are_found_without_parsing() {
+    let df_path = common::empty_temp_dir("world_raws_dropped");
+    write_game_dir(&df_path);
+
+    let dropped = dfraw_json_parser::find_dropped_modules(&world_options(&df_path, 1));
+    assert_eq!(dropped.len(), 1, "{dropped:?}");
+    assert_eq!(dropped[0].get_identifier(), "my_mod");
+    assert_eq!(dropped[0].get_numeric_version(), 2);
+    assert_eq!(dropped[0].get_kept_numeric_version(), 1);
+    assert_eq!(dropped[0].get_parent_directory(), "my_mod (2)");
+}
+
+#[test]
+fn pins_which_match_nothing_are_reported() {
+    let df_path = common::empty_temp_dir("world_raws_missing_pin");
+    write_game_dir(&df_path);
+
+    let mut options = world_options(&df_path, 3);
+    options.set_module_version_policy(ModuleVersionPolicy::Pinned(HashMap::from([
+        (String::from("my_mod"), 3),
+        (String::from("missing_mod"), 1),
+    ])));
+    let (raws, report) = dfraw_json_parser::parse_with_report(&options);
+    // The newest version is parsed instead
+    assert!(
+        raws.iter().any(|raw| raw.get_identifier() == "NEW_BEAST"),
+        "the newest version of my_mod is parsed"
+    );
+    let warnings: Vec<&str> = report
+        .with_severity(DiagnosticSeverity::Warning)
+        .into_iter()
+        .map(|diagnostic| diagnostic.get_message())
+        .collect();
+    assert!(
+        warnings.contains(&"Pinned version 3 of module my_mod was not found, using version 2"),
+        "{warnings:?}"
+    );
+    assert!(
+        warnings.contains(&"Pinned module missing_mod (version 1) was not found"),
+        "{warnings:?}"
+    );
+    assert_eq!(report.get_dropped_modules().len(), 1);
+}